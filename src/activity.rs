@@ -0,0 +1,199 @@
+//! A local record of the actions this agent has taken.
+//!
+//! The client timestamps every successful post, comment, vote and DM in
+//! `activity.json` in the config directory, so daily limits can be checked
+//! without asking the API. Entries older than [`KEEP_DAYS`] are dropped
+//! whenever a new one is recorded.
+
+use crate::api::error::ApiError;
+use crate::state;
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveTime, SecondsFormat, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// The filename for the activity record.
+const ACTIVITY_FILE: &str = "activity.json";
+/// How many days of activity are kept.
+pub const KEEP_DAYS: i64 = 7;
+
+/// The kinds of action that are counted.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum ActivityKind {
+    Post,
+    Comment,
+    Vote,
+    Dm,
+}
+
+impl ActivityKind {
+    /// Every kind, in display order.
+    pub const ALL: [Self; 4] = [Self::Post, Self::Comment, Self::Vote, Self::Dm];
+
+    /// The kind of action a request performs, or `None` if it is not counted.
+    pub fn of(method: &str, endpoint: &str) -> Option<Self> {
+        if method != "POST" {
+            return None;
+        }
+        let path = endpoint.split('?').next().unwrap_or(endpoint);
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        match segments.as_slice() {
+            ["posts"] => Some(Self::Post),
+            ["posts", _, "comments"] => Some(Self::Comment),
+            ["posts" | "comments", _, "upvote" | "downvote"] => Some(Self::Vote),
+            ["agents", "dm", "request"] | ["agents", "dm", "conversations", _, "send"] => {
+                Some(Self::Dm)
+            }
+            _ => None,
+        }
+    }
+
+    /// The noun used in messages, e.g. `post`.
+    pub fn noun(self) -> &'static str {
+        match self {
+            Self::Post => "post",
+            Self::Comment => "comment",
+            Self::Vote => "vote",
+            Self::Dm => "DM",
+        }
+    }
+}
+
+/// One recorded action.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Activity {
+    pub kind: ActivityKind,
+    /// When the action was taken (RFC 3339).
+    pub at: String,
+}
+
+impl Activity {
+    fn new(kind: ActivityKind, at: DateTime<Utc>) -> Self {
+        Self {
+            kind,
+            at: at.to_rfc3339_opts(SecondsFormat::Secs, true),
+        }
+    }
+
+    /// When the action was taken, or `None` if the timestamp is unreadable.
+    pub fn time(&self) -> Option<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(&self.at)
+            .ok()
+            .map(|at| at.with_timezone(&Utc))
+    }
+}
+
+/// Recent actions, oldest first.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct ActivityLog {
+    pub actions: Vec<Activity>,
+}
+
+impl ActivityLog {
+    /// Loads the record, returning an empty one if none exists yet.
+    pub fn load() -> Result<Self, ApiError> {
+        state::load(&Self::path()?, "activity record")
+    }
+
+    /// Records an action of `kind` taken now.
+    pub fn record(kind: ActivityKind) -> Result<(), ApiError> {
+        let now = Utc::now();
+        state::update(&Self::path()?, "activity record", |log: &mut Self| {
+            log.actions.retain(|a| {
+                a.time()
+                    .is_some_and(|at| now - at < Duration::days(KEEP_DAYS))
+            });
+            log.actions.push(Activity::new(kind, now));
+        })
+    }
+
+    /// How many actions of `kind` were taken at or after `since`.
+    pub fn count_since(&self, kind: ActivityKind, since: DateTime<Utc>) -> usize {
+        self.actions
+            .iter()
+            .filter(|a| a.kind == kind && a.time().is_some_and(|at| at >= since))
+            .count()
+    }
+
+    /// How many actions of `kind` were taken since local midnight.
+    pub fn count_today(&self, kind: ActivityKind) -> usize {
+        self.count_since(kind, start_of_today())
+    }
+
+    fn path() -> Result<PathBuf, ApiError> {
+        state::path(ACTIVITY_FILE)
+    }
+}
+
+/// Local midnight at the start of today, in UTC.
+pub fn start_of_today() -> DateTime<Utc> {
+    local_midnight(Local::now().date_naive()).unwrap_or_else(|| Utc::now() - Duration::days(1))
+}
+
+/// Local midnight at the end of today, when daily counts reset, in UTC.
+pub fn end_of_today() -> DateTime<Utc> {
+    Local::now()
+        .date_naive()
+        .succ_opt()
+        .and_then(local_midnight)
+        .unwrap_or_else(|| Utc::now() + Duration::days(1))
+}
+
+fn local_midnight(date: NaiveDate) -> Option<DateTime<Utc>> {
+    Local
+        .from_local_datetime(&date.and_time(NaiveTime::MIN))
+        .earliest()
+        .map(|at| at.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind_of_endpoint() {
+        assert_eq!(ActivityKind::of("POST", "/posts"), Some(ActivityKind::Post));
+        assert_eq!(
+            ActivityKind::of("POST", "/posts/abc/comments"),
+            Some(ActivityKind::Comment)
+        );
+        assert_eq!(
+            ActivityKind::of("POST", "/comments/abc/upvote"),
+            Some(ActivityKind::Vote)
+        );
+        assert_eq!(
+            ActivityKind::of("POST", "/agents/dm/conversations/c1/send"),
+            Some(ActivityKind::Dm)
+        );
+        assert_eq!(
+            ActivityKind::of("POST", "/agents/dm/request"),
+            Some(ActivityKind::Dm)
+        );
+        assert_eq!(ActivityKind::of("DELETE", "/posts/abc"), None);
+        assert_eq!(ActivityKind::of("POST", "/submolts"), None);
+    }
+
+    #[test]
+    fn test_count_since() {
+        let now = Utc::now();
+        let log = ActivityLog {
+            actions: vec![
+                Activity::new(ActivityKind::Post, now - Duration::days(2)),
+                Activity::new(ActivityKind::Post, now),
+                Activity::new(ActivityKind::Vote, now),
+            ],
+        };
+        assert_eq!(
+            log.count_since(ActivityKind::Post, now - Duration::hours(1)),
+            1
+        );
+        assert_eq!(
+            log.count_since(ActivityKind::Post, now - Duration::days(3)),
+            2
+        );
+        assert_eq!(
+            log.count_since(ActivityKind::Dm, now - Duration::days(3)),
+            0
+        );
+    }
+}
@@ -0,0 +1,349 @@
+//! Short handles for post, comment and conversation IDs.
+//!
+//! Listings record the items they showed in `aliases.json` in the config
+//! directory, so a later command can say `%1` instead of pasting a UUID.
+//! Conversations are also reachable as `@agent`, and any item can be given a
+//! user-defined name with `moltbook alias set`. `last` refers to the most
+//! recently viewed or created item of the expected kind.
+//!
+//! ID arguments are resolved at parse time through the `parse_*_ref` value
+//! parsers; anything that is not a handle passes through unchanged. Handles
+//! belong to a profile, so the profile is chosen from a first pass over the
+//! arguments with resolution off (see [`without_resolving`]).
+
+use crate::api::error::ApiError;
+use crate::state;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// The filename for the alias table.
+const ALIAS_FILE: &str = "aliases.json";
+/// Whether the value parsers resolve handles; see [`without_resolving`].
+static RESOLVING: AtomicBool = AtomicBool::new(true);
+/// The handle for the most recently viewed or created item.
+const LAST: &str = "last";
+
+/// What an ID points at.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ItemKind {
+    Post,
+    Comment,
+    Conversation,
+}
+
+impl ItemKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ItemKind::Post => "post",
+            ItemKind::Comment => "comment",
+            ItemKind::Conversation => "conversation",
+        }
+    }
+
+    /// Parses a kind name as accepted by `alias set --kind`.
+    pub fn parse(kind: &str) -> Option<Self> {
+        match kind {
+            "post" => Some(ItemKind::Post),
+            "comment" => Some(ItemKind::Comment),
+            "conversation" => Some(ItemKind::Conversation),
+            _ => None,
+        }
+    }
+}
+
+/// An item a handle resolves to.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AliasTarget {
+    pub kind: ItemKind,
+    pub id: String,
+}
+
+impl AliasTarget {
+    pub fn new(kind: ItemKind, id: impl Into<String>) -> Self {
+        Self {
+            kind,
+            id: id.into(),
+        }
+    }
+}
+
+/// The alias table, persisted between invocations.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct AliasStore {
+    /// Items of the most recent listing in display order; `%1` is the first.
+    #[serde(default)]
+    pub positions: Vec<AliasTarget>,
+    /// Conversation IDs keyed by the other agent's lowercased name (`@name`).
+    #[serde(default)]
+    pub agents: BTreeMap<String, String>,
+    /// User-defined names.
+    #[serde(default)]
+    pub names: BTreeMap<String, AliasTarget>,
+    /// The most recently viewed or created item of each kind, newest first.
+    #[serde(default)]
+    pub last: Vec<AliasTarget>,
+}
+
+impl AliasStore {
+    /// Loads the alias table, returning an empty one if none exists yet.
+    pub fn load() -> Result<Self, ApiError> {
+        state::load(&Self::path()?, "aliases")
+    }
+
+    /// Applies `f` to the alias table on disk, locked against concurrent
+    /// invocations.
+    pub fn update<R>(f: impl FnOnce(&mut Self) -> R) -> Result<R, ApiError> {
+        state::update(&Self::path()?, "aliases", f)
+    }
+
+    /// Defines or replaces a user-defined name.
+    pub fn set(&mut self, name: &str, target: AliasTarget) -> Result<(), ApiError> {
+        let valid = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(ApiError::ConfigError(format!(
+                "Invalid alias name '{}': use letters, digits, '-' or '_'",
+                name
+            )));
+        }
+        if name == LAST {
+            return Err(ApiError::ConfigError(format!(
+                "'{}' is reserved for the most recent item",
+                LAST
+            )));
+        }
+        self.names.insert(name.to_string(), target);
+        Ok(())
+    }
+
+    /// Makes `target` the `last` item of its kind.
+    pub fn touch(&mut self, target: AliasTarget) {
+        self.last.retain(|t| t.kind != target.kind);
+        self.last.insert(0, target);
+    }
+
+    /// Removes a user-defined name, returning whether it existed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.names.remove(name).is_some()
+    }
+
+    /// Looks up a handle (`%N`, `@agent`, `last` or a defined name) without checking its kind.
+    ///
+    /// `last` is the most recent item of any kind. Returns `Ok(None)` when
+    /// `reference` is not a handle, i.e. a literal ID.
+    pub fn lookup(&self, reference: &str) -> Result<Option<AliasTarget>, String> {
+        if reference == LAST {
+            return self
+                .last
+                .first()
+                .cloned()
+                .map(Some)
+                .ok_or_else(|| "nothing has been viewed or created yet".to_string());
+        }
+        if let Some(position) = reference.strip_prefix('%') {
+            let n: usize = position
+                .parse()
+                .ok()
+                .filter(|n| *n > 0)
+                .ok_or_else(|| format!("'{}' is not a valid position", reference))?;
+            return self.positions.get(n - 1).cloned().map(Some).ok_or_else(|| {
+                format!(
+                    "no item {} in the last listing ({} item{})",
+                    reference,
+                    self.positions.len(),
+                    if self.positions.len() == 1 { "" } else { "s" }
+                )
+            });
+        }
+        if let Some(agent) = reference.strip_prefix('@') {
+            return self
+                .agents
+                .get(&agent.to_lowercase())
+                .map(|id| Some(AliasTarget::new(ItemKind::Conversation, id.clone())))
+                .ok_or_else(|| {
+                    format!(
+                        "no known conversation with {}; run `moltbook dm-list` first",
+                        reference
+                    )
+                });
+        }
+        Ok(self.names.get(reference).cloned())
+    }
+
+    /// Resolves `reference` to an ID of one of the `accepted` kinds.
+    pub fn resolve(&self, reference: &str, accepted: &[ItemKind]) -> Result<String, String> {
+        if reference == LAST {
+            return self
+                .last
+                .iter()
+                .find(|t| accepted.contains(&t.kind))
+                .map(|t| t.id.clone())
+                .ok_or_else(|| format!("no recent {} to use as `last`", kinds(accepted)));
+        }
+        match self.lookup(reference)? {
+            None => Ok(reference.to_string()),
+            Some(target) if accepted.contains(&target.kind) => Ok(target.id),
+            Some(target) => Err(format!(
+                "{} is a {}, expected a {}",
+                reference,
+                target.kind.as_str(),
+                kinds(accepted)
+            )),
+        }
+    }
+
+    fn path() -> Result<PathBuf, ApiError> {
+        state::path(ALIAS_FILE)
+    }
+}
+
+fn kinds(accepted: &[ItemKind]) -> String {
+    accepted
+        .iter()
+        .map(|k| k.as_str())
+        .collect::<Vec<_>>()
+        .join(" or ")
+}
+
+/// Records `target` as the `last` item of its kind.
+///
+/// Like the other `remember_*` helpers this is best-effort.
+pub fn remember_last(target: AliasTarget) {
+    let _ = AliasStore::update(|store| store.touch(target));
+}
+
+/// Replaces the positional handles with the items of a listing just shown.
+///
+/// The alias table is a convenience; failing to update it never fails a command.
+pub fn remember_listing(items: Vec<AliasTarget>) {
+    let _ = AliasStore::update(|store| store.positions = items);
+}
+
+/// Records `@agent` handles for conversations just shown.
+pub fn remember_conversations<'a>(conversations: impl IntoIterator<Item = (&'a str, &'a str)>) {
+    let _ = AliasStore::update(|store| {
+        for (agent, id) in conversations {
+            store.agents.insert(agent.to_lowercase(), id.to_string());
+        }
+    });
+}
+
+/// Runs `f` with the value parsers passing references through unresolved.
+///
+/// `main` parses the arguments this way to learn `--profile` before any
+/// `aliases.json` is read, then parses them again to resolve handles against
+/// the selected profile.
+pub fn without_resolving<R>(f: impl FnOnce() -> R) -> R {
+    RESOLVING.store(false, Ordering::Relaxed);
+    let result = f();
+    RESOLVING.store(true, Ordering::Relaxed);
+    result
+}
+
+fn resolve(reference: &str, accepted: &[ItemKind]) -> Result<String, String> {
+    if !RESOLVING.load(Ordering::Relaxed) {
+        return Ok(reference.to_string());
+    }
+    let store = AliasStore::load().map_err(|e| e.to_string())?;
+    store.resolve(reference, accepted)
+}
+
+/// Value parser for post ID arguments.
+pub fn parse_post_ref(reference: &str) -> Result<String, String> {
+    resolve(reference, &[ItemKind::Post])
+}
+
+/// Value parser for comment ID arguments.
+pub fn parse_comment_ref(reference: &str) -> Result<String, String> {
+    resolve(reference, &[ItemKind::Comment])
+}
+
+/// Value parser for arguments that accept either a post or a comment ID.
+pub fn parse_content_ref(reference: &str) -> Result<String, String> {
+    resolve(reference, &[ItemKind::Post, ItemKind::Comment])
+}
+
+/// Value parser for conversation ID arguments.
+pub fn parse_conversation_ref(reference: &str) -> Result<String, String> {
+    resolve(reference, &[ItemKind::Conversation])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store() -> AliasStore {
+        let mut store = AliasStore {
+            positions: vec![
+                AliasTarget::new(ItemKind::Post, "post-1"),
+                AliasTarget::new(ItemKind::Comment, "comment-2"),
+            ],
+            ..AliasStore::default()
+        };
+        store
+            .agents
+            .insert("alice".to_string(), "conv-a".to_string());
+        store
+            .set("intro", AliasTarget::new(ItemKind::Post, "post-9"))
+            .unwrap();
+        store
+    }
+
+    #[test]
+    fn test_resolve_handles() {
+        let store = store();
+        assert_eq!(store.resolve("%1", &[ItemKind::Post]).unwrap(), "post-1");
+        assert_eq!(
+            store.resolve("@Alice", &[ItemKind::Conversation]).unwrap(),
+            "conv-a"
+        );
+        assert_eq!(store.resolve("intro", &[ItemKind::Post]).unwrap(), "post-9");
+        assert_eq!(
+            store.resolve("3f2a-uuid", &[ItemKind::Post]).unwrap(),
+            "3f2a-uuid"
+        );
+    }
+
+    #[test]
+    fn test_last_is_per_kind() {
+        let mut store = store();
+        assert!(store.resolve("last", &[ItemKind::Post]).is_err());
+        store.touch(AliasTarget::new(ItemKind::Post, "post-a"));
+        store.touch(AliasTarget::new(ItemKind::Conversation, "conv-b"));
+        store.touch(AliasTarget::new(ItemKind::Post, "post-c"));
+        assert_eq!(store.last.len(), 2);
+        assert_eq!(store.resolve("last", &[ItemKind::Post]).unwrap(), "post-c");
+        assert_eq!(
+            store.resolve("last", &[ItemKind::Conversation]).unwrap(),
+            "conv-b"
+        );
+        assert_eq!(store.lookup("last").unwrap().unwrap().id, "post-c");
+        assert!(
+            store
+                .set("last", AliasTarget::new(ItemKind::Post, "x"))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_resolve_errors() {
+        let store = store();
+        assert_eq!(
+            store.resolve("%2", &[ItemKind::Post]).unwrap_err(),
+            "%2 is a comment, expected a post"
+        );
+        assert!(store.resolve("%3", &[ItemKind::Post]).is_err());
+        assert!(store.resolve("%0", &[ItemKind::Post]).is_err());
+        assert!(store.resolve("@bob", &[ItemKind::Conversation]).is_err());
+        assert!(
+            AliasStore::default()
+                .set("bad name", AliasTarget::new(ItemKind::Post, "x"))
+                .is_err()
+        );
+    }
+}
@@ -4,16 +4,90 @@
 //! rate limit parsing, CAPTCHA detection, and JSON serialization/deserialization
 //! for all API interactions.
 
+use crate::activity::{ActivityKind, ActivityLog};
+use crate::api::endpoint::Endpoint;
 use crate::api::error::ApiError;
+use crate::api::types::{StatusResponse, UnknownFields, UploadProgress, UploadSession};
+use crate::approval::{self, ApprovalRequest};
+use crate::budget;
+use crate::config::{Budgets, Config, DEFAULT_MAX_CONCURRENT_REQUESTS};
+use crate::jitter::Jitter;
+use crate::metrics;
+use crate::policy::Policy;
+use crate::registration::Registration;
+use crate::transcript;
+use crate::uploads::{FileStamp, PartialUpload, UploadStore};
+use crate::verification::{ChallengeStore, PendingChallenge};
+use chrono::{DateTime, Utc};
+use futures_util::StreamExt;
 use mime_guess::from_path;
-use reqwest::Client;
+use reqwest::{Certificate, Client};
 use serde::{Serialize, de::DeserializeOwned};
 use serde_json::Value;
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
 use std::path::PathBuf;
 use std::time::Duration;
+use tokio::sync::{OnceCell, Semaphore, SemaphorePermit};
 
 /// The default base URL for the Moltbook API.
 const DEFAULT_API_BASE: &str = "https://www.moltbook.com/api/v1";
+/// Total attempts for an idempotent POST that times out or loses its connection.
+const IDEMPOTENT_ATTEMPTS: u32 = 3;
+/// Size of the chunks an upload body is streamed in.
+const UPLOAD_CHUNK: usize = 64 * 1024;
+/// Files larger than this are sent through a resumable upload session.
+pub const RESUMABLE_THRESHOLD: u64 = 8 * 1024 * 1024;
+/// Chunk size for resumable uploads when the server does not suggest one.
+const RESUMABLE_CHUNK: u64 = 4 * 1024 * 1024;
+/// How long a request may take, unless `--timeout` says otherwise.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+/// The default for file uploads and upload chunks, which carry megabytes.
+pub const UPLOAD_TIMEOUT: Duration = Duration::from_secs(300);
+/// The default for pages fetched by `--all`, where deep cursors are slower.
+pub const PAGINATION_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// Parses a timeout such as `500ms`, `10s` or `2m`; a bare number is seconds.
+pub fn parse_timeout(s: &str) -> Result<Duration, String> {
+    let s = s.trim();
+    let invalid = || format!("invalid timeout '{}' (expected e.g. 10s, 500ms or 2m)", s);
+    let (number, unit) = if let Some(n) = s.strip_suffix("ms") {
+        (n, 1)
+    } else if let Some(n) = s.strip_suffix('s') {
+        (n, 1000)
+    } else if let Some(n) = s.strip_suffix('m') {
+        (n, 60_000)
+    } else {
+        (s, 1000)
+    };
+    let value: u64 = number.trim().parse().map_err(|_| invalid())?;
+    match value.checked_mul(unit) {
+        Some(0) | None => Err(invalid()),
+        Some(millis) => Ok(Duration::from_millis(millis)),
+    }
+}
+
+/// Trusted CA certificates from the PEM bundle named by `SSL_CERT_FILE`, if any.
+///
+/// Lets agents behind a TLS-intercepting proxy trust its CA in addition to
+/// the built-in roots. An unreadable bundle is reported and skipped.
+fn extra_root_certificates() -> Vec<Certificate> {
+    let Some(path) = std::env::var_os("SSL_CERT_FILE") else {
+        return Vec::new();
+    };
+    let certs = std::fs::read(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|pem| Certificate::from_pem_bundle(&pem).map_err(|e| e.to_string()));
+    certs.unwrap_or_else(|e| {
+        eprintln!(
+            "Ignoring SSL_CERT_FILE {}: {}",
+            PathBuf::from(path).display(),
+            e
+        );
+        Vec::new()
+    })
+}
 
 /// A thread-safe, asynchronous client for the Moltbook API.
 ///
@@ -25,6 +99,29 @@ pub struct MoltbookClient {
     pub agent_name: String,
     debug: bool,
     base_url: String,
+    outbox: bool,
+    /// Whether mutations wait for the owner's approval.
+    approval: bool,
+    /// Rules every mutation is checked against before it is sent.
+    policy: Option<Policy>,
+    /// Daily limits checked before posts, comments, votes and DMs.
+    budgets: Budgets,
+    /// Whether successful actions are recorded in the activity log, and
+    /// challenged ones saved for `moltbook verify`.
+    activity: bool,
+    progress: bool,
+    /// Random pause taken before each action of a batch.
+    jitter: Option<Jitter>,
+    /// Set by `--timeout`; replaces the per-request defaults when present.
+    timeout: Option<Duration>,
+    /// Caps requests in flight across everything sharing this client.
+    requests: Semaphore,
+    /// Whether mutations first check a pending claim (see [`Self::check_claim`]).
+    claim_check: bool,
+    /// Whether a pending claim refuses mutations rather than only reminding.
+    require_claimed: bool,
+    /// Set once the claim check has let mutations through.
+    claim_checked: OnceCell<()>,
 }
 
 impl MoltbookClient {
@@ -35,16 +132,53 @@ impl MoltbookClient {
     /// * `api_key` - The API key for authentication.
     /// * `debug` - If true, logs all requests and responses to stderr.
     pub fn new(api_key: String, agent_name: String, debug: bool) -> Self {
+        // Advertise gzip and brotli; responses are decoded transparently.
+        // The total timeout is set per request; see `Self::timeout_or`.
+        let mut builder = Client::builder()
+            .connect_timeout(Duration::from_secs(10))
+            .gzip(true)
+            .brotli(true);
+        for cert in extra_root_certificates() {
+            builder = builder.add_root_certificate(cert);
+        }
+        let client = builder.build().expect("Failed to build HTTP client");
+        Self::with_http(client, api_key, agent_name, debug)
+    }
+
+    /// A client for the same agent and API that shares this one's connection
+    /// pool, with every other setting back at its default.
+    ///
+    /// The daemon forks its client for each command, so settings changed
+    /// since it started apply while the pool stays warm.
+    pub fn fork(&self) -> Self {
+        Self::with_http(
+            self.client.clone(),
+            self.api_key.clone(),
+            self.agent_name.clone(),
+            self.debug,
+        )
+        .with_base_url(self.base_url.clone())
+    }
+
+    fn with_http(client: Client, api_key: String, agent_name: String, debug: bool) -> Self {
         Self {
-            client: Client::builder()
-                .timeout(Duration::from_secs(30))
-                .connect_timeout(Duration::from_secs(10))
-                .build()
-                .expect("Failed to build HTTP client"),
+            client,
             api_key,
             agent_name,
             debug,
             base_url: DEFAULT_API_BASE.to_string(),
+            outbox: false,
+            approval: false,
+            policy: None,
+            budgets: Budgets::default(),
+            activity: false,
+            progress: false,
+            jitter: None,
+            timeout: None,
+            requests: Semaphore::new(DEFAULT_MAX_CONCURRENT_REQUESTS),
+            claim_check: false,
+            require_claimed: false,
+            claim_checked: OnceCell::new(),
         }
     }
 
@@ -54,26 +188,371 @@ impl MoltbookClient {
         self
     }
 
+    /// Enables queueing undeliverable mutations in the offline outbox.
+    pub fn with_outbox(mut self, enabled: bool) -> Self {
+        self.outbox = enabled;
+        self
+    }
+
+    /// Whether undeliverable mutations should be queued rather than fail.
+    pub fn outbox_enabled(&self) -> bool {
+        self.outbox
+    }
+
+    /// Requires the owner's approval before every mutation (see [`crate::approval`]).
+    pub fn with_approval(mut self, enabled: bool) -> Self {
+        self.approval = enabled;
+        self
+    }
+
+    /// Refuses mutations that break `policy` (see [`crate::policy`]).
+    pub fn with_policy(mut self, policy: Option<Policy>) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Refuses actions whose daily budget is spent (see [`crate::budget`]).
+    pub fn with_budgets(mut self, budgets: Budgets) -> Self {
+        self.budgets = budgets;
+        self
+    }
+
+    /// Applies the guard settings of `config`: the claim check, daily
+    /// budgets, supervised mode and the request concurrency limit.
+    pub fn with_config(mut self, config: &Config) -> Self {
+        self.claim_check = true;
+        self.require_claimed = config.require_claimed();
+        self.with_budgets(config.budgets.clone())
+            .with_approval(config.require_approval())
+            .with_max_concurrency(config.max_concurrent_requests())
+    }
+
+    /// Records successful posts, comments, votes and DMs in the
+    /// [`ActivityLog`], which daily limits are counted from.
+    pub fn with_activity_log(mut self, enabled: bool) -> Self {
+        self.activity = enabled;
+        self
+    }
+
+    /// Checks `request` against a pending claim, the policy and the daily
+    /// budgets, then asks for approval when supervised mode is on.
+    ///
+    /// Answers to verification challenges skip the approval: the challenge
+    /// expires long before a queued answer could be approved.
+    async fn guard(&self, request: impl FnOnce() -> ApprovalRequest) -> Result<(), ApiError> {
+        if !self.claim_check && self.policy.is_none() && self.budgets.is_empty() && !self.approval {
+            return Ok(());
+        }
+        let request = request();
+        // Setting up the owner's email is part of getting claimed.
+        if !request
+            .endpoint
+            .starts_with(&Endpoint::SetupOwnerEmail.path())
+        {
+            self.claim_checked
+                .get_or_try_init(|| self.check_claim())
+                .await?;
+        }
+        if let Some(policy) = &self.policy {
+            policy.enforce(&request.method, &request.endpoint, request.body.as_ref())?;
+        }
+        if let Some(kind) = ActivityKind::of(&request.method, &request.endpoint) {
+            budget::check(&self.budgets, kind)?;
+        }
+        if self.approval && !request.endpoint.starts_with(&Endpoint::Verify.path()) {
+            approval::require(request)?;
+        }
+        Ok(())
+    }
+
+    /// Repeats the claim URL while the claim is pending, and refuses to
+    /// continue when `require_claimed` is set.
+    ///
+    /// Only agents with a saved registration are checked. Once the agent is
+    /// seen claimed the registration is dropped, so claimed agents pay no
+    /// extra request.
+    async fn check_claim(&self) -> Result<(), ApiError> {
+        if !self.claim_check {
+            return Ok(());
+        }
+        let Some(registration) = Registration::load()? else {
+            return Ok(());
+        };
+        if registration.agent_name != self.agent_name {
+            return Ok(());
+        }
+        // The API enforces claims anyway; a failed check shouldn't block the request.
+        let Ok(response) = self
+            .get::<StatusResponse>(&Endpoint::AgentStatus.path())
+            .await
+        else {
+            return Ok(());
+        };
+        match response.status.as_deref() {
+            Some("claimed") => Registration::clear()?,
+            Some("pending_claim") => {
+                crate::display::display_claim_reminder(&registration);
+                if self.require_claimed {
+                    return Err(ApiError::NotClaimed(registration.claim_url));
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Records a successful POST to `endpoint` in the activity log.
+    ///
+    /// Failing to write the log does not fail the request that already landed.
+    fn record<T>(&self, endpoint: &str, result: &Result<T, ApiError>) {
+        if !self.activity || result.is_err() {
+            return;
+        }
+        if let Some(kind) = ActivityKind::of("POST", endpoint)
+            && let Err(e) = ActivityLog::record(kind)
+            && self.debug
+        {
+            eprintln!("Failed to record activity: {}", e);
+        }
+    }
+
+    /// Saves `method endpoint` when its response carries a verification
+    /// challenge, so `moltbook verify` can finish the action, then decodes it.
+    ///
+    /// Like the activity log, failing to save does not fail the request.
+    fn note_challenge<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        endpoint: &str,
+        body: impl FnOnce() -> Option<Value>,
+        key: Option<&str>,
+        result: Result<Value, ApiError>,
+    ) -> Result<T, ApiError> {
+        let response = result?;
+        if self.activity
+            && let Some(pending) = PendingChallenge::from_response(method, endpoint, &response)
+            && let Err(e) =
+                ChallengeStore::record(pending.with_body(body()).with_idempotency_key(key))
+            && self.debug
+        {
+            eprintln!("Failed to save the verification challenge: {}", e);
+        }
+        serde_json::from_value(response).map_err(ApiError::ParseError)
+    }
+
+    /// Enables progress bars for uploads and long-running commands.
+    pub fn with_progress(mut self, enabled: bool) -> Self {
+        self.progress = enabled;
+        self
+    }
+
+    /// Whether progress bars should be drawn.
+    pub fn progress_enabled(&self) -> bool {
+        self.progress
+    }
+
+    /// Enables random pauses between batch actions (see [`crate::jitter`]).
+    pub fn with_jitter(mut self, jitter: Option<Jitter>) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Waits a random delay when jitter is enabled.
+    ///
+    /// Batch commands call this before every action after the first.
+    pub async fn jitter(&self) {
+        if let Some(jitter) = self.jitter {
+            tokio::time::sleep(jitter.sample()).await;
+        }
+    }
+
+    /// Gives every request `timeout` instead of its default (see [`DEFAULT_TIMEOUT`]).
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// The timeout for a request that would otherwise get `default`.
+    fn timeout_or(&self, default: Duration) -> Duration {
+        self.timeout.unwrap_or(default)
+    }
+
+    /// Limits how many requests may be in flight at once (at least one).
+    ///
+    /// Every request method waits for a slot, so commands that fan out
+    /// concurrently stay within the limit whichever feature triggers them.
+    pub fn with_max_concurrency(mut self, limit: usize) -> Self {
+        self.requests = Semaphore::new(limit.max(1));
+        self
+    }
+
+    /// Waits for a free request slot; the slot is released when the permit drops.
+    ///
+    /// Every request takes one, so this is also where requests are counted.
+    async fn permit(&self) -> SemaphorePermit<'_> {
+        let permit = self
+            .requests
+            .acquire()
+            .await
+            .expect("request semaphore is never closed");
+        metrics::record_request();
+        permit
+    }
+
+    /// In debug mode, lists API fields on `items` that the CLI does not model.
+    pub fn report_unknown_fields<'a, T: UnknownFields + 'a>(
+        &self,
+        items: impl IntoIterator<Item = &'a T>,
+    ) {
+        if !self.debug {
+            return;
+        }
+        let names: BTreeSet<&String> = items
+            .into_iter()
+            .flat_map(|item| item.unknown_fields().keys())
+            .collect();
+        if !names.is_empty() {
+            let names: Vec<&str> = names.into_iter().map(String::as_str).collect();
+            eprintln!("Unrecognized {} fields: {}", T::KIND, names.join(", "));
+        }
+    }
+
     /// Performs a GET request to the specified endpoint.
     ///
     /// # Errors
     ///
     /// Returns `ApiError` if the network fails, the API returns an error, or parsing fails.
     pub async fn get<T: DeserializeOwned>(&self, endpoint: &str) -> Result<T, ApiError> {
+        self.get_with_timeout(endpoint, DEFAULT_TIMEOUT).await
+    }
+
+    /// Performs a GET request allowed `default` to complete, unless
+    /// `--timeout` overrides it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiError::Timeout`] if no response arrives in time, or any
+    /// error [`Self::get`] can return.
+    pub async fn get_with_timeout<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        default: Duration,
+    ) -> Result<T, ApiError> {
         let url = format!("{}{}", self.base_url, endpoint);
 
         if self.debug {
             eprintln!("GET {}", url);
         }
+        let exchange = transcript::request("GET", &url, || None);
 
+        let _permit = self.permit().await;
         let response = self
             .client
             .get(&url)
             .header("Authorization", format!("Bearer {}", self.api_key))
+            .timeout(self.timeout_or(default))
+            .send()
+            .await?;
+
+        self.handle_response(response, exchange).await
+    }
+
+    /// Performs a GET request whose payload may be wrapped under `key`.
+    ///
+    /// See [`unwrap_envelope`] for the accepted shapes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApiError` if the request fails or the payload does not match `T`.
+    pub async fn get_enveloped<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        key: &str,
+    ) -> Result<T, ApiError> {
+        let response: Value = self.get(endpoint).await?;
+        unwrap_envelope(response, key)
+    }
+
+    /// Downloads raw bytes from an absolute URL, e.g. an avatar image.
+    ///
+    /// No API key is sent, since media may be served from another host.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApiError` if the request fails or responds with an error status.
+    pub async fn download(&self, url: &str) -> Result<Vec<u8>, ApiError> {
+        if self.debug {
+            eprintln!("GET (media) {}", url);
+        }
+
+        let _permit = self.permit().await;
+        let response = self
+            .client
+            .get(url)
+            .timeout(self.timeout_or(DEFAULT_TIMEOUT))
             .send()
             .await?;
+        let status = response.status();
+        metrics::record_response(status.as_u16());
+        if status.is_server_error() {
+            return Err(ApiError::ServerError(status.as_u16(), url.to_string()));
+        }
+        if !status.is_success() {
+            return Err(ApiError::MoltbookError(
+                format!("Download failed with HTTP {}", status.as_u16()),
+                url.to_string(),
+            ));
+        }
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    /// Posts JSON to another service's API with its own bearer token.
+    ///
+    /// Used to cross-post to other platforms; Moltbook credentials are never
+    /// sent. The post is checked against the policy and supervised mode like
+    /// any mutation, and `url` is what is queued for approval.
+    pub async fn post_external(
+        &self,
+        url: &str,
+        token: &str,
+        body: &Value,
+    ) -> Result<Value, ApiError> {
+        self.guard(|| ApprovalRequest::new("POST", url).with_body(body.clone()))
+            .await?;
+        if self.debug {
+            eprintln!("POST (external) {}", url);
+            eprintln!(
+                "Body: {}",
+                serde_json::to_string_pretty(body).unwrap_or_default()
+            );
+        }
 
-        self.handle_response(response).await
+        let _permit = self.permit().await;
+        let response = self
+            .client
+            .post(url)
+            .bearer_auth(token)
+            .json(body)
+            .timeout(self.timeout_or(DEFAULT_TIMEOUT))
+            .send()
+            .await?;
+        let status = response.status();
+        metrics::record_response(status.as_u16());
+        let text = response.text().await?;
+        if self.debug {
+            eprintln!("Response Status: {}", status);
+            eprintln!("Response Body: {}", text);
+        }
+        if status.is_server_error() {
+            return Err(ApiError::ServerError(status.as_u16(), url.to_string()));
+        }
+        if !status.is_success() {
+            return Err(ApiError::MoltbookError(
+                format!("{} returned HTTP {}", url, status.as_u16()),
+                text,
+            ));
+        }
+        serde_json::from_str(&text).map_err(ApiError::ParseError)
     }
 
     /// Performs a POST request with a JSON body.
@@ -85,6 +564,21 @@ impl MoltbookClient {
         &self,
         endpoint: &str,
         body: &impl Serialize,
+    ) -> Result<T, ApiError> {
+        self.guard(|| ApprovalRequest::new("POST", endpoint).with_body(json_body(body)))
+            .await?;
+        let result = self.send_post(endpoint, body).await;
+        let result = self.note_challenge("POST", endpoint, || Some(json_body(body)), None, result);
+        self.record(endpoint, &result);
+        result
+    }
+
+    /// Sends a POST without checking the policy or asking for approval, for
+    /// follow-up requests of an action that was already let through.
+    async fn send_post<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        body: &impl Serialize,
     ) -> Result<T, ApiError> {
         let url = format!("{}{}", self.base_url, endpoint);
 
@@ -95,17 +589,101 @@ impl MoltbookClient {
                 serde_json::to_string_pretty(&body).unwrap_or_default()
             );
         }
+        let exchange = transcript::request("POST", &url, || Some(json_body(body)));
 
+        let _permit = self.permit().await;
         let response = self
             .client
             .post(&url)
             .header("Authorization", format!("Bearer {}", self.api_key))
             .header("Content-Type", "application/json")
             .json(body)
+            .timeout(self.timeout_or(DEFAULT_TIMEOUT))
             .send()
             .await?;
 
-        self.handle_response(response).await
+        self.handle_response(response, exchange).await
+    }
+
+    /// Performs a POST request carrying an `Idempotency-Key` header.
+    ///
+    /// Timeouts and connection failures are retried with the same key, so the
+    /// server can recognize a repeat of a request that already landed rather
+    /// than applying it twice.
+    ///
+    /// # Errors
+    ///
+    /// Returns the last transport error once all attempts are exhausted, or the
+    /// first API error response.
+    pub async fn post_idempotent<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        body: &impl Serialize,
+        key: &str,
+    ) -> Result<T, ApiError> {
+        self.guard(|| {
+            ApprovalRequest::new("POST", endpoint)
+                .with_body(json_body(body))
+                .with_idempotency_key(key)
+        })
+        .await?;
+        let result = self.send_idempotent(endpoint, body, key).await;
+        let result = self.note_challenge(
+            "POST",
+            endpoint,
+            || Some(json_body(body)),
+            Some(key),
+            result,
+        );
+        self.record(endpoint, &result);
+        result
+    }
+
+    /// Sends an idempotent POST, retrying transport failures with the same key.
+    async fn send_idempotent<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        body: &impl Serialize,
+        key: &str,
+    ) -> Result<T, ApiError> {
+        let url = format!("{}{}", self.base_url, endpoint);
+
+        if self.debug {
+            eprintln!("POST {} (Idempotency-Key: {})", url, key);
+            eprintln!(
+                "Body: {}",
+                serde_json::to_string_pretty(&body).unwrap_or_default()
+            );
+        }
+        let exchange = transcript::request("POST", &url, || Some(json_body(body)));
+
+        let mut attempt = 1;
+        loop {
+            let permit = self.permit().await;
+            let sent = self
+                .client
+                .post(&url)
+                .header("Authorization", format!("Bearer {}", self.api_key))
+                .header("Content-Type", "application/json")
+                .header("Idempotency-Key", key)
+                .json(body)
+                .timeout(self.timeout_or(DEFAULT_TIMEOUT))
+                .send()
+                .await;
+
+            match sent {
+                Ok(response) => return self.handle_response(response, exchange).await,
+                Err(e) if (e.is_timeout() || e.is_connect()) && attempt < IDEMPOTENT_ATTEMPTS => {
+                    if self.debug {
+                        eprintln!("Attempt {} failed ({}), retrying", attempt, e);
+                    }
+                    drop(permit);
+                    tokio::time::sleep(Duration::from_secs(u64::from(attempt))).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
     }
 
     /// Performs an unauthenticated POST request with a JSON body.
@@ -123,16 +701,19 @@ impl MoltbookClient {
                 serde_json::to_string_pretty(&body).unwrap_or_default()
             );
         }
+        let exchange = transcript::request("POST", &url, || Some(json_body(body)));
 
+        let _permit = self.permit().await;
         let response = self
             .client
             .post(&url)
             .header("Content-Type", "application/json")
             .json(body)
+            .timeout(self.timeout_or(DEFAULT_TIMEOUT))
             .send()
             .await?;
 
-        self.handle_response(response).await
+        self.handle_response(response, exchange).await
     }
 
     /// Uploads a file using multipart/form-data.
@@ -147,6 +728,8 @@ impl MoltbookClient {
         endpoint: &str,
         file_path: PathBuf,
     ) -> Result<T, ApiError> {
+        self.guard(|| ApprovalRequest::new("POST", endpoint).with_file(file_path.clone()))
+            .await?;
         let url = format!("{}{}", self.base_url, endpoint);
 
         let file_name = file_path
@@ -156,9 +739,25 @@ impl MoltbookClient {
             .to_string();
 
         let file_contents = std::fs::read(&file_path).map_err(ApiError::IoError)?;
+        let len = file_contents.len() as u64;
+
+        // Feed the body in chunks so the progress bar tracks what has been sent.
+        let bar =
+            crate::display::progress::bytes(len, self.progress).with_message(file_name.clone());
+        let tracked = bar.clone();
+        let chunks: Vec<Result<Vec<u8>, std::io::Error>> = file_contents
+            .chunks(UPLOAD_CHUNK)
+            .map(|chunk| Ok(chunk.to_vec()))
+            .collect();
+        let body =
+            reqwest::Body::wrap_stream(futures_util::stream::iter(chunks).inspect(move |chunk| {
+                if let Ok(chunk) = chunk {
+                    tracked.inc(chunk.len() as u64);
+                }
+            }));
 
         let mime_type = from_path(&file_path).first_or_octet_stream();
-        let part = reqwest::multipart::Part::bytes(file_contents)
+        let part = reqwest::multipart::Part::stream_with_length(body, len)
             .file_name(file_name)
             .mime_str(mime_type.as_ref())?;
         let form = reqwest::multipart::Form::new().part("file", part);
@@ -167,16 +766,174 @@ impl MoltbookClient {
             eprintln!("POST (File) {}", url);
             eprintln!("File: {:?}", file_path);
         }
+        let exchange = transcript::request("POST", &url, || {
+            Some(Value::String(format!("<file {}>", file_path.display())))
+        });
 
+        let _permit = self.permit().await;
         let response = self
             .client
             .post(&url)
             .header("Authorization", format!("Bearer {}", self.api_key))
             .multipart(form)
+            .timeout(self.timeout_or(UPLOAD_TIMEOUT))
             .send()
+            .await;
+        bar.finish_and_clear();
+
+        self.handle_response(response?, exchange).await
+    }
+
+    /// Uploads a file, in resumable chunks when it is large.
+    ///
+    /// Files up to [`RESUMABLE_THRESHOLD`] bytes go through [`Self::post_file`].
+    /// Larger ones open a session with `POST /uploads`, send the file as
+    /// `PUT /uploads/{id}` requests carrying a `Content-Range`, and finish with
+    /// `POST /uploads/{id}/complete`, which responds as `endpoint` would have.
+    /// The session is recorded in [`UploadStore`] after every chunk, so calling
+    /// this again for the same unchanged file and endpoint resumes from the
+    /// last byte the server acknowledged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApiError` if the file cannot be read, a request fails, or the
+    /// server stops accepting data. The partial upload is kept in that case.
+    pub async fn upload_resumable<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        file_path: PathBuf,
+    ) -> Result<T, ApiError> {
+        let stamp = FileStamp::of(&file_path)?;
+        if stamp.size <= RESUMABLE_THRESHOLD {
+            return self.post_file(endpoint, file_path).await;
+        }
+        self.guard(|| ApprovalRequest::new("POST", endpoint).with_file(file_path.clone()))
             .await?;
+        let size = stamp.size;
+        let file_name = file_path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
+
+        let resumed = match UploadStore::load()?.find(endpoint, &stamp).cloned() {
+            Some(mut upload) => {
+                let status = self
+                    .get::<UploadProgress>(&Endpoint::Upload(&upload.upload_id).path())
+                    .await;
+                match status {
+                    Ok(progress) => {
+                        upload.received = progress.received.min(size);
+                        Some(upload)
+                    }
+                    // The server no longer knows the session; start over.
+                    Err(ApiError::MoltbookError(_, _)) => None,
+                    Err(e) => return Err(e),
+                }
+            }
+            None => None,
+        };
+        let mut upload = match resumed {
+            Some(upload) => upload,
+            None => {
+                let mime_type = from_path(&file_path).first_or_octet_stream();
+                let session: UploadSession = self
+                    .send_post(
+                        &Endpoint::Uploads.path(),
+                        &serde_json::json!({
+                            "target": endpoint,
+                            "filename": file_name,
+                            "size": size,
+                            "content_type": mime_type.as_ref(),
+                        }),
+                    )
+                    .await?;
+                PartialUpload {
+                    target: endpoint.to_string(),
+                    file: stamp,
+                    upload_id: session.upload_id,
+                    chunk_size: session.chunk_size.unwrap_or(RESUMABLE_CHUNK).max(1),
+                    received: session.received.min(size),
+                    started_at: chrono::Utc::now().to_rfc3339(),
+                }
+            }
+        };
+        UploadStore::update(|store| store.upsert(upload.clone()))?;
 
-        self.handle_response(response).await
+        let bar = crate::display::progress::bytes(size, self.progress).with_message(file_name);
+        bar.set_position(upload.received);
+        let mut file = File::open(&file_path)?;
+        while upload.received < size {
+            let start = upload.received;
+            let mut chunk = vec![0; upload.chunk_size.min(size - start) as usize];
+            file.seek(SeekFrom::Start(start))?;
+            file.read_exact(&mut chunk)?;
+
+            let progress = match self.put_chunk(&upload.upload_id, start, chunk, size).await {
+                Ok(progress) if progress.received > start => progress,
+                Ok(_) => {
+                    bar.abandon();
+                    return Err(ApiError::MoltbookError(
+                        format!("Upload stalled at byte {} of {}", start, size),
+                        "Run the command again to resume.".to_string(),
+                    ));
+                }
+                Err(e) => {
+                    bar.abandon();
+                    return Err(e);
+                }
+            };
+            upload.received = progress.received.min(size);
+            bar.set_position(upload.received);
+            UploadStore::update(|store| store.upsert(upload.clone()))?;
+        }
+        bar.finish_and_clear();
+
+        let result = self
+            .send_post(
+                &Endpoint::UploadComplete(&upload.upload_id).path(),
+                &serde_json::json!({}),
+            )
+            .await?;
+        UploadStore::update(|store| store.remove(&upload.upload_id))?;
+        Ok(result)
+    }
+
+    /// Sends bytes `start..start + chunk.len()` of a `total`-byte upload.
+    async fn put_chunk(
+        &self,
+        upload_id: &str,
+        start: u64,
+        chunk: Vec<u8>,
+        total: u64,
+    ) -> Result<UploadProgress, ApiError> {
+        let url = format!("{}{}", self.base_url, Endpoint::Upload(upload_id).path());
+        let range = format!(
+            "bytes {}-{}/{}",
+            start,
+            start + chunk.len() as u64 - 1,
+            total
+        );
+
+        if self.debug {
+            eprintln!("PUT {} (Content-Range: {})", url, range);
+        }
+        let exchange =
+            transcript::request("PUT", &url, || Some(Value::String(format!("<{}>", range))));
+
+        let _permit = self.permit().await;
+        let response = self
+            .client
+            .put(&url)
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .header("Content-Type", "application/octet-stream")
+            .header("Content-Range", range)
+            .body(chunk)
+            .timeout(self.timeout_or(UPLOAD_TIMEOUT))
+            .send()
+            .await?;
+
+        self.handle_response(response, exchange).await
     }
 
     /// Performs a PATCH request with a JSON body.
@@ -185,6 +942,8 @@ impl MoltbookClient {
         endpoint: &str,
         body: &impl Serialize,
     ) -> Result<T, ApiError> {
+        self.guard(|| ApprovalRequest::new("PATCH", endpoint).with_body(json_body(body)))
+            .await?;
         let url = format!("{}{}", self.base_url, endpoint);
 
         if self.debug {
@@ -194,39 +953,51 @@ impl MoltbookClient {
                 serde_json::to_string_pretty(&body).unwrap_or_default()
             );
         }
+        let exchange = transcript::request("PATCH", &url, || Some(json_body(body)));
 
+        let _permit = self.permit().await;
         let response = self
             .client
             .patch(&url)
             .header("Authorization", format!("Bearer {}", self.api_key))
             .header("Content-Type", "application/json")
             .json(body)
+            .timeout(self.timeout_or(DEFAULT_TIMEOUT))
             .send()
             .await?;
 
-        self.handle_response(response).await
+        let result = self.handle_response(response, exchange).await;
+        self.note_challenge("PATCH", endpoint, || Some(json_body(body)), None, result)
     }
 
     /// Performs a DELETE request to the specified endpoint.
     pub async fn delete<T: DeserializeOwned>(&self, endpoint: &str) -> Result<T, ApiError> {
+        self.guard(|| ApprovalRequest::new("DELETE", endpoint))
+            .await?;
         let url = format!("{}{}", self.base_url, endpoint);
 
         if self.debug {
             eprintln!("DELETE {}", url);
         }
+        let exchange = transcript::request("DELETE", &url, || None);
 
+        let _permit = self.permit().await;
         let response = self
             .client
             .delete(&url)
             .header("Authorization", format!("Bearer {}", self.api_key))
+            .timeout(self.timeout_or(DEFAULT_TIMEOUT))
             .send()
             .await?;
 
-        self.handle_response(response).await
+        let result = self.handle_response(response, exchange).await;
+        self.note_challenge("DELETE", endpoint, || None, None, result)
     }
 
     /// Unified handler for API responses, managing errors and parsing.
     ///
+    /// `exchange` is the request's slot in the transcript being recorded, if any.
+    ///
     /// This method specifically handles:
     /// - HTTP 429 Rate Limiting with retry extraction.
     /// - CAPTCHA required status.
@@ -235,15 +1006,28 @@ impl MoltbookClient {
     async fn handle_response<T: DeserializeOwned>(
         &self,
         response: reqwest::Response,
+        exchange: Option<usize>,
     ) -> Result<T, ApiError> {
         let status = response.status();
+        metrics::record_response(status.as_u16());
+        metrics::record_rate_limit(response.headers());
         let text = response.text().await?;
+        transcript::response(exchange, status.as_u16(), &text);
 
         if self.debug {
             eprintln!("Response Status: {}", status);
             eprintln!("Response Body: {}", text);
         }
 
+        if !status.is_success()
+            && let Some(wait) = serde_json::from_str::<Value>(&text)
+                .ok()
+                .as_ref()
+                .and_then(new_agent_cooldown)
+        {
+            return Err(ApiError::NewAgentRestricted(wait));
+        }
+
         if status.as_u16() == 429 {
             if let Ok(json) = serde_json::from_str::<Value>(&text) {
                 if let Some(retry) = json.get("retry_after_minutes").and_then(|v| v.as_u64()) {
@@ -256,6 +1040,14 @@ impl MoltbookClient {
             return Err(ApiError::RateLimited("Wait before retrying".to_string()));
         }
 
+        if status.is_server_error() {
+            let message = serde_json::from_str::<Value>(&text)
+                .ok()
+                .and_then(|json| json.get("error").and_then(|v| v.as_str()).map(String::from))
+                .unwrap_or(text);
+            return Err(ApiError::ServerError(status.as_u16(), message));
+        }
+
         if !status.is_success() {
             if let Ok(json) = serde_json::from_str::<Value>(&text) {
                 let error = json
@@ -280,3 +1072,53 @@ impl MoltbookClient {
         serde_json::from_str(&text).map_err(ApiError::ParseError)
     }
 }
+
+/// A request body as JSON, for showing and queueing it.
+fn json_body(body: &impl Serialize) -> Value {
+    serde_json::to_value(body).unwrap_or(Value::Null)
+}
+
+/// Recognizes the cooldown the API applies to agents in their first 24 hours.
+///
+/// Returns `None` for any other error, otherwise the seconds left if the
+/// response says (`retry_after_*` or a `restricted_until` timestamp).
+fn new_agent_cooldown(json: &Value) -> Option<Option<u64>> {
+    let field = |key: &str| json.get(key).and_then(Value::as_str).unwrap_or("");
+    let mentions_new_agent = |text: &str| {
+        let text = text.to_lowercase().replace('_', " ");
+        text.contains("new agent") || text.contains("new account")
+    };
+    if !mentions_new_agent(field("error")) && !mentions_new_agent(field("code")) {
+        return None;
+    }
+
+    let seconds = json
+        .get("retry_after_seconds")
+        .and_then(Value::as_u64)
+        .or_else(|| {
+            json.get("retry_after_minutes")
+                .and_then(Value::as_u64)
+                .map(|m| m * 60)
+        })
+        .or_else(|| {
+            let until = DateTime::parse_from_rfc3339(field("restricted_until")).ok()?;
+            let left = until.signed_duration_since(Utc::now()).num_seconds();
+            Some(left.max(0) as u64)
+        });
+    Some(seconds)
+}
+
+/// Extracts a payload that the API may or may not wrap in an envelope.
+///
+/// Handles `{"<key>": payload}`, `{"<key>": {"items": [...]}}` and a bare
+/// payload at the root, in that order.
+pub fn unwrap_envelope<T: DeserializeOwned>(mut response: Value, key: &str) -> Result<T, ApiError> {
+    let payload = match response.get_mut(key).map(Value::take) {
+        Some(Value::Object(mut inner)) if inner.get("items").is_some_and(Value::is_array) => {
+            inner.remove("items").unwrap_or_default()
+        }
+        Some(inner) => inner,
+        None => response,
+    };
+    serde_json::from_value(payload).map_err(ApiError::ParseError)
+}
@@ -0,0 +1,376 @@
+//! Typed representation of the Moltbook API surface.
+//!
+//! Every path the CLI talks to is a variant of [`Endpoint`], and
+//! [`Endpoint::path`] is the single place where paths and query strings are
+//! rendered. Adding an API route means adding a variant here, which keeps the
+//! full surface visible in one match.
+
+use crate::api::query::{Query, segment};
+
+/// Sorting and paging parameters shared by list endpoints.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Listing<'a> {
+    /// Sort order (e.g. `hot`, `new`, `top`).
+    pub sort: Option<&'a str>,
+    /// Maximum number of items per page.
+    pub limit: Option<u64>,
+    /// Cursor from a previous page.
+    pub cursor: Option<&'a str>,
+    /// Items to skip, for numbered pages.
+    pub offset: Option<u64>,
+}
+
+impl<'a> Listing<'a> {
+    /// A listing with the given sort order and page size.
+    pub fn new(sort: &'a str, limit: u64) -> Self {
+        Self {
+            sort: Some(sort),
+            limit: Some(limit),
+            cursor: None,
+            offset: None,
+        }
+    }
+
+    /// A listing with only a page size.
+    pub fn limit(limit: u64) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Self::default()
+        }
+    }
+
+    /// Resumes the listing from `cursor`.
+    pub fn cursor(mut self, cursor: Option<&'a str>) -> Self {
+        self.cursor = cursor;
+        self
+    }
+
+    /// Skips the first `offset` items.
+    pub fn offset(mut self, offset: Option<u64>) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    fn query(&self) -> Query {
+        Query::new()
+            .opt("sort", self.sort)
+            .opt("limit", self.limit)
+            .opt("cursor", self.cursor)
+            .opt("offset", self.offset)
+    }
+}
+
+/// A Moltbook API endpoint with its path and query parameters.
+#[derive(Debug, Clone)]
+pub enum Endpoint<'a> {
+    // Agents
+    Register,
+    Me,
+    MyAvatar,
+    MyReports(Listing<'a>),
+    SetupOwnerEmail,
+    ResendOwnerEmail,
+    OwnerEmail,
+    AgentStatus,
+    AgentProfile(&'a str),
+    Follow(&'a str),
+    Followers {
+        name: &'a str,
+        listing: Listing<'a>,
+    },
+    Following {
+        name: &'a str,
+        listing: Listing<'a>,
+    },
+    Home,
+    Verify,
+
+    // Direct messages
+    DmCheck,
+    DmRequests,
+    DmNewRequest,
+    DmApprove(&'a str),
+    DmReject(&'a str),
+    DmConversations,
+    /// Messages of a conversation; `before` pages back from a message ID.
+    DmConversation {
+        id: &'a str,
+        limit: Option<u64>,
+        before: Option<&'a str>,
+    },
+    DmSend(&'a str),
+    DmReact(&'a str),
+
+    // Posts and comments
+    Feed {
+        listing: Listing<'a>,
+        filter: Option<&'a str>,
+    },
+    Posts {
+        listing: Listing<'a>,
+        author: Option<&'a str>,
+    },
+    CreatePost,
+    Post(&'a str),
+    PostUpvote(&'a str),
+    PostDownvote(&'a str),
+    /// `DELETE` retracts the agent's vote either way.
+    PostVote(&'a str),
+    PostPin(&'a str),
+    PostReport(&'a str),
+    PostComments {
+        post_id: &'a str,
+        listing: Listing<'a>,
+    },
+    CreateComment(&'a str),
+    Comment(&'a str),
+    CommentUpvote(&'a str),
+    CommentDownvote(&'a str),
+    /// `DELETE` retracts the agent's vote either way.
+    CommentVote(&'a str),
+    CommentSticky(&'a str),
+    CommentReport(&'a str),
+    Search {
+        query: &'a str,
+        type_filter: &'a str,
+        listing: Listing<'a>,
+    },
+
+    // Submolts
+    Submolts(Listing<'a>),
+    CreateSubmolt,
+    Submolt {
+        name: &'a str,
+        requester_id: Option<&'a str>,
+    },
+    SubmoltFeed {
+        name: &'a str,
+        listing: Listing<'a>,
+    },
+    SubmoltSubscribe(&'a str),
+    SubmoltSettings(&'a str),
+    SubmoltModerators(&'a str),
+    SubmoltModerator {
+        name: &'a str,
+        agent: &'a str,
+    },
+    SubmoltAvatar(&'a str),
+    SubmoltBanner(&'a str),
+    SubmoltWiki(&'a str),
+    SubmoltLabels(&'a str),
+    SubmoltRoles(&'a str),
+
+    // Resumable uploads
+    Uploads,
+    Upload(&'a str),
+    UploadComplete(&'a str),
+
+    // Labels
+    LabelAttach,
+    LabelAttachment(&'a str),
+
+    // Notifications and settings
+    Notifications {
+        listing: Listing<'a>,
+        unread: bool,
+    },
+    NotificationsReadByPost(&'a str),
+    NotificationsReadAll,
+    NotificationSettings,
+}
+
+impl Endpoint<'_> {
+    /// Renders the path (relative to the API base URL) including any query string.
+    ///
+    /// Path segments and query values are percent-encoded.
+    pub fn path(&self) -> String {
+        use Endpoint::*;
+        match self {
+            Register => "/agents/register".to_string(),
+            Me => "/agents/me".to_string(),
+            MyAvatar => "/agents/me/avatar".to_string(),
+            MyReports(listing) => listing.query().append_to("/agents/me/reports"),
+            SetupOwnerEmail => "/agents/me/setup-owner-email".to_string(),
+            ResendOwnerEmail => "/agents/me/setup-owner-email/resend".to_string(),
+            OwnerEmail => "/agents/me/owner-email".to_string(),
+            AgentStatus => "/agents/status".to_string(),
+            AgentProfile(name) => Query::new()
+                .param("name", name)
+                .append_to("/agents/profile"),
+            Follow(name) => format!("/agents/{}/follow", segment(name)),
+            Followers { name, listing } => listing
+                .query()
+                .append_to(&format!("/agents/{}/followers", segment(name))),
+            Following { name, listing } => listing
+                .query()
+                .append_to(&format!("/agents/{}/following", segment(name))),
+            Home => "/home".to_string(),
+            Verify => "/verify".to_string(),
+
+            DmCheck => "/agents/dm/check".to_string(),
+            DmRequests => "/agents/dm/requests".to_string(),
+            DmNewRequest => "/agents/dm/request".to_string(),
+            DmApprove(id) => format!("/agents/dm/requests/{}/approve", segment(id)),
+            DmReject(id) => format!("/agents/dm/requests/{}/reject", segment(id)),
+            DmConversations => "/agents/dm/conversations".to_string(),
+            DmConversation { id, limit, before } => Query::new()
+                .opt("limit", *limit)
+                .opt("before", *before)
+                .append_to(&format!("/agents/dm/conversations/{}", segment(id))),
+            DmSend(id) => format!("/agents/dm/conversations/{}/send", segment(id)),
+            DmReact(message_id) => {
+                format!("/agents/dm/messages/{}/reactions", segment(message_id))
+            }
+
+            Feed { listing, filter } => listing.query().opt("filter", *filter).append_to("/feed"),
+            Posts { listing, author } => Query::new()
+                .opt("author", *author)
+                .extend(listing.query())
+                .append_to("/posts"),
+            CreatePost => "/posts".to_string(),
+            Post(id) => format!("/posts/{}", segment(id)),
+            PostUpvote(id) => format!("/posts/{}/upvote", segment(id)),
+            PostDownvote(id) => format!("/posts/{}/downvote", segment(id)),
+            PostVote(id) => format!("/posts/{}/vote", segment(id)),
+            PostPin(id) => format!("/posts/{}/pin", segment(id)),
+            PostReport(id) => format!("/posts/{}/report", segment(id)),
+            PostComments { post_id, listing } => listing
+                .query()
+                .append_to(&format!("/posts/{}/comments", segment(post_id))),
+            CreateComment(post_id) => format!("/posts/{}/comments", segment(post_id)),
+            Comment(id) => format!("/comments/{}", segment(id)),
+            CommentUpvote(id) => format!("/comments/{}/upvote", segment(id)),
+            CommentDownvote(id) => format!("/comments/{}/downvote", segment(id)),
+            CommentVote(id) => format!("/comments/{}/vote", segment(id)),
+            CommentSticky(id) => format!("/comments/{}/sticky", segment(id)),
+            CommentReport(id) => format!("/comments/{}/report", segment(id)),
+            Search {
+                query,
+                type_filter,
+                listing,
+            } => Query::new()
+                .param("q", query)
+                .param("type", type_filter)
+                .extend(listing.query())
+                .append_to("/search"),
+
+            Submolts(listing) => listing.query().append_to("/submolts"),
+            CreateSubmolt => "/submolts".to_string(),
+            Submolt { name, requester_id } => Query::new()
+                .opt("requester_id", *requester_id)
+                .append_to(&format!("/submolts/{}", segment(name))),
+            SubmoltFeed { name, listing } => listing
+                .query()
+                .append_to(&format!("/submolts/{}/feed", segment(name))),
+            SubmoltSubscribe(name) => format!("/submolts/{}/subscribe", segment(name)),
+            SubmoltSettings(name) => format!("/submolts/{}/settings", segment(name)),
+            SubmoltModerators(name) => format!("/submolts/{}/moderators", segment(name)),
+            SubmoltModerator { name, agent } => {
+                format!("/submolts/{}/moderators/{}", segment(name), segment(agent))
+            }
+            SubmoltAvatar(name) => format!("/submolts/{}/avatar", segment(name)),
+            SubmoltBanner(name) => format!("/submolts/{}/banner", segment(name)),
+            SubmoltWiki(name) => format!("/submolts/{}/wiki", segment(name)),
+            SubmoltLabels(name) => format!("/submolts/{}/labels", segment(name)),
+            SubmoltRoles(name) => format!("/submolts/{}/roles", segment(name)),
+
+            Uploads => "/uploads".to_string(),
+            Upload(id) => format!("/uploads/{}", segment(id)),
+            UploadComplete(id) => format!("/uploads/{}/complete", segment(id)),
+
+            LabelAttach => "/labels/attach".to_string(),
+            LabelAttachment(id) => format!("/labels/attach/{}", segment(id)),
+
+            Notifications { listing, unread } => listing
+                .query()
+                .opt("filter", unread.then_some("unread"))
+                .append_to("/notifications"),
+            NotificationsReadByPost(post_id) => {
+                format!("/notifications/read-by-post/{}", segment(post_id))
+            }
+            NotificationsReadAll => "/notifications/read-all".to_string(),
+            NotificationSettings => "/settings/notifications".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paths_and_queries() {
+        assert_eq!(Endpoint::Post("p1").path(), "/posts/p1");
+        assert_eq!(Endpoint::PostVote("p1").path(), "/posts/p1/vote");
+        assert_eq!(Endpoint::CommentVote("c1").path(), "/comments/c1/vote");
+        assert_eq!(
+            Endpoint::DmConversation {
+                id: "c1",
+                limit: Some(20),
+                before: Some("m9"),
+            }
+            .path(),
+            "/agents/dm/conversations/c1?limit=20&before=m9"
+        );
+        assert_eq!(
+            Endpoint::Feed {
+                listing: Listing::new("hot", 25),
+                filter: Some("following"),
+            }
+            .path(),
+            "/feed?sort=hot&limit=25&filter=following"
+        );
+        assert_eq!(
+            Endpoint::Posts {
+                listing: Listing::new("new", 10).offset(Some(20)),
+                author: None,
+            }
+            .path(),
+            "/posts?sort=new&limit=10&offset=20"
+        );
+        assert_eq!(
+            Endpoint::Notifications {
+                listing: Listing::limit(10).cursor(Some("c1")),
+                unread: false,
+            }
+            .path(),
+            "/notifications?limit=10&cursor=c1"
+        );
+        assert_eq!(
+            Endpoint::Submolt {
+                name: "rust",
+                requester_id: None,
+            }
+            .path(),
+            "/submolts/rust"
+        );
+    }
+
+    #[test]
+    fn test_names_are_encoded() {
+        assert_eq!(
+            Endpoint::Posts {
+                listing: Listing::new("new", 5),
+                author: Some("Crab Bot"),
+            }
+            .path(),
+            "/posts?author=Crab%20Bot&sort=new&limit=5"
+        );
+        assert_eq!(
+            Endpoint::SubmoltFeed {
+                name: "café",
+                listing: Listing::default(),
+            }
+            .path(),
+            "/submolts/caf%C3%A9/feed"
+        );
+        assert_eq!(
+            Endpoint::Followers {
+                name: "Crab Bot",
+                listing: Listing::limit(50),
+            }
+            .path(),
+            "/agents/Crab%20Bot/followers?limit=50"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 //! Error types for the Moltbook API client.
 
+use std::error::Error as _;
 use thiserror::Error;
 
 /// Errors that can occur when interacting with the Moltbook API or local configuration.
@@ -7,20 +8,109 @@ use thiserror::Error;
 pub enum ApiError {
     /// Failure during an HTTP request (e.g., network issues).
     #[error("HTTP Request failed: {0}")]
-    RequestFailed(#[from] reqwest::Error),
+    RequestFailed(reqwest::Error),
+
+    /// No response arrived within the request's timeout. Contains the URL.
+    #[error(
+        "Request to {0} timed out. ⏳ Try again, or allow more time with --timeout (e.g. --timeout 60s)"
+    )]
+    Timeout(String),
+
+    /// The API host name could not be resolved.
+    #[error("DNS lookup failed for {0}. 🌐 Check your network connection, DNS settings or proxy")]
+    DnsFailed(String),
+
+    /// The TLS handshake failed, usually because the server certificate was rejected.
+    /// Contains the reason and a hint for the user.
+    #[error("TLS handshake failed: {0}. 🔒 {1}")]
+    TlsFailed(String, String), // reason, hint
 
     /// A specific error returned by the Moltbook API.
     /// Contains an error message and a hint for the user.
     #[error("API Error: {0} {1}")]
     MoltbookError(String, String), // error, hint
 
+    /// The API failed with a 5xx status.
+    #[error("Server error (HTTP {0}): {1}")]
+    ServerError(u16, String),
+
+    /// The request could not be delivered and was saved to the offline outbox.
+    #[error("API unreachable; queued in the outbox as {0}. Run `moltbook outbox flush` later")]
+    Queued(String),
+
     /// The agent has reached a rate limit on the API.
     #[error("Rate limited. ⏳ Retry after {0}")]
     RateLimited(String),
 
     /// Specific restriction applied to new agents (e.g., must wait before posting).
-    #[error("New Agent Restriction: {0}")]
-    NewAgentRestricted(String),
+    /// Contains the seconds until the cooldown ends, if the API reported it.
+    #[error("New Agent Restriction: {}", restriction_wait(.0))]
+    NewAgentRestricted(Option<u64>),
+
+    /// A moderator-only action was attempted on a submolt the agent does not moderate.
+    #[error("You are not a moderator of m/{0}")]
+    NotModerator(String),
+
+    /// `create-submolt` stopped before sending because the name is invalid or
+    /// already taken. Lists free alternatives, if any were found.
+    #[error("Cannot create m/{name}: {reason}{}", name_alternatives(.suggestions))]
+    SubmoltNameUnavailable {
+        name: String,
+        reason: String,
+        suggestions: Vec<String>,
+    },
+
+    /// A mutating command was refused because `require_claimed` is set and
+    /// the agent's claim is still pending. Contains the claim URL.
+    #[error("The agent is not claimed yet (require_claimed is set). Claim it at {0}")]
+    NotClaimed(String),
+
+    /// `require_approval` is set and nobody could approve the request, so it
+    /// was queued. Contains the short id of the queued request.
+    #[error(
+        "Approval required (require_approval is set); queued as {0}. The owner can run `moltbook approvals approve {0}`"
+    )]
+    AwaitingApproval(String),
+
+    /// The owner declined a request in supervised mode. Contains the action.
+    #[error("Declined {0}; nothing was sent")]
+    Declined(String),
+
+    /// The request breaks a rule of the owner's `policy.toml` and was not sent.
+    /// Contains the policy key and what the request did.
+    #[error("Policy violation ({rule}): {detail}")]
+    PolicyViolation { rule: String, detail: String },
+
+    /// The daily budget for this kind of action (the `budgets` section of the
+    /// configuration) is used up. Contains the seconds until it resets.
+    #[error(
+        "Daily {kind} budget {used}/{limit} used, resets in {}. See `moltbook budget`",
+        format_wait(*.resets_in)
+    )]
+    BudgetExhausted {
+        kind: String,
+        used: usize,
+        limit: usize,
+        resets_in: u64,
+    },
+
+    /// The text is longer than the API or `--max-chars` allows, and was not sent.
+    #[error(
+        "{what} is {length} characters, over the limit of {limit}. ✂️  Shorten it or pass --truncate"
+    )]
+    TooLong {
+        what: String,
+        length: usize,
+        limit: usize,
+    },
+
+    /// The command was stopped with Ctrl-C after saving what it had done.
+    #[error("Interrupted; stopped early after saving progress")]
+    Interrupted,
+
+    /// Input was needed but stdin is not a terminal.
+    #[error("Interactive input unavailable (stdin is not a terminal). {0}")]
+    NonInteractive(String),
 
     /// A CAPTCHA challenge is required to complete the operation.
     #[error("CAPTCHA required. 🛡️  Token: {0}")]
@@ -38,3 +128,111 @@ pub enum ApiError {
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
 }
+
+impl From<reqwest::Error> for ApiError {
+    /// Gives DNS and certificate failures their own variants so they can carry a hint.
+    ///
+    /// Every transport failure passes through here, so it is also counted.
+    fn from(error: reqwest::Error) -> Self {
+        crate::metrics::record_network_error();
+        if error.is_timeout() {
+            let url = error.url().map(|u| u.to_string()).unwrap_or_default();
+            return ApiError::Timeout(url);
+        }
+        if !error.is_connect() {
+            return ApiError::RequestFailed(error);
+        }
+        let mut causes = Vec::new();
+        let mut source = error.source();
+        while let Some(cause) = source {
+            causes.push(cause.to_string());
+            source = cause.source();
+        }
+        let host = error
+            .url()
+            .and_then(|u| u.host_str())
+            .unwrap_or("the API host")
+            .to_string();
+
+        if causes.iter().any(|c| c.starts_with("dns error")) {
+            return ApiError::DnsFailed(host);
+        }
+        match causes.iter().find_map(|c| tls_hint(c).map(|h| (c, h))) {
+            Some((reason, hint)) => ApiError::TlsFailed(reason.clone(), hint.to_string()),
+            None => ApiError::RequestFailed(error),
+        }
+    }
+}
+
+fn restriction_wait(seconds: &Option<u64>) -> String {
+    match seconds {
+        Some(seconds) => format!("⏳ try again in {}", format_wait(*seconds)),
+        None => "⏳ new agents must wait before doing this".to_string(),
+    }
+}
+
+fn name_alternatives(suggestions: &[String]) -> String {
+    if suggestions.is_empty() {
+        return String::new();
+    }
+    let names: Vec<String> = suggestions.iter().map(|s| format!("m/{}", s)).collect();
+    format!(". 💡 Try {}", names.join(", "))
+}
+
+/// Formats a wait as e.g. `45s`, `12m` or `1h 5m`, rounding up to whole minutes.
+pub fn format_wait(seconds: u64) -> String {
+    if seconds < 60 {
+        return format!("{}s", seconds);
+    }
+    let minutes = seconds.div_ceil(60);
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h {}m", h, m),
+    }
+}
+
+/// A hint for a TLS error message, or `None` if it is not a TLS error.
+fn tls_hint(message: &str) -> Option<&'static str> {
+    if !message.contains("certificate") && !message.contains("fatal alert") {
+        return None;
+    }
+    let hint = if message.contains("Expired") || message.contains("NotValidYet") {
+        "Check that the system clock is correct"
+    } else if message.contains("UnknownIssuer") || message.contains("NotValidForName") {
+        "A corporate proxy may be intercepting HTTPS; \
+         point SSL_CERT_FILE at its CA certificate (PEM)"
+    } else {
+        "Check the system clock, any HTTPS proxy, and SSL_CERT_FILE"
+    };
+    Some(hint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tls_hint() {
+        assert!(
+            tls_hint("invalid peer certificate: Expired")
+                .unwrap()
+                .contains("clock")
+        );
+        assert!(
+            tls_hint("invalid peer certificate: UnknownIssuer")
+                .unwrap()
+                .contains("SSL_CERT_FILE")
+        );
+        assert!(tls_hint("received fatal alert: HandshakeFailure").is_some());
+        assert!(tls_hint("connection refused").is_none());
+    }
+
+    #[test]
+    fn test_format_wait() {
+        assert_eq!(format_wait(45), "45s");
+        assert_eq!(format_wait(61), "2m");
+        assert_eq!(format_wait(3600), "1h");
+        assert_eq!(format_wait(4980), "1h 23m");
+    }
+}
@@ -4,5 +4,8 @@
 //! including the HTTP client, data models, and error handling.
 
 pub mod client;
+pub mod endpoint;
 pub mod error;
+pub mod pagination;
+pub mod query;
 pub mod types;
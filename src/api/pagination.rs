@@ -0,0 +1,155 @@
+//! Keyset (cursor) pagination over list endpoints.
+//!
+//! [`Pages`] is a pull-based async stream: each call to [`Pages::next`]
+//! fetches and decodes exactly one page, so callers can render it before the
+//! next request goes out.
+
+use crate::api::client::{DEFAULT_TIMEOUT, MoltbookClient};
+use crate::api::error::ApiError;
+use crate::api::query::Query;
+use crate::api::types::{
+    FeedResponse, FollowListResponse, LenientVec, Post, SearchResponse, SubmoltFeedResponse,
+};
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+/// A list response that can point at a following page.
+pub trait CursorPage: DeserializeOwned {
+    /// The cursor for the next page, or `None` when this is the last one.
+    fn next_cursor(&self) -> Option<&str>;
+}
+
+/// A page of posts from one of the feed endpoints.
+pub trait FeedPage: CursorPage {
+    fn posts(&self) -> &LenientVec<Post>;
+
+    /// The 1-based number of this page, when the API reports it.
+    fn page_number(&self) -> Option<u64> {
+        None
+    }
+
+    /// Posts across all pages, when the API reports it.
+    fn total(&self) -> Option<u64> {
+        None
+    }
+}
+
+fn cursor_if_more(has_more: Option<bool>, cursor: &Option<String>) -> Option<&str> {
+    if has_more.unwrap_or(false) {
+        cursor.as_deref()
+    } else {
+        None
+    }
+}
+
+impl CursorPage for FeedResponse {
+    fn next_cursor(&self) -> Option<&str> {
+        cursor_if_more(self.has_more, &self.next_cursor)
+    }
+}
+
+impl FeedPage for FeedResponse {
+    fn posts(&self) -> &LenientVec<Post> {
+        &self.posts
+    }
+
+    fn page_number(&self) -> Option<u64> {
+        self.context.as_ref().and_then(|c| c.page)
+    }
+
+    fn total(&self) -> Option<u64> {
+        self.context.as_ref().and_then(|c| c.total)
+    }
+}
+
+impl CursorPage for SubmoltFeedResponse {
+    fn next_cursor(&self) -> Option<&str> {
+        cursor_if_more(self.has_more, &self.next_cursor)
+    }
+}
+
+impl FeedPage for SubmoltFeedResponse {
+    fn posts(&self) -> &LenientVec<Post> {
+        &self.posts
+    }
+
+    fn total(&self) -> Option<u64> {
+        self.total
+    }
+}
+
+impl CursorPage for SearchResponse {
+    fn next_cursor(&self) -> Option<&str> {
+        cursor_if_more(self.has_more, &self.next_cursor)
+    }
+}
+
+impl CursorPage for FollowListResponse {
+    fn next_cursor(&self) -> Option<&str> {
+        cursor_if_more(self.has_more, &self.next_cursor)
+    }
+}
+
+/// Walks a cursor-paginated endpoint one page at a time.
+pub struct Pages<'a, T> {
+    client: &'a MoltbookClient,
+    endpoint: String,
+    cursor: Option<String>,
+    finished: bool,
+    /// Default timeout per page; `--timeout` still overrides it.
+    timeout: Duration,
+    _page: PhantomData<T>,
+}
+
+impl<'a, T: CursorPage> Pages<'a, T> {
+    /// Starts paging `endpoint` (which may already carry a query string),
+    /// optionally resuming from `cursor`.
+    pub fn new(client: &'a MoltbookClient, endpoint: String, cursor: Option<String>) -> Self {
+        Self {
+            client,
+            endpoint,
+            cursor,
+            finished: false,
+            timeout: DEFAULT_TIMEOUT,
+            _page: PhantomData,
+        }
+    }
+
+    /// Allows each page `timeout` by default, e.g.
+    /// [`PAGINATION_TIMEOUT`](crate::api::client::PAGINATION_TIMEOUT) for `--all`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Fetches the next page, or returns `None` once the listing is exhausted.
+    ///
+    /// An error ends the stream.
+    pub async fn next(&mut self) -> Option<Result<T, ApiError>> {
+        if self.finished {
+            return None;
+        }
+
+        let url = Query::new()
+            .opt("cursor", self.cursor.as_deref())
+            .append_to(&self.endpoint);
+
+        match self.client.get_with_timeout::<T>(&url, self.timeout).await {
+            Ok(page) => {
+                self.cursor = page.next_cursor().map(String::from);
+                self.finished = self.cursor.is_none();
+                Some(Ok(page))
+            }
+            Err(e) => {
+                self.finished = true;
+                Some(Err(e))
+            }
+        }
+    }
+
+    /// The cursor that would fetch the next page, if any remain.
+    pub fn cursor(&self) -> Option<&str> {
+        self.cursor.as_deref()
+    }
+}
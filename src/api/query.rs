@@ -0,0 +1,97 @@
+//! Query-string construction with consistent percent-encoding.
+//!
+//! Every key and value passed to [`Query`] is percent-encoded, so submolt
+//! names, agent names, search terms and cursors containing spaces, `&`, `#`
+//! or non-ASCII characters cannot corrupt the URL.
+
+use std::fmt::Display;
+
+/// An ordered set of query parameters.
+#[derive(Debug, Clone, Default)]
+pub struct Query {
+    pairs: Vec<(String, String)>,
+}
+
+impl Query {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a parameter.
+    pub fn param(mut self, key: &str, value: impl Display) -> Self {
+        self.pairs.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Adds a parameter only when `value` is present.
+    pub fn opt(self, key: &str, value: Option<impl Display>) -> Self {
+        match value {
+            Some(v) => self.param(key, v),
+            None => self,
+        }
+    }
+
+    /// Appends every parameter of `other`.
+    pub fn extend(mut self, other: Query) -> Self {
+        self.pairs.extend(other.pairs);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// Renders the encoded `key=value&...` string, without a leading `?`.
+    pub fn encode(&self) -> String {
+        self.pairs
+            .iter()
+            .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(v)))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
+    /// Appends the parameters to `path`, which may already carry a query string.
+    pub fn append_to(&self, path: &str) -> String {
+        if self.is_empty() {
+            return path.to_string();
+        }
+        let sep = if path.contains('?') { '&' } else { '?' };
+        format!("{}{}{}", path, sep, self.encode())
+    }
+}
+
+/// Percent-encodes a single path segment such as a submolt or agent name.
+pub fn segment(value: &str) -> String {
+    urlencoding::encode(value).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encodes_all_values() {
+        let query = Query::new()
+            .param("author", "Crab Bot")
+            .param("sort", "new&top")
+            .opt("filter", None::<&str>)
+            .param("limit", 25);
+        assert_eq!(query.encode(), "author=Crab%20Bot&sort=new%26top&limit=25");
+    }
+
+    #[test]
+    fn test_append_to_existing_query() {
+        let query = Query::new().param("cursor", "a/b=");
+        assert_eq!(query.append_to("/feed"), "/feed?cursor=a%2Fb%3D");
+        assert_eq!(
+            query.append_to("/feed?limit=5"),
+            "/feed?limit=5&cursor=a%2Fb%3D"
+        );
+        assert_eq!(Query::new().append_to("/feed"), "/feed");
+    }
+
+    #[test]
+    fn test_segment_encodes_unicode() {
+        assert_eq!(segment("café bots"), "caf%C3%A9%20bots");
+    }
+}
@@ -4,7 +4,34 @@
 //! to represent API requests and responses, covering agents, posts, submolts,
 //! search results, and direct messages.
 
-use serde::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::ops::Deref;
+
+/// Access to the unmodelled fields captured on core API types.
+///
+/// Lets `--debug` runs report server-side schema additions instead of
+/// silently dropping them.
+pub trait UnknownFields {
+    /// Type name used in debug output.
+    const KIND: &'static str;
+
+    fn unknown_fields(&self) -> &serde_json::Map<String, serde_json::Value>;
+}
+
+macro_rules! impl_unknown_fields {
+    ($($ty:ident),*) => {
+        $(impl UnknownFields for $ty {
+            const KIND: &'static str = stringify!($ty);
+
+            fn unknown_fields(&self) -> &serde_json::Map<String, serde_json::Value> {
+                &self.extra
+            }
+        })*
+    };
+}
+
+impl_unknown_fields!(Agent, Post, Submolt, Message);
 
 /// A generic wrapper for Moltbook API responses.
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -73,6 +100,12 @@ pub struct Agent {
     pub owner_id: Option<String>,
     /// Detailed information about the human owner.
     pub owner: Option<OwnerInfo>,
+    /// Email the owner uses to log in to the dashboard (own profile only).
+    #[serde(alias = "ownerEmail")]
+    pub owner_email: Option<String>,
+    /// Whether the owner has confirmed `owner_email`.
+    #[serde(alias = "ownerEmailVerified")]
+    pub owner_email_verified: Option<bool>,
     /// URL to the agent's avatar image.
     #[serde(alias = "avatarUrl")]
     pub avatar_url: Option<String>,
@@ -82,6 +115,9 @@ pub struct Agent {
     pub metadata: Option<serde_json::Value>,
     /// A list of the agent's most recent posts.
     pub recent_posts: Option<Vec<Post>>,
+    /// Fields returned by the API that this version does not model yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about the human owner of an agent (typically imported from X/Twitter).
@@ -139,6 +175,21 @@ pub struct StatusResponse {
     pub agent: Option<Agent>,
 }
 
+/// Response from `GET /agents/me/owner-email`: whether the owner can log in to
+/// the dashboard yet.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OwnerEmailStatus {
+    /// The owner email, if one was set.
+    #[serde(alias = "owner_email")]
+    pub email: Option<String>,
+    /// Whether the owner clicked the verification link.
+    #[serde(alias = "email_verified")]
+    pub verified: Option<bool>,
+    /// When the last verification email went out.
+    #[serde(alias = "verificationSentAt")]
+    pub verification_sent_at: Option<String>,
+}
+
 /// Response from the post creation endpoint.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PostResponse {
@@ -175,10 +226,10 @@ pub struct Post {
     /// External URL associated with the post.
     pub url: Option<String>,
     /// Current upvote count.
-    #[serde(deserialize_with = "serde_helpers::deserialize_string_or_i64")]
+    #[serde(default, deserialize_with = "serde_helpers::deserialize_string_or_i64")]
     pub upvotes: i64,
     /// Current downvote count.
-    #[serde(deserialize_with = "serde_helpers::deserialize_string_or_i64")]
+    #[serde(default, deserialize_with = "serde_helpers::deserialize_string_or_i64")]
     pub downvotes: i64,
     /// Number of comments on this post.
     #[serde(
@@ -187,6 +238,7 @@ pub struct Post {
     )]
     pub comment_count: Option<u64>,
     /// Timestamp when the post was created.
+    #[serde(default)]
     pub created_at: String,
     /// Details about the agent who authored the post.
     pub author: Author,
@@ -215,8 +267,51 @@ pub struct Post {
     pub is_locked: Option<bool>,
     /// Whether the post is deleted.
     pub is_deleted: Option<bool>,
+    /// Whether moderators removed the post.
+    pub is_removed: Option<bool>,
+    /// The moderators' reason for removing the post.
+    pub removal_reason: Option<String>,
     /// Timestamp when the post was last updated.
     pub updated_at: Option<String>,
+    /// Fields returned by the API that this version does not model yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Post {
+    /// Why the post no longer shows its content, if it was deleted or removed.
+    pub fn tombstone(&self) -> Option<Tombstone> {
+        if self.is_removed.unwrap_or(false) {
+            Some(Tombstone::Removed(self.removal_reason.clone()))
+        } else if self.is_deleted.unwrap_or(false) {
+            Some(Tombstone::Deleted)
+        } else {
+            None
+        }
+    }
+}
+
+/// Why a post or comment is shown as a placeholder instead of its content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Tombstone {
+    /// Deleted by its author.
+    Deleted,
+    /// Removed by moderators, with their reason if the API gave one.
+    Removed(Option<String>),
+}
+
+impl Tombstone {
+    /// Reads the state of a comment, which the CLI keeps as raw JSON.
+    pub fn of_comment(comment: &serde_json::Value) -> Option<Self> {
+        if comment["is_removed"].as_bool().unwrap_or(false) {
+            let reason = comment["removal_reason"].as_str().map(String::from);
+            Some(Self::Removed(reason))
+        } else if comment["is_deleted"].as_bool().unwrap_or(false) {
+            Some(Self::Deleted)
+        } else {
+            None
+        }
+    }
 }
 
 /// Simplified author information used in lists and feeds.
@@ -246,6 +341,7 @@ pub struct SubmoltInfo {
     /// The programmatic name (slug) of the submolt.
     pub name: String,
     /// The user-visible display name.
+    #[serde(default)]
     pub display_name: String,
 }
 
@@ -264,6 +360,9 @@ pub struct SearchResult {
     pub similarity: Option<f64>,
     pub author: Author,
     pub post_id: Option<String>,
+    /// The community the matching post or comment lives in.
+    pub submolt: Option<SubmoltInfo>,
+    pub created_at: Option<String>,
 }
 
 /// Response containing submolt details and the current user's role.
@@ -273,6 +372,50 @@ pub struct SubmoltResponse {
     pub your_role: Option<String>,
 }
 
+impl SubmoltResponse {
+    /// Roles allowed to pin posts, edit settings and manage moderators.
+    pub const MODERATOR_ROLES: &[&str] = &["owner", "moderator", "admin"];
+
+    /// Whether the requesting agent holds a moderator role here.
+    pub fn is_moderator(&self) -> bool {
+        self.your_role
+            .as_deref()
+            .is_some_and(|role| Self::MODERATOR_ROLES.contains(&role.to_ascii_lowercase().as_str()))
+    }
+}
+
+/// Long-form about/wiki page of a submolt.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SubmoltWiki {
+    /// Markdown body of the page.
+    pub content: Option<String>,
+    /// Timestamp of the last edit.
+    pub updated_at: Option<String>,
+    /// Name of the agent who last edited the page.
+    pub updated_by: Option<String>,
+}
+
+/// An agent allowed to moderate a submolt.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Moderator {
+    /// Name of the moderating agent.
+    #[serde(alias = "agent_name")]
+    pub agent: String,
+    /// `owner` or `moderator`.
+    #[serde(default = "Moderator::default_role")]
+    pub role: String,
+    /// When the agent was made a moderator.
+    pub added_at: Option<String>,
+    /// Name of the agent who added them.
+    pub added_by: Option<String>,
+}
+
+impl Moderator {
+    fn default_role() -> String {
+        "moderator".to_string()
+    }
+}
+
 /// Represents a community (submolt) on Moltbook.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Submolt {
@@ -281,6 +424,7 @@ pub struct Submolt {
     /// Programmatic name (slug).
     pub name: String,
     /// User-visible display name.
+    #[serde(default)]
     pub display_name: String,
     /// Description of the community purpose and rules.
     pub description: Option<String>,
@@ -310,6 +454,13 @@ pub struct Submolt {
     pub created_at: Option<String>,
     /// Timestamp of the most recent activity in this community.
     pub last_activity_at: Option<String>,
+    /// Banner color as a hex string.
+    pub banner_color: Option<String>,
+    /// Theme color as a hex string.
+    pub theme_color: Option<String>,
+    /// Fields returned by the API that this version does not model yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Represents a Direct Message request from another agent.
@@ -340,6 +491,36 @@ pub struct Conversation {
     /// Unread count — optional, may not be present per-conversation.
     #[serde(default)]
     pub unread_count: u64,
+    /// The newest message, when the API includes it.
+    #[serde(default)]
+    pub last_message: Option<MessagePreview>,
+    /// When the conversation was last active.
+    #[serde(alias = "last_message_at", alias = "updatedAt", default)]
+    pub updated_at: Option<String>,
+}
+
+impl Conversation {
+    /// When the conversation was last active: its newest message, or else
+    /// the conversation's own timestamp.
+    pub fn last_activity(&self) -> Option<&str> {
+        self.last_message
+            .as_ref()
+            .map(|m| m.created_at.as_str())
+            .filter(|at| !at.is_empty())
+            .or(self.updated_at.as_deref())
+    }
+}
+
+/// The newest message of a conversation, as listed with it.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MessagePreview {
+    #[serde(alias = "message", default)]
+    pub content: String,
+    /// Who sent it, when the API says.
+    #[serde(alias = "from_agent", default)]
+    pub sender: Option<Author>,
+    #[serde(alias = "createdAt", default)]
+    pub created_at: String,
 }
 
 /// A specific message within a conversation thread.
@@ -358,8 +539,155 @@ pub struct Message {
     #[serde(alias = "needs_human_input", default)]
     pub needs_human_input: bool,
     /// Message timestamp.
-    #[serde(alias = "createdAt")]
+    #[serde(alias = "createdAt", default)]
     pub created_at: String,
+    /// Emoji reactions left on the message.
+    #[serde(default)]
+    pub reactions: LenientVec<Reaction>,
+    /// Fields returned by the API that this version does not model yet.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Message {
+    /// Each emoji reacted with and how often, in the order first seen.
+    ///
+    /// The API may list a reaction per agent or one per emoji with a count;
+    /// both add up the same way.
+    pub fn reaction_counts(&self) -> Vec<(&str, u64)> {
+        let mut counts: Vec<(&str, u64)> = Vec::new();
+        for reaction in self.reactions.iter() {
+            match counts
+                .iter_mut()
+                .find(|(emoji, _)| *emoji == reaction.emoji)
+            {
+                Some((_, count)) => *count += reaction.count,
+                None => counts.push((&reaction.emoji, reaction.count)),
+            }
+        }
+        counts
+    }
+}
+
+/// An emoji reaction on a direct message.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Reaction {
+    pub emoji: String,
+    /// How many agents reacted with this emoji.
+    #[serde(default = "one")]
+    pub count: u64,
+}
+
+fn one() -> u64 {
+    1
+}
+
+/// One page of a conversation's messages.
+#[derive(Deserialize, Debug, Default)]
+pub struct MessagePage {
+    #[serde(default, alias = "items")]
+    pub messages: Vec<Message>,
+    pub has_more: Option<bool>,
+    /// Where the API says older messages start, if it does.
+    #[serde(alias = "next_cursor", alias = "before")]
+    pub older_cursor: Option<String>,
+}
+
+impl MessagePage {
+    /// The `before` value that fetches the messages preceding this page, or
+    /// `None` when there are none.
+    ///
+    /// Falls back to the oldest message on the page when the API says there
+    /// is more but gives no cursor.
+    pub fn older(&self) -> Option<String> {
+        if self.has_more == Some(false) {
+            return None;
+        }
+        if let Some(cursor) = &self.older_cursor {
+            return Some(cursor.clone());
+        }
+        if self.has_more != Some(true) {
+            return None;
+        }
+        self.messages
+            .iter()
+            .filter(|m| !m.id.is_empty())
+            .min_by(|a, b| a.created_at.cmp(&b.created_at))
+            .map(|m| m.id.clone())
+    }
+}
+
+/// An entry that could not be decoded by [`LenientVec`].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SkippedItem {
+    /// Position of the entry in the original array.
+    pub index: usize,
+    /// The entry's `id`, when it had one.
+    pub id: Option<String>,
+    /// Why decoding failed.
+    pub error: String,
+}
+
+/// A list decoded entry by entry.
+///
+/// One malformed element no longer fails the whole response: it is recorded
+/// in `skipped` and the remaining items are kept. Derefs to `Vec<T>` so it
+/// can be used anywhere a plain list of items was expected.
+#[derive(Debug, Clone)]
+pub struct LenientVec<T> {
+    pub items: Vec<T>,
+    pub skipped: Vec<SkippedItem>,
+}
+
+impl<T> Default for LenientVec<T> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            skipped: Vec::new(),
+        }
+    }
+}
+
+impl<T> Deref for LenientVec<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Vec<T> {
+        &self.items
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LenientVec<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for LenientVec<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = Vec::<serde_json::Value>::deserialize(deserializer)?;
+        let mut list = LenientVec::default();
+        for (index, value) in raw.into_iter().enumerate() {
+            let id = value.get("id").and_then(|v| v.as_str()).map(String::from);
+            match serde_json::from_value(value) {
+                Ok(item) => list.items.push(item),
+                Err(e) => list.skipped.push(SkippedItem {
+                    index,
+                    id,
+                    error: e.to_string(),
+                }),
+            }
+        }
+        Ok(list)
+    }
+}
+
+impl<T: Serialize> Serialize for LenientVec<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.items.serialize(serializer)
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -372,18 +700,56 @@ pub struct FeedContext {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct FeedResponse {
     pub success: bool,
-    pub posts: Vec<Post>,
+    pub posts: LenientVec<Post>,
     pub feed_type: Option<String>,
     pub context: Option<FeedContext>,
     pub has_more: Option<bool>,
     pub next_cursor: Option<String>,
 }
 
+/// A page of an agent's followers or of the agents it follows.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FollowListResponse {
+    #[serde(default, alias = "followers", alias = "following")]
+    pub agents: LenientVec<Author>,
+    pub has_more: Option<bool>,
+    pub next_cursor: Option<String>,
+}
+
+/// A report filed against a post or comment.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Report {
+    pub id: String,
+    /// `"post"` | `"comment"`
+    pub target_type: Option<String>,
+    pub target_id: Option<String>,
+    pub reason: Option<String>,
+    pub note: Option<String>,
+    /// `"open"`, `"actioned"`, `"dismissed"`, etc.
+    pub status: Option<String>,
+    pub created_at: Option<String>,
+    pub resolved_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReportsResponse {
+    pub success: Option<bool>,
+    pub reports: Option<Vec<Report>>,
+    pub has_more: Option<bool>,
+    pub next_cursor: Option<String>,
+}
+
 /// Response from the search endpoint.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SearchResponse {
     pub results: Vec<SearchResult>,
     pub count: Option<u64>,
+    /// Total matches across all pages, when the API reports it.
+    #[serde(
+        default,
+        deserialize_with = "serde_helpers::deserialize_option_string_or_u64"
+    )]
+    pub total: Option<u64>,
     pub has_more: Option<bool>,
     pub next_cursor: Option<String>,
 }
@@ -411,7 +777,7 @@ pub struct DmCheckResponse {
 /// Paginated response for a submolt feed.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SubmoltFeedResponse {
-    pub posts: Vec<Post>,
+    pub posts: LenientVec<Post>,
     #[serde(
         default,
         deserialize_with = "serde_helpers::deserialize_option_string_or_u64"
@@ -453,6 +819,33 @@ pub struct DmConversationsData {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_moderator_roles() {
+        let response = |role: Option<&str>| SubmoltResponse {
+            submolt: serde_json::from_str(r#"{"name": "tech"}"#).unwrap(),
+            your_role: role.map(String::from),
+        };
+        assert!(response(Some("owner")).is_moderator());
+        assert!(response(Some("Moderator")).is_moderator());
+        assert!(!response(Some("member")).is_moderator());
+        assert!(!response(None).is_moderator());
+    }
+
+    #[test]
+    fn test_moderator_deserialization() {
+        let mods: Vec<Moderator> = serde_json::from_str(
+            r#"[
+                {"agent_name": "founder", "role": "owner", "added_at": "2025-01-01T00:00:00Z"},
+                {"agent": "helper", "added_by": "founder"}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(mods[0].agent, "founder");
+        assert_eq!(mods[0].role, "owner");
+        assert_eq!(mods[1].role, "moderator");
+        assert_eq!(mods[1].added_by.as_deref(), Some("founder"));
+    }
+
     #[test]
     fn test_post_deserialization() {
         let json = r#"{
@@ -471,6 +864,68 @@ mod tests {
         assert_eq!(post.upvotes, 10);
     }
 
+    #[test]
+    fn test_tombstones() {
+        let json = r#"{
+            "id": "123",
+            "title": "Gone",
+            "author": {"name": "Bot"},
+            "is_deleted": true,
+            "is_removed": true,
+            "removal_reason": "spam"
+        }"#;
+        let post: Post = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            post.tombstone(),
+            Some(Tombstone::Removed(Some("spam".to_string())))
+        );
+
+        let comment = serde_json::json!({"id": "c1", "is_deleted": true});
+        assert_eq!(Tombstone::of_comment(&comment), Some(Tombstone::Deleted));
+        assert_eq!(
+            Tombstone::of_comment(&serde_json::json!({"id": "c2"})),
+            None
+        );
+    }
+
+    #[test]
+    fn test_post_preserves_unknown_fields() {
+        let json = r#"{
+            "id": "123",
+            "title": "Test Post",
+            "upvotes": 1,
+            "downvotes": 0,
+            "created_at": "2024-01-01T00:00:00Z",
+            "author": {"name": "Bot"},
+            "flair": {"text": "New"}
+        }"#;
+
+        let post: Post = serde_json::from_str(json).unwrap();
+        assert_eq!(post.unknown_fields().len(), 1);
+        assert_eq!(post.extra["flair"]["text"], "New");
+
+        let round_trip = serde_json::to_value(&post).unwrap();
+        assert_eq!(round_trip["flair"]["text"], "New");
+    }
+
+    #[test]
+    fn test_feed_skips_malformed_posts() {
+        let json = r#"{
+            "success": true,
+            "posts": [
+                {"id": "1", "title": "Good", "author": {"name": "Bot"}},
+                {"id": "2", "author": {"name": "Bot"}},
+                {"id": "3", "title": "Also good", "author": {"name": "Bot"}}
+            ]
+        }"#;
+
+        let feed: FeedResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(feed.posts.len(), 2);
+        assert_eq!(feed.posts.skipped.len(), 1);
+        assert_eq!(feed.posts.skipped[0].index, 1);
+        assert_eq!(feed.posts.skipped[0].id.as_deref(), Some("2"));
+    }
+
     #[test]
     fn test_api_response_success() {
         let json = r#"{"success": true, "id": "123", "name": "Test"}"#;
@@ -488,6 +943,53 @@ mod tests {
         assert_eq!(resp.error, Some("Invalid key".to_string()));
         assert_eq!(resp.hint, Some("Check your credentials".to_string()));
     }
+
+    #[test]
+    fn test_message_reactions() {
+        let message: Message = serde_json::from_str(
+            r#"{
+                "id": "m1",
+                "sender": {"name": "a"},
+                "content": "Shipped!",
+                "reactions": [
+                    {"emoji": "🎉", "count": 2},
+                    {"emoji": "👍"},
+                    {"emoji": "🎉"},
+                    {"count": 4}
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(message.reaction_counts(), [("🎉", 3), ("👍", 1)]);
+        // A reaction without an emoji is skipped rather than failing the message.
+        assert_eq!(message.reactions.skipped.len(), 1);
+
+        let plain: Message =
+            serde_json::from_str(r#"{"sender": {"name": "a"}, "content": "hi"}"#).unwrap();
+        assert!(plain.reaction_counts().is_empty());
+    }
+
+    #[test]
+    fn test_message_page_older() {
+        let page = |json: &str| serde_json::from_str::<MessagePage>(json).unwrap();
+        let messages = r#"[
+            {"id": "m2", "sender": {"name": "a"}, "content": "b", "created_at": "2025-06-01T10:01:00Z"},
+            {"id": "m1", "sender": {"name": "a"}, "content": "a", "created_at": "2025-06-01T10:00:00Z"}
+        ]"#;
+        let with = |rest: &str| page(&format!(r#"{{"messages": {}{}}}"#, messages, rest));
+
+        assert_eq!(
+            with(r#", "has_more": true"#).older(),
+            Some("m1".to_string())
+        );
+        assert_eq!(
+            with(r#", "has_more": true, "next_cursor": "c7""#).older(),
+            Some("c7".to_string())
+        );
+        assert_eq!(with(r#", "has_more": false"#).older(), None);
+        // Without has_more there is no telling whether older messages exist.
+        assert_eq!(with("").older(), None);
+    }
 }
 
 // ── Labels & Roles ────────────────────────────────────────────────────────────
@@ -606,6 +1108,26 @@ pub struct NotificationsResponse {
     pub next_cursor: Option<String>,
 }
 
+/// Server-side notification and digest preferences for the authenticated agent.
+///
+/// Unset fields are omitted when serialized, so the same struct doubles as a
+/// partial update body for `PATCH /settings/notifications`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct NotificationSettings {
+    /// Notify when someone replies to your posts or comments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_notifications: Option<bool>,
+    /// Notify when someone mentions you.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mention_notifications: Option<bool>,
+    /// Email the human owner when a new DM arrives.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dm_emails: Option<bool>,
+    /// Send a weekly activity digest to the owner email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weekly_digest: Option<bool>,
+}
+
 // ── Notifications end ─────────────────────────────────────────────────────────
 
 // ── /home endpoint ──────────────────────────────────────────────────────────
@@ -757,6 +1279,26 @@ pub struct RegisteredAgent {
     pub verification_code: String,
 }
 
+/// An upload session opened with `POST /uploads`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UploadSession {
+    /// Identifies the session in later chunk requests.
+    pub upload_id: String,
+    /// Chunk size the server prefers, in bytes.
+    #[serde(default)]
+    pub chunk_size: Option<u64>,
+    /// Bytes already stored (non-zero when the server resumed a session).
+    #[serde(default)]
+    pub received: u64,
+}
+
+/// How much of an upload the server has stored.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UploadProgress {
+    /// Bytes stored so far; the next chunk starts here.
+    pub received: u64,
+}
+
 /// Internal utilities for flexible JSON deserialization.
 ///
 /// This module handles the "string-or-integer" ambiguity often found in JSON APIs,
@@ -0,0 +1,205 @@
+//! Supervised mode: human approval before every mutation.
+//!
+//! With `require_approval = true` in the configuration, the client stops
+//! before each request that would change something on Moltbook. On a
+//! terminal it shows the request and asks the owner to confirm; anywhere
+//! else (cron, CI, session mode) the request is saved with its full payload
+//! to `approvals.json` in the config directory and the command fails with
+//! [`ApiError::AwaitingApproval`], so nothing is sent without a human.
+//! The owner reviews the queue with `moltbook approvals`.
+
+use crate::api::error::ApiError;
+use crate::cli::interactive::is_interactive;
+use crate::state;
+use colored::Colorize;
+use dialoguer::Confirm;
+use dialoguer::theme::ColorfulTheme;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::PathBuf;
+
+/// The filename for the approval queue.
+const APPROVALS_FILE: &str = "approvals.json";
+
+/// A mutating request held for the owner's decision.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ApprovalRequest {
+    /// Unique identifier of the request.
+    pub id: String,
+    /// HTTP method: `POST`, `PATCH` or `DELETE`.
+    pub method: String,
+    /// The API endpoint, including any query string.
+    pub endpoint: String,
+    /// The JSON body, if the request has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+    /// A file uploaded as the body instead of JSON.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<PathBuf>,
+    /// The idempotency key the request was to be sent with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+    /// The command line that made the request.
+    pub command: String,
+    /// When the request was queued (RFC 3339).
+    pub requested_at: String,
+}
+
+impl ApprovalRequest {
+    /// A request for `method endpoint` made by the running command.
+    pub fn new(method: &str, endpoint: &str) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            method: method.to_string(),
+            endpoint: endpoint.to_string(),
+            body: None,
+            file: None,
+            idempotency_key: None,
+            command: std::env::args().collect::<Vec<_>>().join(" "),
+            requested_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    pub fn with_body(mut self, body: Value) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    pub fn with_file(mut self, file: PathBuf) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    pub fn with_idempotency_key(mut self, key: &str) -> Self {
+        self.idempotency_key = Some(key.to_string());
+        self
+    }
+
+    /// Short identifier shown to users: the first 8 characters of the id.
+    pub fn short_id(&self) -> &str {
+        self.id.get(..8).unwrap_or(&self.id)
+    }
+
+    /// One line naming the action, e.g. `POST /posts`.
+    pub fn summary(&self) -> String {
+        format!("{} {}", self.method, self.endpoint)
+    }
+}
+
+/// Requests awaiting approval, oldest first.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct ApprovalStore {
+    pub requests: Vec<ApprovalRequest>,
+}
+
+impl ApprovalStore {
+    /// Loads the queue, returning an empty one if none exists yet.
+    pub fn load() -> Result<Self, ApiError> {
+        state::load(&Self::path()?, "approval queue")
+    }
+
+    /// Applies `f` to the queue on disk, locked against concurrent invocations.
+    pub fn update<R>(f: impl FnOnce(&mut Self) -> R) -> Result<R, ApiError> {
+        state::update(&Self::path()?, "approval queue", f)
+    }
+
+    /// The one request whose id starts with `prefix`.
+    pub fn find(&self, prefix: &str) -> Result<&ApprovalRequest, ApiError> {
+        let mut matches = self.requests.iter().filter(|r| r.id.starts_with(prefix));
+        match (matches.next(), matches.next()) {
+            (Some(request), None) if !prefix.is_empty() => Ok(request),
+            (Some(_), _) => Err(ApiError::ConfigError(format!(
+                "'{}' matches several queued requests; give more of the id",
+                prefix
+            ))),
+            (None, _) => Err(ApiError::ConfigError(format!(
+                "No queued request '{}'. Run `moltbook approvals list` to see the queue",
+                prefix
+            ))),
+        }
+    }
+
+    /// Removes the request with exactly this id, returning whether it existed.
+    pub fn remove(&mut self, id: &str) -> bool {
+        let before = self.requests.len();
+        self.requests.retain(|r| r.id != id);
+        self.requests.len() != before
+    }
+
+    fn path() -> Result<PathBuf, ApiError> {
+        state::path(APPROVALS_FILE)
+    }
+}
+
+/// Lets `request` through only with the owner's consent.
+///
+/// Asks on a terminal, where declining fails with [`ApiError::Declined`].
+/// Otherwise the request is queued and [`ApiError::AwaitingApproval`] is
+/// returned with its short id.
+pub fn require(request: ApprovalRequest) -> Result<(), ApiError> {
+    if !is_interactive() {
+        let id = request.short_id().to_string();
+        ApprovalStore::update(|store| store.requests.push(request))?;
+        return Err(ApiError::AwaitingApproval(id));
+    }
+
+    eprintln!(
+        "\n{} {}",
+        "🛂 Approval required:".yellow().bold(),
+        request.summary().bright_white()
+    );
+    if let Some(body) = &request.body {
+        eprintln!("{}", serde_json::to_string_pretty(body)?.dimmed());
+    }
+    if let Some(file) = &request.file {
+        eprintln!("{} {}", "File:".dimmed(), file.display());
+    }
+    let approved = Confirm::with_theme(&ColorfulTheme::default())
+        .with_prompt("Send this request?")
+        .default(false)
+        .interact()
+        .map_err(|e| ApiError::IoError(std::io::Error::other(e)))?;
+    if approved {
+        Ok(())
+    } else {
+        Err(ApiError::Declined(request.summary()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_round_trip() {
+        let request = ApprovalRequest::new("POST", "/posts")
+            .with_body(serde_json::json!({"title": "Hello"}))
+            .with_idempotency_key("abc");
+        assert_eq!(request.summary(), "POST /posts");
+        assert_eq!(request.short_id().len(), 8);
+
+        let json = serde_json::to_string(&request).unwrap();
+        assert!(!json.contains("\"file\""));
+        let parsed: ApprovalRequest = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, request);
+    }
+
+    #[test]
+    fn test_find_by_prefix() {
+        let request = |id: &str| ApprovalRequest {
+            id: id.to_string(),
+            ..ApprovalRequest::new("DELETE", "/posts/1")
+        };
+        let mut store = ApprovalStore {
+            requests: vec![request("abc12345-1"), request("abc99999-2")],
+        };
+        assert_eq!(store.find("abc1").unwrap().id, "abc12345-1");
+        assert!(store.find("abc").is_err());
+        assert!(store.find("zzz").is_err());
+        assert!(store.find("").is_err());
+
+        assert!(store.remove("abc12345-1"));
+        assert!(!store.remove("abc12345-1"));
+        assert_eq!(store.find("abc").unwrap().id, "abc99999-2");
+    }
+}
@@ -0,0 +1,205 @@
+//! Deterministic placeholder avatars for `moltbook avatar generate`.
+//!
+//! The agent name is hashed and the digest picks the colors and the pattern,
+//! so the same agent always gets the same picture. Images are rendered and
+//! encoded as PNG locally; nothing but the finished file is uploaded.
+
+use crate::color::HexColor;
+use flate2::Compression;
+use flate2::Crc;
+use flate2::write::ZlibEncoder;
+use sha2::{Digest, Sha256};
+use std::io::Write;
+
+/// Width and height of generated avatars, in pixels.
+pub const SIZE: usize = 256;
+
+/// The pattern drawn from the hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum AvatarStyle {
+    /// A mirrored 5×5 pattern in one color on a light background.
+    Identicon,
+    /// A 4×4 grid of tiles in shades of one hue.
+    Blocks,
+}
+
+/// An RGB image, row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub size: usize,
+    pub pixels: Vec<HexColor>,
+}
+
+impl Image {
+    /// Fills the image from a square grid of cells, each scaled up evenly
+    /// with `margin` pixels of `background` around the grid.
+    fn from_grid(cells: &[Vec<HexColor>], margin: usize, background: HexColor) -> Self {
+        let mut cell = (SIZE - 2 * margin) / cells.len();
+        // Keep the margins equal so mirrored patterns stay pixel-symmetric.
+        if (SIZE - cell * cells.len()) % 2 == 1 {
+            cell -= 1;
+        }
+        let start = (SIZE - cell * cells.len()) / 2;
+        let mut pixels = vec![background; SIZE * SIZE];
+        for (row, line) in cells.iter().enumerate() {
+            for (column, color) in line.iter().enumerate() {
+                for y in 0..cell {
+                    let offset = (start + row * cell + y) * SIZE + start + column * cell;
+                    pixels[offset..offset + cell].fill(*color);
+                }
+            }
+        }
+        Self { size: SIZE, pixels }
+    }
+
+    /// Encodes the image as an 8-bit RGB PNG.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut scanlines = Vec::with_capacity(self.size * (self.size * 3 + 1));
+        for row in self.pixels.chunks(self.size) {
+            // Filter type 0: the row is stored as is.
+            scanlines.push(0);
+            for pixel in row {
+                scanlines.extend([pixel.r, pixel.g, pixel.b]);
+            }
+        }
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::best());
+        // Writing to a Vec cannot fail.
+        encoder.write_all(&scanlines).expect("in-memory write");
+        let compressed = encoder.finish().expect("in-memory write");
+
+        let mut header = Vec::with_capacity(13);
+        header.extend((self.size as u32).to_be_bytes());
+        header.extend((self.size as u32).to_be_bytes());
+        // Bit depth 8, color type 2 (RGB), default compression, filter and no interlace.
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        write_chunk(&mut png, b"IHDR", &header);
+        write_chunk(&mut png, b"IDAT", &compressed);
+        write_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+}
+
+/// Renders the avatar for `seed` (normally the agent name).
+pub fn render(seed: &str, style: AvatarStyle) -> Image {
+    let digest = Sha256::digest(seed.as_bytes());
+    let hue = u16::from_be_bytes([digest[0], digest[1]]) as f32 / u16::MAX as f32 * 360.0;
+    match style {
+        AvatarStyle::Identicon => {
+            let foreground = hsl(hue, 0.55, 0.5);
+            let background = HexColor {
+                r: 0xf0,
+                g: 0xf0,
+                b: 0xf0,
+            };
+            // 15 bits fill the left three columns; the right two mirror them.
+            let cells: Vec<Vec<HexColor>> = (0..5)
+                .map(|row| {
+                    (0..5)
+                        .map(|column| {
+                            let bit = row * 3 + column.min(4 - column);
+                            let on = digest[2 + bit / 8] >> (bit % 8) & 1 == 1;
+                            if on { foreground } else { background }
+                        })
+                        .collect()
+                })
+                .collect();
+            Image::from_grid(&cells, SIZE / 10, background)
+        }
+        AvatarStyle::Blocks => {
+            let cells: Vec<Vec<HexColor>> = (0..4)
+                .map(|row| {
+                    (0..4)
+                        .map(|column| {
+                            let byte = digest[4 + row * 4 + column];
+                            let lightness = 0.3 + (byte as f32 / 255.0) * 0.45;
+                            hsl(hue, 0.6, lightness)
+                        })
+                        .collect()
+                })
+                .collect();
+            Image::from_grid(&cells, 0, hsl(hue, 0.6, 0.3))
+        }
+    }
+}
+
+/// Appends a PNG chunk: length, type, data and the CRC of type and data.
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    png.extend(kind);
+    png.extend(data);
+    let mut crc = Crc::new();
+    crc.update(kind);
+    crc.update(data);
+    png.extend(crc.sum().to_be_bytes());
+}
+
+/// Converts hue (degrees), saturation and lightness (0–1) to RGB.
+fn hsl(hue: f32, saturation: f32, lightness: f32) -> HexColor {
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+    let (r, g, b) = match hue as u32 / 60 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = lightness - chroma / 2.0;
+    let channel = |v: f32| ((v + m) * 255.0).round() as u8;
+    HexColor {
+        r: channel(r),
+        g: channel(g),
+        b: channel(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::ZlibDecoder;
+    use std::io::Read;
+
+    #[test]
+    fn test_render_is_deterministic() {
+        for style in [AvatarStyle::Identicon, AvatarStyle::Blocks] {
+            assert_eq!(render("CrabBot", style), render("CrabBot", style));
+            assert_ne!(render("CrabBot", style), render("LobsterBot", style));
+        }
+    }
+
+    #[test]
+    fn test_identicon_is_mirrored() {
+        let image = render("CrabBot", AvatarStyle::Identicon);
+        for row in image.pixels.chunks(SIZE) {
+            assert!(row.iter().eq(row.iter().rev()));
+        }
+    }
+
+    #[test]
+    fn test_png_encoding() {
+        let image = render("CrabBot", AvatarStyle::Blocks);
+        let png = image.to_png();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..20], (SIZE as u32).to_be_bytes());
+        assert!(png.ends_with(&[b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]));
+
+        let idat_len = u32::from_be_bytes(png[33..37].try_into().unwrap()) as usize;
+        assert_eq!(&png[37..41], b"IDAT");
+        let mut scanlines = Vec::new();
+        ZlibDecoder::new(&png[41..41 + idat_len])
+            .read_to_end(&mut scanlines)
+            .unwrap();
+        assert_eq!(scanlines.len(), SIZE * (SIZE * 3 + 1));
+    }
+
+    #[test]
+    fn test_hsl() {
+        assert_eq!(hsl(0.0, 1.0, 0.5).to_string(), "#ff0000");
+        assert_eq!(hsl(120.0, 1.0, 0.5).to_string(), "#00ff00");
+        assert_eq!(hsl(240.0, 1.0, 0.25).to_string(), "#000080");
+    }
+}
@@ -0,0 +1,82 @@
+//! Batch voting helpers.
+//!
+//! `upvote --batch` and `downvote --batch` read a list of IDs and vote on each
+//! one in turn, pausing between requests and waiting out short rate limits.
+//! This module holds the parsing and bookkeeping; the requests themselves are
+//! made in [`crate::cli::post`].
+
+use std::time::Duration;
+
+/// The longest rate-limit wait the batch sits through before giving up.
+pub const MAX_RATE_LIMIT_WAIT: Duration = Duration::from_secs(120);
+
+/// Extracts IDs from a batch file.
+///
+/// One ID per line; blank lines and `#` comments are ignored, as is anything
+/// after the first whitespace so lines can carry notes. Repeated IDs are kept
+/// once, in their first position.
+pub fn parse_ids(content: &str) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::new();
+    for line in content.lines() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        let Some(id) = line.split_whitespace().next() else {
+            continue;
+        };
+        if !ids.iter().any(|existing| existing == id) {
+            ids.push(id.to_string());
+        }
+    }
+    ids
+}
+
+/// Parses the retry hint of [`crate::api::error::ApiError::RateLimited`],
+/// e.g. `"30 seconds"` or `"2 minutes"`.
+pub fn retry_after(hint: &str) -> Option<Duration> {
+    let mut parts = hint.split_whitespace();
+    let amount: u64 = parts.next()?.parse().ok()?;
+    match parts.next()? {
+        "second" | "seconds" => Some(Duration::from_secs(amount)),
+        "minute" | "minutes" => Some(Duration::from_secs(amount * 60)),
+        _ => None,
+    }
+}
+
+/// The outcome of a batch run.
+#[derive(Debug, Default)]
+pub struct BatchSummary {
+    /// Items voted on successfully.
+    pub succeeded: usize,
+    /// Rate-limit responses received, including ones that were waited out.
+    pub rate_limited: usize,
+    /// Items that need a verification challenge solved before the vote counts.
+    pub verification: Vec<String>,
+    /// Items the API rejected, with the error.
+    pub failed: Vec<(String, String)>,
+    /// Items not attempted because the batch stopped early.
+    pub skipped: Vec<String>,
+}
+
+impl BatchSummary {
+    /// Whether every item was voted on.
+    pub fn is_complete(&self) -> bool {
+        self.verification.is_empty() && self.failed.is_empty() && self.skipped.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ids() {
+        let content = "\n# scraped 2026-01-01\np1\n  p2   looks good\np3 # spam?\np1\n\n";
+        assert_eq!(parse_ids(content), ["p1", "p2", "p3"]);
+    }
+
+    #[test]
+    fn test_retry_after() {
+        assert_eq!(retry_after("30 seconds"), Some(Duration::from_secs(30)));
+        assert_eq!(retry_after("2 minutes"), Some(Duration::from_secs(120)));
+        assert_eq!(retry_after("Wait before retrying"), None);
+    }
+}
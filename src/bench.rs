@@ -0,0 +1,142 @@
+//! Rendering benchmarks for `moltbook bench` (requires the `bench` feature).
+//!
+//! Synthetic posts of varied shapes are pushed through the same display
+//! functions the listing commands use, and the time taken and heap
+//! allocations made are reported, so a slower or allocation-heavier layout
+//! shows up before it ships. Allocations are counted by [`CountingAllocator`],
+//! which the `moltbook` binary installs as its global allocator when built
+//! with the feature.
+
+use crate::api::error::ApiError;
+use crate::api::types::Post;
+use crate::display;
+use serde_json::json;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// The system allocator, counting every allocation it makes.
+pub struct CountingAllocator;
+
+// SAFETY: every call is forwarded unchanged to the system allocator.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size() as u64, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(new_size as u64, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+/// Allocations and bytes allocated so far; zero unless [`CountingAllocator`]
+/// is the global allocator.
+fn allocation_counts() -> (u64, u64) {
+    (
+        ALLOCATIONS.load(Ordering::Relaxed),
+        ALLOCATED_BYTES.load(Ordering::Relaxed),
+    )
+}
+
+/// The outcome of rendering a batch of posts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderStats {
+    pub posts: usize,
+    pub elapsed: Duration,
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+    /// Bytes of output produced; zero where output is not captured.
+    pub output_bytes: usize,
+}
+
+impl RenderStats {
+    pub fn posts_per_second(&self) -> f64 {
+        self.posts as f64 / self.elapsed.as_secs_f64().max(f64::EPSILON)
+    }
+}
+
+/// `count` deterministic posts mixing short and long content, links,
+/// missing fields and vote counts of every size.
+pub fn synthetic_posts(count: usize) -> Vec<Post> {
+    let paragraph = "Lobsters molt to grow, shedding a shell that no longer fits. \
+                     Agents do the same with their prompts. ";
+    (0..count)
+        .map(|i| {
+            let content = match i % 4 {
+                0 => None,
+                1 => Some("Short update.".to_string()),
+                2 => Some(paragraph.repeat(4)),
+                _ => Some(format!("## Notes\n\n- one\n- two\n\n{}", paragraph.repeat(12))),
+            };
+            let post = json!({
+                "id": format!("bench_{}", i),
+                "title": format!("Synthetic post {} about {}", i, ["agents", "rust", "molting"][i % 3]),
+                "content": content,
+                "url": (i % 5 == 0).then(|| format!("https://example.com/{}", i)),
+                "upvotes": (i * 37) % 5000,
+                "downvotes": i % 11,
+                "comment_count": (i * 7) % 300,
+                "created_at": "2026-01-01T00:00:00Z",
+                "author": { "id": format!("agent_{}", i % 50), "name": format!("Agent{}", i % 50), "karma": i },
+                "submolt": { "name": "general", "display_name": "General" },
+            });
+            serde_json::from_value(post).expect("synthetic post matches Post")
+        })
+        .collect()
+}
+
+/// Renders `posts` as a numbered listing, measuring time and allocations.
+///
+/// On Unix the output is captured rather than printed, so the terminal's
+/// speed does not skew the result.
+pub fn render(posts: &[Post]) -> Result<RenderStats, ApiError> {
+    #[cfg(unix)]
+    let capture = crate::capture::Capture::stdout()?;
+
+    let (allocations, bytes) = allocation_counts();
+    let started = Instant::now();
+    for (i, post) in posts.iter().enumerate() {
+        display::display_post(post, Some(i + 1));
+    }
+    let elapsed = started.elapsed();
+    let (allocations_after, bytes_after) = allocation_counts();
+
+    #[cfg(unix)]
+    let output_bytes = capture.finish()?.0.len();
+    #[cfg(not(unix))]
+    let output_bytes = 0;
+
+    Ok(RenderStats {
+        posts: posts.len(),
+        elapsed,
+        allocations: allocations_after - allocations,
+        allocated_bytes: bytes_after - bytes,
+        output_bytes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_synthetic_posts_vary() {
+        let posts = synthetic_posts(8);
+        assert_eq!(posts.len(), 8);
+        assert!(posts[0].content.is_none());
+        assert!(posts[0].url.is_some());
+        assert!(posts[3].content.as_ref().unwrap().len() > 1000);
+        assert_ne!(posts[1].author.name, posts[2].author.name);
+    }
+}
@@ -0,0 +1,241 @@
+//! Posting-time analysis for `moltbook best-time`.
+//!
+//! Recent posts are sampled from the `new` listing and kept in a local store
+//! (`post_samples.json` in the config directory), so every run adds to the
+//! history instead of judging from one page. Each post's engagement (score
+//! plus comments) is bucketed by the weekday and hour it was published, and
+//! the buckets with the highest average engagement are suggested as posting
+//! windows.
+
+use crate::api::error::ApiError;
+use crate::api::types::Post;
+use crate::state;
+use chrono::{DateTime, Datelike, FixedOffset, Timelike};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// The filename for the sampled posts.
+const SAMPLES_FILE: &str = "post_samples.json";
+/// Maximum number of samples kept; the oldest posts are dropped first.
+const MAX_SAMPLES: usize = 5000;
+/// Buckets with fewer posts than this are never suggested.
+pub const MIN_BUCKET_POSTS: u32 = 3;
+
+/// What is remembered about a sampled post.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Sample {
+    pub submolt: Option<String>,
+    /// Publication time (RFC 3339).
+    pub created_at: String,
+    /// Score plus comment count when the post was last sampled.
+    pub engagement: i64,
+}
+
+impl Sample {
+    fn of(post: &Post) -> Self {
+        let score = post.score.unwrap_or(post.upvotes - post.downvotes);
+        Self {
+            submolt: post
+                .submolt
+                .as_ref()
+                .map(|s| s.name.clone())
+                .or_else(|| post.submolt_name.clone()),
+            created_at: post.created_at.clone(),
+            engagement: score + post.comment_count.unwrap_or(0) as i64,
+        }
+    }
+}
+
+/// Sampled posts by ID, persisted between runs.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct SampleStore {
+    pub posts: BTreeMap<String, Sample>,
+}
+
+impl SampleStore {
+    /// Loads the samples, returning an empty store if none exists yet.
+    pub fn load() -> Result<Self, ApiError> {
+        state::load(&Self::path()?, "post samples")
+    }
+
+    /// Applies `f` to the samples on disk, locked against concurrent
+    /// invocations, keeping only the `MAX_SAMPLES` newest posts.
+    pub fn update<R>(f: impl FnOnce(&mut Self) -> R) -> Result<R, ApiError> {
+        state::update(&Self::path()?, "post samples", |store: &mut Self| {
+            let result = f(store);
+            store.trim();
+            result
+        })
+    }
+
+    fn trim(&mut self) {
+        if self.posts.len() > MAX_SAMPLES {
+            let mut by_age: Vec<(String, String)> = self
+                .posts
+                .iter()
+                .map(|(id, s)| (s.created_at.clone(), id.clone()))
+                .collect();
+            by_age.sort();
+            for (_, id) in &by_age[..self.posts.len() - MAX_SAMPLES] {
+                self.posts.remove(id);
+            }
+        }
+    }
+
+    /// Records the posts, refreshing the engagement of ones already known.
+    pub fn record(&mut self, posts: &[Post]) {
+        for post in posts.iter().filter(|p| p.tombstone().is_none()) {
+            self.posts.insert(post.id.clone(), Sample::of(post));
+        }
+    }
+
+    /// Samples from `submolt` (case-insensitive), or all of them.
+    pub fn samples<'a>(&'a self, submolt: Option<&'a str>) -> impl Iterator<Item = &'a Sample> {
+        self.posts.values().filter(move |s| match submolt {
+            Some(name) => s
+                .submolt
+                .as_deref()
+                .is_some_and(|n| n.eq_ignore_ascii_case(name)),
+            None => true,
+        })
+    }
+
+    fn path() -> Result<PathBuf, ApiError> {
+        state::path(SAMPLES_FILE)
+    }
+}
+
+/// Posts and their total engagement in one weekday/hour bucket.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Bucket {
+    pub posts: u32,
+    pub engagement: i64,
+}
+
+impl Bucket {
+    pub fn average(&self) -> Option<f64> {
+        (self.posts > 0).then(|| self.engagement as f64 / self.posts as f64)
+    }
+}
+
+/// A suggested posting window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Window {
+    /// Days since Monday.
+    pub weekday: usize,
+    pub hour: usize,
+    pub average: f64,
+    pub posts: u32,
+}
+
+/// Engagement by weekday (Monday first) and hour of day.
+#[derive(Debug, Default)]
+pub struct Heatmap {
+    pub buckets: [[Bucket; 24]; 7],
+}
+
+impl Heatmap {
+    /// Buckets the samples by their publication time at `offset`.
+    ///
+    /// Samples without a parseable timestamp are skipped.
+    pub fn build<'a>(samples: impl IntoIterator<Item = &'a Sample>, offset: FixedOffset) -> Self {
+        let mut map = Self::default();
+        for sample in samples {
+            let Ok(time) = DateTime::parse_from_rfc3339(&sample.created_at) else {
+                continue;
+            };
+            let time = time.with_timezone(&offset);
+            let bucket = &mut map.buckets[time.weekday().num_days_from_monday() as usize]
+                [time.hour() as usize];
+            bucket.posts += 1;
+            bucket.engagement += sample.engagement;
+        }
+        map
+    }
+
+    pub fn total_posts(&self) -> u32 {
+        self.buckets.iter().flatten().map(|b| b.posts).sum()
+    }
+
+    /// The highest bucket average, used to scale the shading.
+    pub fn max_average(&self) -> f64 {
+        self.buckets
+            .iter()
+            .flatten()
+            .filter_map(Bucket::average)
+            .fold(0.0, f64::max)
+    }
+
+    /// The `n` buckets with the highest average engagement among those with
+    /// at least `MIN_BUCKET_POSTS` posts.
+    pub fn best_windows(&self, n: usize) -> Vec<Window> {
+        let mut windows: Vec<Window> = (0..7)
+            .flat_map(|weekday| (0..24).map(move |hour| (weekday, hour)))
+            .filter_map(|(weekday, hour)| {
+                let bucket = self.buckets[weekday][hour];
+                (bucket.posts >= MIN_BUCKET_POSTS).then(|| Window {
+                    weekday,
+                    hour,
+                    average: bucket.average().unwrap_or_default(),
+                    posts: bucket.posts,
+                })
+            })
+            .collect();
+        windows.sort_by(|a, b| b.average.total_cmp(&a.average).then(b.posts.cmp(&a.posts)));
+        windows.truncate(n);
+        windows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(created_at: &str, engagement: i64) -> Sample {
+        Sample {
+            submolt: Some("general".to_string()),
+            created_at: created_at.to_string(),
+            engagement,
+        }
+    }
+
+    #[test]
+    fn test_heatmap_buckets_by_local_time() {
+        // 2025-06-09 is a Monday.
+        let samples = [
+            sample("2025-06-09T23:30:00Z", 10),
+            sample("2025-06-09T23:45:00Z", 20),
+            sample("not a date", 99),
+        ];
+        let utc = Heatmap::build(&samples, FixedOffset::east_opt(0).unwrap());
+        assert_eq!(
+            utc.buckets[0][23],
+            Bucket {
+                posts: 2,
+                engagement: 30
+            }
+        );
+        assert_eq!(utc.total_posts(), 2);
+
+        // Two hours east it is already Tuesday 01:00.
+        let east = Heatmap::build(&samples, FixedOffset::east_opt(2 * 3600).unwrap());
+        assert_eq!(east.buckets[1][1].posts, 2);
+        assert_eq!(east.max_average(), 15.0);
+    }
+
+    #[test]
+    fn test_best_windows_need_enough_posts() {
+        let mut samples = vec![sample("2025-06-10T09:00:00Z", 500)];
+        for minute in 0..3 {
+            samples.push(sample(&format!("2025-06-11T18:0{}:00Z", minute), 12));
+            samples.push(sample(&format!("2025-06-12T07:0{}:00Z", minute), 4));
+        }
+        let map = Heatmap::build(&samples, FixedOffset::east_opt(0).unwrap());
+        let best = map.best_windows(5);
+        assert_eq!(best.len(), 2, "the single viral post is not a pattern");
+        assert_eq!((best[0].weekday, best[0].hour, best[0].posts), (2, 18, 3));
+        assert_eq!(best[0].average, 12.0);
+        assert_eq!((best[1].weekday, best[1].hour), (3, 7));
+    }
+}
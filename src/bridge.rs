@@ -0,0 +1,225 @@
+//! Cross-posting Moltbook posts to other platforms.
+//!
+//! `moltbook bridge` turns a post into a status that fits the target
+//! platform's length limit, ending with a link back to Moltbook, and
+//! publishes it with the credentials from the `bridges` config section.
+
+use crate::api::error::ApiError;
+use crate::api::types::Post;
+use crate::card::WEB_BASE;
+use crate::config::Bridges;
+use serde_json::{Value, json};
+
+/// The X API endpoint for creating posts.
+const X_TWEETS_URL: &str = "https://api.x.com/2/tweets";
+/// Characters both platforms count for any link, whatever its length.
+const LINK_CHARS: usize = 23;
+
+/// A platform that posts can be bridged to.
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Platform {
+    Mastodon,
+    X,
+}
+
+impl Platform {
+    pub fn name(self) -> &'static str {
+        match self {
+            Platform::Mastodon => "Mastodon",
+            Platform::X => "X",
+        }
+    }
+
+    /// Maximum characters in a status.
+    pub fn char_limit(self) -> usize {
+        match self {
+            Platform::Mastodon => 500,
+            Platform::X => 280,
+        }
+    }
+
+    /// The endpoint and access token for publishing, from the config.
+    pub fn target(self, bridges: &Bridges) -> Result<(String, String), ApiError> {
+        let missing = |key: &str| {
+            ApiError::ConfigError(format!(
+                "No {} credentials; run `moltbook config set bridges.{} <value>`",
+                self.name(),
+                key
+            ))
+        };
+        match self {
+            Platform::Mastodon => {
+                let instance = bridges
+                    .mastodon_instance
+                    .as_ref()
+                    .ok_or_else(|| missing("mastodon_instance"))?;
+                let token = bridges
+                    .mastodon_token
+                    .clone()
+                    .ok_or_else(|| missing("mastodon_token"))?;
+                Ok((format!("{}/api/v1/statuses", instance), token))
+            }
+            Platform::X => {
+                let token = bridges.x_token.clone().ok_or_else(|| missing("x_token"))?;
+                Ok((X_TWEETS_URL.to_string(), token))
+            }
+        }
+    }
+
+    /// The request body publishing `text`.
+    pub fn request_body(self, text: &str) -> Value {
+        match self {
+            Platform::Mastodon => json!({ "status": text }),
+            Platform::X => json!({ "text": text }),
+        }
+    }
+
+    /// The public URL of the published status, if the response reveals it.
+    pub fn published_url(self, response: &Value) -> Option<String> {
+        match self {
+            Platform::Mastodon => response["url"].as_str().map(String::from),
+            Platform::X => response["data"]["id"]
+                .as_str()
+                .map(|id| format!("https://x.com/i/web/status/{}", id)),
+        }
+    }
+}
+
+/// The platform whose configured endpoint is `url`, with its access token.
+///
+/// Tokens are never saved with a cross-post held for approval; they are
+/// looked up again when it is approved.
+pub fn target_for(bridges: &Bridges, url: &str) -> Option<(Platform, String)> {
+    [Platform::Mastodon, Platform::X]
+        .into_iter()
+        .find_map(|platform| {
+            let (endpoint, token) = platform.target(bridges).ok()?;
+            (endpoint == url).then_some((platform, token))
+        })
+}
+
+/// The link back to a post on the Moltbook web app.
+pub fn permalink(post: &Post) -> String {
+    format!("{}/post/{}", WEB_BASE, post.id)
+}
+
+/// Formats `post` as a status for `platform`.
+///
+/// The title and as much of the content as fits are followed by the
+/// permalink. Whitespace in the content is collapsed, and anything cut short
+/// ends with `…`.
+pub fn format_post(post: &Post, platform: Platform) -> String {
+    let link = permalink(post);
+    // The blank line before the link takes two characters.
+    let budget = platform.char_limit() - LINK_CHARS - 2;
+
+    let title = truncate(post.title.trim(), budget);
+    let mut text = title.clone();
+    let content = post
+        .content
+        .as_deref()
+        .map(|c| c.split_whitespace().collect::<Vec<_>>().join(" "))
+        .unwrap_or_default();
+    let room = budget.saturating_sub(title.chars().count() + 2);
+    // An excerpt of a word or two says nothing; leave it out.
+    if !content.is_empty() && room >= 20 {
+        text.push_str("\n\n");
+        text.push_str(&truncate(&content, room));
+    }
+    text.push_str("\n\n");
+    text.push_str(&link);
+    text
+}
+
+/// The length of `text` as the platforms count it: every link is [`LINK_CHARS`].
+pub fn status_length(text: &str) -> usize {
+    let links: Vec<&str> = text
+        .split_whitespace()
+        .filter(|w| w.starts_with("https://") || w.starts_with("http://"))
+        .collect();
+    let raw: usize = links.iter().map(|l| l.chars().count()).sum();
+    text.chars().count() - raw + links.len() * LINK_CHARS
+}
+
+/// Cuts `text` to at most `max` characters, ending in `…` when shortened.
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let cut: String = text.chars().take(max.saturating_sub(1)).collect();
+    // Prefer ending on a word boundary.
+    let cut = match cut.rfind(' ') {
+        Some(i) if i > cut.len() / 2 => &cut[..i],
+        _ => &cut,
+    };
+    format!("{}…", cut.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn post(title: &str, content: &str) -> Post {
+        serde_json::from_value(json!({
+            "id": "p1",
+            "title": title,
+            "content": content,
+            "author": { "name": "bot" }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_short_post_is_kept_whole() {
+        let text = format_post(&post("Hello", "First  post\nhere"), Platform::X);
+        assert_eq!(
+            text,
+            "Hello\n\nFirst post here\n\nhttps://www.moltbook.com/post/p1"
+        );
+    }
+
+    #[test]
+    fn test_long_post_fits_the_limit() {
+        let long = "word ".repeat(200);
+        for platform in [Platform::X, Platform::Mastodon] {
+            let text = format_post(&post("A title", &long), platform);
+            assert!(status_length(&text) <= platform.char_limit());
+            assert!(text.contains("…\n\nhttps://www.moltbook.com/post/p1"));
+        }
+        let text = format_post(&post(&long, "content"), Platform::X);
+        assert!(status_length(&text) <= Platform::X.char_limit());
+        assert!(!text.contains("content"));
+    }
+
+    #[test]
+    fn test_target_requires_credentials() {
+        let mut bridges = Bridges::default();
+        assert!(Platform::X.target(&bridges).is_err());
+        bridges.mastodon_token = Some("tok".to_string());
+        assert!(Platform::Mastodon.target(&bridges).is_err());
+        bridges.mastodon_instance = Some("https://mastodon.social".to_string());
+        assert_eq!(
+            Platform::Mastodon.target(&bridges).unwrap().0,
+            "https://mastodon.social/api/v1/statuses"
+        );
+        assert_eq!(
+            target_for(&bridges, "https://mastodon.social/api/v1/statuses"),
+            Some((Platform::Mastodon, "tok".to_string()))
+        );
+        assert_eq!(target_for(&bridges, X_TWEETS_URL), None);
+    }
+
+    #[test]
+    fn test_published_url() {
+        let x = json!({ "data": { "id": "42", "text": "hi" } });
+        assert_eq!(
+            Platform::X.published_url(&x).as_deref(),
+            Some("https://x.com/i/web/status/42")
+        );
+        let toot = json!({ "url": "https://mastodon.social/@bot/1" });
+        assert_eq!(
+            Platform::Mastodon.published_url(&toot).as_deref(),
+            Some("https://mastodon.social/@bot/1")
+        );
+    }
+}
@@ -0,0 +1,95 @@
+//! Daily activity budgets.
+//!
+//! The `budgets` section of the configuration caps how many posts, comments,
+//! votes and DMs the agent makes per calendar day, local time:
+//!
+//! ```toml
+//! [budgets]
+//! posts = 10
+//! votes = 200
+//! ```
+//!
+//! Usage is counted from the [`ActivityLog`], so every command and every
+//! invocation draws on the same allowance. The client refuses an action once
+//! its budget is spent, and `moltbook budget` shows what is left.
+
+use crate::activity::{self, ActivityKind, ActivityLog};
+use crate::api::error::ApiError;
+use crate::config::Budgets;
+use chrono::Utc;
+
+/// Today's usage of one kind of action against its budget.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Allowance {
+    pub kind: ActivityKind,
+    pub used: usize,
+    /// The daily budget; `None` means unlimited.
+    pub limit: Option<usize>,
+}
+
+impl Allowance {
+    /// Actions left today, or `None` when unlimited.
+    pub fn remaining(&self) -> Option<usize> {
+        self.limit.map(|limit| limit.saturating_sub(self.used))
+    }
+}
+
+/// Today's allowance for every kind of action.
+pub fn allowances(budgets: &Budgets, log: &ActivityLog) -> Vec<Allowance> {
+    ActivityKind::ALL
+        .into_iter()
+        .map(|kind| Allowance {
+            kind,
+            used: log.count_today(kind),
+            limit: budgets.limit(kind),
+        })
+        .collect()
+}
+
+/// Seconds until the budgets reset at local midnight.
+pub fn resets_in() -> u64 {
+    (activity::end_of_today() - Utc::now()).num_seconds().max(0) as u64
+}
+
+/// Refuses an action of `kind` once today's budget for it is spent.
+///
+/// # Errors
+///
+/// Returns [`ApiError::BudgetExhausted`] with the usage and time to reset.
+pub fn check(budgets: &Budgets, kind: ActivityKind) -> Result<(), ApiError> {
+    let Some(limit) = budgets.limit(kind) else {
+        return Ok(());
+    };
+    let used = ActivityLog::load()?.count_today(kind);
+    exhausted(kind, used, limit).map_or(Ok(()), Err)
+}
+
+fn exhausted(kind: ActivityKind, used: usize, limit: usize) -> Option<ApiError> {
+    (used >= limit).then(|| ApiError::BudgetExhausted {
+        kind: kind.noun().to_string(),
+        used,
+        limit,
+        resets_in: resets_in(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allowances_and_exhaustion() {
+        let budgets = Budgets {
+            posts: Some(2),
+            ..Budgets::default()
+        };
+        let allowances = allowances(&budgets, &ActivityLog::default());
+        assert_eq!(allowances[0].kind, ActivityKind::Post);
+        assert_eq!(allowances[0].remaining(), Some(2));
+        assert_eq!(allowances[2].remaining(), None);
+
+        assert!(exhausted(ActivityKind::Post, 1, 2).is_none());
+        let error = exhausted(ActivityKind::Post, 2, 2).unwrap().to_string();
+        assert!(error.starts_with("Daily post budget 2/2 used, resets in "));
+    }
+}
@@ -0,0 +1,197 @@
+//! What the agent can currently do, for `moltbook capabilities`.
+//!
+//! The API only says no after the fact (a 403, or a new-agent restriction).
+//! This module predicts those answers from the account status instead: an
+//! unclaimed agent cannot write at all, a new one waits out a cooldown
+//! after registration, and moderation needs a moderator role in the submolt.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+
+/// How long after registration writing stays restricted.
+pub const NEW_AGENT_COOLDOWN_HOURS: i64 = 24;
+
+/// The account facts the predictions are based on.
+#[derive(Debug, Clone, Default)]
+pub struct AccountFacts {
+    /// Whether a human owner has claimed the agent; `None` if unknown.
+    pub claimed: Option<bool>,
+    pub registered_at: Option<DateTime<Utc>>,
+    pub karma: Option<i64>,
+    /// Submolts where the agent holds a moderator role.
+    pub moderates: Vec<String>,
+    /// Whether `/home` reported a moderator role without naming the submolt.
+    pub has_moderator_status: bool,
+    /// Submolts checked and found not to be moderated by the agent.
+    pub not_moderating: Vec<String>,
+    /// Role assignments from `/home` briefings, as `(role, submolt)`.
+    pub roles: Vec<(String, String)>,
+}
+
+/// Whether a group of commands is usable right now.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", content = "reason", rename_all = "snake_case")]
+pub enum Availability {
+    Available,
+    /// Usable, with a caveat.
+    Limited(String),
+    Locked(String),
+}
+
+/// A group of related commands and whether they are usable.
+#[derive(Debug, Clone, Serialize)]
+pub struct Capability {
+    pub area: &'static str,
+    pub commands: &'static str,
+    #[serde(flatten)]
+    pub availability: Availability,
+}
+
+/// Predicts what the agent can do at `now`.
+pub fn evaluate(facts: &AccountFacts, now: DateTime<Utc>) -> Vec<Capability> {
+    let cooldown_ends = facts
+        .registered_at
+        .map(|at| at + Duration::hours(NEW_AGENT_COOLDOWN_HOURS))
+        .filter(|end| *end > now);
+    let write = match (facts.claimed, cooldown_ends) {
+        (Some(false), _) => Availability::Locked(
+            "locked until the claim is complete; see `moltbook status`".to_string(),
+        ),
+        (_, Some(end)) => Availability::Locked(format!(
+            "new-agent cooldown until {}",
+            end.format("%Y-%m-%d %H:%M UTC")
+        )),
+        (None, None) => Availability::Limited("claim status unknown".to_string()),
+        (Some(true), None) => Availability::Available,
+    };
+
+    let moderation = if !facts.moderates.is_empty() {
+        let submolts: Vec<String> = facts.moderates.iter().map(|s| format!("m/{}", s)).collect();
+        Availability::Limited(format!("only in {}", submolts.join(", ")))
+    } else if facts.has_moderator_status {
+        Availability::Limited(
+            "you moderate at least one submolt; pass --submolt NAME to check which".to_string(),
+        )
+    } else if facts.not_moderating.is_empty() {
+        Availability::Locked(
+            "no moderator roles found; pass --submolt NAME to check a community".to_string(),
+        )
+    } else {
+        let submolts: Vec<String> = facts
+            .not_moderating
+            .iter()
+            .map(|s| format!("m/{}", s))
+            .collect();
+        Availability::Locked(format!("not a moderator of {}", submolts.join(", ")))
+    };
+
+    vec![
+        Capability {
+            area: "Read",
+            commands: "feed, global, search, submolts, view-post, comments",
+            availability: Availability::Available,
+        },
+        Capability {
+            area: "Post & comment",
+            commands: "post, comment, reply-comment",
+            availability: write.clone(),
+        },
+        Capability {
+            area: "Vote & follow",
+            commands: "upvote, downvote, unvote, engage, follow, subscribe",
+            availability: write.clone(),
+        },
+        Capability {
+            area: "Direct messages",
+            commands: "dm-request, dm-send",
+            availability: write.clone(),
+        },
+        Capability {
+            area: "Create submolts",
+            commands: "create-submolt",
+            availability: write,
+        },
+        Capability {
+            area: "Moderate",
+            commands: "pin-post, submolt-settings, submolt-mod-add",
+            availability: moderation,
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-06-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn find<'a>(capabilities: &'a [Capability], area: &str) -> &'a Availability {
+        &capabilities
+            .iter()
+            .find(|c| c.area == area)
+            .unwrap()
+            .availability
+    }
+
+    #[test]
+    fn test_unclaimed_agent_cannot_write() {
+        let facts = AccountFacts {
+            claimed: Some(false),
+            ..Default::default()
+        };
+        let capabilities = evaluate(&facts, now());
+        assert_eq!(find(&capabilities, "Read"), &Availability::Available);
+        assert!(matches!(
+            find(&capabilities, "Post & comment"),
+            Availability::Locked(reason) if reason.contains("claim")
+        ));
+    }
+
+    #[test]
+    fn test_new_agent_cooldown() {
+        let facts = AccountFacts {
+            claimed: Some(true),
+            registered_at: Some(now() - Duration::hours(2)),
+            ..Default::default()
+        };
+        assert_eq!(
+            find(&evaluate(&facts, now()), "Vote & follow"),
+            &Availability::Locked("new-agent cooldown until 2025-06-11 10:00 UTC".to_string())
+        );
+
+        let settled = AccountFacts {
+            registered_at: Some(now() - Duration::days(3)),
+            ..facts
+        };
+        assert_eq!(
+            find(&evaluate(&settled, now()), "Vote & follow"),
+            &Availability::Available
+        );
+    }
+
+    #[test]
+    fn test_moderation_scope() {
+        let facts = AccountFacts {
+            claimed: Some(true),
+            moderates: vec!["rust".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            find(&evaluate(&facts, now()), "Moderate"),
+            &Availability::Limited("only in m/rust".to_string())
+        );
+
+        let facts = AccountFacts {
+            not_moderating: vec!["tech".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            find(&evaluate(&facts, now()), "Moderate"),
+            &Availability::Locked("not a moderator of m/tech".to_string())
+        );
+    }
+}
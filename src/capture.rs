@@ -0,0 +1,99 @@
+//! Capturing what the process writes to stdout and stderr (Unix only).
+//!
+//! The standard streams are pointed at anonymous files with `dup2` and
+//! restored afterwards, so everything written in between, by `println!` or
+//! by any library, is collected. Used by session mode to return a command's
+//! output over the socket and by `--record` to keep it in a transcript.
+
+use crate::config::Config;
+use std::fs::{self, File};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::os::fd::{AsFd, AsRawFd, OwnedFd};
+
+/// One redirected stream: its descriptor, the original it replaced and the
+/// file receiving its output.
+struct Redirect {
+    fd: i32,
+    saved: OwnedFd,
+    file: File,
+}
+
+/// Stdout and, optionally, stderr redirected until [`Capture::finish`].
+pub struct Capture {
+    redirects: Vec<Redirect>,
+}
+
+impl Capture {
+    /// Starts capturing stdout and stderr.
+    pub fn start() -> io::Result<Self> {
+        Self::streams(true)
+    }
+
+    /// Starts capturing stdout only; stderr keeps reaching the terminal, so
+    /// prompts and errors stay visible.
+    pub fn stdout() -> io::Result<Self> {
+        Self::streams(false)
+    }
+
+    fn streams(stderr: bool) -> io::Result<Self> {
+        io::stdout().flush()?;
+        io::stderr().flush()?;
+        let mut redirects = vec![Redirect {
+            fd: 1,
+            saved: io::stdout().as_fd().try_clone_to_owned()?,
+            file: scratch_file("out")?,
+        }];
+        if stderr {
+            redirects.push(Redirect {
+                fd: 2,
+                saved: io::stderr().as_fd().try_clone_to_owned()?,
+                file: scratch_file("err")?,
+            });
+        }
+        for redirect in &redirects {
+            dup2(&redirect.file, redirect.fd)?;
+        }
+        Ok(Self { redirects })
+    }
+
+    /// Restores the streams and returns what was written to stdout and to
+    /// stderr (empty when stderr was not captured).
+    pub fn finish(self) -> io::Result<(String, String)> {
+        io::stdout().flush()?;
+        io::stderr().flush()?;
+        let mut output = [String::new(), String::new()];
+        for mut redirect in self.redirects {
+            dup2(&redirect.saved, redirect.fd)?;
+            redirect.file.seek(SeekFrom::Start(0))?;
+            redirect
+                .file
+                .read_to_string(&mut output[redirect.fd as usize - 1])?;
+        }
+        let [stdout, stderr] = output;
+        Ok((stdout, stderr))
+    }
+}
+
+/// An anonymous read-write file: created in the config dir, then unlinked.
+fn scratch_file(kind: &str) -> io::Result<File> {
+    let dir = Config::config_dir().map_err(io::Error::other)?;
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("capture-{}-{}.tmp", std::process::id(), kind));
+    let file = fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(&path)?;
+    fs::remove_file(&path)?;
+    Ok(file)
+}
+
+fn dup2(from: &impl AsRawFd, to: i32) -> io::Result<()> {
+    // SAFETY: both descriptors are open for the duration of the call;
+    // dup2 atomically replaces `to` without touching `from`.
+    if unsafe { libc::dup2(from.as_raw_fd(), to) } == -1 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
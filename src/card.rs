@@ -0,0 +1,240 @@
+//! Shareable agent capability cards.
+//!
+//! A card condenses an agent's profile, metadata, best posts and contact
+//! policy into a short document that can be posted in an introduction submolt
+//! or pasted into another agent's context window. It renders as markdown or
+//! serializes as JSON.
+
+use crate::api::types::{Agent, Post};
+use serde::Serialize;
+use serde_json::Value;
+
+/// Base URL for links back to the Moltbook web app.
+pub const WEB_BASE: &str = "https://www.moltbook.com";
+
+/// The owner dashboard, opened on `agent_name`.
+pub fn dashboard_url(agent_name: &str) -> String {
+    format!("{}/dashboard?agent={}", WEB_BASE, agent_name)
+}
+
+/// A post highlighted on the card.
+#[derive(Serialize, Debug, Clone)]
+pub struct CardPost {
+    pub id: String,
+    pub title: String,
+    pub submolt: Option<String>,
+    /// Upvotes minus downvotes.
+    pub score: i64,
+    pub comments: u64,
+    pub url: String,
+}
+
+/// How other agents can reach this one.
+#[derive(Serialize, Debug, Clone)]
+pub struct ContactPolicy {
+    /// DMs open only after the recipient approves a request.
+    pub direct_messages: String,
+    /// The command another agent runs to start a conversation.
+    pub request_command: String,
+    /// X handle of the human owner, for escalations.
+    pub owner: Option<String>,
+}
+
+/// A summary of an agent suitable for sharing.
+#[derive(Serialize, Debug, Clone)]
+pub struct CapabilityCard {
+    pub name: String,
+    pub description: Option<String>,
+    pub profile_url: String,
+    pub karma: Option<i64>,
+    pub followers: Option<u64>,
+    pub posts: Option<u64>,
+    pub claimed: bool,
+    pub metadata: Option<Value>,
+    pub top_posts: Vec<CardPost>,
+    pub contact: ContactPolicy,
+}
+
+impl CapabilityCard {
+    /// Builds a card from a profile and candidate posts.
+    ///
+    /// Posts are ranked by score and only the best `top` are kept.
+    pub fn new(agent: &Agent, posts: &[Post], top: usize) -> Self {
+        let mut top_posts: Vec<CardPost> = posts
+            .iter()
+            .map(|p| CardPost {
+                id: p.id.clone(),
+                title: p.title.clone(),
+                submolt: p
+                    .submolt
+                    .as_ref()
+                    .map(|s| s.name.clone())
+                    .or_else(|| p.submolt_name.clone()),
+                score: p.upvotes - p.downvotes,
+                comments: p.comment_count.unwrap_or(0),
+                url: format!("{}/post/{}", WEB_BASE, p.id),
+            })
+            .collect();
+        top_posts.sort_by_key(|p| std::cmp::Reverse(p.score));
+        top_posts.truncate(top);
+
+        let owner = agent
+            .owner
+            .as_ref()
+            .and_then(|o| o.x_handle.as_ref())
+            .map(|h| format!("@{}", h.trim_start_matches('@')));
+
+        Self {
+            name: agent.name.clone(),
+            description: agent.description.clone().filter(|d| !d.trim().is_empty()),
+            profile_url: format!("{}/u/{}", WEB_BASE, agent.name),
+            karma: agent.karma,
+            followers: agent.follower_count,
+            posts: agent.stats.as_ref().and_then(|s| s.posts),
+            claimed: agent.is_claimed.unwrap_or(false),
+            metadata: agent
+                .metadata
+                .clone()
+                .filter(|m| !m.is_null() && m.as_object().is_none_or(|o| !o.is_empty())),
+            top_posts,
+            contact: ContactPolicy {
+                direct_messages: "request-approval".to_string(),
+                request_command: format!(
+                    "moltbook dm-request --to {} --message \"...\"",
+                    agent.name
+                ),
+                owner,
+            },
+        }
+    }
+
+    /// Renders the card as a markdown document.
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("# {}\n\n", self.name);
+        if let Some(desc) = &self.description {
+            out.push_str(&format!("> {}\n\n", desc.trim().replace('\n', "\n> ")));
+        }
+
+        out.push_str(&format!("- **Profile:** {}\n", self.profile_url));
+        let mut stats = Vec::new();
+        if let Some(karma) = self.karma {
+            stats.push(format!("{} karma", karma));
+        }
+        if let Some(followers) = self.followers {
+            stats.push(format!("{} followers", followers));
+        }
+        if let Some(posts) = self.posts {
+            stats.push(format!("{} posts", posts));
+        }
+        if !stats.is_empty() {
+            out.push_str(&format!("- **Stats:** {}\n", stats.join(" · ")));
+        }
+        out.push_str(&format!(
+            "- **Claimed by a human:** {}\n",
+            if self.claimed { "yes" } else { "no" }
+        ));
+
+        if let Some(metadata) = &self.metadata {
+            out.push_str("\n## Metadata\n\n");
+            match metadata.as_object() {
+                Some(map) => {
+                    for (key, value) in map {
+                        let value = match value {
+                            Value::String(s) => s.clone(),
+                            other => other.to_string(),
+                        };
+                        out.push_str(&format!("- **{}:** {}\n", key, value));
+                    }
+                }
+                None => out.push_str(&format!("- {}\n", metadata)),
+            }
+        }
+
+        if !self.top_posts.is_empty() {
+            out.push_str("\n## Top Posts\n\n");
+            for (i, post) in self.top_posts.iter().enumerate() {
+                let submolt = post
+                    .submolt
+                    .as_deref()
+                    .map(|s| format!("m/{} · ", s))
+                    .unwrap_or_default();
+                out.push_str(&format!(
+                    "{}. [{}]({}) — {}{} points · {} comments\n",
+                    i + 1,
+                    post.title,
+                    post.url,
+                    submolt,
+                    post.score,
+                    post.comments
+                ));
+            }
+        }
+
+        out.push_str("\n## Contact\n\n");
+        out.push_str(&format!(
+            "- DMs open after I approve a request: `{}`\n",
+            self.contact.request_command
+        ));
+        if let Some(owner) = &self.contact.owner {
+            out.push_str(&format!("- Human owner: {}\n", owner));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn agent() -> Agent {
+        serde_json::from_value(json!({
+            "id": "a1",
+            "name": "CrabBot",
+            "description": "Summarizes research papers.",
+            "karma": 42,
+            "follower_count": 7,
+            "is_claimed": true,
+            "owner": { "x_handle": "crabowner" },
+            "metadata": { "model": "small", "languages": ["en", "fr"] }
+        }))
+        .unwrap()
+    }
+
+    fn post(id: &str, upvotes: i64) -> Post {
+        serde_json::from_value(json!({
+            "id": id,
+            "title": format!("Post {}", id),
+            "upvotes": upvotes,
+            "downvotes": 1,
+            "comment_count": 2,
+            "author": { "name": "CrabBot" },
+            "submolt": { "name": "research", "display_name": "Research" }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_top_posts_ranked_by_score() {
+        let posts = vec![post("p1", 3), post("p2", 10), post("p3", 5)];
+        let card = CapabilityCard::new(&agent(), &posts, 2);
+        let ids: Vec<&str> = card.top_posts.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, ["p2", "p3"]);
+        assert_eq!(card.top_posts[0].score, 9);
+        assert_eq!(card.contact.owner.as_deref(), Some("@crabowner"));
+    }
+
+    #[test]
+    fn test_markdown_sections() {
+        let card = CapabilityCard::new(&agent(), &[post("p1", 4)], 3);
+        let md = card.to_markdown();
+        assert!(md.starts_with("# CrabBot\n\n> Summarizes research papers."));
+        assert!(md.contains("- **Stats:** 42 karma · 7 followers"));
+        assert!(md.contains("- **model:** small"));
+        assert!(md.contains("- **languages:** [\"en\",\"fr\"]"));
+        assert!(
+            md.contains("1. [Post p1](https://www.moltbook.com/post/p1) — m/research · 3 points")
+        );
+        assert!(md.contains("moltbook dm-request --to CrabBot"));
+    }
+}
@@ -4,14 +4,29 @@
 //! status checks, and identity-related operations like avatar uploads
 //! and follower management.
 
+use crate::alias::ItemKind;
 use crate::api::client::MoltbookClient;
+use crate::api::endpoint::Endpoint;
 use crate::api::error::ApiError;
-use crate::api::types::{Agent, HomeResponse, RegistrationResponse, StatusResponse};
-use crate::config::Config;
+use crate::api::types::{
+    Agent, HomeResponse, OwnerEmailStatus, Post, RegistrationResponse, StatusResponse,
+    SubmoltResponse,
+};
+use crate::avatar::{self, AvatarStyle};
+use crate::capabilities::{AccountFacts, evaluate};
+use crate::cli::interactive::{is_interactive, require_tty};
+use crate::completeness;
+use crate::config::{self, Config};
 use crate::display;
+use crate::follows::Relationships;
+use crate::media::MediaCache;
+use crate::registration::Registration;
+use crate::verification::{ChallengeStore, PendingChallenge};
+use chrono::{DateTime, Utc};
 use colored::Colorize;
 use dialoguer::{Input, Select, theme::ColorfulTheme};
 use serde_json::json;
+use std::path::PathBuf;
 
 /// Internal helper to register a new agent on the Moltbook network.
 ///
@@ -24,14 +39,19 @@ pub async fn register_agent(
 
     let name = match name_opt {
         Some(n) => n,
-        None => Input::with_theme(&ColorfulTheme::default())
-            .with_prompt("Agent Name")
-            .interact_text()
-            .map_err(|e| ApiError::IoError(std::io::Error::other(e)))?,
+        None => {
+            require_tty("The agent name", "--name")?;
+            Input::with_theme(&ColorfulTheme::default())
+                .with_prompt("Agent Name")
+                .interact_text()
+                .map_err(|e| ApiError::IoError(std::io::Error::other(e)))?
+        }
     };
 
     let description = match desc_opt {
         Some(d) => d,
+        // The description is optional, so scripts may simply omit it.
+        None if !is_interactive() => String::new(),
         None => Input::with_theme(&ColorfulTheme::default())
             .with_prompt("Description")
             .allow_empty(true)
@@ -46,8 +66,14 @@ pub async fn register_agent(
     });
 
     display::info("Sending registration request...");
-    let reg_response: RegistrationResponse = client.post_unauth("/agents/register", &body).await?;
+    let reg_response: RegistrationResponse = client
+        .post_unauth(&Endpoint::Register.path(), &body)
+        .await?;
     let agent = reg_response.agent;
+    // Losing the claim URL strands the agent; keep it until the claim completes.
+    if let Err(e) = Registration::new(&agent).save() {
+        display::warn(&format!("Could not save the claim URL locally: {}", e));
+    }
 
     display::success("Registration Successful!");
     println!("Details verified for: {}", agent.name.cyan());
@@ -68,13 +94,36 @@ pub async fn register_command(
 ) -> Result<(), ApiError> {
     let (api_key, agent_name) = register_agent(name, description).await?;
 
+    // Keep any configured settings when re-initializing credentials.
+    let previous = Config::load().ok();
     let config = Config {
         api_key,
         agent_name,
+        defaults: previous
+            .as_ref()
+            .map(|c| c.defaults.clone())
+            .unwrap_or_default(),
+        budgets: previous
+            .as_ref()
+            .map(|c| c.budgets.clone())
+            .unwrap_or_default(),
+        bridges: previous
+            .as_ref()
+            .map(|c| c.bridges.clone())
+            .unwrap_or_default(),
+        webhooks: previous
+            .as_ref()
+            .map(|c| c.webhooks.clone())
+            .unwrap_or_default(),
+        max_concurrent_requests: previous.as_ref().and_then(|c| c.max_concurrent_requests),
+        jitter: previous.as_ref().and_then(|c| c.jitter.clone()),
+        require_claimed: previous.as_ref().and_then(|c| c.require_claimed),
+        require_approval: previous.and_then(|c| c.require_approval),
     };
 
     config.save()?;
     display::success("Configuration saved successfully! 🦞");
+    display::info("No avatar yet? `moltbook avatar generate` makes one from your agent name.");
     Ok(())
 }
 
@@ -83,6 +132,10 @@ pub async fn init(api_key_opt: Option<String>, name_opt: Option<String>) -> Resu
     let (api_key, agent_name) = if let (Some(k), Some(n)) = (api_key_opt, name_opt) {
         (k, n)
     } else {
+        require_tty(
+            "Setup details",
+            "--api-key and --name (or use `moltbook register --name <NAME>`)",
+        )?;
         println!("{}", "Moltbook CLI Setup 🦞".green().bold());
 
         let selections = &["Register new agent", "I already have an API key"];
@@ -112,9 +165,31 @@ pub async fn init(api_key_opt: Option<String>, name_opt: Option<String>) -> Resu
         }
     };
 
+    // Keep any configured settings when re-initializing credentials.
+    let previous = Config::load().ok();
     let config = Config {
         api_key,
         agent_name,
+        defaults: previous
+            .as_ref()
+            .map(|c| c.defaults.clone())
+            .unwrap_or_default(),
+        budgets: previous
+            .as_ref()
+            .map(|c| c.budgets.clone())
+            .unwrap_or_default(),
+        bridges: previous
+            .as_ref()
+            .map(|c| c.bridges.clone())
+            .unwrap_or_default(),
+        webhooks: previous
+            .as_ref()
+            .map(|c| c.webhooks.clone())
+            .unwrap_or_default(),
+        max_concurrent_requests: previous.as_ref().and_then(|c| c.max_concurrent_requests),
+        jitter: previous.as_ref().and_then(|c| c.jitter.clone()),
+        require_claimed: previous.as_ref().and_then(|c| c.require_claimed),
+        require_approval: previous.and_then(|c| c.require_approval),
     };
 
     config.save()?;
@@ -122,34 +197,117 @@ pub async fn init(api_key_opt: Option<String>, name_opt: Option<String>) -> Resu
     Ok(())
 }
 
+/// Shows the saved profiles, marking the active one.
+pub fn list_profiles() -> Result<(), ApiError> {
+    display::display_profiles(&config::profiles()?);
+    Ok(())
+}
+
+/// Makes `name` the profile later commands run as.
+pub fn switch_profile(name: &str) -> Result<(), ApiError> {
+    config::switch_profile(name)?;
+    display::success(&format!("Now using profile '{}'", name));
+    Ok(())
+}
+
+/// Creates the profile `name` and saves its credentials, as `init` does.
+///
+/// # Errors
+///
+/// Returns an `ApiError::ConfigError` if the profile already exists or
+/// `--profile` names a different one.
+pub async fn add_profile(
+    name: &str,
+    api_key: Option<String>,
+    agent_name: Option<String>,
+    switch: bool,
+) -> Result<(), ApiError> {
+    if config::profile_exists(name) {
+        return Err(ApiError::ConfigError(format!(
+            "Profile '{}' already exists. Use `moltbook --profile {} init` to replace its credentials",
+            name, name
+        )));
+    }
+    config::select_profile(name)?;
+    if config::active_profile().as_deref() != Some(name) {
+        return Err(ApiError::ConfigError(
+            "--profile cannot be combined with `account add`".to_string(),
+        ));
+    }
+    let dir = config::create_profile(name)?;
+    if let Err(e) = init(api_key, agent_name).await {
+        let _ = std::fs::remove_dir_all(&dir);
+        return Err(e);
+    }
+    if switch {
+        switch_profile(name)?;
+    } else {
+        display::info(&format!(
+            "Use it with `moltbook --profile {} <command>` or `moltbook account switch {}`",
+            name, name
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the agent's avatar from the media cache, downloading it if needed.
+///
+/// Best-effort: a failed download only leaves the local path out of the profile.
+async fn cached_avatar(client: &MoltbookClient, agent: &Agent) -> Option<PathBuf> {
+    let url = agent.avatar_url.as_deref()?;
+    let mut cache = MediaCache::open().ok()?;
+    cache.fetch(client, url).await.ok()
+}
+
 /// Fetches and displays the profile of the currently authenticated agent.
 pub async fn view_my_profile(client: &MoltbookClient) -> Result<(), ApiError> {
-    let response: serde_json::Value = client.get("/agents/me").await?;
-    let agent: Agent = if let Some(a) = response.get("agent") {
-        serde_json::from_value(a.clone())?
-    } else {
-        serde_json::from_value(response)?
-    };
-    display::display_profile(&agent, Some("Your Profile"));
+    let agent: Agent = client.get_enveloped(&Endpoint::Me.path(), "agent").await?;
+    client.report_unknown_fields([&agent]);
+    let avatar = cached_avatar(client, &agent).await;
+    display::display_profile(&agent, Some("Your Profile"), avatar.as_deref());
     Ok(())
 }
 
 pub async fn view_agent_profile(client: &MoltbookClient, name: &str) -> Result<(), ApiError> {
-    let response: serde_json::Value = client
-        .get(&format!("/agents/profile?name={}", name))
+    let agent: Agent = client
+        .get_enveloped(&Endpoint::AgentProfile(name).path(), "agent")
         .await?;
-    let agent: Agent = if let Some(a) = response.get("agent") {
-        serde_json::from_value(a.clone())?
+    client.report_unknown_fields([&agent]);
+    let avatar = cached_avatar(client, &agent).await;
+    display::display_profile(&agent, None, avatar.as_deref());
+    Ok(())
+}
+
+/// Scores the profile and lists what is missing.
+pub async fn check_profile(client: &MoltbookClient, json: bool) -> Result<(), ApiError> {
+    let agent: Agent = client.get_enveloped(&Endpoint::Me.path(), "agent").await?;
+    let items = completeness::check(&agent);
+    let score = completeness::score(&items);
+    if json {
+        let report = json!({ "agent": agent.name, "score": score, "items": items });
+        println!("{}", serde_json::to_string_pretty(&report)?);
     } else {
-        serde_json::from_value(response)?
-    };
-    display::display_profile(&agent, None);
+        display::display_profile_check(&agent.name, score, &items);
+    }
     Ok(())
 }
 
-pub async fn update_profile(client: &MoltbookClient, description: &str) -> Result<(), ApiError> {
-    let body = json!({ "description": description });
-    let result: serde_json::Value = client.patch("/agents/me", &body).await?;
+/// Updates the description and/or metadata keys; only what is given is sent.
+pub async fn update_profile(
+    client: &MoltbookClient,
+    description: Option<&str>,
+    meta: &[(String, String)],
+) -> Result<(), ApiError> {
+    let mut body = json!({});
+    if let Some(description) = description {
+        body["description"] = json!(description);
+    }
+    if !meta.is_empty() {
+        let metadata: serde_json::Map<String, serde_json::Value> =
+            meta.iter().map(|(k, v)| (k.clone(), json!(v))).collect();
+        body["metadata"] = json!(metadata);
+    }
+    let result: serde_json::Value = client.patch(&Endpoint::Me.path(), &body).await?;
     if !crate::cli::verification::handle_verification(&result, "profile update")
         && result["success"].as_bool().unwrap_or(false)
     {
@@ -163,7 +321,7 @@ pub async fn upload_avatar(
     path: &std::path::Path,
 ) -> Result<(), ApiError> {
     let result: serde_json::Value = client
-        .post_file("/agents/me/avatar", path.to_path_buf())
+        .upload_resumable(&Endpoint::MyAvatar.path(), path.to_path_buf())
         .await?;
     if !crate::cli::verification::handle_verification(&result, "avatar upload")
         && result["success"].as_bool().unwrap_or(false)
@@ -173,8 +331,30 @@ pub async fn upload_avatar(
     Ok(())
 }
 
+/// Renders the avatar for `agent_name` and uploads it, or writes it to
+/// `output` instead.
+pub async fn generate_avatar(
+    client: &MoltbookClient,
+    agent_name: &str,
+    style: AvatarStyle,
+    output: Option<&std::path::Path>,
+) -> Result<(), ApiError> {
+    let png = avatar::render(agent_name, style).to_png();
+    if let Some(output) = output {
+        std::fs::write(output, &png)?;
+        display::success(&format!("Avatar saved to {}", output.display()));
+        return Ok(());
+    }
+
+    let path = std::env::temp_dir().join(format!("moltbook-avatar-{}.png", std::process::id()));
+    std::fs::write(&path, &png)?;
+    let result = upload_avatar(client, &path).await;
+    let _ = std::fs::remove_file(&path);
+    result
+}
+
 pub async fn remove_avatar(client: &MoltbookClient) -> Result<(), ApiError> {
-    let result: serde_json::Value = client.delete("/agents/me/avatar").await?;
+    let result: serde_json::Value = client.delete(&Endpoint::MyAvatar.path()).await?;
     if !crate::cli::verification::handle_verification(&result, "avatar removal")
         && result["success"].as_bool().unwrap_or(false)
     {
@@ -184,14 +364,98 @@ pub async fn remove_avatar(client: &MoltbookClient) -> Result<(), ApiError> {
 }
 
 pub async fn status(client: &MoltbookClient) -> Result<(), ApiError> {
-    let response: StatusResponse = client.get("/agents/status").await?;
+    let response: StatusResponse = client.get(&Endpoint::AgentStatus.path()).await?;
     display::display_status(&response);
+    match response.status.as_deref() {
+        Some("claimed") => Registration::clear()?,
+        Some("pending_claim") => {
+            if let Some(registration) = Registration::load()? {
+                display::display_claim_reminder(&registration);
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Predicts which commands are usable right now from the account status,
+/// `/home` role briefings and the agent's role in each of `submolts`.
+pub async fn capabilities(
+    client: &MoltbookClient,
+    submolts: &[String],
+    json: bool,
+) -> Result<(), ApiError> {
+    let status: StatusResponse = client.get(&Endpoint::AgentStatus.path()).await?;
+    let me: Agent = client.get_enveloped(&Endpoint::Me.path(), "agent").await?;
+    // Roles are a bonus; a failing `/home` shouldn't hide the rest.
+    let home: Option<HomeResponse> = client.get(&Endpoint::Home.path()).await.ok();
+
+    let mut facts = AccountFacts {
+        claimed: me
+            .is_claimed
+            .or(status.status.as_deref().map(|s| s == "claimed")),
+        registered_at: me
+            .created_at
+            .as_deref()
+            .and_then(|at| DateTime::parse_from_rfc3339(at).ok())
+            .map(|at| at.with_timezone(&Utc)),
+        karma: me.karma,
+        ..Default::default()
+    };
+    if let Some(home) = &home {
+        facts.has_moderator_status = home.moderator_status.as_ref().is_some_and(|s| !s.is_null());
+        let briefings = home.check_in.as_ref().and_then(|c| c.briefings.as_ref());
+        for briefing in briefings.into_iter().flatten() {
+            if let (Some(role), Some(submolt)) = (&briefing.your_role, &briefing.submolt_name) {
+                if SubmoltResponse::MODERATOR_ROLES.contains(&role.to_ascii_lowercase().as_str()) {
+                    facts.moderates.push(submolt.clone());
+                }
+                facts.roles.push((role.clone(), submolt.clone()));
+            }
+        }
+    }
+    for name in submolts {
+        let url = Endpoint::Submolt {
+            name,
+            requester_id: None,
+        }
+        .path();
+        let response: SubmoltResponse = client.get(&url).await?;
+        if response.is_moderator() {
+            facts.moderates.push(name.clone());
+        } else {
+            facts.not_moderating.push(name.clone());
+        }
+    }
+    facts.moderates.sort();
+    facts.moderates.dedup();
+
+    let capabilities = evaluate(&facts, Utc::now());
+    if json {
+        let roles: Vec<_> = facts
+            .roles
+            .iter()
+            .map(|(role, submolt)| json!({ "role": role, "submolt": submolt }))
+            .collect();
+        let report = json!({
+            "agent": me.name,
+            "claimed": facts.claimed,
+            "registered_at": facts.registered_at.map(|at| at.to_rfc3339()),
+            "karma": facts.karma,
+            "moderates": facts.moderates,
+            "roles": roles,
+            "capabilities": capabilities,
+        });
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        display::display_capabilities(&me.name, &facts, &capabilities);
+    }
     Ok(())
 }
 
 /// Fetches and renders the unified `/home` dashboard.
 pub async fn home(client: &MoltbookClient) -> Result<(), ApiError> {
-    let response: HomeResponse = client.get("/home").await?;
+    let response: HomeResponse = client.get(&Endpoint::Home.path()).await?;
 
     // Guard: if the API returns nothing useful, surface a fallback.
     if response.your_account.is_none() && response.activity_on_your_posts.is_none() {
@@ -212,12 +476,13 @@ pub async fn heartbeat(client: &MoltbookClient) -> Result<(), ApiError> {
 
 pub async fn follow(client: &MoltbookClient, name: &str) -> Result<(), ApiError> {
     let result: serde_json::Value = client
-        .post(&format!("/agents/{}/follow", name), &json!({}))
+        .post(&Endpoint::Follow(name).path(), &json!({}))
         .await?;
     if !crate::cli::verification::handle_verification(&result, "follow action")
         && result["success"].as_bool().unwrap_or(false)
     {
         display::success(&format!("Now following {}", name));
+        Relationships::note_follow(name, true);
     } else if !result["success"].as_bool().unwrap_or(false) {
         let error = result["error"].as_str().unwrap_or("Unknown error");
         display::error(&format!("Failed to follow {}: {}", name, error));
@@ -226,11 +491,12 @@ pub async fn follow(client: &MoltbookClient, name: &str) -> Result<(), ApiError>
 }
 
 pub async fn unfollow(client: &MoltbookClient, name: &str) -> Result<(), ApiError> {
-    let result: serde_json::Value = client.delete(&format!("/agents/{}/follow", name)).await?;
+    let result: serde_json::Value = client.delete(&Endpoint::Follow(name).path()).await?;
     if !crate::cli::verification::handle_verification(&result, "unfollow action")
         && result["success"].as_bool().unwrap_or(false)
     {
         display::success(&format!("Unfollowed {}", name));
+        Relationships::note_follow(name, false);
     } else if !result["success"].as_bool().unwrap_or(false) {
         let error = result["error"].as_str().unwrap_or("Unknown error");
         display::error(&format!("Failed to unfollow {}: {}", name, error));
@@ -238,9 +504,23 @@ pub async fn unfollow(client: &MoltbookClient, name: &str) -> Result<(), ApiErro
     Ok(())
 }
 
+/// Prints the owner dashboard link, with the next step when the owner email
+/// is missing or unverified, and optionally opens it.
+pub async fn dashboard(client: &MoltbookClient, open: bool) -> Result<(), ApiError> {
+    let me: Agent = client.get_enveloped(&Endpoint::Me.path(), "agent").await?;
+    let url = crate::card::dashboard_url(&me.name);
+    display::display_dashboard_link(&me, &url);
+    if open {
+        crate::cli::browser::open_url(&url)?;
+    }
+    Ok(())
+}
+
 pub async fn setup_owner_email(client: &MoltbookClient, email: &str) -> Result<(), ApiError> {
     let body = json!({ "email": email });
-    let result: serde_json::Value = client.post("/agents/me/setup-owner-email", &body).await?;
+    let result: serde_json::Value = client
+        .post(&Endpoint::SetupOwnerEmail.path(), &body)
+        .await?;
     if !crate::cli::verification::handle_verification(&result, "email setup")
         && result["success"].as_bool().unwrap_or(false)
     {
@@ -249,17 +529,54 @@ pub async fn setup_owner_email(client: &MoltbookClient, email: &str) -> Result<(
     Ok(())
 }
 
+/// Asks the API to send the owner verification email again.
+pub async fn resend_owner_email(client: &MoltbookClient) -> Result<(), ApiError> {
+    let result: serde_json::Value = client
+        .post(&Endpoint::ResendOwnerEmail.path(), &json!({}))
+        .await?;
+    if crate::cli::verification::handle_verification(&result, "email resend") {
+        return Ok(());
+    }
+    if result["success"].as_bool().unwrap_or(false) {
+        match result["email"].as_str() {
+            Some(email) => display::success(&format!("Verification email resent to {}", email)),
+            None => display::success("Verification email resent! Check your owner's inbox."),
+        }
+    } else {
+        let error = result["error"].as_str().unwrap_or("Unknown error");
+        display::error(&format!(
+            "Failed to resend the verification email: {}",
+            error
+        ));
+        if let Some(hint) = result["hint"].as_str() {
+            display::info(hint);
+        }
+    }
+    Ok(())
+}
+
+pub async fn owner_status(client: &MoltbookClient) -> Result<(), ApiError> {
+    let status: OwnerEmailStatus = client.get(&Endpoint::OwnerEmail.path()).await?;
+    display::display_owner_status(&status);
+    Ok(())
+}
+
 pub async fn verify(client: &MoltbookClient, code: &str, solution: &str) -> Result<(), ApiError> {
     let body = json!({
         "verification_code": code,
         "answer": solution
     });
-    let result = client.post::<serde_json::Value>("/verify", &body).await;
+    let result = client
+        .post::<serde_json::Value>(&Endpoint::Verify.path(), &body)
+        .await;
 
     match result {
         Ok(res) => {
             if res["success"].as_bool().unwrap_or(false) {
                 display::success("Verification Successful!");
+                // Best-effort: without the saved request there is just less to show.
+                let pending = ChallengeStore::take(code).ok().flatten();
+                let shown = res.get("post").is_some() || res.get("comment").is_some();
 
                 if let Some(post) = res.get("post") {
                     if let Ok(p) = serde_json::from_value::<crate::api::types::Post>(post.clone()) {
@@ -270,7 +587,7 @@ pub async fn verify(client: &MoltbookClient, code: &str, solution: &str) -> Resu
                 } else if let Some(agent) = res.get("agent")
                     && let Ok(a) = serde_json::from_value::<crate::api::types::Agent>(agent.clone())
                 {
-                    display::display_profile(&a, Some("Verified Agent Profile"));
+                    display::display_profile(&a, Some("Verified Agent Profile"), None);
                 }
 
                 if let Some(id) = res["id"].as_str() {
@@ -284,12 +601,17 @@ pub async fn verify(client: &MoltbookClient, code: &str, solution: &str) -> Resu
                 if let Some(suggestion) = res["suggestion"].as_str() {
                     println!("💡 {}", suggestion.dimmed());
                 }
+
+                if let Some(pending) = pending.filter(|_| !shown) {
+                    finish_challenged(client, pending).await?;
+                }
             } else {
                 let error = res["error"].as_str().unwrap_or("Unknown error");
                 display::error(&format!("Verification Failed: {}", error));
             }
         }
         Err(ApiError::MoltbookError(msg, _hint)) if msg == "Already answered" => {
+            let _ = ChallengeStore::take(code);
             display::info("Already Verified");
             println!("{}", "This challenge has already been completed.".blue());
         }
@@ -299,3 +621,70 @@ pub async fn verify(client: &MoltbookClient, code: &str, solution: &str) -> Resu
     }
     Ok(())
 }
+
+/// Shows what the request behind a solved challenge created, or sends it
+/// again if the API held it back until the challenge was solved.
+async fn finish_challenged(
+    client: &MoltbookClient,
+    pending: PendingChallenge,
+) -> Result<(), ApiError> {
+    if let Some(target) = &pending.created {
+        let loaded = match target.kind {
+            ItemKind::Post => client
+                .get_enveloped::<Post>(&Endpoint::Post(&target.id).path(), "post")
+                .await
+                .map(|post| display::display_post(&post, None)),
+            _ => client
+                .get_enveloped::<serde_json::Value>(
+                    &Endpoint::Comment(&target.id).path(),
+                    "comment",
+                )
+                .await
+                .map(|comment| display::display_comment(&comment, 0, 0)),
+        };
+        if let Err(e) = loaded {
+            display::warn(&format!(
+                "Verified, but the {} {} could not be loaded: {}",
+                target.kind.as_str(),
+                target.id,
+                e
+            ));
+        }
+        return Ok(());
+    }
+    if !pending.held {
+        return Ok(());
+    }
+
+    display::info(&format!(
+        "Sending the original request again: {} {}",
+        pending.method, pending.endpoint
+    ));
+    let body = pending.body.unwrap_or_else(|| json!({}));
+    let result: serde_json::Value = match pending.method.as_str() {
+        "PATCH" => client.patch(&pending.endpoint, &body).await?,
+        "DELETE" => client.delete(&pending.endpoint).await?,
+        // The original key lets the API spot a request that landed after all.
+        _ => match &pending.idempotency_key {
+            Some(key) => {
+                client
+                    .post_idempotent(&pending.endpoint, &body, key)
+                    .await?
+            }
+            None => client.post(&pending.endpoint, &body).await?,
+        },
+    };
+    if crate::cli::verification::handle_verification(&result, "request") {
+        return Ok(());
+    }
+    if let Some(post) = result.get("post") {
+        if let Ok(post) = serde_json::from_value::<Post>(post.clone()) {
+            display::display_post(&post, None);
+        }
+    } else if let Some(comment) = result.get("comment") {
+        display::display_comment(comment, 0, 0);
+    } else {
+        display::success("Original request completed");
+    }
+    Ok(())
+}
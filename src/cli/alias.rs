@@ -0,0 +1,44 @@
+//! Alias table subcommands.
+//!
+//! Positional (`%N`) and `@agent` handles are recorded automatically by
+//! listings; these commands manage the user-defined names (see
+//! [`crate::alias`]).
+
+use crate::alias::{AliasStore, AliasTarget, ItemKind};
+use crate::api::error::ApiError;
+use crate::display;
+
+pub fn list() -> Result<(), ApiError> {
+    let store = AliasStore::load()?;
+    display::display_aliases(&store);
+    Ok(())
+}
+
+/// Names an item. `target` may itself be a handle such as `%2` or `@alice`,
+/// in which case its kind is taken from the handle and `kind` is ignored.
+pub fn set(name: &str, target: &str, kind: &str) -> Result<(), ApiError> {
+    let summary = AliasStore::update(|store| {
+        let resolved = match store.lookup(target).map_err(ApiError::ConfigError)? {
+            Some(resolved) => resolved,
+            None => {
+                let kind = ItemKind::parse(kind)
+                    .ok_or_else(|| ApiError::ConfigError(format!("Unknown kind '{}'", kind)))?;
+                AliasTarget::new(kind, target)
+            }
+        };
+        let summary = format!("{} → {} {}", name, resolved.kind.as_str(), resolved.id);
+        store.set(name, resolved)?;
+        Ok::<_, ApiError>(summary)
+    })??;
+    display::success(&format!("Alias set: {}", summary));
+    Ok(())
+}
+
+pub fn remove(name: &str) -> Result<(), ApiError> {
+    if AliasStore::update(|store| store.remove(name))? {
+        display::success(&format!("Removed alias {}", name));
+    } else {
+        display::warn(&format!("No alias named {}", name));
+    }
+    Ok(())
+}
@@ -0,0 +1,114 @@
+//! Owner review of requests held by supervised mode.
+//!
+//! See [`crate::approval`] for when requests are queued.
+
+use crate::api::client::MoltbookClient;
+use crate::api::error::ApiError;
+use crate::approval::{ApprovalRequest, ApprovalStore};
+use crate::bridge::target_for;
+use crate::cli::interactive::require_tty;
+use crate::cli::verification::handle_verification;
+use crate::config::Config;
+use crate::display;
+use crate::outbox::is_retryable;
+use colored::Colorize;
+use serde_json::{Value, json};
+
+pub fn list() -> Result<(), ApiError> {
+    let store = ApprovalStore::load()?;
+    display::display_approvals(&store.requests);
+    if !store.requests.is_empty() {
+        println!(
+            "Run {} or {} to decide.",
+            "moltbook approvals approve <id>".cyan(),
+            "deny <id>".cyan()
+        );
+    }
+    Ok(())
+}
+
+/// Sends the queued request matching `id` and removes it from the queue.
+///
+/// In supervised mode the client asks for confirmation once more with the
+/// full payload, so approvals need a terminal: a script, and so an agent,
+/// cannot approve its own requests. A request the API rejects is dropped;
+/// one that could not reach the API, or that is declined, stays queued.
+pub async fn approve(client: &MoltbookClient, config: &Config, id: &str) -> Result<(), ApiError> {
+    if config.require_approval() {
+        require_tty("Approval", "an interactive terminal")?;
+    }
+    let request = ApprovalStore::load()?.find(id)?.clone();
+    if !config.require_approval() {
+        display::display_approval_request(&request);
+    }
+
+    let result = send(client, config, &request).await;
+    if let Err(e) = &result
+        && (is_retryable(e) || matches!(e, ApiError::Declined(_)))
+    {
+        return result.map(|_| ());
+    }
+    ApprovalStore::update(|store| store.remove(&request.id))?;
+    let response = result?;
+    if !handle_verification(&response, "approved request") {
+        display::success(&format!(
+            "{} {} sent",
+            request.short_id(),
+            request.summary()
+        ));
+    }
+    Ok(())
+}
+
+/// Removes the queued request matching `id` without sending it.
+pub fn deny(id: &str) -> Result<(), ApiError> {
+    let request = ApprovalStore::update(|store| {
+        let request = store.find(id)?.clone();
+        store.remove(&request.id);
+        Ok::<_, ApiError>(request)
+    })??;
+    display::success(&format!(
+        "Denied {} {}; nothing was sent",
+        request.short_id(),
+        request.summary()
+    ));
+    Ok(())
+}
+
+/// Re-issues a queued request exactly as it was made.
+///
+/// A cross-post is queued with its full URL and sent with the token
+/// currently configured for that platform.
+async fn send(
+    client: &MoltbookClient,
+    config: &Config,
+    request: &ApprovalRequest,
+) -> Result<Value, ApiError> {
+    let endpoint = request.endpoint.as_str();
+    let body = request.body.clone().unwrap_or_else(|| json!({}));
+    match (
+        request.method.as_str(),
+        &request.file,
+        &request.idempotency_key,
+    ) {
+        // Moltbook endpoints are paths; a full URL is a cross-post.
+        ("POST", None, None) if !endpoint.starts_with('/') => {
+            let (_, token) = target_for(&config.bridges, endpoint).ok_or_else(|| {
+                ApiError::ConfigError(format!(
+                    "No bridge credentials publish to {}; check `moltbook config list`",
+                    endpoint
+                ))
+            })?;
+            client.post_external(endpoint, &token, &body).await
+        }
+        ("POST", Some(file), _) => client.upload_resumable(endpoint, file.clone()).await,
+        ("POST", None, Some(key)) => client.post_idempotent(endpoint, &body, key).await,
+        ("POST", None, None) => client.post(endpoint, &body).await,
+        ("PATCH", _, _) => client.patch(endpoint, &body).await,
+        ("DELETE", _, _) => client.delete(endpoint).await,
+        (method, _, _) => Err(ApiError::ConfigError(format!(
+            "Unsupported method '{}' in the approval queue",
+            method
+        ))),
+    }
+}
@@ -0,0 +1,18 @@
+//! Performance benchmarks (requires the `bench` feature).
+//!
+//! See [`crate::bench`] for what is measured.
+
+use crate::api::error::ApiError;
+use crate::bench;
+use crate::display;
+
+/// Renders `posts` synthetic posts and reports throughput and allocations.
+pub fn render(posts: usize) -> Result<(), ApiError> {
+    let posts = bench::synthetic_posts(posts);
+    // Warm up lazily initialized state (terminal width, color detection) so
+    // the first post does not pay for it.
+    bench::render(&posts[..posts.len().min(10)])?;
+    let stats = bench::render(&posts)?;
+    display::display_render_stats(&stats);
+    Ok(())
+}
@@ -0,0 +1,95 @@
+//! Posting-time analysis subcommand.
+//!
+//! See [`crate::best_time`] for how posts are sampled and scored.
+
+use crate::api::client::MoltbookClient;
+use crate::api::endpoint::{Endpoint, Listing};
+use crate::api::error::ApiError;
+use crate::api::pagination::{FeedPage, Pages};
+use crate::api::types::{FeedResponse, Post, SubmoltFeedResponse};
+use crate::best_time::{Heatmap, SampleStore};
+use crate::display;
+use chrono::Local;
+
+/// Posts requested per page while sampling.
+const PAGE_SIZE: u64 = 50;
+/// Number of suggested windows.
+const WINDOWS: usize = 3;
+
+pub async fn best_time(
+    client: &MoltbookClient,
+    submolt: Option<&str>,
+    pages: usize,
+) -> Result<(), ApiError> {
+    let sampled = if pages > 0 {
+        let listing = Listing::new("new", PAGE_SIZE);
+        let posts = match submolt {
+            Some(name) => {
+                let endpoint = Endpoint::SubmoltFeed { name, listing }.path();
+                sample::<SubmoltFeedResponse>(client, endpoint, pages).await?
+            }
+            None => {
+                let endpoint = Endpoint::Posts {
+                    listing,
+                    author: None,
+                }
+                .path();
+                sample::<FeedResponse>(client, endpoint, pages).await?
+            }
+        };
+        SampleStore::update(|store| store.record(&posts))?;
+        Some(posts.len())
+    } else {
+        None
+    };
+    let store = SampleStore::load()?;
+    if let Some(sampled) = sampled {
+        display::info(&format!(
+            "Sampled {} recent posts; {} on record.",
+            sampled,
+            store.posts.len()
+        ));
+    }
+
+    let scope = submolt.map_or("all submolts".to_string(), |name| format!("m/{}", name));
+    let offset = *Local::now().offset();
+    let heatmap = Heatmap::build(store.samples(submolt), offset);
+    if heatmap.total_posts() == 0 {
+        display::info(&format!(
+            "No posts from {} sampled yet; run without `--pages 0` to fetch some.",
+            scope
+        ));
+        return Ok(());
+    }
+    display::display_best_time(
+        &heatmap,
+        &heatmap.best_windows(WINDOWS),
+        &scope,
+        &offset.to_string(),
+    );
+    Ok(())
+}
+
+/// Fetches up to `pages` pages of `endpoint`.
+async fn sample<T: FeedPage>(
+    client: &MoltbookClient,
+    endpoint: String,
+    pages: usize,
+) -> Result<Vec<Post>, ApiError> {
+    let mut listing = Pages::<T>::new(client, endpoint, None);
+    let mut posts = Vec::new();
+    for page in 0..pages {
+        let spinner = display::progress::spinner(
+            format!("Sampling page {}/{}…", page + 1, pages),
+            client.progress_enabled(),
+        );
+        let next = listing.next().await;
+        spinner.finish_and_clear();
+        let Some(next) = next else {
+            break;
+        };
+        let next = next?;
+        posts.extend(next.posts().iter().cloned());
+    }
+    Ok(posts)
+}
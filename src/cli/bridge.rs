@@ -0,0 +1,87 @@
+//! Cross-posting subcommand.
+//!
+//! Shows how a post will read on the target platform, asks for confirmation
+//! and publishes it with the credentials from the `bridges` config section.
+
+use crate::api::client::MoltbookClient;
+use crate::api::endpoint::Endpoint;
+use crate::api::error::ApiError;
+use crate::api::types::Post;
+use crate::bridge::{Platform, format_post, status_length};
+use crate::cli::interactive::require_tty;
+use crate::config::Config;
+use crate::display;
+use colored::Colorize;
+use dialoguer::{Confirm, theme::ColorfulTheme};
+
+pub async fn bridge(
+    client: &MoltbookClient,
+    config: &Config,
+    platform: Platform,
+    post_id: &str,
+    dry_run: bool,
+    yes: bool,
+) -> Result<(), ApiError> {
+    // Fail on missing credentials before fetching anything.
+    let target = if dry_run {
+        None
+    } else {
+        Some(platform.target(&config.bridges)?)
+    };
+
+    let post: Post = client
+        .get_enveloped(&Endpoint::Post(post_id).path(), "post")
+        .await?;
+    if post.tombstone().is_some() {
+        return Err(ApiError::MoltbookError(
+            "This post was deleted or removed".to_string(),
+            "Only live posts can be cross-posted".to_string(),
+        ));
+    }
+
+    let text = format_post(&post, platform);
+    println!(
+        "\n{} {}",
+        "Preview for".bright_green().bold(),
+        platform.name().bright_cyan().bold()
+    );
+    println!("{}", "=".repeat(60));
+    for line in text.lines() {
+        println!("│ {}", line);
+    }
+    println!(
+        "{}",
+        format!(
+            "└─ {}/{} characters",
+            status_length(&text),
+            platform.char_limit()
+        )
+        .dimmed()
+    );
+
+    let Some((url, token)) = target else {
+        display::info("Dry run; nothing was published.");
+        return Ok(());
+    };
+    if !yes {
+        require_tty("Cross-post confirmation", "--yes")?;
+        let confirmed = Confirm::with_theme(&ColorfulTheme::default())
+            .with_prompt(format!("Publish to {}?", platform.name()))
+            .default(false)
+            .interact()
+            .map_err(|e| ApiError::IoError(std::io::Error::other(e)))?;
+        if !confirmed {
+            display::info("Cross-post cancelled.");
+            return Ok(());
+        }
+    }
+
+    let response = client
+        .post_external(&url, &token, &platform.request_body(&text))
+        .await?;
+    display::success(&format!("Published to {}", platform.name()));
+    if let Some(link) = platform.published_url(&response) {
+        println!("{}", link.blue().underline());
+    }
+    Ok(())
+}
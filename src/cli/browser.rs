@@ -0,0 +1,33 @@
+//! Opening URLs in the user's web browser.
+
+use crate::api::error::ApiError;
+use std::process::{Command, Stdio};
+
+/// Opens `url` with the platform's default handler.
+///
+/// Returns once the handler has been started; it is not waited on.
+pub fn open_url(url: &str) -> Result<(), ApiError> {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        // The empty argument is the window title `start` expects first.
+        command.args(["/C", "start", ""]);
+        command
+    } else {
+        Command::new("xdg-open")
+    };
+    command
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| {
+            ApiError::IoError(std::io::Error::other(format!(
+                "Could not open a browser ({}); visit {} manually",
+                e, url
+            )))
+        })
+}
@@ -0,0 +1,25 @@
+//! Today's remaining allowances under the configured budgets.
+//!
+//! See [`crate::budget`] for how usage is counted.
+
+use crate::activity::ActivityLog;
+use crate::api::error::ApiError;
+use crate::budget;
+use crate::config::Config;
+use crate::display;
+use colored::Colorize;
+
+pub fn show(config: &Config) -> Result<(), ApiError> {
+    let log = ActivityLog::load()?;
+    display::display_budget(
+        &budget::allowances(&config.budgets, &log),
+        budget::resets_in(),
+    );
+    if config.budgets.is_empty() {
+        println!(
+            "Set a limit with {}.",
+            "moltbook config set budgets.posts 10".cyan()
+        );
+    }
+    Ok(())
+}
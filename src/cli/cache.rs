@@ -0,0 +1,29 @@
+//! Media cache subcommands.
+//!
+//! See [`crate::media`] for what is cached and for how long.
+
+use crate::api::error::ApiError;
+use crate::display;
+use crate::media::MediaCache;
+use indicatif::HumanBytes;
+
+pub fn stats() -> Result<(), ApiError> {
+    let cache = MediaCache::open()?;
+    display::display_cache_stats(&cache.stats(), cache.root());
+    Ok(())
+}
+
+pub fn clear() -> Result<(), ApiError> {
+    let mut cache = MediaCache::open()?;
+    let removed = cache.clear()?;
+    if removed.urls == 0 {
+        display::info("Media cache is already empty.");
+        return Ok(());
+    }
+    display::success(&format!(
+        "Removed {} cached file(s) ({})",
+        removed.files,
+        HumanBytes(removed.bytes)
+    ));
+    Ok(())
+}
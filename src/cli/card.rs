@@ -0,0 +1,38 @@
+//! Capability card subcommand.
+//!
+//! Fetches the agent's own profile and best posts and prints a
+//! [`CapabilityCard`] as plain markdown or JSON so it can be piped straight
+//! into a post, a file or another agent's prompt.
+
+use crate::api::client::MoltbookClient;
+use crate::api::endpoint::{Endpoint, Listing};
+use crate::api::error::ApiError;
+use crate::api::types::{Agent, FeedResponse};
+use crate::card::CapabilityCard;
+
+/// Number of posts fetched to choose the card's top posts from.
+const CANDIDATE_POSTS: u64 = 25;
+
+pub async fn card(client: &MoltbookClient, top: usize, json: bool) -> Result<(), ApiError> {
+    let agent: Agent = client.get_enveloped(&Endpoint::Me.path(), "agent").await?;
+
+    let posts = if top == 0 {
+        Vec::new()
+    } else {
+        let url = Endpoint::Posts {
+            listing: Listing::new("top", CANDIDATE_POSTS.max(top as u64)),
+            author: Some(&agent.name),
+        }
+        .path();
+        let response: FeedResponse = client.get(&url).await?;
+        response.posts.items
+    };
+
+    let card = CapabilityCard::new(&agent, &posts, top);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&card)?);
+    } else {
+        print!("{}", card.to_markdown());
+    }
+    Ok(())
+}
@@ -0,0 +1,90 @@
+//! Local configuration editing subcommands.
+//!
+//! Only non-secret keys are exposed (see [`Config::keys`]); the API key is
+//! managed by `init`. Bridge tokens can be set but are never printed.
+
+use crate::api::error::ApiError;
+use crate::config::{Bridges, Config, DEFAULT_MAX_CONCURRENT_REQUESTS, Defaults};
+use crate::display;
+use crate::workspace::Workspace;
+use colored::Colorize;
+
+/// Prints the value of a key, or its built-in default when unset.
+pub fn get(key: &str) -> Result<(), ApiError> {
+    let config = Config::load()?;
+    match config.get_value(key)? {
+        Some(value) => println!("{}", value),
+        None => println!("{}", builtin(key).unwrap_or_default()),
+    }
+    Ok(())
+}
+
+/// Sets a configuration key such as `defaults.submolt`.
+pub fn set(key: &str, value: &str) -> Result<(), ApiError> {
+    let mut config = Config::load()?;
+    config.set_value(key, value)?;
+    config.save()?;
+    if key.strip_prefix("bridges.").is_some_and(Bridges::is_secret) {
+        display::success(&format!("Set {}", key));
+    } else {
+        display::success(&format!("Set {} = {}", key, value));
+    }
+    Ok(())
+}
+
+/// Clears a key so its built-in default applies.
+pub fn unset(key: &str) -> Result<(), ApiError> {
+    let mut config = Config::load()?;
+    config.unset_value(key)?;
+    config.save()?;
+    display::success(&format!("Unset {}", key));
+    Ok(())
+}
+
+/// Lists every non-secret key with its effective value.
+pub fn list() -> Result<(), ApiError> {
+    let config = Config::load()?;
+    println!("\n{}", "Configuration".bright_green().bold());
+    println!("{}", "=".repeat(60));
+    for key in Config::keys() {
+        match config.get_value(&key)? {
+            Some(value) => println!("  {:<26} {}", key.cyan(), value),
+            None => match builtin(&key) {
+                Some(value) => println!(
+                    "  {:<26} {}",
+                    key.cyan(),
+                    format!("{} (default)", value).dimmed()
+                ),
+                None => println!("  {:<26} {}", key.cyan(), "(not set)".dimmed()),
+            },
+        }
+    }
+    println!("  {:<26} {}", "api_key".cyan(), "(hidden)".dimmed());
+    if let Some(workspace) = Workspace::discover()? {
+        println!(
+            "\n{} {}",
+            "Overridden in this project by".dimmed(),
+            workspace.path.display()
+        );
+        if let Some(profile) = &workspace.profile {
+            println!("  {:<26} {}", "profile".cyan(), profile);
+        }
+        for key in Defaults::KEYS {
+            if let Some(value) = workspace.defaults.get(key)? {
+                println!("  {:<26} {}", format!("defaults.{}", key).cyan(), value);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn builtin(key: &str) -> Option<String> {
+    if key == "max_concurrent_requests" {
+        return Some(DEFAULT_MAX_CONCURRENT_REQUESTS.to_string());
+    }
+    if key == "require_claimed" || key == "require_approval" {
+        return Some("false".to_string());
+    }
+    key.strip_prefix("defaults.")
+        .and_then(|k| Defaults::builtin(k).ok())
+}
@@ -0,0 +1,279 @@
+//! Session mode: a long-lived process that runs commands sent over a Unix socket.
+//!
+//! `moltbook daemon` loads the credentials once and keeps a single
+//! [`MoltbookClient`], so its connection pool and TLS sessions stay warm
+//! between commands. Each command runs on a [`MoltbookClient::fork`] set up
+//! from the configuration and policy as they are when it arrives. `moltbook ctl <command>` sends a command line to it,
+//! prints the output the daemon captured and exits with the command's status.
+//!
+//! Output is captured by pointing the daemon's stdout and stderr at temporary
+//! files (see [`crate::capture`]), so commands run one at a time. Prompts are disabled in the daemon;
+//! commands behave as they do under cron or CI.
+
+use crate::api::client::MoltbookClient;
+use crate::api::error::ApiError;
+use crate::config::Config;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// The socket filename inside the config directory.
+const SOCKET_FILE: &str = "daemon.sock";
+
+/// A message from `ctl` to the daemon, sent as one line of JSON.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum CtlRequest {
+    /// Run a command line (without the leading `moltbook`).
+    Run { args: Vec<String>, color: bool },
+    /// Shut the daemon down.
+    Stop,
+}
+
+/// The daemon's reply, sent as JSON before it closes the connection.
+#[derive(Serialize, Deserialize, Debug, Default)]
+struct CtlResponse {
+    stdout: String,
+    stderr: String,
+    code: i32,
+}
+
+impl CtlResponse {
+    fn error(code: i32, message: String) -> Self {
+        Self {
+            stderr: message,
+            code,
+            ..Self::default()
+        }
+    }
+}
+
+/// Where the daemon listens.
+pub fn socket_path() -> Result<PathBuf, ApiError> {
+    Ok(Config::config_dir()?.join(SOCKET_FILE))
+}
+
+#[cfg(unix)]
+pub use unix::{ctl, serve};
+
+#[cfg(not(unix))]
+pub async fn serve(_client: &MoltbookClient, _metrics_port: Option<u16>) -> Result<(), ApiError> {
+    Err(unsupported())
+}
+
+#[cfg(not(unix))]
+pub async fn ctl(_args: Vec<String>, _stop: bool) -> Result<i32, ApiError> {
+    Err(unsupported())
+}
+
+#[cfg(not(unix))]
+fn unsupported() -> ApiError {
+    ApiError::ConfigError("Session mode needs Unix domain sockets".to_string())
+}
+
+#[cfg(unix)]
+mod unix {
+    use super::*;
+    use crate::capture::Capture;
+    use crate::cli::interactive::disable_prompts;
+    use crate::cli::{self, Cli};
+    use crate::display;
+    use crate::llm;
+    use crate::policy::Policy;
+    use clap::Parser;
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::{TcpListener, UnixListener, UnixStream};
+
+    /// Serves commands until `ctl --stop` or Ctrl-C, and metrics if a port is given.
+    pub async fn serve(client: &MoltbookClient, metrics_port: Option<u16>) -> Result<(), ApiError> {
+        let path = socket_path()?;
+        if path.exists() {
+            if UnixStream::connect(&path).await.is_ok() {
+                return Err(ApiError::ConfigError(format!(
+                    "A daemon is already listening on {}",
+                    path.display()
+                )));
+            }
+            // Left behind by a daemon that did not shut down cleanly.
+            fs::remove_file(&path)?;
+        }
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let metrics = match metrics_port {
+            Some(port) => Some(TcpListener::bind(("127.0.0.1", port)).await?),
+            None => None,
+        };
+
+        let listener = UnixListener::bind(&path)?;
+        // Anyone who can connect acts as this agent, like reading credentials.toml.
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600))?;
+        disable_prompts();
+        display::success(&format!(
+            "Listening on {} (stop with Ctrl-C or `moltbook ctl --stop`)",
+            path.display()
+        ));
+        if let Some(metrics) = metrics {
+            display::info(&format!(
+                "Metrics at http://{}/metrics",
+                metrics.local_addr()?
+            ));
+            // Scrapes never touch the client, so they are answered even while a command runs.
+            tokio::spawn(crate::metrics::serve(metrics));
+        }
+
+        let result = loop {
+            tokio::select! {
+                accepted = listener.accept() => {
+                    let stream = match accepted {
+                        Ok((stream, _)) => stream,
+                        Err(e) => break Err(e.into()),
+                    };
+                    match handle(client, stream).await {
+                        Ok(true) => break Ok(()),
+                        Ok(false) => {}
+                        Err(e) => display::warn(&format!("Dropped a ctl connection: {}", e)),
+                    }
+                }
+                _ = crate::interrupt::cancelled() => break Ok(()),
+            }
+        };
+        let _ = fs::remove_file(&path);
+        display::info("Daemon stopped.");
+        result
+    }
+
+    /// Answers one connection; returns whether the daemon should stop.
+    async fn handle(client: &MoltbookClient, stream: UnixStream) -> Result<bool, ApiError> {
+        let (read, mut write) = stream.into_split();
+        let mut line = String::new();
+        BufReader::new(read).read_line(&mut line).await?;
+        if line.trim().is_empty() {
+            // A liveness probe, e.g. from a second `moltbook daemon`.
+            return Ok(false);
+        }
+
+        let (response, stop) = match serde_json::from_str(&line)? {
+            CtlRequest::Stop => (CtlResponse::default(), true),
+            CtlRequest::Run { args, color } => (run(client, args, color).await, false),
+        };
+        write
+            .write_all(serde_json::to_string(&response)?.as_bytes())
+            .await?;
+        write.shutdown().await?;
+        Ok(stop)
+    }
+
+    async fn run(client: &MoltbookClient, args: Vec<String>, color: bool) -> CtlResponse {
+        let cli = match Cli::try_parse_from(std::iter::once("moltbook".to_string()).chain(args)) {
+            Ok(cli) => cli,
+            Err(e) => {
+                let text = e.render().to_string();
+                return if e.use_stderr() {
+                    CtlResponse::error(e.exit_code(), text)
+                } else {
+                    CtlResponse {
+                        stdout: text,
+                        ..CtlResponse::default()
+                    }
+                };
+            }
+        };
+        if cli.command.is_standalone() || cli.command.runs_until_stopped() {
+            return CtlResponse::error(
+                2,
+                "This command cannot run inside the daemon; run it directly.\n".to_string(),
+            );
+        }
+        // The daemon acts as the agent it was started for.
+        if cli.profile.is_some() {
+            return CtlResponse::error(
+                2,
+                "--profile cannot be used inside the daemon; pass it before `ctl` to reach that profile's daemon.\n"
+                    .to_string(),
+            );
+        }
+        if cli.record.is_some() {
+            return CtlResponse::error(
+                2,
+                "--record cannot be used in session mode; run the command directly.\n".to_string(),
+            );
+        }
+        // Re-read so `config set` and policy changes apply to the following
+        // commands, supervised mode and budgets included.
+        let config = match Config::load() {
+            Ok(config) => config,
+            Err(e) => return CtlResponse::error(1, format!("Configuration Error: {}\n", e)),
+        };
+        let policy = match Policy::load() {
+            Ok(policy) => policy,
+            Err(e) => return CtlResponse::error(1, format!("Policy Error: {}\n", e)),
+        };
+        let client = client
+            .fork()
+            .with_outbox(cli.outbox)
+            .with_policy(policy)
+            .with_config(&config)
+            .with_activity_log(true)
+            .with_jitter(cli.jitter.or(config.jitter()))
+            .with_timeout(cli.timeout);
+
+        let capture = match Capture::start() {
+            Ok(capture) => capture,
+            Err(e) => return CtlResponse::error(1, format!("Could not capture output: {}\n", e)),
+        };
+        colored::control::set_override(color && !cli.llm);
+        llm::set_enabled(cli.llm);
+        let result = cli::execute(cli.command, &client, &config).await;
+        if let Err(e) = &result {
+            display::error(&format!("{}", e));
+        }
+        llm::set_enabled(false);
+        colored::control::unset_override();
+
+        match capture.finish() {
+            Ok((stdout, stderr)) => CtlResponse {
+                stdout,
+                stderr,
+                code: i32::from(result.is_err()),
+            },
+            Err(e) => CtlResponse::error(1, format!("Could not capture output: {}\n", e)),
+        }
+    }
+
+    /// Sends a command line to the daemon and relays its output.
+    ///
+    /// Returns the exit code of the command.
+    pub async fn ctl(args: Vec<String>, stop: bool) -> Result<i32, ApiError> {
+        let path = socket_path()?;
+        let mut stream = UnixStream::connect(&path).await.map_err(|_| {
+            ApiError::ConfigError(format!(
+                "No daemon is listening on {}; start one with `moltbook daemon`",
+                path.display()
+            ))
+        })?;
+
+        let request = if stop {
+            CtlRequest::Stop
+        } else {
+            CtlRequest::Run {
+                args,
+                color: colored::control::SHOULD_COLORIZE.should_colorize(),
+            }
+        };
+        let mut line = serde_json::to_string(&request)?;
+        line.push('\n');
+        stream.write_all(line.as_bytes()).await?;
+
+        let mut reply = String::new();
+        stream.read_to_string(&mut reply).await?;
+        let response: CtlResponse = serde_json::from_str(&reply)?;
+        print!("{}", response.stdout);
+        eprint!("{}", response.stderr);
+        if stop {
+            display::success("Daemon stopped");
+        }
+        Ok(response.code)
+    }
+}
@@ -0,0 +1,102 @@
+//! Submolt digest subscription subcommands.
+//!
+//! Subscriptions are stored locally (see [`crate::digest`]); `digest run`
+//! fetches each community's top posts for the elapsed period and renders an
+//! aggregated report.
+
+use crate::api::client::MoltbookClient;
+use crate::api::endpoint::{Endpoint, Listing};
+use crate::api::error::ApiError;
+use crate::api::types::{Post, SubmoltFeedResponse};
+use crate::digest::{DigestFrequency, DigestStore};
+use crate::display;
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+
+pub fn subscribe(submolt: &str, frequency: DigestFrequency) -> Result<(), ApiError> {
+    DigestStore::update(|store| store.subscribe(submolt, frequency))?;
+    display::success(&format!(
+        "Subscribed to a {} digest of m/{}",
+        frequency.as_str(),
+        submolt
+    ));
+    println!(
+        "Run {} to generate it (add {} for scheduled use).",
+        "moltbook digest run".cyan(),
+        "--due".cyan()
+    );
+    Ok(())
+}
+
+pub fn unsubscribe(submolt: &str) -> Result<(), ApiError> {
+    if DigestStore::update(|store| store.unsubscribe(submolt))? {
+        display::success(&format!("Removed digest subscription for m/{}", submolt));
+    } else {
+        display::warn(&format!("No digest subscription for m/{}", submolt));
+    }
+    Ok(())
+}
+
+pub fn list() -> Result<(), ApiError> {
+    let store = DigestStore::load()?;
+    display::display_digest_subscriptions(&store.subscriptions);
+    Ok(())
+}
+
+/// Generates the aggregated digest report.
+///
+/// With `due_only`, subscriptions whose period has not yet elapsed are skipped.
+/// Every reported subscription has its `last_run` advanced to now.
+pub async fn run(client: &MoltbookClient, due_only: bool, limit: u64) -> Result<(), ApiError> {
+    let store = DigestStore::load()?;
+    if store.subscriptions.is_empty() {
+        display::info("No digest subscriptions yet.");
+        println!(
+            "Try: {}",
+            "moltbook digest subscribe <submolt> --weekly".cyan()
+        );
+        return Ok(());
+    }
+
+    let now = Utc::now();
+    let mut reported = Vec::new();
+    for sub in &store.subscriptions {
+        if due_only && !sub.is_due(now) {
+            continue;
+        }
+
+        let since = sub.window_start(now);
+        let url = Endpoint::SubmoltFeed {
+            name: &sub.submolt,
+            listing: Listing::new("top", limit),
+        }
+        .path();
+        let response: SubmoltFeedResponse = client.get(&url).await?;
+        display::warn_skipped("post", &response.posts.skipped);
+        let posts: Vec<&Post> = response
+            .posts
+            .iter()
+            .filter(|p| {
+                DateTime::parse_from_rfc3339(&p.created_at)
+                    .map(|t| t.with_timezone(&Utc) >= since)
+                    .unwrap_or(true)
+            })
+            .collect();
+
+        display::display_digest(&sub.submolt, sub.frequency.as_str(), &since, &posts);
+        reported.push(sub.submolt.clone());
+    }
+
+    if reported.is_empty() {
+        display::info("No digests are due yet.");
+    } else {
+        DigestStore::update(|store| {
+            for sub in &mut store.subscriptions {
+                if reported.contains(&sub.submolt) {
+                    sub.last_run = Some(now.to_rfc3339());
+                }
+            }
+        })?;
+    }
+    Ok(())
+}
@@ -3,37 +3,39 @@
 //! This module implements the secure messaging layer of the Moltbook CLI,
 //! including request-based chat initiation and human-in-the-loop signaling.
 
+use crate::alias::{self, AliasTarget, ItemKind};
 use crate::api::client::MoltbookClient;
+use crate::api::endpoint::Endpoint;
 use crate::api::error::ApiError;
-use crate::api::types::{Conversation, DmCheckResponse, DmRequest, Message};
+use crate::api::types::{Conversation, DmCheckResponse, DmRequest, Message, MessagePage};
+use crate::cli::interactive::require_tty;
 use crate::display;
+use crate::idempotency::post_tracked;
 use colored::Colorize;
 use dialoguer::{Input, theme::ColorfulTheme};
-use serde_json::json;
+use serde_json::{Value, json};
+
+/// Upper bound on pages fetched by `dm-read --all`, guarding against cursor loops.
+pub(crate) const MAX_MESSAGE_PAGES: usize = 50;
 
 /// Checks for any new DM activity (requests or unread messages).
 pub async fn check_dms(client: &MoltbookClient) -> Result<(), ApiError> {
-    let response: DmCheckResponse = client.get("/agents/dm/check").await?;
+    let response: DmCheckResponse = client.get(&Endpoint::DmCheck.path()).await?;
     display::display_dm_check(&response);
     Ok(())
 }
 
 /// Lists all pending DM requests received by the agent.
 pub async fn list_dm_requests(client: &MoltbookClient) -> Result<(), ApiError> {
-    let response: serde_json::Value = client.get("/agents/dm/requests").await?;
-    let items: Vec<DmRequest> = if let Some(r) = response.get("requests") {
-        if r.is_array() {
-            serde_json::from_value(r.clone())?
-        } else if let Some(items) = r.get("items") {
-            serde_json::from_value(items.clone())?
-        } else {
-            vec![]
-        }
-    } else if response.is_array() {
-        serde_json::from_value(response)?
-    } else {
-        vec![]
-    };
+    let items: Vec<DmRequest> = client
+        .get_enveloped(&Endpoint::DmRequests.path(), "requests")
+        .await?;
+
+    alias::remember_conversations(
+        items
+            .iter()
+            .map(|r| (r.from.name.as_str(), r.conversation_id.as_str())),
+    );
 
     println!("\n{}", "Pending DM Requests".bright_green().bold());
     println!("{}", "=".repeat(60));
@@ -48,49 +50,103 @@ pub async fn list_dm_requests(client: &MoltbookClient) -> Result<(), ApiError> {
 }
 
 pub async fn list_conversations(client: &MoltbookClient) -> Result<(), ApiError> {
-    let response: serde_json::Value = client.get("/agents/dm/conversations").await?;
-    let items: Vec<Conversation> = if let Some(c) = response.get("conversations") {
-        if c.is_array() {
-            serde_json::from_value(c.clone())?
-        } else if let Some(i) = c.get("items") {
-            serde_json::from_value(i.clone())?
-        } else {
-            vec![]
-        }
-    } else {
-        vec![]
-    };
+    let mut items: Vec<Conversation> = client
+        .get_enveloped(&Endpoint::DmConversations.path(), "conversations")
+        .await?;
+    crate::ordering::sort_conversations(&mut items);
+
+    alias::remember_conversations(
+        items
+            .iter()
+            .map(|c| (c.with_agent.name.as_str(), c.conversation_id.as_str())),
+    );
 
     println!("\n{}", "DM Conversations".bright_green().bold());
     println!("{}", "=".repeat(60));
     if items.is_empty() {
         display::info("No active conversations.");
     } else {
-        for conv in items {
-            display::display_conversation(&conv);
+        for conv in &items {
+            display::display_conversation(conv);
         }
+        display::display_dm_list_summary(&items);
     }
     Ok(())
 }
 
-pub async fn read_dm(client: &MoltbookClient, conversation_id: &str) -> Result<(), ApiError> {
-    let response: serde_json::Value = client
-        .get(&format!("/agents/dm/conversations/{}", conversation_id))
-        .await?;
-    let messages: Vec<Message> = if let Some(m) = response.get("messages") {
-        serde_json::from_value(m.clone())?
-    } else {
-        vec![]
-    };
+/// Shows a conversation's messages, oldest first.
+///
+/// Fetches one page of up to `limit` messages (the API's default if `None`)
+/// ending just before the message `before`, or with `all` keeps paging back
+/// to the start of the conversation.
+pub async fn read_dm(
+    client: &MoltbookClient,
+    conversation_id: &str,
+    limit: Option<u64>,
+    before: Option<&str>,
+    all: bool,
+) -> Result<(), ApiError> {
+    let pages = if all { MAX_MESSAGE_PAGES } else { 1 };
+    let (messages, older) = fetch_messages(client, conversation_id, limit, before, pages).await?;
+    alias::remember_last(AliasTarget::new(ItemKind::Conversation, conversation_id));
 
     println!("\n{}", "Messages".bright_green().bold());
     println!("{}", "=".repeat(60));
     for msg in messages {
         display::display_message(&msg, &client.agent_name);
     }
+    if let Some(before) = older {
+        display::display_older_messages_hint(conversation_id, &before);
+    }
     Ok(())
 }
 
+/// Fetches up to `pages` pages of a conversation, newest first, going back
+/// from `before`. Returns the messages oldest first, with the cursor for the
+/// page before them if there is one.
+pub(crate) async fn fetch_messages(
+    client: &MoltbookClient,
+    conversation_id: &str,
+    limit: Option<u64>,
+    before: Option<&str>,
+    pages: usize,
+) -> Result<(Vec<Message>, Option<String>), ApiError> {
+    let mut before = before.map(String::from);
+    let mut messages: Vec<Message> = Vec::new();
+    let mut older = None;
+    for _ in 0..pages {
+        let url = Endpoint::DmConversation {
+            id: conversation_id,
+            limit,
+            before: before.as_deref(),
+        }
+        .path();
+        let page = message_page(client.get(&url).await?)?;
+        // A cursor that does not move would fetch the same page forever.
+        older = page.older().filter(|c| Some(c) != before.as_ref());
+        messages.extend(page.messages);
+        match &older {
+            Some(cursor) => before = Some(cursor.clone()),
+            None => break,
+        }
+    }
+    client.report_unknown_fields(&messages);
+    crate::ordering::sort_messages(&mut messages);
+    Ok((messages, older))
+}
+
+/// Decodes a page of messages, whether nested under `messages` or not.
+fn message_page(response: Value) -> Result<MessagePage, ApiError> {
+    let page = match response {
+        Value::Object(mut root) if root.get("messages").is_some_and(Value::is_object) => {
+            root.remove("messages").unwrap_or_default()
+        }
+        Value::Array(messages) => json!({ "messages": messages }),
+        other => other,
+    };
+    Ok(serde_json::from_value(page)?)
+}
+
 /// Sends a direct message in an established conversation.
 pub async fn send_dm(
     client: &MoltbookClient,
@@ -100,19 +156,19 @@ pub async fn send_dm(
 ) -> Result<(), ApiError> {
     let message = match message {
         Some(m) => m,
-        None => Input::with_theme(&ColorfulTheme::default())
-            .with_prompt("Message")
-            .interact_text()
-            .map_err(|e| ApiError::IoError(std::io::Error::other(e)))?,
+        None => {
+            require_tty("The message", "--message")?;
+            Input::with_theme(&ColorfulTheme::default())
+                .with_prompt("Message")
+                .interact_text()
+                .map_err(|e| ApiError::IoError(std::io::Error::other(e)))?
+        }
     };
 
     let body = json!({ "message": message, "needs_human_input": needs_human });
-    let result: serde_json::Value = client
-        .post(
-            &format!("/agents/dm/conversations/{}/send", conversation_id),
-            &body,
-        )
-        .await?;
+    let result: serde_json::Value =
+        post_tracked(client, &Endpoint::DmSend(conversation_id).path(), &body).await?;
+    alias::remember_last(AliasTarget::new(ItemKind::Conversation, conversation_id));
 
     if !crate::cli::verification::handle_verification(&result, "message")
         && result["success"].as_bool().unwrap_or(false)
@@ -122,6 +178,20 @@ pub async fn send_dm(
     Ok(())
 }
 
+/// Reacts to a direct message with an emoji.
+pub async fn react(client: &MoltbookClient, message_id: &str, emoji: &str) -> Result<(), ApiError> {
+    let body = json!({ "emoji": emoji });
+    let result: serde_json::Value = client
+        .post(&Endpoint::DmReact(message_id).path(), &body)
+        .await?;
+    if !crate::cli::verification::handle_verification(&result, "reaction")
+        && result["success"].as_bool().unwrap_or(false)
+    {
+        display::success(&format!("Reacted with {}", emoji));
+    }
+    Ok(())
+}
+
 /// Sends a new DM request to another agent.
 pub async fn send_request(
     client: &MoltbookClient,
@@ -129,6 +199,10 @@ pub async fn send_request(
     message: Option<String>,
     by_owner: bool,
 ) -> Result<(), ApiError> {
+    if to.is_none() || message.is_none() {
+        require_tty("The recipient and message", "--to and --message")?;
+    }
+
     let to = match to {
         Some(t) => t,
         None => Input::with_theme(&ColorfulTheme::default())
@@ -150,7 +224,8 @@ pub async fn send_request(
     } else {
         json!({ "to": to, "message": message })
     };
-    let result: serde_json::Value = client.post("/agents/dm/request", &body).await?;
+    let result: serde_json::Value =
+        post_tracked(client, &Endpoint::DmNewRequest.path(), &body).await?;
 
     if !crate::cli::verification::handle_verification(&result, "request")
         && result["success"].as_bool().unwrap_or(false)
@@ -165,11 +240,9 @@ pub async fn approve_request(
     conversation_id: &str,
 ) -> Result<(), ApiError> {
     let result: serde_json::Value = client
-        .post(
-            &format!("/agents/dm/requests/{}/approve", conversation_id),
-            &json!({}),
-        )
+        .post(&Endpoint::DmApprove(conversation_id).path(), &json!({}))
         .await?;
+    alias::remember_last(AliasTarget::new(ItemKind::Conversation, conversation_id));
     if result["success"].as_bool().unwrap_or(false) {
         display::success("Request approved! 🦞");
     }
@@ -183,10 +256,7 @@ pub async fn reject_request(
 ) -> Result<(), ApiError> {
     let body = json!({ "block": block });
     let result: serde_json::Value = client
-        .post(
-            &format!("/agents/dm/requests/{}/reject", conversation_id),
-            &body,
-        )
+        .post(&Endpoint::DmReject(conversation_id).path(), &body)
         .await?;
     if result["success"].as_bool().unwrap_or(false) {
         if block {
@@ -0,0 +1,138 @@
+//! Post drafts staged locally and published later.
+//!
+//! See [`crate::draft`] for where drafts are kept.
+
+use crate::api::client::MoltbookClient;
+use crate::api::error::ApiError;
+use crate::cli::editor::edit_text;
+use crate::cli::interactive::require_tty;
+use crate::cli::post::{self, PostParams};
+use crate::display;
+use crate::draft::{DraftStore, PostDraft};
+use colored::Colorize;
+
+/// Fields to change with `draft edit`; `None` keeps the saved value.
+#[derive(Debug, Default)]
+pub struct DraftChanges {
+    pub title: Option<String>,
+    pub submolt: Option<String>,
+    pub content: Option<String>,
+    pub url: Option<String>,
+}
+
+impl DraftChanges {
+    fn is_empty(&self) -> bool {
+        self.title.is_none()
+            && self.submolt.is_none()
+            && self.content.is_none()
+            && self.url.is_none()
+    }
+}
+
+/// Saves a new draft. With neither content nor a URL, the content is
+/// written in the editor.
+pub fn save(
+    title: String,
+    submolt: String,
+    content: Option<String>,
+    url: Option<String>,
+) -> Result<(), ApiError> {
+    let content = match (content, &url) {
+        (None, None) => {
+            require_tty("Draft content", "--content, --content-file or --url")?;
+            Some(edit_text("", ".md")?)
+        }
+        (content, _) => content,
+    };
+    let content = content.filter(|c| !c.trim().is_empty());
+    let draft = PostDraft::new(title, submolt, content, url);
+    let id = draft.short_id().to_string();
+    DraftStore::update(|store| store.drafts.push(draft))?;
+
+    display::success(&format!("Saved draft {}", id));
+    println!(
+        "Publish it with {}",
+        format!("moltbook draft publish {}", id).cyan()
+    );
+    Ok(())
+}
+
+pub fn list() -> Result<(), ApiError> {
+    let store = DraftStore::load()?;
+    display::display_drafts(&store.drafts);
+    Ok(())
+}
+
+/// Applies `changes` to the draft matching `id`, or opens its content in the
+/// editor when there are none.
+pub fn edit(id: &str, mut changes: DraftChanges) -> Result<(), ApiError> {
+    let mut draft = DraftStore::load()?.find(id)?.clone();
+    if changes.is_empty() {
+        require_tty("Draft content", "--title, --submolt, --content or --url")?;
+        let content = edit_text(draft.content.as_deref().unwrap_or(""), ".md")?;
+        changes.content = Some(content);
+    }
+
+    if let Some(title) = changes.title {
+        draft.title = title;
+    }
+    if let Some(submolt) = changes.submolt {
+        draft.submolt = submolt;
+    }
+    if let Some(content) = changes.content {
+        draft.content = Some(content).filter(|c| !c.trim().is_empty());
+    }
+    if let Some(url) = changes.url {
+        draft.url = Some(url).filter(|u| !u.is_empty());
+    }
+    draft.updated_at = chrono::Utc::now().to_rfc3339();
+
+    let short_id = draft.short_id().to_string();
+    // Deleted (or published) by another invocation while being edited.
+    if !DraftStore::update(|store| store.replace(draft))? {
+        return Err(ApiError::ConfigError(format!(
+            "Draft {} no longer exists; nothing was saved",
+            short_id
+        )));
+    }
+    display::success(&format!("Updated draft {}", short_id));
+    Ok(())
+}
+
+/// Posts the draft matching `id` and removes it once the API accepts it.
+///
+/// A post that is refused, queued in the outbox or held for approval keeps
+/// its draft.
+pub async fn publish(client: &MoltbookClient, id: &str) -> Result<(), ApiError> {
+    let draft = DraftStore::load()?.find(id)?.clone();
+    post::create_post(
+        client,
+        PostParams {
+            title: Some(draft.title.clone()),
+            content: draft.content.clone(),
+            url: draft.url.clone(),
+            submolt: Some(draft.submolt.clone()),
+            default_submolt: draft.submolt.clone(),
+            ..Default::default()
+        },
+    )
+    .await?;
+    DraftStore::update(|store| store.remove(&draft.id))?;
+    display::info(&format!("Draft {} published and removed", draft.short_id()));
+    Ok(())
+}
+
+/// Removes the draft matching `id` without posting it.
+pub fn delete(id: &str) -> Result<(), ApiError> {
+    let draft = DraftStore::update(|store| {
+        let draft = store.find(id)?.clone();
+        store.remove(&draft.id);
+        Ok::<_, ApiError>(draft)
+    })??;
+    display::success(&format!(
+        "Deleted draft {} \"{}\"",
+        draft.short_id(),
+        draft.title
+    ));
+    Ok(())
+}
@@ -0,0 +1,35 @@
+//! `$EDITOR` integration for composing long-form text.
+
+use crate::api::error::ApiError;
+use std::fs;
+use std::process::Command;
+
+/// Opens `initial` in the user's editor and returns the saved text.
+///
+/// Resolves the editor from `VISUAL`, then `EDITOR`, falling back to `vi`.
+/// `suffix` is appended to the temporary file name so editors can pick up
+/// syntax highlighting (e.g. `.md`).
+pub fn edit_text(initial: &str, suffix: &str) -> Result<String, ApiError> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+
+    let path = std::env::temp_dir().join(format!("moltbook-edit-{}{}", std::process::id(), suffix));
+    fs::write(&path, initial)?;
+
+    // Editors like "code --wait" carry their own arguments.
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or("vi");
+    let status = Command::new(program).args(parts).arg(&path).status();
+
+    let result = match status {
+        Ok(s) if s.success() => fs::read_to_string(&path).map_err(ApiError::IoError),
+        Ok(s) => Err(ApiError::IoError(std::io::Error::other(format!(
+            "Editor '{}' exited with {}",
+            editor, s
+        )))),
+        Err(e) => Err(ApiError::IoError(e)),
+    };
+    let _ = fs::remove_file(&path);
+    result
+}
@@ -0,0 +1,62 @@
+//! Engagement report subcommand.
+//!
+//! See [`crate::engagement`] for what the report covers.
+
+use crate::api::client::MoltbookClient;
+use crate::api::endpoint::{Endpoint, Listing};
+use crate::api::error::ApiError;
+use crate::api::pagination::Pages;
+use crate::api::types::{Agent, FeedResponse, Post};
+use crate::display;
+use crate::engagement::{EngagementHistory, EngagementReport, created_at};
+use chrono::{TimeDelta, Utc};
+
+/// Posts requested per page.
+const PAGE_SIZE: u64 = 50;
+/// Upper bound on pages fetched, in case post dates cannot be read.
+const MAX_PAGES: usize = 20;
+
+pub async fn engagement(
+    client: &MoltbookClient,
+    period: TimeDelta,
+    json: bool,
+) -> Result<(), ApiError> {
+    let agent: Agent = client.get_enveloped(&Endpoint::Me.path(), "agent").await?;
+    let end = Utc::now();
+    let start = end - period;
+
+    let url = Endpoint::Posts {
+        listing: Listing::new("new", PAGE_SIZE),
+        author: Some(&agent.name),
+    }
+    .path();
+    let mut pages = Pages::<FeedResponse>::new(client, url, None);
+    let mut posts: Vec<Post> = Vec::new();
+    for _ in 0..MAX_PAGES {
+        let Some(page) = pages.next().await else {
+            break;
+        };
+        let page = page?.posts.items;
+        // Posts come newest first: once a page reaches past the start, stop.
+        let reached_start = page.last().and_then(created_at).is_some_and(|t| t < start);
+        posts.extend(page);
+        if reached_start {
+            break;
+        }
+    }
+
+    let follower_change = EngagementHistory::update(|history| {
+        let followers = agent.follower_count?;
+        let change = history.change_since(start, followers);
+        history.record(end, followers);
+        change
+    })?;
+
+    let report = EngagementReport::new(&posts, start, end, agent.follower_count, follower_change);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        display::display_engagement_report(&report);
+    }
+    Ok(())
+}
@@ -0,0 +1,141 @@
+//! Follow relationship subcommands.
+//!
+//! See [`crate::follows`] for how the relationship lists are cached.
+
+use crate::api::client::MoltbookClient;
+use crate::api::endpoint::Endpoint;
+use crate::api::error::ApiError;
+use crate::api::types::Agent;
+use crate::batch::{MAX_RATE_LIMIT_WAIT, retry_after};
+use crate::cli::account;
+use crate::cli::graph::follow_list;
+use crate::cli::interactive::{is_interactive, require_tty};
+use crate::display;
+use crate::follows::Relationships;
+use chrono::Utc;
+use dialoguer::{Confirm, MultiSelect, theme::ColorfulTheme};
+use std::collections::HashMap;
+
+/// Upper bound on agents read from each list.
+const MAX_AGENTS: usize = 5000;
+
+/// Lists one-way follow relationships and optionally unfollows the agents
+/// that do not follow back.
+///
+/// With `unfollow_nonmutual` every such agent is unfollowed after one
+/// confirmation (skipped by `yes`); otherwise an interactive terminal is
+/// offered a checklist to pick from.
+pub async fn audit(
+    client: &MoltbookClient,
+    refresh: bool,
+    unfollow_nonmutual: bool,
+    yes: bool,
+    hydrate: bool,
+) -> Result<(), ApiError> {
+    let me: Agent = client.get_enveloped(&Endpoint::Me.path(), "agent").await?;
+    let now = Utc::now();
+    let cached = if refresh {
+        None
+    } else {
+        Relationships::load()?.filter(|r| r.is_fresh(&me.name, now))
+    };
+    let relationships = match cached {
+        Some(cached) => cached,
+        None => {
+            let spinner = display::progress::spinner(
+                "Fetching followers and following...".to_string(),
+                client.progress_enabled(),
+            );
+            let followers = follow_list(client, &me.name, true, MAX_AGENTS).await;
+            let following = follow_list(client, &me.name, false, MAX_AGENTS).await;
+            spinner.finish_and_clear();
+            let (followers, followers_truncated) = followers?;
+            let (following, following_truncated) = following?;
+            let fetched = Relationships {
+                agent: me.name.clone(),
+                fetched_at: now.to_rfc3339(),
+                followers: followers.into_iter().map(|a| a.name).collect(),
+                following: following.into_iter().map(|a| a.name).collect(),
+                truncated: followers_truncated || following_truncated,
+            };
+            fetched.save()?;
+            fetched
+        }
+    };
+
+    let audit = relationships.audit();
+    let profiles = if hydrate {
+        let names = audit
+            .not_following_back
+            .iter()
+            .chain(&audit.not_followed_back);
+        crate::cli::hydrate::hydrate(client, names.map(String::as_str)).await
+    } else {
+        HashMap::new()
+    };
+    display::display_follow_audit(&audit, &relationships.fetched_at, &profiles);
+    let candidates = &audit.not_following_back;
+    if candidates.is_empty() {
+        return Ok(());
+    }
+    if relationships.truncated {
+        let refusal = format!(
+            "Only the first {} agents of each list were read, so some of these may follow back; nothing is unfollowed",
+            MAX_AGENTS
+        );
+        if unfollow_nonmutual {
+            return Err(ApiError::ConfigError(refusal));
+        }
+        display::warn(&refusal);
+        return Ok(());
+    }
+
+    let chosen: Vec<&String> = if unfollow_nonmutual {
+        if !yes {
+            require_tty("Unfollow confirmation", "--yes")?;
+            let confirmed = Confirm::with_theme(&ColorfulTheme::default())
+                .with_prompt(format!(
+                    "Unfollow {} agents that don't follow back?",
+                    candidates.len()
+                ))
+                .default(false)
+                .interact()
+                .map_err(|e| ApiError::IoError(std::io::Error::other(e)))?;
+            if !confirmed {
+                display::info("Nothing was unfollowed.");
+                return Ok(());
+            }
+        }
+        candidates.iter().collect()
+    } else if is_interactive() {
+        MultiSelect::with_theme(&ColorfulTheme::default())
+            .with_prompt("Unfollow (space to select, enter to confirm)")
+            .items(candidates)
+            .interact()
+            .map_err(|e| ApiError::IoError(std::io::Error::other(e)))?
+            .into_iter()
+            .map(|index| &candidates[index])
+            .collect()
+    } else {
+        return Ok(());
+    };
+
+    for (i, name) in chosen.into_iter().enumerate() {
+        if i > 0 {
+            client.jitter().await;
+        }
+        // `unfollow` also drops the agent from the cached lists.
+        loop {
+            match account::unfollow(client, name).await {
+                Err(ApiError::RateLimited(hint)) => {
+                    match retry_after(&hint).filter(|wait| *wait <= MAX_RATE_LIMIT_WAIT) {
+                        Some(wait) => tokio::time::sleep(wait).await,
+                        None => return Err(ApiError::RateLimited(hint)),
+                    }
+                }
+                result => break result?,
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,135 @@
+//! Follower graph subcommands.
+//!
+//! See [`crate::graph`] for the shape of the exported graph.
+
+use crate::api::client::MoltbookClient;
+use crate::api::endpoint::{Endpoint, Listing};
+use crate::api::error::ApiError;
+use crate::api::pagination::CursorPage;
+use crate::api::types::{Agent, Author, FollowListResponse};
+use crate::batch::{MAX_RATE_LIMIT_WAIT, retry_after};
+use crate::display;
+use crate::graph::{Graph, GraphFormat};
+use std::fs;
+use std::path::Path;
+
+/// Agents requested per page of a follower list.
+const PAGE_SIZE: u64 = 50;
+
+/// Walks follower and following lists out to `depth` hops and writes the
+/// graph to `output`, or to stdout.
+///
+/// Progress and warnings go to stderr so stdout can be redirected to a file.
+pub async fn export(
+    client: &MoltbookClient,
+    format: GraphFormat,
+    depth: usize,
+    limit: usize,
+    output: Option<&Path>,
+) -> Result<(), ApiError> {
+    let me: Agent = client.get_enveloped(&Endpoint::Me.path(), "agent").await?;
+    let mut graph = Graph::new(&me.name);
+    if let Some(root) = graph.nodes.get_mut(&me.name) {
+        root.karma = me.karma;
+        root.followers = me.follower_count;
+    }
+
+    let spinner = display::progress::spinner(String::new(), client.progress_enabled());
+    let mut frontier = vec![me.name.clone()];
+    let mut skipped = Vec::new();
+    let mut stopped = None;
+    'walk: for hop in 1..=depth {
+        let mut next = Vec::new();
+        for name in &frontier {
+            for followers in [true, false] {
+                let list = if followers { "followers" } else { "following" };
+                spinner.set_message(format!("Hop {}/{}: {} of {}", hop, depth, list, name));
+                let agents = match follow_list(client, name, followers, limit).await {
+                    Ok((agents, _)) => agents,
+                    Err(e @ ApiError::RateLimited(_)) => {
+                        stopped = Some(e);
+                        break 'walk;
+                    }
+                    // Without the agent's own lists there is no graph to export.
+                    Err(e) if hop == 1 => return Err(e),
+                    Err(e) => {
+                        skipped.push(format!("{} of {}: {}", list, name, e));
+                        continue;
+                    }
+                };
+                for agent in &agents {
+                    if graph.add_agent(agent, hop) {
+                        next.push(agent.name.clone());
+                    }
+                    if followers {
+                        graph.add_follow(&agent.name, name);
+                    } else {
+                        graph.add_follow(name, &agent.name);
+                    }
+                }
+            }
+        }
+        frontier = next;
+    }
+    spinner.finish_and_clear();
+
+    let rendered = graph.render(format);
+    match output {
+        Some(path) => fs::write(path, rendered)?,
+        None => print!("{}", rendered),
+    }
+    eprintln!(
+        "Exported {} agents and {} follows{}",
+        graph.nodes.len(),
+        graph.edges.len(),
+        output.map_or(String::new(), |p| format!(" to {}", p.display()))
+    );
+    for reason in &skipped {
+        eprintln!("  skipped {}", reason);
+    }
+    if let Some(e) = stopped {
+        eprintln!("The walk stopped early ({}); the graph is partial.", e);
+    }
+    Ok(())
+}
+
+/// Up to `limit` agents from one of `name`'s lists, waiting out short rate
+/// limits, and whether the list went on past them.
+pub async fn follow_list(
+    client: &MoltbookClient,
+    name: &str,
+    followers: bool,
+    limit: usize,
+) -> Result<(Vec<Author>, bool), ApiError> {
+    let mut agents = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let listing = Listing::limit(PAGE_SIZE).cursor(cursor.as_deref());
+        let url = if followers {
+            Endpoint::Followers { name, listing }
+        } else {
+            Endpoint::Following { name, listing }
+        }
+        .path();
+
+        let page = loop {
+            match client.get::<FollowListResponse>(&url).await {
+                Err(ApiError::RateLimited(hint)) => {
+                    match retry_after(&hint).filter(|wait| *wait <= MAX_RATE_LIMIT_WAIT) {
+                        Some(wait) => tokio::time::sleep(wait).await,
+                        None => return Err(ApiError::RateLimited(hint)),
+                    }
+                }
+                result => break result?,
+            }
+        };
+        cursor = page.next_cursor().map(String::from);
+        agents.extend(page.agents.items);
+        if agents.len() >= limit || cursor.is_none() {
+            break;
+        }
+    }
+    let truncated = agents.len() > limit || cursor.is_some();
+    agents.truncate(limit);
+    Ok((agents, truncated))
+}
@@ -0,0 +1,143 @@
+//! Handoff subcommand.
+//!
+//! Fetches a conversation, the other agent's profile and the posts linked
+//! from it, and writes a [`Handoff`] packet for the human owner to a file,
+//! stdout or the clipboard.
+
+use crate::api::client::MoltbookClient;
+use crate::api::endpoint::Endpoint;
+use crate::api::error::ApiError;
+use crate::api::types::{Agent, Post};
+use crate::cli::dm::{MAX_MESSAGE_PAGES, fetch_messages};
+use crate::display;
+use crate::handoff::{self, Handoff};
+use futures_util::future::join_all;
+use std::fs;
+use std::io::{ErrorKind, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Clipboard tools tried in order, with their arguments.
+const CLIPBOARD_TOOLS: &[(&str, &[&str])] = &[
+    ("wl-copy", &[]),
+    ("xclip", &["-selection", "clipboard"]),
+    ("xsel", &["--clipboard", "--input"]),
+    ("pbcopy", &[]),
+    ("clip", &[]),
+];
+
+/// Builds the handoff packet for `conversation_id` and writes it to
+/// `output`, the clipboard, or stdout.
+///
+/// A profile or post that cannot be fetched is left out with a warning on
+/// stderr rather than failing the handoff.
+pub async fn handoff(
+    client: &MoltbookClient,
+    conversation_id: &str,
+    output: Option<&Path>,
+    clipboard: bool,
+) -> Result<(), ApiError> {
+    let (messages, _) =
+        fetch_messages(client, conversation_id, None, None, MAX_MESSAGE_PAGES).await?;
+    let other = handoff::other_agent(&messages, &client.agent_name).ok_or_else(|| {
+        ApiError::ConfigError(format!(
+            "Conversation {} has no messages from another agent to hand off",
+            conversation_id
+        ))
+    })?;
+
+    let profile: Option<Agent> = match client
+        .get_enveloped(&Endpoint::AgentProfile(other).path(), "agent")
+        .await
+    {
+        Ok(agent) => Some(agent),
+        Err(e) => {
+            eprintln!("Left out the profile of {}: {}", other, e);
+            None
+        }
+    };
+
+    let ids = handoff::linked_posts(&messages);
+    let fetches = ids.iter().take(handoff::MAX_POSTS).map(|id| async move {
+        let post: Result<Post, ApiError> = client
+            .get_enveloped(&Endpoint::Post(id).path(), "post")
+            .await;
+        (id, post)
+    });
+    let mut posts = Vec::new();
+    for (id, post) in join_all(fetches).await {
+        match post {
+            Ok(post) => posts.push(post),
+            Err(e) => eprintln!("Left out post {}: {}", id, e),
+        }
+    }
+    if ids.len() > handoff::MAX_POSTS {
+        eprintln!(
+            "Only the first {} of {} linked posts are included",
+            handoff::MAX_POSTS,
+            ids.len()
+        );
+    }
+
+    let packet = Handoff {
+        conversation_id,
+        me: &client.agent_name,
+        other,
+        profile: profile.as_ref(),
+        messages: &messages,
+        posts: &posts,
+    };
+    let markdown = packet.to_markdown();
+    let flagged = packet.flagged().len();
+
+    if clipboard {
+        copy_to_clipboard(&markdown)?;
+    }
+    match output {
+        Some(path) => fs::write(path, &markdown)?,
+        None if clipboard => {}
+        None => print!("{}", markdown),
+    }
+
+    let destination = match (output, clipboard) {
+        (Some(path), true) => format!("{} and the clipboard", path.display()),
+        (Some(path), false) => path.display().to_string(),
+        (None, true) => "the clipboard".to_string(),
+        (None, false) => return Ok(()),
+    };
+    display::success(&format!(
+        "Wrote the handoff for {} ({} messages, {} flagged) to {}",
+        other,
+        messages.len(),
+        flagged,
+        destination
+    ));
+    Ok(())
+}
+
+/// Pipes `text` into the first clipboard tool found on the system.
+fn copy_to_clipboard(text: &str) -> Result<(), ApiError> {
+    for (tool, args) in CLIPBOARD_TOOLS {
+        let mut child = match Command::new(tool)
+            .args(*args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        {
+            Ok(child) => child,
+            Err(e) if e.kind() == ErrorKind::NotFound => continue,
+            Err(e) => return Err(e.into()),
+        };
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(text.as_bytes())?;
+        }
+        if child.wait()?.success() {
+            return Ok(());
+        }
+    }
+    Err(ApiError::ConfigError(
+        "No working clipboard tool found (wl-copy, xclip, xsel, pbcopy or clip); use --output instead"
+            .to_string(),
+    ))
+}
@@ -0,0 +1,71 @@
+//! Profile hydration for agent lists rendered with `--hydrate`.
+//!
+//! See [`crate::hydrate`] for what is cached and for how long.
+
+use crate::api::client::MoltbookClient;
+use crate::api::endpoint::Endpoint;
+use crate::api::types::Agent;
+use crate::display;
+use crate::hydrate::{AgentSummary, ProfileCache};
+use chrono::Utc;
+use futures_util::future::join_all;
+use std::collections::{BTreeSet, HashMap};
+
+/// Profile summaries for `names`, from the cache or fetched concurrently.
+///
+/// Requests go out together and are bounded by the client's
+/// `max_concurrent_requests`. Profiles that fail to load are left out with
+/// one warning; hydration never fails the listing it decorates.
+pub async fn hydrate<'a>(
+    client: &MoltbookClient,
+    names: impl IntoIterator<Item = &'a str>,
+) -> HashMap<String, AgentSummary> {
+    let now = Utc::now();
+    let cache = ProfileCache::load().unwrap_or_default();
+    let names: BTreeSet<&str> = names.into_iter().collect();
+    let mut summaries = HashMap::new();
+    let mut missing = Vec::new();
+    for name in names {
+        match cache.fresh(name, now) {
+            Some(summary) => {
+                summaries.insert(name.to_string(), summary.clone());
+            }
+            None => missing.push(name),
+        }
+    }
+    if missing.is_empty() {
+        return summaries;
+    }
+
+    let spinner = display::progress::spinner(
+        format!("Fetching {} profile(s)…", missing.len()),
+        client.progress_enabled(),
+    );
+    let fetched = join_all(missing.iter().map(|name| async move {
+        let path = Endpoint::AgentProfile(name).path();
+        (*name, client.get_enveloped::<Agent>(&path, "agent").await)
+    }))
+    .await;
+    spinner.finish_and_clear();
+
+    let mut loaded = Vec::new();
+    for (name, result) in fetched {
+        if let Ok(agent) = result {
+            loaded.push((name, AgentSummary::new(&agent, now)));
+        }
+    }
+    let failed = missing.len() - loaded.len();
+    if failed > 0 {
+        display::warn(&format!(
+            "Could not load {} profile(s); shown without details.",
+            failed
+        ));
+    }
+    let _ = ProfileCache::update(now, |cache| {
+        for (name, summary) in &loaded {
+            cache.insert(name, summary.clone());
+        }
+    });
+    summaries.extend(loaded.into_iter().map(|(name, s)| (name.to_string(), s)));
+    summaries
+}
@@ -0,0 +1,36 @@
+//! Guard for commands that fall back to interactive prompts.
+//!
+//! Under cron, CI or `docker run` without `-t`, stdin is not a terminal and a
+//! dialoguer prompt would hang or fail with an opaque IO error. Every prompt
+//! fallback calls [`require_tty`] first so the command fails fast and names
+//! the flags that make it non-interactive.
+
+use crate::api::error::ApiError;
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set by session mode, whose stdin belongs to the daemon rather than the caller.
+static PROMPTS_DISABLED: AtomicBool = AtomicBool::new(false);
+
+/// Treats the process as non-interactive from now on, whatever stdin is.
+pub fn disable_prompts() {
+    PROMPTS_DISABLED.store(true, Ordering::Relaxed);
+}
+
+/// Whether stdin is attached to a terminal and prompts are allowed.
+pub fn is_interactive() -> bool {
+    !PROMPTS_DISABLED.load(Ordering::Relaxed) && std::io::stdin().is_terminal()
+}
+
+/// Returns an error describing `flags` when stdin is not a terminal.
+///
+/// `action` names what would have been prompted for, e.g. "Comment content".
+pub fn require_tty(action: &str, flags: &str) -> Result<(), ApiError> {
+    if is_interactive() {
+        return Ok(());
+    }
+    Err(ApiError::NonInteractive(format!(
+        "{} must be provided with {}",
+        action, flags
+    )))
+}
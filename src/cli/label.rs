@@ -1,11 +1,13 @@
 // author: kelexine <https://github.com/kelexine>
 
 use crate::api::client::MoltbookClient;
+use crate::api::endpoint::Endpoint;
 use crate::api::error::ApiError;
 use crate::api::types::{LabelAttachResponse, LabelsResponse, RolesResponse};
 use crate::display;
 use serde_json::{Value, json};
 
+#[allow(clippy::too_many_arguments)]
 pub async fn define(
     client: &MoltbookClient,
     submolt: &str,
@@ -32,7 +34,7 @@ pub async fn define(
     }
 
     let result: Value = client
-        .post(&format!("/submolts/{}/labels", submolt), &body)
+        .post(&Endpoint::SubmoltLabels(submolt).path(), &body)
         .await?;
 
     if result["success"].as_bool().unwrap_or(false) {
@@ -52,17 +54,13 @@ pub async fn define(
 }
 
 pub async fn list(client: &MoltbookClient, submolt: &str) -> Result<(), ApiError> {
-    let response: LabelsResponse = client
-        .get(&format!("/submolts/{}/labels", submolt))
-        .await?;
+    let response: LabelsResponse = client.get(&Endpoint::SubmoltLabels(submolt).path()).await?;
     display::display_labels(&response, submolt);
     Ok(())
 }
 
 pub async fn roles(client: &MoltbookClient, submolt: &str) -> Result<(), ApiError> {
-    let response: RolesResponse = client
-        .get(&format!("/submolts/{}/roles", submolt))
-        .await?;
+    let response: RolesResponse = client.get(&Endpoint::SubmoltRoles(submolt).path()).await?;
     display::display_roles(&response, submolt);
     Ok(())
 }
@@ -88,7 +86,7 @@ pub async fn attach(
         body["placement"] = json!("metadata");
     }
 
-    let result: LabelAttachResponse = client.post("/labels/attach", &body).await?;
+    let result: LabelAttachResponse = client.post(&Endpoint::LabelAttach.path(), &body).await?;
 
     if result.success {
         let attachment_id = result
@@ -112,7 +110,7 @@ pub async fn attach(
 
 pub async fn revoke(client: &MoltbookClient, attachment_id: &str) -> Result<(), ApiError> {
     let result: Value = client
-        .delete(&format!("/labels/attach/{}", attachment_id))
+        .delete(&Endpoint::LabelAttachment(attachment_id).path())
         .await?;
 
     if result["success"].as_bool().unwrap_or(false) {
@@ -0,0 +1,163 @@
+//! Webhook listener subcommand.
+//!
+//! Serves a minimal HTTP/1.1 endpoint that turns signed `POST /hooks/<name>`
+//! calls into the actions configured in the `webhooks` section (see
+//! [`crate::webhook`]). Calls are handled one at a time, which is plenty for
+//! CI notifications and keeps actions in the order they arrived. `GET /metrics`
+//! answers Prometheus scrapes (see [`crate::metrics`]).
+
+use crate::api::client::MoltbookClient;
+use crate::api::error::ApiError;
+use crate::config::Config;
+use crate::display;
+use crate::http::{READ_TIMEOUT, Request, read_request, reason, write_response};
+use crate::idempotency::post_tracked;
+use crate::interrupt;
+use crate::metrics;
+use crate::webhook::{SIGNATURE_HEADER, Webhooks, verify};
+use chrono::Local;
+use colored::Colorize;
+use serde_json::{Value, json};
+use tokio::net::{TcpListener, TcpStream};
+
+/// Serves webhook calls until Ctrl-C.
+pub async fn listen(
+    client: &MoltbookClient,
+    config: &Config,
+    host: &str,
+    port: u16,
+) -> Result<(), ApiError> {
+    let webhooks = &config.webhooks;
+    if webhooks.secret.as_deref().is_none_or(str::is_empty) {
+        return Err(ApiError::ConfigError(
+            "Set webhooks.secret in credentials.toml first; callers sign request bodies with it"
+                .to_string(),
+        ));
+    }
+    if webhooks.hooks.is_empty() {
+        return Err(ApiError::ConfigError(
+            "No hooks defined; add them under [webhooks.hooks] in config.toml".to_string(),
+        ));
+    }
+
+    let listener = TcpListener::bind((host, port)).await?;
+    display::success(&format!(
+        "Listening on http://{} (stop with Ctrl-C)",
+        listener.local_addr()?
+    ));
+    for (name, action) in &webhooks.hooks {
+        println!(
+            "  {} {}",
+            format!("POST /hooks/{:<16}", name).cyan(),
+            action.describe().dimmed()
+        );
+    }
+    println!();
+
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => {
+                let (stream, _) = accepted?;
+                if let Err(e) = handle(client, webhooks, stream).await {
+                    display::warn(&format!("Dropped a webhook connection: {}", e));
+                }
+            }
+            _ = interrupt::cancelled() => break,
+        }
+    }
+    display::info("Listener stopped.");
+    Ok(())
+}
+
+async fn handle(
+    client: &MoltbookClient,
+    webhooks: &Webhooks,
+    mut stream: TcpStream,
+) -> Result<(), ApiError> {
+    let (status, body) = match tokio::time::timeout(READ_TIMEOUT, read_request(&mut stream)).await {
+        // Scrapes are frequent and unsigned; answer them without logging.
+        Ok(Ok(request)) if request.method == "GET" && request.path == "/metrics" => {
+            metrics::respond(&mut stream).await?;
+            return Ok(());
+        }
+        Ok(Ok(request)) => {
+            let (status, body) = respond(client, webhooks, &request).await;
+            metrics::record_webhook(status);
+            println!(
+                "{} {} {} → {}",
+                Local::now().format("%H:%M:%S").to_string().dimmed(),
+                request.method,
+                request.path,
+                status_label(status)
+            );
+            (status, body)
+        }
+        Ok(Err(status)) => (status, json!({ "ok": false, "error": reason(status) })),
+        Err(_) => (408, json!({ "ok": false, "error": reason(408) })),
+    };
+
+    write_response(&mut stream, status, "application/json", &body.to_string()).await?;
+    Ok(())
+}
+
+/// Authenticates a call and runs its hook.
+async fn respond(client: &MoltbookClient, webhooks: &Webhooks, request: &Request) -> (u16, Value) {
+    let fail = |status: u16, error: String| (status, json!({ "ok": false, "error": error }));
+
+    if request.method != "POST" {
+        return fail(405, "Only POST is accepted".to_string());
+    }
+    // Authenticate before revealing whether a hook exists.
+    let secret = webhooks.secret.as_deref().unwrap_or_default();
+    let signed = request
+        .header(SIGNATURE_HEADER)
+        .is_some_and(|s| verify(secret, &request.body, s));
+    if !signed {
+        return fail(
+            401,
+            format!("Missing or invalid {} header", SIGNATURE_HEADER),
+        );
+    }
+    let Some(action) = request
+        .path
+        .strip_prefix("/hooks/")
+        .and_then(|name| webhooks.hooks.get(name))
+    else {
+        return fail(404, format!("No hook at {}", request.path));
+    };
+
+    let payload: Value = if request.body.is_empty() {
+        json!({})
+    } else {
+        match serde_json::from_slice(&request.body) {
+            Ok(payload) => payload,
+            Err(e) => return fail(400, format!("Body is not JSON: {}", e)),
+        }
+    };
+    let (endpoint, body) = match action.render(&payload) {
+        Ok(rendered) => rendered,
+        Err(field) => return fail(422, format!("Payload has no value for {{{{{}}}}}", field)),
+    };
+
+    match post_tracked::<Value>(client, &endpoint, &body).await {
+        Ok(result) => {
+            crate::cli::verification::handle_verification(&result, "webhook action");
+            (200, json!({ "ok": true, "result": result }))
+        }
+        Err(e @ (ApiError::RateLimited(_) | ApiError::NewAgentRestricted(_))) => {
+            fail(429, e.to_string())
+        }
+        Err(e) => fail(502, e.to_string()),
+    }
+}
+
+fn status_label(status: u16) -> colored::ColoredString {
+    let label = format!("{} {}", status, reason(status));
+    if status < 300 {
+        label.green()
+    } else if status < 500 {
+        label.yellow()
+    } else {
+        label.red()
+    }
+}
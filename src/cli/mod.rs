@@ -4,17 +4,54 @@
 //! specifically focused submodules (account, dm, post, submolt).
 
 pub mod account;
+pub mod alias;
+pub mod approvals;
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod best_time;
+pub mod bridge;
+pub mod browser;
+pub mod budget;
+pub mod cache;
+pub mod card;
+pub mod config;
+pub mod daemon;
+pub mod digest;
 pub mod dm;
+pub mod drafts;
+pub mod editor;
+pub mod engagement;
+pub mod follows;
+pub mod graph;
+pub mod handoff;
+pub mod hydrate;
+pub mod interactive;
 pub mod label;
+pub mod listen;
 pub mod notification;
+pub mod outbox;
+pub mod pending;
 pub mod post;
+pub mod replay;
+pub mod rss;
+pub mod settings;
+pub mod state;
 pub mod submolt;
+pub mod supervise;
+#[cfg(feature = "tui")]
+pub mod tui;
 pub mod verification;
+pub mod watch;
 
+use crate::alias::{parse_comment_ref, parse_content_ref, parse_conversation_ref, parse_post_ref};
 use crate::api::client::MoltbookClient;
 use crate::api::error::ApiError;
+use crate::api::types::NotificationSettings;
+use crate::config::{Config, Defaults};
+use crate::display::comments::DEFAULT_REPLY_DEPTH;
 use clap::{Parser, Subcommand};
 use colored::Colorize;
+use std::time::Duration;
 
 /// The root CLI structure for Moltbook.
 #[derive(Parser)]
@@ -42,6 +79,62 @@ pub struct Cli {
     /// Enable debug mode to see raw API requests and responses.
     #[arg(long, global = true)]
     pub debug: bool,
+
+    /// Queue posts, comments and DMs in the offline outbox if the API is unreachable.
+    #[arg(long, global = true)]
+    pub outbox: bool,
+
+    /// Hide progress bars (also hidden when stdout is not a terminal).
+    #[arg(long, global = true)]
+    pub quiet: bool,
+
+    /// Compact, uncolored plain text with capped lengths, for pasting into an LLM context.
+    #[arg(long, global = true)]
+    pub llm: bool,
+
+    /// Pause a random delay between batch actions; takes an optional range like --jitter=2-8s.
+    #[arg(
+        long,
+        global = true,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = crate::jitter::DEFAULT_RANGE,
+        value_name = "RANGE"
+    )]
+    pub jitter: Option<crate::jitter::Jitter>,
+
+    /// Give up on any request that takes longer, e.g. 10s or 2m [default: 30s; 90s per
+    /// page with --all; 5m for uploads]
+    #[arg(long, global = true, value_name = "DURATION", value_parser = crate::api::client::parse_timeout)]
+    pub timeout: Option<std::time::Duration>,
+
+    /// Save the API requests, responses and output of this run to a transcript file.
+    #[arg(long, global = true, value_name = "FILE")]
+    pub record: Option<std::path::PathBuf>,
+
+    /// Run as a saved profile instead of the active one (see `account list`).
+    #[arg(long, global = true, value_name = "NAME", value_parser = parse_profile)]
+    pub profile: Option<String>,
+}
+
+fn parse_profile(s: &str) -> Result<String, String> {
+    crate::config::validate_profile_name(s).map(|()| s.to_string())
+}
+
+/// Shortest interval between polls of the long-running watchers.
+const MIN_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Parses a poll interval such as `30s` or `2m`; a bare number is seconds.
+fn parse_interval(s: &str) -> Result<Duration, String> {
+    let interval = crate::api::client::parse_timeout(s)
+        .map_err(|_| format!("invalid interval '{}' (expected e.g. 30s or 2m)", s.trim()))?;
+    if interval < MIN_POLL_INTERVAL {
+        return Err(format!(
+            "interval must be at least {}s",
+            MIN_POLL_INTERVAL.as_secs()
+        ));
+    }
+    Ok(interval)
 }
 
 #[derive(Subcommand, Debug)]
@@ -69,16 +162,25 @@ pub enum Commands {
     },
 
     /// View your profile information (One-shot)
-    Profile,
+    Profile {
+        /// Score how complete the profile is and list what to fill in
+        #[arg(long)]
+        check: bool,
+
+        /// With --check, emit JSON instead of a checklist
+        #[arg(long, requires = "check")]
+        json: bool,
+    },
 
     /// Get your personalized feed (One-shot)
     Feed {
-        /// Sort order (hot, new, top, rising)
-        #[arg(short, long, default_value = "hot")]
-        sort: String,
+        /// Sort order (hot, new, top, rising) [default: defaults.feed_sort or hot]
+        #[arg(short, long)]
+        sort: Option<String>,
 
-        #[arg(short, long, default_value = "25")]
-        limit: u64,
+        /// Posts per page [default: defaults.feed_limit or 25]
+        #[arg(short, long)]
+        limit: Option<u64>,
 
         /// Filter: all (default) | following
         #[arg(long, default_value = "all")]
@@ -87,6 +189,48 @@ pub enum Commands {
         /// Pagination cursor from a previous response
         #[arg(long)]
         cursor: Option<String>,
+
+        /// Show this page (1-based) of `limit` posts
+        #[arg(long, value_parser = clap::value_parser!(u64).range(1..), conflicts_with_all = ["cursor", "all", "offset"])]
+        page: Option<u64>,
+
+        /// Skip this many posts before the first one listed
+        #[arg(long, conflicts_with_all = ["cursor", "all"])]
+        offset: Option<u64>,
+
+        /// Show near-duplicate posts individually instead of collapsing them
+        #[arg(long)]
+        no_dedupe: bool,
+
+        /// Fetch every page, rendering each as it arrives
+        #[arg(long)]
+        all: bool,
+        /// Only show posts from submolts you subscribed to via `moltbook subscribe`
+        #[arg(long)]
+        only_subscribed: bool,
+
+        /// Only show posts by agents you follow
+        #[arg(long)]
+        only_following: bool,
+        /// Include deleted and removed posts, shown as tombstones (for moderators)
+        #[arg(long)]
+        include_deleted: bool,
+
+        /// Output format; jsonl writes one post per line as pages arrive [default: defaults.format or text]
+        #[arg(long, value_enum)]
+        format: Option<crate::display::OutputFormat>,
+
+        /// Content lines shown per post, 0 for all [default: defaults.preview_lines or 3]
+        #[arg(long, value_name = "N")]
+        preview_lines: Option<usize>,
+
+        /// Show each post's full content
+        #[arg(long, conflicts_with = "preview_lines")]
+        no_truncate: bool,
+
+        /// Only list posts new or changed since the hashes in this state file, then update it
+        #[arg(long, value_name = "STATE_FILE")]
+        changed_since: Option<std::path::PathBuf>,
     },
 
     /// List posts by a specific agent (defaults to yourself)
@@ -99,26 +243,106 @@ pub enum Commands {
         #[arg(short, long, default_value = "new")]
         sort: String,
 
-        #[arg(short, long, default_value = "25")]
-        limit: u64,
+        /// Posts per page [default: defaults.feed_limit or 25]
+        #[arg(short, long)]
+        limit: Option<u64>,
 
         /// Pagination cursor from a previous response
         #[arg(long)]
         cursor: Option<String>,
+
+        /// Show this page (1-based) of `limit` posts
+        #[arg(long, value_parser = clap::value_parser!(u64).range(1..), conflicts_with_all = ["cursor", "all", "offset"])]
+        page: Option<u64>,
+
+        /// Skip this many posts before the first one listed
+        #[arg(long, conflicts_with_all = ["cursor", "all"])]
+        offset: Option<u64>,
+
+        /// Show near-duplicate posts individually instead of collapsing them
+        #[arg(long)]
+        no_dedupe: bool,
+
+        /// Fetch every page, rendering each as it arrives
+        #[arg(long)]
+        all: bool,
+
+        /// Include deleted and removed posts, shown as tombstones (for moderators)
+        #[arg(long)]
+        include_deleted: bool,
+
+        /// Output format; jsonl writes one post per line as pages arrive [default: defaults.format or text]
+        #[arg(long, value_enum)]
+        format: Option<crate::display::OutputFormat>,
+
+        /// Content lines shown per post, 0 for all [default: defaults.preview_lines or 3]
+        #[arg(long, value_name = "N")]
+        preview_lines: Option<usize>,
+
+        /// Show each post's full content
+        #[arg(long, conflicts_with = "preview_lines")]
+        no_truncate: bool,
+
+        /// Only list posts new or changed since the hashes in this state file, then update it
+        #[arg(long, value_name = "STATE_FILE")]
+        changed_since: Option<std::path::PathBuf>,
     },
 
     /// Get global posts (not personalized) (One-shot)
     Global {
-        /// Sort order (hot, new, top, rising)
-        #[arg(short, long, default_value = "hot")]
-        sort: String,
+        /// Sort order (hot, new, top, rising) [default: defaults.feed_sort or hot]
+        #[arg(short, long)]
+        sort: Option<String>,
 
-        #[arg(short, long, default_value = "25")]
-        limit: u64,
+        /// Posts per page [default: defaults.feed_limit or 25]
+        #[arg(short, long)]
+        limit: Option<u64>,
 
         /// Pagination cursor from a previous response
         #[arg(long)]
         cursor: Option<String>,
+
+        /// Show this page (1-based) of `limit` posts
+        #[arg(long, value_parser = clap::value_parser!(u64).range(1..), conflicts_with_all = ["cursor", "all", "offset"])]
+        page: Option<u64>,
+
+        /// Skip this many posts before the first one listed
+        #[arg(long, conflicts_with_all = ["cursor", "all"])]
+        offset: Option<u64>,
+
+        /// Show near-duplicate posts individually instead of collapsing them
+        #[arg(long)]
+        no_dedupe: bool,
+
+        /// Fetch every page, rendering each as it arrives
+        #[arg(long)]
+        all: bool,
+        /// Only show posts from submolts you subscribed to via `moltbook subscribe`
+        #[arg(long)]
+        only_subscribed: bool,
+
+        /// Only show posts by agents you follow
+        #[arg(long)]
+        only_following: bool,
+        /// Include deleted and removed posts, shown as tombstones (for moderators)
+        #[arg(long)]
+        include_deleted: bool,
+
+        /// Output format; jsonl writes one post per line as pages arrive [default: defaults.format or text]
+        #[arg(long, value_enum)]
+        format: Option<crate::display::OutputFormat>,
+
+        /// Content lines shown per post, 0 for all [default: defaults.preview_lines or 3]
+        #[arg(long, value_name = "N")]
+        preview_lines: Option<usize>,
+
+        /// Show each post's full content
+        #[arg(long, conflicts_with = "preview_lines")]
+        no_truncate: bool,
+
+        /// Only list posts new or changed since the hashes in this state file, then update it
+        #[arg(long, value_name = "STATE_FILE")]
+        changed_since: Option<std::path::PathBuf>,
     },
 
     /// Create a new post (One-shot)
@@ -154,6 +378,10 @@ pub enum Commands {
         /// URL (Positional)
         #[arg(index = 4)]
         url_pos: Option<String>,
+
+        /// Read the post content from a file (`-` for stdin)
+        #[arg(long, value_name = "PATH", conflicts_with_all = ["content", "content_pos"])]
+        content_file: Option<std::path::PathBuf>,
     },
 
     /// View posts from a specific submolt (One-shot)
@@ -161,32 +389,143 @@ pub enum Commands {
         /// Submolt name
         name: String,
 
-        /// Sort order (hot, new, top, rising)
-        #[arg(short, long, default_value = "hot")]
-        sort: String,
+        /// Sort order (hot, new, top, rising) [default: defaults.feed_sort or hot]
+        #[arg(short, long)]
+        sort: Option<String>,
 
-        #[arg(short, long, default_value = "25")]
-        limit: u64,
+        /// Posts per page [default: defaults.feed_limit or 25]
+        #[arg(short, long)]
+        limit: Option<u64>,
 
         /// Pagination cursor from a previous response
         #[arg(long)]
         cursor: Option<String>,
+
+        /// Show this page (1-based) of `limit` posts
+        #[arg(long, value_parser = clap::value_parser!(u64).range(1..), conflicts_with_all = ["cursor", "all", "offset"])]
+        page: Option<u64>,
+
+        /// Skip this many posts before the first one listed
+        #[arg(long, conflicts_with_all = ["cursor", "all"])]
+        offset: Option<u64>,
+
+        /// Show near-duplicate posts individually instead of collapsing them
+        #[arg(long)]
+        no_dedupe: bool,
+
+        /// Fetch every page, rendering each as it arrives
+        #[arg(long)]
+        all: bool,
+
+        /// Include deleted and removed posts, shown as tombstones (for moderators)
+        #[arg(long)]
+        include_deleted: bool,
+
+        /// Output format; jsonl writes one post per line as pages arrive [default: defaults.format or text]
+        #[arg(long, value_enum)]
+        format: Option<crate::display::OutputFormat>,
+
+        /// Content lines shown per post, 0 for all [default: defaults.preview_lines or 3]
+        #[arg(long, value_name = "N")]
+        preview_lines: Option<usize>,
+
+        /// Show each post's full content
+        #[arg(long, conflicts_with = "preview_lines")]
+        no_truncate: bool,
+
+        /// Only list posts new or changed since the hashes in this state file, then update it
+        #[arg(long, value_name = "STATE_FILE")]
+        changed_since: Option<std::path::PathBuf>,
+    },
+
+    /// Suggest posting windows from the engagement of recent posts by weekday and hour (One-shot)
+    BestTime {
+        /// Only analyze posts in this submolt
+        #[arg(short, long)]
+        submolt: Option<String>,
+
+        /// Pages of new posts to sample first; 0 analyzes only posts sampled before
+        #[arg(long, default_value = "3")]
+        pages: usize,
+    },
+
+    /// Run configured actions when signed webhook calls arrive (Long-running)
+    Listen {
+        /// Port to listen on
+        #[arg(short, long, default_value = "9090")]
+        port: u16,
+
+        /// Address to bind; use 0.0.0.0 to accept calls from other machines
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+    },
+
+    /// Stream new posts from a submolt as they appear (Long-running)
+    WatchSubmolt {
+        /// Submolt name
+        name: String,
+
+        /// Seconds between polls
+        #[arg(long, default_value = "60", value_parser = clap::value_parser!(u64).range(10..))]
+        interval: u64,
+
+        /// Newest posts fetched per poll
+        #[arg(short, long, default_value = "25")]
+        limit: u64,
+
+        /// Output format; jsonl writes one post per line [default: defaults.format or text]
+        #[arg(long, value_enum)]
+        format: Option<crate::display::OutputFormat>,
+    },
+
+    /// Create link posts from the new items of an RSS or Atom feed (One-shot)
+    ImportRss {
+        /// URL of the feed
+        feed_url: String,
+
+        /// Submolt to post in [default: defaults.submolt or general]
+        #[arg(short, long)]
+        submolt: Option<String>,
+
+        /// Maximum number of items to import in this run
+        #[arg(long, default_value = "5")]
+        max: usize,
+
+        /// List the items that would be imported without posting
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// View a specific post (One-shot)
     ViewPost {
-        /// Post ID
+        /// Post ID, %N from the last listing, or an alias
+        #[arg(value_parser = parse_post_ref)]
         post_id: String,
+
+        /// Also show the top N comments (default 10), fetched alongside the post
+        #[arg(
+            long,
+            value_name = "N",
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "10"
+        )]
+        with_comments: Option<u64>,
+
+        /// Reply levels shown under each comment; deeper replies are collapsed
+        #[arg(long, default_value_t = DEFAULT_REPLY_DEPTH, requires = "with_comments")]
+        depth: usize,
     },
 
     /// View comments on a post (One-shot)
     Comments {
-        /// Post ID
+        /// Post ID, %N from the last listing, or an alias
+        #[arg(value_parser = parse_post_ref)]
         post_id: String,
 
-        /// Sort order (best, new, old)
-        #[arg(short, long, default_value = "best")]
-        sort: String,
+        /// Sort order (best, new, old) [default: defaults.comment_sort or best]
+        #[arg(short, long)]
+        sort: Option<String>,
 
         #[arg(short, long, default_value = "35")]
         limit: u64,
@@ -194,11 +533,20 @@ pub enum Commands {
         /// Pagination cursor from a previous response
         #[arg(long)]
         cursor: Option<String>,
+
+        /// Only show comments and replies posted since you last viewed this post
+        #[arg(long)]
+        new_only: bool,
+
+        /// Reply levels shown under each comment; deeper replies are collapsed
+        #[arg(long, default_value_t = DEFAULT_REPLY_DEPTH)]
+        depth: usize,
     },
 
     /// Comment on a post (One-shot)
     Comment {
-        /// Post ID
+        /// Post ID, %N from the last listing, or an alias
+        #[arg(value_parser = parse_post_ref)]
         post_id: String,
 
         /// Comment content (positional)
@@ -207,51 +555,184 @@ pub enum Commands {
         /// Comment content (flagged)
         #[arg(short, long = "content")]
         content_flag: Option<String>,
+
+        /// Read the comment from a file (`-` for stdin)
+        #[arg(long, alias = "from-file", value_name = "PATH", conflicts_with_all = ["content", "content_flag"])]
+        content_file: Option<std::path::PathBuf>,
+
+        /// Refuse comments longer than this (capped at the API's limit)
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+        max_chars: Option<u64>,
+
+        /// Cut an over-long comment at the last sentence that fits
+        #[arg(long)]
+        truncate: bool,
+
+        /// Show the final text and its length without posting
+        #[arg(long)]
+        preview: bool,
+
+        /// Mark the comment as an official moderator reply
+        #[arg(long)]
+        distinguish: bool,
     },
 
     /// Reply to a comment (One-shot)
     ReplyComment {
-        /// Post ID
+        /// Post ID, %N from the last listing, or an alias
+        #[arg(value_parser = parse_post_ref)]
         post_id: String,
 
-        /// Parent comment ID
+        /// Parent comment ID, %N from the last listing, or an alias
+        #[arg(value_parser = parse_comment_ref)]
         parent_id: String,
 
         /// Comment content
         #[arg(short, long)]
         content: Option<String>,
+
+        /// Mark the reply as an official moderator reply
+        #[arg(long)]
+        distinguish: bool,
     },
 
-    /// Upvote a post (One-shot)
-    Upvote {
-        /// Post ID
+    /// Reply to a comment, showing it first; the post is looked up (One-shot | Interactive)
+    Reply {
+        /// Comment ID, %N from the last listing, or an alias
+        #[arg(value_parser = parse_comment_ref)]
+        comment_id: String,
+
+        /// Reply content (prompted for when omitted)
+        content: Option<String>,
+
+        /// Start the reply with a quoted excerpt of the comment
+        #[arg(short, long)]
+        quote: bool,
+
+        /// Post the comment belongs to, if the API does not report it
+        #[arg(long, value_parser = parse_post_ref)]
+        post: Option<String>,
+
+        /// Reply without the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Mark the reply as an official moderator reply
+        #[arg(long)]
+        distinguish: bool,
+    },
+
+    /// Upvote a post and comment on it in one step (One-shot)
+    Engage {
+        /// Post ID, %N from the last listing, or an alias
+        #[arg(value_parser = parse_post_ref)]
         post_id: String,
+
+        /// Upvote the post
+        #[arg(long, required_unless_present = "comment")]
+        upvote: bool,
+
+        /// Comment content
+        #[arg(short, long)]
+        comment: Option<String>,
+    },
+
+    /// Upvote a post, or every post listed in a file with --batch (One-shot)
+    Upvote {
+        /// Post ID, %N from the last listing, or an alias
+        #[arg(value_parser = parse_post_ref, required_unless_present = "batch")]
+        post_id: Option<String>,
+
+        /// File of post IDs, one per line (`-` for stdin)
+        #[arg(long, value_name = "FILE", conflicts_with = "post_id")]
+        batch: Option<std::path::PathBuf>,
+
+        /// Pause between votes in a batch, in milliseconds
+        #[arg(long, default_value = "1500", requires = "batch")]
+        delay_ms: u64,
     },
 
-    /// Downvote a post (One-shot)
+    /// Downvote a post, or every post listed in a file with --batch (One-shot)
     Downvote {
-        /// Post ID
-        post_id: String,
+        /// Post ID, %N from the last listing, or an alias
+        #[arg(value_parser = parse_post_ref, required_unless_present = "batch")]
+        post_id: Option<String>,
+
+        /// File of post IDs, one per line (`-` for stdin)
+        #[arg(long, value_name = "FILE", conflicts_with = "post_id")]
+        batch: Option<std::path::PathBuf>,
+
+        /// Pause between votes in a batch, in milliseconds
+        #[arg(long, default_value = "1500", requires = "batch")]
+        delay_ms: u64,
     },
 
-    /// Report a post to moderators (One-shot)
+    /// Report a post or comment to moderators (One-shot)
     Report {
-        /// Post ID
-        post_id: String,
-        /// Report reason (spam, harassment, rule-violation, other)
-        #[arg(short, long, default_value = "spam")]
+        /// Post ID (or comment ID with --comment), %N, or an alias
+        #[arg(value_parser = parse_content_ref)]
+        target_id: String,
+        /// Treat the ID as a comment rather than a post
+        #[arg(long)]
+        comment: bool,
+        /// Report reason
+        #[arg(
+            short,
+            long,
+            default_value = "spam",
+            value_parser = ["spam", "abuse", "harassment", "rule-violation", "other"]
+        )]
         reason: String,
+        /// Additional context for the moderators
+        #[arg(short, long)]
+        note: Option<String>,
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+
+    /// List reports you have filed and their status (One-shot)
+    MyReports {
+        #[arg(short, long, default_value = "25")]
+        limit: u64,
+
+        /// Pagination cursor from a previous response
+        #[arg(long)]
+        cursor: Option<String>,
     },
 
     /// Delete a post (One-shot)
     DeletePost {
-        /// Post ID
+        /// Post ID, %N from the last listing, or an alias
+        #[arg(value_parser = parse_post_ref)]
         post_id: String,
     },
 
     /// Upvote a comment (One-shot)
     UpvoteComment {
-        /// Comment ID
+        /// Comment ID, %N from the last listing, or an alias
+        #[arg(value_parser = parse_comment_ref)]
+        comment_id: String,
+    },
+
+    /// Downvote a comment (One-shot)
+    DownvoteComment {
+        /// Comment ID, %N from the last listing, or an alias
+        #[arg(value_parser = parse_comment_ref)]
+        comment_id: String,
+    },
+
+    /// Remove your vote from a post (One-shot)
+    Unvote {
+        /// Post ID, %N from the last listing, or an alias
+        #[arg(value_parser = parse_post_ref)]
+        post_id: String,
+    },
+
+    /// Remove your vote from a comment (One-shot)
+    UnvoteComment {
+        /// Comment ID, %N from the last listing, or an alias
+        #[arg(value_parser = parse_comment_ref)]
         comment_id: String,
     },
 
@@ -278,8 +759,32 @@ pub enum Commands {
         limit: u64,
 
         /// Pagination cursor from a previous response
-        #[arg(long)]
+        #[arg(long, conflicts_with = "page")]
         cursor: Option<String>,
+
+        /// Start at this page (1-based), following cursors from the first page
+        #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+        page: Option<u64>,
+
+        /// Skip this many results before the first one listed
+        #[arg(long, conflicts_with_all = ["cursor", "page"])]
+        offset: Option<u64>,
+
+        /// Fetch every page, hiding results repeated across pages
+        #[arg(long)]
+        all: bool,
+
+        /// Show why each result matched: score breakdown and matched terms
+        #[arg(long)]
+        explain: bool,
+
+        /// Output format; jsonl writes one result per line as pages arrive [default: defaults.format or text]
+        #[arg(long, value_enum, conflicts_with = "explain")]
+        format: Option<crate::display::OutputFormat>,
+
+        /// Show karma and owner for each result's author, fetching profiles concurrently
+        #[arg(long, conflicts_with = "format")]
+        hydrate: bool,
     },
 
     /// List all submolts (One-shot)
@@ -292,6 +797,27 @@ pub enum Commands {
         limit: u64,
     },
 
+    /// Suggest submolts for a topic, then optionally subscribe (One-shot | Interactive)
+    Discover {
+        /// Topic to find communities for, e.g. "reinforcement learning"
+        topic: String,
+
+        /// Maximum number of suggestions
+        #[arg(short, long, default_value = "5")]
+        limit: usize,
+    },
+
+    /// Compare submolts side by side: subscribers, volume, score, contributors (One-shot)
+    CompareSubmolts {
+        /// Submolt names (two or more)
+        #[arg(required = true, num_args = 2..)]
+        names: Vec<String>,
+
+        /// Newest posts sampled per submolt for volume, score and contributors
+        #[arg(long, default_value = "50")]
+        sample: u64,
+    },
+
     /// Create a new submolt (One-shot)
     CreateSubmolt {
         /// URL-safe name (lowercase, hyphens)
@@ -304,12 +830,24 @@ pub enum Commands {
         /// Allow cryptocurrency posts
         #[arg(long)]
         allow_crypto: bool,
+        /// Send without first checking the name is valid and not taken
+        #[arg(long)]
+        skip_check: bool,
     },
 
     /// Subscribe to a submolt (One-shot)
     Subscribe {
         /// Submolt name
-        name: String,
+        #[arg(required_unless_present = "interactive")]
+        name: Option<String>,
+
+        /// Pick several popular submolts from a list instead
+        #[arg(short, long, conflicts_with = "name")]
+        interactive: bool,
+
+        /// Popular submolts to list with --interactive
+        #[arg(short, long, default_value = "25", requires = "interactive")]
+        limit: u64,
     },
 
     /// Unsubscribe from a submolt (One-shot)
@@ -355,6 +893,12 @@ pub enum Commands {
         name: String,
     },
 
+    /// Audit follow relationships that only go one way (One-shot)
+    Follows {
+        #[command(subcommand)]
+        command: FollowsCommands,
+    },
+
     /// View another molty's profile (One-shot)
     ViewProfile {
         /// Molty name
@@ -364,7 +908,12 @@ pub enum Commands {
     /// Update your profile description (One-shot)
     UpdateProfile {
         /// New description
-        description: String,
+        #[arg(required_unless_present = "meta")]
+        description: Option<String>,
+
+        /// Set a metadata key shown on your profile and card (repeatable)
+        #[arg(long = "meta", value_name = "KEY=VALUE", value_parser = crate::completeness::parse_meta)]
+        meta: Vec<(String, String)>,
     },
 
     /// Upload a new avatar (One-shot)
@@ -376,25 +925,131 @@ pub enum Commands {
     /// Remove your avatar (One-shot)
     RemoveAvatar,
 
+    /// Generated avatars
+    Avatar {
+        #[command(subcommand)]
+        command: AvatarCommands,
+    },
+
+    /// Print a shareable capability card for your agent as markdown or JSON (One-shot)
+    Card {
+        /// Number of top posts to include
+        #[arg(short, long, default_value = "3")]
+        top: usize,
+
+        /// Emit JSON instead of markdown
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Report impressions, votes, comments and follower change for your recent posts (One-shot)
+    Engagement {
+        /// How far back to look, e.g. 30d, 2w or 12h
+        #[arg(long, default_value = "30d", value_parser = crate::engagement::parse_period)]
+        period: chrono::TimeDelta,
+
+        /// Output format; json suits dashboards
+        #[arg(long, default_value = "text", value_parser = ["text", "json"])]
+        format: String,
+    },
+
+    /// Export the follower graph around your agent (One-shot)
+    Graph {
+        #[command(subcommand)]
+        command: GraphCommands,
+    },
+
+    /// Cross-post one of your posts to Mastodon or X (One-shot)
+    Bridge {
+        /// Platform to publish on
+        #[arg(long, value_enum)]
+        to: crate::bridge::Platform,
+
+        /// Post ID, %N from the last listing, or an alias
+        #[arg(long, value_parser = parse_post_ref)]
+        post: String,
+
+        /// Show the formatted status without publishing it
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Publish without asking for confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+
+    /// Print (or open) the owner dashboard link for this agent (One-shot)
+    Dashboard {
+        /// Open the link in the default browser
+        #[arg(long)]
+        open: bool,
+    },
+
     /// Set up owner email for dashboard access (One-shot)
     SetupOwnerEmail {
         /// Human owner's email
-        email: String,
+        #[arg(required_unless_present = "resend")]
+        email: Option<String>,
+
+        /// Resend the verification email to the address already set
+        #[arg(long, conflicts_with = "email")]
+        resend: bool,
     },
 
+    /// Show whether the owner email is set and verified (One-shot)
+    OwnerStatus,
+
     /// Unified home dashboard — activity, DMs, briefings, feed highlights (One-shot)
     Home,
 
     /// Consolidated check of status, DMs, and feed (Heartbeat)
-    Heartbeat,
+    Heartbeat {
+        /// Keep a refreshing dashboard of status, DMs, rate limits and queues on screen
+        #[arg(long)]
+        watch: bool,
+
+        /// Seconds between refreshes with --watch
+        #[arg(long, default_value = "300", requires = "watch", value_parser = clap::value_parser!(u64).range(10..))]
+        interval: u64,
+    },
+
+    /// Check account status (One-shot | Long-running with --watch)
+    Status {
+        /// Keep a refreshing dashboard of status, DMs, rate limits and queues on screen
+        #[arg(long)]
+        watch: bool,
+
+        /// Seconds between refreshes with --watch
+        #[arg(long, default_value = "300", requires = "watch", value_parser = clap::value_parser!(u64).range(10..))]
+        interval: u64,
+    },
+
+    /// Show which commands your account can use right now, and why not (One-shot)
+    Capabilities {
+        /// Also check your moderator role in this submolt (repeatable)
+        #[arg(short, long = "submolt", value_name = "NAME")]
+        submolts: Vec<String>,
 
-    /// Check account status (One-shot)
-    Status,
+        /// Emit JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
 
     // === DM Commands ===
     /// Check for DM activity (One-shot)
     DmCheck,
 
+    /// Report new DM requests and unread messages as they arrive (Long-running)
+    DmWatch {
+        /// Time between polls, e.g. 30s or 2m (at least 10s)
+        #[arg(long, default_value = "60s", value_parser = parse_interval)]
+        interval: Duration,
+
+        /// Output format; jsonl writes one event per line [default: defaults.format or text]
+        #[arg(long, value_enum)]
+        format: Option<crate::display::OutputFormat>,
+    },
+
     /// List pending DM requests (One-shot)
     DmRequests,
 
@@ -415,13 +1070,15 @@ pub enum Commands {
 
     /// Approve a DM request (One-shot)
     DmApprove {
-        /// Conversation ID
+        /// Conversation ID, @agent, or an alias
+        #[arg(value_parser = parse_conversation_ref)]
         conversation_id: String,
     },
 
     /// Reject a DM request (One-shot)
     DmReject {
-        /// Conversation ID
+        /// Conversation ID, @agent, or an alias
+        #[arg(value_parser = parse_conversation_ref)]
         conversation_id: String,
 
         /// Block future requests
@@ -434,13 +1091,27 @@ pub enum Commands {
 
     /// Read messages in a conversation (One-shot)
     DmRead {
-        /// Conversation ID
+        /// Conversation ID, @agent, or an alias
+        #[arg(value_parser = parse_conversation_ref)]
         conversation_id: String,
+
+        /// Messages per page [default: the API's]
+        #[arg(short, long, value_parser = clap::value_parser!(u64).range(1..))]
+        limit: Option<u64>,
+
+        /// Show the messages before this message ID
+        #[arg(long, value_name = "MESSAGE_ID")]
+        before: Option<String>,
+
+        /// Keep paging back to the start of the conversation
+        #[arg(long)]
+        all: bool,
     },
 
     /// Send a DM (One-shot)
     DmSend {
-        /// Conversation ID
+        /// Conversation ID, @agent, or an alias
+        #[arg(value_parser = parse_conversation_ref)]
         conversation_id: String,
 
         /// Message text
@@ -452,6 +1123,31 @@ pub enum Commands {
         needs_human: bool,
     },
 
+    /// React to a DM with an emoji (One-shot)
+    DmReact {
+        /// Message ID, as shown by dm-read
+        message_id: String,
+
+        /// The emoji, e.g. 👍
+        #[arg(value_parser = clap::builder::NonEmptyStringValueParser::new())]
+        emoji: String,
+    },
+
+    /// Bundle a conversation, the other agent's profile and linked posts into markdown for your human owner (One-shot)
+    Handoff {
+        /// Conversation ID, @agent, or an alias
+        #[arg(value_parser = parse_conversation_ref)]
+        conversation_id: String,
+
+        /// Write the packet here instead of stdout
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+
+        /// Copy the packet to the clipboard
+        #[arg(long)]
+        clipboard: bool,
+    },
+
     // === Label & Role Commands ===
 
     /// Define a label (tag/status/role) in a submolt — moderator only (One-shot)
@@ -529,25 +1225,167 @@ pub enum Commands {
 
     /// Mark all notifications on a post as read (One-shot)
     NotificationsReadPost {
-        /// Post ID whose notifications to mark read
+        /// Post ID whose notifications to mark read (or %N / alias)
+        #[arg(value_parser = parse_post_ref)]
         post_id: String,
     },
 
     /// Mark all notifications as read (One-shot)
     NotificationsReadAll,
 
-    /// Pin a post in a submolt you moderate (One-shot)
-    PinPost {
-        /// Post ID
-        post_id: String,
+    /// Manage and run submolt digests (One-shot)
+    Digest {
+        #[command(subcommand)]
+        command: DigestCommands,
+    },
+
+    /// List posts, comments or DMs whose outcome is unknown after a crash or timeout (One-shot)
+    Pending {
+        /// Re-send each action with its original idempotency key
+        #[arg(long)]
+        replay: bool,
+
+        /// Forget all pending actions without re-sending them
+        #[arg(long, conflicts_with = "replay")]
+        clear: bool,
+    },
+
+    /// Manage short names for post, comment and conversation IDs (One-shot)
+    Alias {
+        #[command(subcommand)]
+        command: AliasCommands,
+    },
+
+    /// Stay running with a warm connection and serve `ctl` commands over a Unix socket
+    Daemon {
+        /// Also serve Prometheus metrics at http://127.0.0.1:<port>/metrics
+        #[arg(long)]
+        metrics_port: Option<u16>,
+    },
+
+    /// Run a command through the session daemon, e.g. `ctl feed --limit 5` (One-shot)
+    Ctl {
+        /// Stop the daemon instead of running a command
+        #[arg(long, conflicts_with = "args")]
+        stop: bool,
+
+        /// The command line to run, without the leading `moltbook`
+        #[arg(
+            trailing_var_arg = true,
+            allow_hyphen_values = true,
+            required_unless_present = "stop"
+        )]
+        args: Vec<String>,
+    },
+
+    /// Inspect or clear the local media cache (One-shot)
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommands,
+    },
+
+    /// List, add and switch between the agent profiles on this machine (One-shot)
+    Account {
+        #[command(subcommand)]
+        command: AccountCommands,
+    },
+
+    /// Maintain the local state files kept in the config directory (One-shot)
+    State {
+        #[command(subcommand)]
+        command: StateCommands,
+    },
+
+    /// Measure rendering performance (requires the `bench` feature) (One-shot)
+    #[cfg(feature = "bench")]
+    Bench {
+        #[command(subcommand)]
+        command: BenchCommands,
+    },
+
+    /// Browse the feed, read comments, upvote and reply in a terminal UI (requires the `tui` feature) (Interactive)
+    #[cfg(feature = "tui")]
+    Tui {
+        /// Sort order (hot, new, top, rising) [default: defaults.feed_sort or hot]
+        #[arg(short, long)]
+        sort: Option<String>,
+
+        /// Posts per page [default: defaults.feed_limit or 25]
+        #[arg(short, long)]
+        limit: Option<u64>,
+    },
+
+    /// Re-render a transcript saved with --record (One-shot)
+    Replay {
+        /// The transcript file
+        file: std::path::PathBuf,
+
+        /// Also list each API request and response
+        #[arg(long)]
+        exchanges: bool,
+    },
+
+    /// Edit local CLI configuration (One-shot)
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+
+    /// Save posts locally and publish them later (One-shot)
+    Draft {
+        #[command(subcommand)]
+        command: DraftCommands,
+    },
+
+    /// Manage requests queued while the API was unreachable (One-shot)
+    Outbox {
+        #[command(subcommand)]
+        command: OutboxCommands,
+    },
+
+    /// Review requests held by `require_approval` (One-shot)
+    Approvals {
+        #[command(subcommand)]
+        command: ApprovalCommands,
+    },
+
+    /// Show today's posts, comments, votes and DMs against the daily budgets (One-shot)
+    Budget,
+
+    /// View or update account settings (One-shot)
+    Settings {
+        #[command(subcommand)]
+        command: SettingsCommands,
+    },
+
+    /// Pin a post in a submolt you moderate (One-shot)
+    PinPost {
+        /// Post ID, %N from the last listing, or an alias
+        #[arg(value_parser = parse_post_ref)]
+        post_id: String,
     },
 
     /// Unpin a post (One-shot)
     UnpinPost {
-        /// Post ID
+        /// Post ID, %N from the last listing, or an alias
+        #[arg(value_parser = parse_post_ref)]
         post_id: String,
     },
 
+    /// Sticky a comment to the top of its thread — moderator only (One-shot)
+    StickyComment {
+        /// Comment ID, %N from the last listing, or an alias
+        #[arg(value_parser = parse_comment_ref)]
+        comment_id: String,
+    },
+
+    /// Remove a comment's sticky status — moderator only (One-shot)
+    UnstickyComment {
+        /// Comment ID, %N from the last listing, or an alias
+        #[arg(value_parser = parse_comment_ref)]
+        comment_id: String,
+    },
+
     /// Update submolt settings (One-shot)
     SubmoltSettings {
         /// Submolt name
@@ -555,18 +1393,37 @@ pub enum Commands {
         /// New description
         #[arg(short, long)]
         description: Option<String>,
-        /// Banner color (Hex)
+        /// Banner color, e.g. #ff4500 or #f40
+        #[arg(long, value_parser = crate::color::parse_hex_color)]
+        banner_color: Option<crate::color::HexColor>,
+        /// Theme color, e.g. #ff4500 or #f40
+        #[arg(long, value_parser = crate::color::parse_hex_color)]
+        theme_color: Option<crate::color::HexColor>,
+        /// Draw swatches of the new colors before submitting
         #[arg(long)]
-        banner_color: Option<String>,
-        /// Theme color (Hex)
+        preview: bool,
+        /// Show what would change without applying it
+        #[arg(long, conflicts_with = "force")]
+        dry_run: bool,
+        /// Apply without fetching the current settings or asking for confirmation
         #[arg(long)]
-        theme_color: Option<String>,
+        force: bool,
+    },
+
+    /// View or edit a submolt's about/wiki page (One-shot | Interactive)
+    SubmoltWiki {
+        #[command(subcommand)]
+        command: WikiCommands,
     },
 
     /// List submolt moderators (One-shot)
     SubmoltMods {
         /// Submolt name
         name: String,
+
+        /// Show karma, owner and avatar for each moderator, fetching profiles concurrently
+        #[arg(long)]
+        hydrate: bool,
     },
 
     /// Add a submolt moderator (One-shot | Owner Only)
@@ -589,47 +1446,606 @@ pub enum Commands {
     },
 }
 
+#[derive(Subcommand, Debug)]
+pub enum WikiCommands {
+    /// Show the wiki page
+    Get {
+        /// Submolt name
+        name: String,
+    },
+
+    /// Replace the wiki page (opens $EDITOR unless --file is given)
+    Set {
+        /// Submolt name
+        name: String,
+
+        /// Read the new content from a markdown file
+        #[arg(short, long)]
+        file: Option<std::path::PathBuf>,
+
+        /// Save without the preview confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum AvatarCommands {
+    /// Render an avatar from your agent name and upload it
+    Generate {
+        /// Pattern to draw
+        #[arg(long, value_enum, default_value = "identicon")]
+        style: crate::avatar::AvatarStyle,
+
+        /// Write the PNG here instead of uploading it
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum GraphCommands {
+    /// Walk followers and following breadth-first and write a graph file
+    Export {
+        /// File format: DOT for Graphviz, GraphML for Gephi
+        #[arg(long, value_enum, default_value = "dot")]
+        format: crate::graph::GraphFormat,
+
+        /// Hops to walk out from your agent
+        #[arg(long, default_value = "1", value_parser = clap::value_parser!(u8).range(1..=3))]
+        depth: u8,
+
+        /// Maximum agents read from each follower or following list
+        #[arg(long, default_value = "200")]
+        limit: usize,
+
+        /// Write the graph here instead of stdout
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum FollowsCommands {
+    /// List agents who don't follow back and followers you don't follow
+    Audit {
+        /// Unfollow every agent that doesn't follow back
+        #[arg(long)]
+        unfollow_nonmutual: bool,
+
+        /// Re-fetch both lists instead of using the cached copy
+        #[arg(long)]
+        refresh: bool,
+
+        /// Unfollow without asking for confirmation
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Show karma and owner for each listed agent, fetching profiles concurrently
+        #[arg(long)]
+        hydrate: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum CacheCommands {
+    /// Show how many files the cache holds and their size
+    Stats,
+
+    /// Delete every cached file
+    Clear,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum AccountCommands {
+    /// Show the saved profiles and which one is active
+    List,
+
+    /// Make a profile the one used when --profile is not given
+    Switch {
+        /// Profile name, or `default`
+        name: String,
+    },
+
+    /// Add a profile and save its credentials (One-shot | Interactive)
+    Add {
+        /// Profile name (letters, digits, '-' or '_')
+        name: String,
+
+        /// API Key
+        #[arg(short, long)]
+        api_key: Option<String>,
+
+        /// Agent name
+        #[arg(long)]
+        agent_name: Option<String>,
+
+        /// Also make it the active profile
+        #[arg(long)]
+        switch: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum StateCommands {
+    /// Delete caches and leftovers of interrupted writes, and set aside unreadable files
+    Clean {
+        /// Show what would be removed without touching anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[cfg(feature = "bench")]
+#[derive(Subcommand, Debug)]
+pub enum BenchCommands {
+    /// Render synthetic posts and report throughput and allocations
+    Render {
+        /// Number of posts to render
+        #[arg(long, default_value_t = 1000)]
+        posts: usize,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum AliasCommands {
+    /// Show user-defined names and recorded handles
+    List,
+
+    /// Name an item, e.g. `alias set intro %1`
+    Set {
+        /// Alias name (letters, digits, '-' or '_')
+        name: String,
+
+        /// ID or handle (%N, @agent) to name
+        target: String,
+
+        /// Kind of a raw ID; handles carry their own kind
+        #[arg(long, default_value = "post", value_parser = ["post", "comment", "conversation"])]
+        kind: String,
+    },
+
+    /// Delete a user-defined name
+    Remove {
+        /// Alias name
+        name: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommands {
+    /// Show every setting and its effective value
+    List,
+
+    /// Print a single value
+    Get {
+        /// Key: agent_name | defaults.submolt | defaults.feed_sort | defaults.feed_limit | defaults.comment_sort | defaults.preview_lines | defaults.format
+        key: String,
+    },
+
+    /// Set a value, e.g. `config set defaults.submolt rust`
+    Set {
+        /// Key: agent_name | defaults.submolt | defaults.feed_sort | defaults.feed_limit | defaults.comment_sort | defaults.preview_lines | defaults.format
+        key: String,
+
+        /// New value
+        value: String,
+    },
+
+    /// Clear a default so the built-in value applies
+    Unset {
+        /// Key to clear (defaults.*)
+        key: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum DraftCommands {
+    /// Save a post as a draft; without content or a URL, opens $EDITOR
+    Save {
+        /// Post title
+        title: String,
+
+        /// Submolt to post in [default: defaults.submolt or general]
+        #[arg(short, long)]
+        submolt: Option<String>,
+
+        /// Post content
+        #[arg(short, long)]
+        content: Option<String>,
+
+        /// Read the post content from a file (`-` for stdin)
+        #[arg(long, value_name = "PATH", conflicts_with = "content")]
+        content_file: Option<std::path::PathBuf>,
+
+        /// URL for a link post
+        #[arg(short, long)]
+        url: Option<String>,
+    },
+
+    /// List saved drafts
+    List,
+
+    /// Change a draft; without options, opens its content in $EDITOR
+    Edit {
+        /// Draft ID as shown by `draft list`
+        id: String,
+
+        /// New title
+        #[arg(short, long)]
+        title: Option<String>,
+
+        /// New submolt
+        #[arg(short, long)]
+        submolt: Option<String>,
+
+        /// New content
+        #[arg(short, long)]
+        content: Option<String>,
+
+        /// Read the new content from a file (`-` for stdin)
+        #[arg(long, value_name = "PATH", conflicts_with = "content")]
+        content_file: Option<std::path::PathBuf>,
+
+        /// New URL (empty to remove it)
+        #[arg(short, long)]
+        url: Option<String>,
+    },
+
+    /// Post a draft, removing it once the post is created
+    Publish {
+        /// Draft ID as shown by `draft list`
+        id: String,
+    },
+
+    /// Delete a draft without posting it
+    Delete {
+        /// Draft ID as shown by `draft list`
+        id: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum OutboxCommands {
+    /// List queued requests
+    List,
+
+    /// Deliver queued requests in order
+    Flush,
+
+    /// Discard a queued request, or all of them, without sending
+    Drop {
+        /// Entry ID as shown by `outbox list`, or enough of it to match one entry
+        #[arg(required_unless_present = "all")]
+        id: Option<String>,
+
+        /// Discard every queued request
+        #[arg(long, conflicts_with = "id")]
+        all: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ApprovalCommands {
+    /// List requests awaiting approval
+    List,
+
+    /// Send a queued request (needs a terminal while require_approval is set)
+    Approve {
+        /// Request ID as shown by `approvals list`
+        id: String,
+    },
+
+    /// Discard a queued request without sending it
+    Deny {
+        /// Request ID as shown by `approvals list`
+        id: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum SettingsCommands {
+    /// View or update notification and digest preferences
+    Notifications {
+        /// Notify on replies to your posts and comments (true/false)
+        #[arg(long)]
+        replies: Option<bool>,
+
+        /// Notify when you are mentioned (true/false)
+        #[arg(long)]
+        mentions: Option<bool>,
+
+        /// Email your owner when a DM arrives (true/false)
+        #[arg(long)]
+        dm_emails: Option<bool>,
+
+        /// Send a weekly digest to your owner (true/false)
+        #[arg(long)]
+        weekly_digest: Option<bool>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum DigestCommands {
+    /// Subscribe to a periodic digest of a submolt
+    Subscribe {
+        /// Submolt name
+        submolt: String,
+
+        /// Generate the digest weekly (default)
+        #[arg(long, conflicts_with = "daily")]
+        weekly: bool,
+
+        /// Generate the digest daily
+        #[arg(long)]
+        daily: bool,
+    },
+
+    /// Remove a digest subscription
+    Unsubscribe {
+        /// Submolt name
+        submolt: String,
+    },
+
+    /// List digest subscriptions
+    List,
+
+    /// Generate the digest report now
+    Run {
+        /// Only report subscriptions whose period has elapsed
+        #[arg(long)]
+        due: bool,
+
+        /// Max posts fetched per submolt
+        #[arg(short, long, default_value = "10")]
+        limit: u64,
+    },
+}
+
+impl Commands {
+    /// Whether `main` runs the command itself, before any client exists.
+    ///
+    /// These never reach [`execute`], and the daemon refuses them.
+    pub fn is_standalone(&self) -> bool {
+        match self {
+            Commands::Init { .. }
+            | Commands::Register { .. }
+            | Commands::Daemon { .. }
+            | Commands::Ctl { .. }
+            | Commands::Account { .. }
+            | Commands::State { .. }
+            | Commands::Replay { .. } => true,
+            #[cfg(feature = "bench")]
+            Commands::Bench { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the command keeps running until it is interrupted, or takes
+    /// over the terminal.
+    ///
+    /// The daemon refuses these: it runs one command at a time, so one that
+    /// never returns would hang its `ctl` caller and every command after it.
+    pub fn runs_until_stopped(&self) -> bool {
+        #[cfg(feature = "tui")]
+        if matches!(self, Commands::Tui { .. }) {
+            return true;
+        }
+        matches!(
+            self,
+            Commands::Listen { .. }
+                | Commands::WatchSubmolt { .. }
+                | Commands::DmWatch { .. }
+                | Commands::Status { watch: true, .. }
+                | Commands::Heartbeat { watch: true, .. }
+        )
+    }
+}
+
+fn standalone_error() -> ApiError {
+    ApiError::ConfigError("This command cannot run here; run it directly".to_string())
+}
+
 // Re-export core functions needed by main.rs
 pub use account::{init, register_command};
 
 /// Dispatches the chosen command to its respective implementation function.
 ///
 /// This function acts as the central router for the CLI application.
-pub async fn execute(command: Commands, client: &MoltbookClient) -> Result<(), ApiError> {
+pub async fn execute(
+    command: Commands,
+    client: &MoltbookClient,
+    config: &Config,
+) -> Result<(), ApiError> {
+    let defaults = &config.defaults;
     match command {
         Commands::Init { .. } => {
             println!("{}", "Configuration already initialized.".yellow());
             Ok(())
         }
-        Commands::Register { .. } => {
-            unreachable!("Register command handled in main.rs");
+        // Handled in main.rs; an error rather than a panic keeps the daemon alive.
+        Commands::Register { .. }
+        | Commands::Daemon { .. }
+        | Commands::Ctl { .. }
+        | Commands::Account { .. }
+        | Commands::State { .. }
+        | Commands::Replay { .. } => Err(standalone_error()),
+        #[cfg(feature = "bench")]
+        Commands::Bench { .. } => Err(standalone_error()),
+        #[cfg(feature = "tui")]
+        Commands::Tui { sort, limit } => {
+            let options = tui::TuiOptions {
+                sort: sort.as_deref().unwrap_or(defaults.feed_sort()),
+                limit: limit.unwrap_or(defaults.feed_limit()),
+                comment_sort: defaults.comment_sort(),
+            };
+            tui::run(client, options).await
         }
         // Account Commands
-        Commands::Profile => account::view_my_profile(client).await,
-        Commands::Status => account::status(client).await,
+        Commands::Profile { check, json } => {
+            if check {
+                account::check_profile(client, json).await
+            } else {
+                account::view_my_profile(client).await
+            }
+        }
+        Commands::Status { watch, interval } => {
+            if watch {
+                supervise::supervise(client, Duration::from_secs(interval)).await
+            } else {
+                account::status(client).await
+            }
+        }
+        Commands::Capabilities { submolts, json } => {
+            account::capabilities(client, &submolts, json).await
+        }
         Commands::Home => account::home(client).await,
-        Commands::Heartbeat => account::heartbeat(client).await,
+        Commands::Heartbeat { watch, interval } => {
+            if watch {
+                supervise::supervise(client, Duration::from_secs(interval)).await
+            } else {
+                account::heartbeat(client).await
+            }
+        }
         Commands::ViewProfile { name } => account::view_agent_profile(client, &name).await,
-        Commands::UpdateProfile { description } => {
-            account::update_profile(client, &description).await
+        Commands::UpdateProfile { description, meta } => {
+            account::update_profile(client, description.as_deref(), &meta).await
         }
         Commands::UploadAvatar { path } => account::upload_avatar(client, &path).await,
         Commands::RemoveAvatar => account::remove_avatar(client).await,
+        Commands::Avatar { command } => match command {
+            AvatarCommands::Generate { style, output } => {
+                account::generate_avatar(client, &config.agent_name, style, output.as_deref()).await
+            }
+        },
+        Commands::Card { top, json } => card::card(client, top, json).await,
+        Commands::Engagement { period, format } => {
+            engagement::engagement(client, period, format == "json").await
+        }
+        Commands::Graph { command } => match command {
+            GraphCommands::Export {
+                format,
+                depth,
+                limit,
+                output,
+            } => graph::export(client, format, depth.into(), limit, output.as_deref()).await,
+        },
+        Commands::Bridge {
+            to,
+            post,
+            dry_run,
+            yes,
+        } => bridge::bridge(client, config, to, &post, dry_run, yes).await,
         Commands::Follow { name } => account::follow(client, &name).await,
         Commands::Unfollow { name } => account::unfollow(client, &name).await,
-        Commands::SetupOwnerEmail { email } => account::setup_owner_email(client, &email).await,
+        Commands::Follows { command } => match command {
+            FollowsCommands::Audit {
+                unfollow_nonmutual,
+                refresh,
+                yes,
+                hydrate,
+            } => follows::audit(client, refresh, unfollow_nonmutual, yes, hydrate).await,
+        },
+        Commands::Dashboard { open } => account::dashboard(client, open).await,
+        Commands::SetupOwnerEmail { email, resend } => match email {
+            Some(email) if !resend => account::setup_owner_email(client, &email).await,
+            _ => account::resend_owner_email(client).await,
+        },
+        Commands::OwnerStatus => account::owner_status(client).await,
         Commands::Verify { code, solution } => account::verify(client, &code, &solution).await,
 
         // Post Commands
-        Commands::Feed { sort, limit, filter, cursor } => {
-            post::feed(client, &sort, limit, &filter, cursor.as_deref()).await
+        Commands::Feed {
+            sort,
+            limit,
+            filter,
+            cursor,
+            page,
+            offset,
+            no_dedupe,
+            all,
+            only_subscribed,
+            only_following,
+            include_deleted,
+            format,
+            preview_lines,
+            no_truncate,
+            changed_since,
+        } => {
+            let sort = sort.as_deref().unwrap_or(defaults.feed_sort());
+            let limit = limit.unwrap_or(defaults.feed_limit());
+            let options = post::ListingOptions {
+                dedupe: !no_dedupe,
+                all,
+                offset: offset_for(page, offset, limit),
+                only_subscribed,
+                only_following,
+                include_deleted,
+                format: format.unwrap_or(defaults.format()),
+                preview_lines: preview_lines_for(preview_lines, no_truncate, defaults),
+                changed_since,
+            };
+            post::feed(client, sort, limit, &filter, cursor.as_deref(), options).await
         }
-        Commands::Posts { author, sort, limit, cursor } => {
+        Commands::Posts {
+            author,
+            sort,
+            limit,
+            cursor,
+            page,
+            offset,
+            no_dedupe,
+            all,
+            include_deleted,
+            format,
+            preview_lines,
+            no_truncate,
+            changed_since,
+        } => {
             let name = author.unwrap_or_else(|| client.agent_name.clone());
-            post::agent_posts(client, &name, &sort, limit, cursor.as_deref()).await
+            let limit = limit.unwrap_or(defaults.feed_limit());
+            let options = post::ListingOptions {
+                dedupe: !no_dedupe,
+                all,
+                offset: offset_for(page, offset, limit),
+                include_deleted,
+                format: format.unwrap_or(defaults.format()),
+                preview_lines: preview_lines_for(preview_lines, no_truncate, defaults),
+                changed_since,
+                ..Default::default()
+            };
+            post::agent_posts(client, &name, &sort, limit, cursor.as_deref(), options).await
         }
-        Commands::Global { sort, limit, cursor } => {
-            post::global_feed(client, &sort, limit, cursor.as_deref()).await
+        Commands::Global {
+            sort,
+            limit,
+            cursor,
+            page,
+            offset,
+            no_dedupe,
+            all,
+            only_subscribed,
+            only_following,
+            include_deleted,
+            format,
+            preview_lines,
+            no_truncate,
+            changed_since,
+        } => {
+            let sort = sort.as_deref().unwrap_or(defaults.feed_sort());
+            let limit = limit.unwrap_or(defaults.feed_limit());
+            let options = post::ListingOptions {
+                dedupe: !no_dedupe,
+                all,
+                offset: offset_for(page, offset, limit),
+                only_subscribed,
+                only_following,
+                include_deleted,
+                format: format.unwrap_or(defaults.format()),
+                preview_lines: preview_lines_for(preview_lines, no_truncate, defaults),
+                changed_since,
+            };
+            post::global_feed(client, sort, limit, cursor.as_deref(), options).await
         }
         Commands::Post {
             title,
@@ -640,7 +2056,12 @@ pub async fn execute(command: Commands, client: &MoltbookClient) -> Result<(), A
             submolt_pos,
             content_pos,
             url_pos,
+            content_file,
         } => {
+            let content = match content_file {
+                Some(path) => Some(post::read_input(&path)?),
+                None => content,
+            };
             post::create_post(
                 client,
                 post::PostParams {
@@ -652,35 +2073,204 @@ pub async fn execute(command: Commands, client: &MoltbookClient) -> Result<(), A
                     submolt_pos,
                     content_pos,
                     url_pos,
+                    default_submolt: defaults.submolt().to_string(),
                 },
             )
             .await
         }
-        Commands::ViewPost { post_id } => post::view_post(client, &post_id).await,
+        Commands::BestTime { submolt, pages } => {
+            best_time::best_time(client, submolt.as_deref(), pages).await
+        }
+        Commands::Listen { port, host } => listen::listen(client, config, &host, port).await,
+        Commands::WatchSubmolt {
+            name,
+            interval,
+            limit,
+            format,
+        } => {
+            let interval = Duration::from_secs(interval);
+            let format = format.unwrap_or(defaults.format());
+            watch::watch_submolt(client, &name, interval, limit, format).await
+        }
+        Commands::ImportRss {
+            feed_url,
+            submolt,
+            max,
+            dry_run,
+        } => {
+            let submolt = submolt.as_deref().unwrap_or(defaults.submolt());
+            rss::import_rss(client, &feed_url, submolt, max, dry_run).await
+        }
+        Commands::ViewPost {
+            post_id,
+            with_comments,
+            depth,
+        } => {
+            let comments = with_comments.map(|limit| (limit, defaults.comment_sort()));
+            post::view_post(client, &post_id, comments, depth).await
+        }
         Commands::DeletePost { post_id } => post::delete_post(client, &post_id).await,
-        Commands::Upvote { post_id } => post::upvote_post(client, &post_id).await,
-        Commands::Downvote { post_id } => post::downvote_post(client, &post_id).await,
-        Commands::Report { post_id, reason } => post::report_post(client, &post_id, Some(reason)).await,
+        Commands::Upvote {
+            post_id,
+            batch,
+            delay_ms,
+        } => match (post_id, batch) {
+            (_, Some(path)) => {
+                post::vote_batch(client, &path, true, Duration::from_millis(delay_ms)).await
+            }
+            (Some(post_id), None) => post::upvote_post(client, &post_id).await,
+            (None, None) => unreachable!("clap requires a post ID or --batch"),
+        },
+        Commands::Downvote {
+            post_id,
+            batch,
+            delay_ms,
+        } => match (post_id, batch) {
+            (_, Some(path)) => {
+                post::vote_batch(client, &path, false, Duration::from_millis(delay_ms)).await
+            }
+            (Some(post_id), None) => post::downvote_post(client, &post_id).await,
+            (None, None) => unreachable!("clap requires a post ID or --batch"),
+        },
+        Commands::Report {
+            target_id,
+            comment,
+            reason,
+            note,
+            yes,
+        } => post::report(client, &target_id, comment, &reason, note, yes).await,
+        Commands::MyReports { limit, cursor } => {
+            post::my_reports(client, limit, cursor.as_deref()).await
+        }
         Commands::Search {
             query,
             type_filter,
             limit,
             cursor,
-        } => post::search(client, &query, &type_filter, limit, cursor.as_deref()).await,
-        Commands::Comments { post_id, sort, limit, cursor } => {
-            post::comments(client, &post_id, &sort, limit, cursor.as_deref()).await
+            page,
+            offset,
+            all,
+            explain,
+            format,
+            hydrate,
+        } => {
+            let options = post::SearchOptions {
+                page: page.map(|p| p as usize),
+                offset,
+                all,
+                explain,
+                // --explain and --hydrate render for the terminal whatever the default.
+                format: match format {
+                    Some(format) => format,
+                    None if explain || hydrate => crate::display::OutputFormat::Text,
+                    None => defaults.format(),
+                },
+                hydrate,
+            };
+            post::search(
+                client,
+                &query,
+                &type_filter,
+                limit,
+                cursor.as_deref(),
+                options,
+            )
+            .await
+        }
+        Commands::Comments {
+            post_id,
+            sort,
+            limit,
+            cursor,
+            new_only,
+            depth,
+        } => {
+            let sort = sort.as_deref().unwrap_or(defaults.comment_sort());
+            post::comments(
+                client,
+                &post_id,
+                sort,
+                limit,
+                cursor.as_deref(),
+                new_only,
+                depth,
+            )
+            .await
         }
         Commands::Comment {
             post_id,
             content,
             content_flag,
-        } => post::create_comment(client, &post_id, content, content_flag, None).await,
+            content_file,
+            max_chars,
+            truncate,
+            preview,
+            distinguish,
+        } => {
+            let content = match content_file {
+                Some(path) => Some(post::read_input(&path)?),
+                None => content,
+            };
+            let draft = post::DraftOptions {
+                max_chars: max_chars.map(|n| n as usize),
+                truncate,
+                preview,
+            };
+            post::create_comment(
+                client,
+                &post_id,
+                content,
+                content_flag,
+                None,
+                distinguish,
+                draft,
+            )
+            .await
+        }
         Commands::ReplyComment {
             post_id,
             parent_id,
             content,
-        } => post::create_comment(client, &post_id, content, None, Some(parent_id)).await,
+            distinguish,
+        } => {
+            post::create_comment(
+                client,
+                &post_id,
+                content,
+                None,
+                Some(parent_id),
+                distinguish,
+                post::DraftOptions::default(),
+            )
+            .await
+        }
+        Commands::Reply {
+            comment_id,
+            content,
+            quote,
+            post: post_id,
+            yes,
+            distinguish,
+        } => {
+            let options = post::ReplyOptions {
+                post_id,
+                quote,
+                yes,
+                distinguish,
+            };
+            post::reply(client, &comment_id, content, options).await
+        }
         Commands::UpvoteComment { comment_id } => post::upvote_comment(client, &comment_id).await,
+        Commands::DownvoteComment { comment_id } => {
+            post::downvote_comment(client, &comment_id).await
+        }
+        Commands::Unvote { post_id } => post::unvote_post(client, &post_id).await,
+        Commands::UnvoteComment { comment_id } => post::unvote_comment(client, &comment_id).await,
+        Commands::Engage {
+            post_id,
+            upvote,
+            comment,
+        } => post::engage(client, &post_id, upvote, comment).await,
 
         // Label & Role Commands
         Commands::LabelDefine {
@@ -703,19 +2293,170 @@ pub async fn execute(command: Commands, client: &MoltbookClient) -> Result<(), A
             notification::read_by_post(client, &post_id).await
         }
         Commands::NotificationsReadAll => notification::read_all(client).await,
+        Commands::Pending { replay, clear } => {
+            if replay {
+                pending::replay(client).await
+            } else if clear {
+                pending::clear()
+            } else {
+                pending::list()
+            }
+        }
+        Commands::Alias { command } => match command {
+            AliasCommands::List => alias::list(),
+            AliasCommands::Set { name, target, kind } => alias::set(&name, &target, &kind),
+            AliasCommands::Remove { name } => alias::remove(&name),
+        },
+        Commands::Cache { command } => match command {
+            CacheCommands::Stats => cache::stats(),
+            CacheCommands::Clear => cache::clear(),
+        },
+        Commands::Config { command } => match command {
+            ConfigCommands::List => config::list(),
+            ConfigCommands::Get { key } => config::get(&key),
+            ConfigCommands::Set { key, value } => config::set(&key, &value),
+            ConfigCommands::Unset { key } => config::unset(&key),
+        },
+        Commands::Draft { command } => match command {
+            DraftCommands::Save {
+                title,
+                submolt,
+                content,
+                content_file,
+                url,
+            } => {
+                let content = match content_file {
+                    Some(path) => Some(post::read_input(&path)?),
+                    None => content,
+                };
+                let submolt = submolt.unwrap_or_else(|| defaults.submolt().to_string());
+                drafts::save(title, submolt, content, url)
+            }
+            DraftCommands::List => drafts::list(),
+            DraftCommands::Edit {
+                id,
+                title,
+                submolt,
+                content,
+                content_file,
+                url,
+            } => {
+                let content = match content_file {
+                    Some(path) => Some(post::read_input(&path)?),
+                    None => content,
+                };
+                let changes = drafts::DraftChanges {
+                    title,
+                    submolt,
+                    content,
+                    url,
+                };
+                drafts::edit(&id, changes)
+            }
+            DraftCommands::Publish { id } => drafts::publish(client, &id).await,
+            DraftCommands::Delete { id } => drafts::delete(&id),
+        },
+        Commands::Outbox { command } => match command {
+            OutboxCommands::List => outbox::list(),
+            OutboxCommands::Flush => outbox::flush(client).await,
+            OutboxCommands::Drop { id, all } => outbox::drop(id.as_deref(), all),
+        },
+        Commands::Approvals { command } => match command {
+            ApprovalCommands::List => approvals::list(),
+            ApprovalCommands::Approve { id } => approvals::approve(client, config, &id).await,
+            ApprovalCommands::Deny { id } => approvals::deny(&id),
+        },
+        Commands::Budget => budget::show(config),
+        Commands::Digest { command } => match command {
+            DigestCommands::Subscribe { submolt, daily, .. } => {
+                let frequency = if daily {
+                    crate::digest::DigestFrequency::Daily
+                } else {
+                    crate::digest::DigestFrequency::Weekly
+                };
+                digest::subscribe(&submolt, frequency)
+            }
+            DigestCommands::Unsubscribe { submolt } => digest::unsubscribe(&submolt),
+            DigestCommands::List => digest::list(),
+            DigestCommands::Run { due, limit } => digest::run(client, due, limit).await,
+        },
+        Commands::Settings { command } => match command {
+            SettingsCommands::Notifications {
+                replies,
+                mentions,
+                dm_emails,
+                weekly_digest,
+            } => {
+                let update = NotificationSettings {
+                    reply_notifications: replies,
+                    mention_notifications: mentions,
+                    dm_emails,
+                    weekly_digest,
+                };
+                settings::notifications(client, update).await
+            }
+        },
 
         // Submolt Commands
         Commands::Submolts { sort, limit } => submolt::list_submolts(client, &sort, limit).await,
-        Commands::Submolt { name, sort, limit, cursor } => {
-            submolt::view_submolt(client, &name, &sort, limit, cursor.as_deref()).await
+        Commands::Discover { topic, limit } => submolt::discover(client, &topic, limit).await,
+        Commands::CompareSubmolts { names, sample } => {
+            submolt::compare(client, &names, sample).await
+        }
+        Commands::Submolt {
+            name,
+            sort,
+            limit,
+            cursor,
+            page,
+            offset,
+            no_dedupe,
+            all,
+            include_deleted,
+            format,
+            preview_lines,
+            no_truncate,
+            changed_since,
+        } => {
+            let sort = sort.as_deref().unwrap_or(defaults.feed_sort());
+            let limit = limit.unwrap_or(defaults.feed_limit());
+            let options = post::ListingOptions {
+                dedupe: !no_dedupe,
+                all,
+                offset: offset_for(page, offset, limit),
+                include_deleted,
+                format: format.unwrap_or(defaults.format()),
+                preview_lines: preview_lines_for(preview_lines, no_truncate, defaults),
+                changed_since,
+                ..Default::default()
+            };
+            submolt::view_submolt(client, &name, sort, limit, cursor.as_deref(), options).await
         }
         Commands::CreateSubmolt {
             name,
             display_name,
             description,
             allow_crypto,
-        } => submolt::create_submolt(client, &name, &display_name, description, allow_crypto).await,
-        Commands::Subscribe { name } => submolt::subscribe(client, &name).await,
+            skip_check,
+        } => {
+            submolt::create_submolt(
+                client,
+                &name,
+                &display_name,
+                description,
+                allow_crypto,
+                skip_check,
+            )
+            .await
+        }
+        Commands::Subscribe {
+            name,
+            interactive,
+            limit,
+        } => match name {
+            Some(name) if !interactive => submolt::subscribe(client, &name).await,
+            _ => submolt::subscribe_interactive(client, limit).await,
+        },
         Commands::Unsubscribe { name } => submolt::unsubscribe(client, &name).await,
         Commands::SubmoltInfo { name, requester_id } => {
             submolt::submolt_info(client, &name, requester_id.as_deref()).await
@@ -728,13 +2469,37 @@ pub async fn execute(command: Commands, client: &MoltbookClient) -> Result<(), A
         }
         Commands::PinPost { post_id } => submolt::pin_post(client, &post_id).await,
         Commands::UnpinPost { post_id } => submolt::unpin_post(client, &post_id).await,
+        Commands::StickyComment { comment_id } => {
+            submolt::sticky_comment(client, &comment_id).await
+        }
+        Commands::UnstickyComment { comment_id } => {
+            submolt::unsticky_comment(client, &comment_id).await
+        }
         Commands::SubmoltSettings {
             name,
             description,
             banner_color,
             theme_color,
-        } => submolt::update_settings(client, &name, description, banner_color, theme_color).await,
-        Commands::SubmoltMods { name } => submolt::list_moderators(client, &name).await,
+            preview,
+            dry_run,
+            force,
+        } => {
+            let update = submolt::SettingsUpdate {
+                description,
+                banner_color,
+                theme_color,
+            };
+            submolt::update_settings(client, &name, update, preview, dry_run, force).await
+        }
+        Commands::SubmoltWiki { command } => match command {
+            WikiCommands::Get { name } => submolt::wiki_get(client, &name).await,
+            WikiCommands::Set { name, file, yes } => {
+                submolt::wiki_set(client, &name, file.as_deref(), yes).await
+            }
+        },
+        Commands::SubmoltMods { name, hydrate } => {
+            submolt::list_moderators(client, &name, hydrate).await
+        }
         Commands::SubmoltModAdd {
             name,
             agent_name,
@@ -746,14 +2511,29 @@ pub async fn execute(command: Commands, client: &MoltbookClient) -> Result<(), A
 
         // DM Commands
         Commands::DmCheck => dm::check_dms(client).await,
+        Commands::DmWatch { interval, format } => {
+            let format = format.unwrap_or(defaults.format());
+            watch::watch_dms(client, interval, format).await
+        }
         Commands::DmRequests => dm::list_dm_requests(client).await,
         Commands::DmList => dm::list_conversations(client).await,
-        Commands::DmRead { conversation_id } => dm::read_dm(client, &conversation_id).await,
+        Commands::DmRead {
+            conversation_id,
+            limit,
+            before,
+            all,
+        } => dm::read_dm(client, &conversation_id, limit, before.as_deref(), all).await,
         Commands::DmSend {
             conversation_id,
             message,
             needs_human,
         } => dm::send_dm(client, &conversation_id, message, needs_human).await,
+        Commands::DmReact { message_id, emoji } => dm::react(client, &message_id, &emoji).await,
+        Commands::Handoff {
+            conversation_id,
+            output,
+            clipboard,
+        } => handoff::handoff(client, &conversation_id, output.as_deref(), clipboard).await,
         Commands::DmRequest {
             to,
             message,
@@ -768,3 +2548,24 @@ pub async fn execute(command: Commands, client: &MoltbookClient) -> Result<(), A
         } => dm::reject_request(client, &conversation_id, block).await,
     }
 }
+
+/// Resolves `--page` / `--offset` to the number of posts to skip.
+fn offset_for(page: Option<u64>, offset: Option<u64>, limit: u64) -> Option<u64> {
+    page.map(|page| (page - 1) * limit).or(offset)
+}
+
+/// Resolves `--preview-lines` / `--no-truncate` against `defaults.preview_lines`.
+///
+/// Like the config key, `--preview-lines 0` turns truncation off.
+fn preview_lines_for(
+    preview_lines: Option<usize>,
+    no_truncate: bool,
+    defaults: &Defaults,
+) -> Option<usize> {
+    match preview_lines {
+        _ if no_truncate => None,
+        Some(0) => None,
+        Some(lines) => Some(lines),
+        None => defaults.preview_lines(),
+    }
+}
@@ -1,6 +1,7 @@
 // author: kelexine <https://github.com/kelexine>
 
 use crate::api::client::MoltbookClient;
+use crate::api::endpoint::{Endpoint, Listing};
 use crate::api::error::ApiError;
 use crate::api::types::NotificationsResponse;
 use crate::display;
@@ -12,13 +13,11 @@ pub async fn list(
     cursor: Option<String>,
     unread: bool,
 ) -> Result<(), ApiError> {
-    let mut query = format!("/notifications?limit={}", limit);
-    if let Some(c) = &cursor {
-        query.push_str(&format!("&cursor={}", c));
-    }
-    if unread {
-        query.push_str("&filter=unread");
+    let query = Endpoint::Notifications {
+        listing: Listing::limit(limit).cursor(cursor.as_deref()),
+        unread,
     }
+    .path();
 
     let response: NotificationsResponse = client.get(&query).await?;
     display::display_notifications(&response);
@@ -28,7 +27,7 @@ pub async fn list(
 pub async fn read_by_post(client: &MoltbookClient, post_id: &str) -> Result<(), ApiError> {
     let result: serde_json::Value = client
         .post(
-            &format!("/notifications/read-by-post/{}", post_id),
+            &Endpoint::NotificationsReadByPost(post_id).path(),
             &json!({}),
         )
         .await?;
@@ -43,8 +42,9 @@ pub async fn read_by_post(client: &MoltbookClient, post_id: &str) -> Result<(),
 }
 
 pub async fn read_all(client: &MoltbookClient) -> Result<(), ApiError> {
-    let result: serde_json::Value =
-        client.post("/notifications/read-all", &json!({})).await?;
+    let result: serde_json::Value = client
+        .post(&Endpoint::NotificationsReadAll.path(), &json!({}))
+        .await?;
 
     if result["success"].as_bool().unwrap_or(false) {
         display::success("All notifications marked as read");
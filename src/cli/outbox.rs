@@ -0,0 +1,125 @@
+//! Offline outbox subcommands.
+//!
+//! See [`crate::outbox`] for when requests are queued.
+
+use crate::api::client::MoltbookClient;
+use crate::api::error::ApiError;
+use crate::cli::verification::handle_verification;
+use crate::display;
+use crate::interrupt;
+use crate::outbox::{OutboxStore, is_deferred, is_retryable};
+use colored::Colorize;
+
+pub fn list() -> Result<(), ApiError> {
+    let store = OutboxStore::load()?;
+    display::display_outbox(&store.entries);
+    Ok(())
+}
+
+/// Delivers queued requests in order, stopping at the first one that still
+/// cannot reach the API, or that it turns down for now (rate limits, budgets),
+/// so later entries are not sent ahead of it.
+///
+/// Only a definitive rejection drops an entry without delivering it.
+pub async fn flush(client: &MoltbookClient) -> Result<(), ApiError> {
+    let mut store = OutboxStore::load()?;
+    if store.entries.is_empty() {
+        display::info("Outbox is empty.");
+        return Ok(());
+    }
+
+    let mut delivered = 0;
+    while let Some(entry) = store.entries.first().cloned() {
+        if delivered > 0 {
+            let _ = interrupt::or_cancel(client.jitter()).await;
+        }
+        // A request already sent is let finish, so its entry is settled.
+        if interrupt::requested() {
+            break;
+        }
+        let result = client
+            .post_idempotent::<serde_json::Value>(&entry.endpoint, &entry.body, &entry.key)
+            .await;
+        match result {
+            Ok(response) => {
+                if handle_verification(&response, "queued request") {
+                    // The challenge is saved; `verify` sends the request again.
+                } else if response["success"].as_bool().unwrap_or(false) {
+                    display::success(&format!(
+                        "{} POST {} delivered",
+                        entry.short_id(),
+                        entry.endpoint
+                    ));
+                    delivered += 1;
+                } else {
+                    display::error(&format!(
+                        "{} POST {} rejected: {}",
+                        entry.short_id(),
+                        entry.endpoint,
+                        response["error"]
+                            .as_str()
+                            .unwrap_or("unsuccessful response")
+                    ));
+                }
+            }
+            Err(e) if is_retryable(&e) => {
+                display::warn(&format!("API still unreachable: {}", e));
+                break;
+            }
+            Err(e) if is_deferred(&e) => {
+                display::warn(&format!(
+                    "{} POST {} kept for later: {}",
+                    entry.short_id(),
+                    entry.endpoint,
+                    e
+                ));
+                break;
+            }
+            Err(e) => {
+                display::error(&format!(
+                    "{} POST {} rejected: {}",
+                    entry.short_id(),
+                    entry.endpoint,
+                    e
+                ));
+            }
+        }
+        store.entries.remove(0);
+        // Another invocation may have queued entries meanwhile; drop only this one.
+        OutboxStore::update(|queued| queued.entries.retain(|e| e.key != entry.key))?;
+    }
+
+    if !store.entries.is_empty() {
+        println!(
+            "{} request(s) still queued. Delivered {} this run.",
+            store.entries.len().to_string().yellow(),
+            delivered
+        );
+    }
+    if interrupt::requested() {
+        return Err(ApiError::Interrupted);
+    }
+    Ok(())
+}
+
+/// Discards the one entry whose id starts with `id`, or every entry with `all`.
+pub fn drop(id: Option<&str>, all: bool) -> Result<(), ApiError> {
+    let removed = OutboxStore::update(|store| match id {
+        Some(id) if !all => {
+            let key = store.find(id)?.key.clone();
+            Ok::<_, ApiError>(usize::from(store.remove(&key)))
+        }
+        _ => {
+            let count = store.entries.len();
+            store.entries.clear();
+            Ok(count)
+        }
+    })??;
+
+    if removed == 0 {
+        display::warn("No matching outbox entries.");
+        return Ok(());
+    }
+    display::success(&format!("Dropped {} queued request(s)", removed));
+    Ok(())
+}
@@ -0,0 +1,84 @@
+//! Inspection and replay of mutations whose outcome is unknown.
+//!
+//! See [`crate::idempotency`] for how actions end up in the journal.
+
+use crate::api::client::MoltbookClient;
+use crate::api::error::ApiError;
+use crate::display;
+use crate::idempotency::{PendingStore, is_outcome_unknown};
+use chrono::Utc;
+use colored::Colorize;
+
+/// Lists unresolved actions.
+pub fn list() -> Result<(), ApiError> {
+    let mut store = PendingStore::load()?;
+    store.prune(Utc::now());
+    display::display_pending_actions(&store.actions);
+    if !store.actions.is_empty() {
+        println!(
+            "Run {} to find out whether they landed.",
+            "moltbook pending --replay".cyan()
+        );
+    }
+    Ok(())
+}
+
+/// Forgets every unresolved action without re-sending it.
+pub fn clear() -> Result<(), ApiError> {
+    let count = PendingStore::update(|store| std::mem::take(&mut store.actions).len())?;
+    display::success(&format!("Cleared {} pending action(s)", count));
+    Ok(())
+}
+
+/// Re-sends each unresolved action with its original idempotency key.
+///
+/// If the original request landed, the server answers with its result instead
+/// of applying it again. Actions that still cannot reach the server are kept.
+pub async fn replay(client: &MoltbookClient) -> Result<(), ApiError> {
+    let mut store = PendingStore::load()?;
+    store.prune(Utc::now());
+    if store.actions.is_empty() {
+        display::info("No pending actions.");
+        return Ok(());
+    }
+
+    let actions = store.actions.clone();
+    for (i, action) in actions.iter().enumerate() {
+        if i > 0 {
+            client.jitter().await;
+        }
+        let result = client
+            .post_idempotent::<serde_json::Value>(&action.endpoint, &action.body, &action.key)
+            .await;
+        match result {
+            Ok(_) => {
+                display::success(&format!("POST {} confirmed", action.endpoint));
+                store.finish(&action.key);
+            }
+            Err(e) if is_outcome_unknown(&e) => {
+                display::warn(&format!(
+                    "POST {} still unreachable: {}",
+                    action.endpoint, e
+                ));
+            }
+            Err(e) => {
+                display::error(&format!("POST {} rejected: {}", action.endpoint, e));
+                store.finish(&action.key);
+            }
+        }
+    }
+
+    // Keep actions journaled by other invocations while this one replayed.
+    let settled: Vec<&str> = actions
+        .iter()
+        .filter(|a| !store.actions.iter().any(|kept| kept.key == a.key))
+        .map(|a| a.key.as_str())
+        .collect();
+    PendingStore::update(|journal| {
+        journal.prune(Utc::now());
+        journal
+            .actions
+            .retain(|a| !settled.contains(&a.key.as_str()));
+    })?;
+    Ok(())
+}
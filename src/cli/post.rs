@@ -3,13 +3,33 @@
 //! This module implements the main social loop of the Moltbook network,
 //! providing tools for content discovery, engagement, and creation.
 
-use crate::api::client::MoltbookClient;
+use crate::alias::{self, AliasTarget, ItemKind};
+use crate::api::client::{DEFAULT_TIMEOUT, MoltbookClient, PAGINATION_TIMEOUT};
+use crate::api::endpoint::{Endpoint, Listing};
 use crate::api::error::ApiError;
-use crate::api::types::{FeedResponse, Post, SearchResponse};
-use crate::display;
+use crate::api::pagination::{FeedPage, Pages};
+use crate::api::query::Query;
+use crate::api::types::{FeedResponse, Post, ReportsResponse, SearchResponse};
+use crate::batch::{BatchSummary, MAX_RATE_LIMIT_WAIT, parse_ids, retry_after};
+use crate::cli::interactive::require_tty;
+use crate::config::DEFAULT_PREVIEW_LINES;
+use crate::content_hash::{HashState, Stamped};
+use crate::dedupe::{PostGroup, SeenStore, group_posts};
+use crate::display::comments::build_thread;
+use crate::display::{self, OutputFormat};
+use crate::draft::{self, COMMENT_CHAR_LIMIT};
+use crate::idempotency::post_tracked;
+use crate::interrupt;
+use crate::llm;
+use crate::ordering;
+use crate::subscriptions::SubscriptionStore;
+use crate::visits::VisitStore;
 use colored::Colorize;
-use dialoguer::{Input, theme::ColorfulTheme};
+use dialoguer::{Confirm, Input, theme::ColorfulTheme};
 use serde_json::json;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 /// Parameters for creating a new post, supporting both positional and flagged args.
 #[derive(Debug, Default)]
@@ -30,48 +50,305 @@ pub struct PostParams {
     pub content_pos: Option<String>,
     /// Post URL from fourth positional argument.
     pub url_pos: Option<String>,
+    /// Submolt used when none is given (`defaults.submolt`).
+    pub default_submolt: String,
 }
 
-/// Fetches and displays the agent's personalized feed.
-pub async fn feed(
+/// Options shared by the feed listing commands.
+#[derive(Debug, Clone)]
+pub struct ListingOptions {
+    /// Collapse near-duplicate posts into a single entry.
+    pub dedupe: bool,
+    /// Keep following cursors until the feed is exhausted.
+    pub all: bool,
+    /// Posts to skip before the first one listed, from `--page` or `--offset`.
+    pub offset: Option<u64>,
+    /// Only show posts from submolts in the local subscription record.
+    pub only_subscribed: bool,
+    /// Only show posts by agents you follow.
+    pub only_following: bool,
+    /// Ask for deleted and removed posts and show them as tombstones.
+    pub include_deleted: bool,
+    /// Render posts, or write them as JSON lines without grouping duplicates.
+    pub format: OutputFormat,
+    /// Content lines shown per post; `None` shows the full content.
+    pub preview_lines: Option<usize>,
+    /// State file of post hashes; only posts that are new or changed since
+    /// the last run are listed, and the file is updated afterwards.
+    pub changed_since: Option<PathBuf>,
+}
+
+impl Default for ListingOptions {
+    fn default() -> Self {
+        Self {
+            dedupe: false,
+            all: false,
+            offset: None,
+            only_subscribed: false,
+            only_following: false,
+            include_deleted: false,
+            format: OutputFormat::default(),
+            preview_lines: Some(DEFAULT_PREVIEW_LINES),
+            changed_since: None,
+        }
+    }
+}
+
+/// Upper bound on pages fetched by `--all`, guarding against cursor loops.
+const MAX_ALL_PAGES: usize = 50;
+
+/// The 1-based number of the page starting at `offset` and the number of
+/// pages, given `limit` items per page and `total` items if known.
+fn page_position(offset: u64, limit: u64, total: Option<u64>) -> (u64, Option<u64>) {
+    let limit = limit.max(1);
+    (offset / limit + 1, total.map(|t| t.div_ceil(limit).max(1)))
+}
+
+/// The default timeout per page, longer when `--all` walks deep cursors.
+fn page_timeout(all: bool) -> Duration {
+    if all {
+        PAGINATION_TIMEOUT
+    } else {
+        DEFAULT_TIMEOUT
+    }
+}
+
+fn submolt_of(post: &Post) -> Option<&str> {
+    post.submolt
+        .as_ref()
+        .map(|s| s.name.as_str())
+        .or(post.submolt_name.as_deref())
+}
+
+/// Renders a numbered list of feed posts, numbering from `offset + 1`.
+///
+/// Posts excluded by the `only_*` filters are skipped, as are deleted and
+/// removed posts unless `include_deleted` is set. When `dedupe` is set,
+/// near-duplicate posts (within the batch or against the local seen-post
+/// store) are collapsed into a single "×N similar" entry. Posts from
+/// subscribed submolts get a badge. Returns the IDs of the rendered posts in
+/// display order.
+pub fn display_post_list(
+    posts: &[Post],
+    options: &ListingOptions,
+    subscriptions: &SubscriptionStore,
+    offset: usize,
+) -> Vec<String> {
+    let is_subscribed = |p: &Post| submolt_of(p).is_some_and(|s| subscriptions.contains(s));
+    let posts = visible_posts(posts, options, subscriptions);
+
+    let groups = if options.dedupe {
+        // The seen-post store is a best-effort cache; never fail a listing over it.
+        let seen = SeenStore::load().unwrap_or_default();
+        let groups = group_posts(&posts, &seen);
+        let _ = SeenStore::update(|seen| seen.record(&posts));
+        groups
+    } else {
+        posts
+            .iter()
+            .map(|post| PostGroup {
+                post,
+                similar_ids: vec![post.id.clone()],
+            })
+            .collect()
+    };
+
+    for (i, group) in groups.iter().enumerate() {
+        display::display_post_group(
+            group,
+            offset + i + 1,
+            is_subscribed(group.post),
+            options.preview_lines,
+        );
+    }
+    groups.iter().map(|g| g.post.id.clone()).collect()
+}
+
+/// The posts the `only_*` and `include_deleted` options let through.
+fn visible_posts(
+    posts: &[Post],
+    options: &ListingOptions,
+    subscriptions: &SubscriptionStore,
+) -> Vec<Post> {
+    posts
+        .iter()
+        .filter(|p| {
+            !options.only_subscribed || submolt_of(p).is_some_and(|s| subscriptions.contains(s))
+        })
+        .filter(|p| !options.only_following || p.you_follow_author.unwrap_or(false))
+        .filter(|p| options.include_deleted || p.tombstone().is_none())
+        .cloned()
+        .collect()
+}
+
+/// Streams a feed endpoint, rendering each page as soon as it is decoded.
+///
+/// `header` is printed once the first page arrives and `on_empty` runs when
+/// the listing turns out to have no posts. Without `options.all` only one
+/// page is fetched and the continuation cursor is printed.
+///
+/// `listing` is what `endpoint` was built with. Each page is put in a
+/// deterministic order for its sort first (see [`crate::ordering`]), and its
+/// limit and offset number the pages for the `--page` hint shown after a
+/// single page.
+///
+/// With [`OutputFormat::Jsonl`] each visible post is written as a JSON line
+/// instead; the header and empty-listing hints are skipped and the cursor goes
+/// to stderr.
+pub async fn stream_feed<T: FeedPage>(
     client: &MoltbookClient,
-    sort: &str,
-    limit: u64,
-    filter: &str,
+    endpoint: String,
+    listing: Listing<'_>,
     cursor: Option<&str>,
+    options: ListingOptions,
+    header: impl FnOnce(),
+    on_empty: impl FnOnce(),
 ) -> Result<(), ApiError> {
-    let mut url = format!("/feed?sort={}&limit={}", sort, limit);
-    if filter != "all" {
-        url.push_str(&format!("&filter={}", filter));
-    }
-    if let Some(c) = cursor {
-        url.push_str(&format!("&cursor={}", urlencoding::encode(c)));
+    let endpoint = if options.include_deleted {
+        Query::new()
+            .param("include_deleted", true)
+            .append_to(&endpoint)
+    } else {
+        endpoint
+    };
+    let mut pages = Pages::<T>::new(client, endpoint, cursor.map(String::from))
+        .with_timeout(page_timeout(options.all));
+    let mut header = Some(header);
+    let mut shown = Vec::new();
+    let mut fetched = 0;
+    let mut position = None;
+    // Like the seen-post store, a missing or unreadable record only drops badges.
+    let subscriptions = SubscriptionStore::load().unwrap_or_default();
+    let jsonl = options.format == OutputFormat::Jsonl;
+    let mut changes = match &options.changed_since {
+        Some(path) => Some(HashState::load(path)?),
+        None => None,
+    };
+    if options.only_subscribed && subscriptions.submolts.is_empty() && !jsonl {
+        display::warn("No subscriptions recorded yet; `moltbook subscribe <submolt>` adds them.");
     }
-    let response: FeedResponse = client.get(&url).await?;
-    println!("\n{} ({})", "Your Feed".bright_green().bold(), sort);
-    println!("{}", "=".repeat(60));
-    if response.posts.is_empty() {
-        display::info("No posts in your feed yet.");
-        println!("Try:");
-        println!("  - {} to see what's happening", "moltbook global".cyan());
-        println!("  - {} to find communities", "moltbook submolts".cyan());
-        println!(
-            "  - {} to explore topics",
-            "moltbook search \"your interest\"".cyan()
+
+    let mut interrupted = false;
+    loop {
+        let spinner = display::progress::spinner(
+            format!("Fetching page {}…", fetched + 1),
+            options.all && client.progress_enabled(),
         );
-    } else {
-        for (i, post) in response.posts.iter().enumerate() {
-            display::display_post(post, Some(i + 1));
+        let next = interrupt::or_cancel(pages.next()).await;
+        spinner.finish_and_clear();
+        let Ok(Some(page)) = next else {
+            interrupted = next.is_err();
+            break;
+        };
+        let page = page?;
+        fetched += 1;
+        if position.is_none() {
+            let (number, pages) = page_position(
+                listing.offset.unwrap_or(0),
+                listing.limit.unwrap_or_default(),
+                page.total(),
+            );
+            position = Some((page.page_number().unwrap_or(number), pages));
         }
-        if response.has_more.unwrap_or(false) {
-            if let Some(next) = &response.next_cursor {
-                display::print_next_cursor(next);
+        client.report_unknown_fields(page.posts());
+        let mut posts = page.posts().items.clone();
+        ordering::sort_posts(&mut posts, listing.sort.unwrap_or_default());
+        if let Some(state) = changes.as_mut() {
+            posts.retain(|post| state.changed(post));
+            state.record(&posts);
+        }
+        if jsonl {
+            for post in visible_posts(&posts, &options, &subscriptions) {
+                display::write_jsonl(&Stamped::post(&post))?;
+                shown.push(AliasTarget::new(ItemKind::Post, post.id));
+            }
+        } else {
+            if let Some(header) = header.take().filter(|_| !llm::is_enabled()) {
+                header();
             }
+            display::warn_skipped("post", &page.posts().skipped);
+            let listed = display_post_list(&posts, &options, &subscriptions, shown.len());
+            shown.extend(
+                listed
+                    .into_iter()
+                    .map(|id| AliasTarget::new(ItemKind::Post, id)),
+            );
+        }
+
+        if !options.all || fetched >= MAX_ALL_PAGES {
+            break;
         }
     }
+
+    if let (Some(state), Some(path)) = (&changes, &options.changed_since) {
+        state.save(path)?;
+    }
+    if !shown.is_empty() {
+        alias::remember_listing(shown);
+    } else if !jsonl && !interrupted {
+        if changes.is_some() {
+            display::info("Nothing new or changed since the last run.");
+        } else {
+            on_empty();
+        }
+    }
+    match pages.cursor() {
+        Some(next) if jsonl => eprintln!("next page cursor: {}", next),
+        Some(next) => display::print_next_cursor(next),
+        None => {}
+    }
+    if let Some((page, page_count)) = position
+        && !options.all
+        && !jsonl
+        && page_count.map_or(pages.cursor().is_some(), |count| page < count)
+    {
+        display::print_page_hint(page, page_count);
+    }
+    if interrupted {
+        return Err(ApiError::Interrupted);
+    }
     Ok(())
 }
 
+/// Fetches and displays the agent's personalized feed.
+pub async fn feed(
+    client: &MoltbookClient,
+    sort: &str,
+    limit: u64,
+    filter: &str,
+    cursor: Option<&str>,
+    options: ListingOptions,
+) -> Result<(), ApiError> {
+    let listing = Listing::new(sort, limit).offset(options.offset);
+    let url = Endpoint::Feed {
+        listing,
+        filter: (filter != "all").then_some(filter),
+    }
+    .path();
+    stream_feed::<FeedResponse>(
+        client,
+        url,
+        listing,
+        cursor,
+        options,
+        || {
+            println!("\n{} ({})", "Your Feed".bright_green().bold(), sort);
+            println!("{}", "=".repeat(60));
+        },
+        || {
+            display::info("No posts in your feed yet.");
+            println!("Try:");
+            println!("  - {} to see what's happening", "moltbook global".cyan());
+            println!("  - {} to find communities", "moltbook submolts".cyan());
+            println!(
+                "  - {} to explore topics",
+                "moltbook search \"your interest\"".cyan()
+            );
+        },
+    )
+    .await
+}
+
 /// Fetches and displays posts by a specific agent.
 pub async fn agent_posts(
     client: &MoltbookClient,
@@ -79,32 +356,31 @@ pub async fn agent_posts(
     sort: &str,
     limit: u64,
     cursor: Option<&str>,
+    options: ListingOptions,
 ) -> Result<(), ApiError> {
-    let encoded = urlencoding::encode(author);
-    let mut url = format!("/posts?author={}&sort={}&limit={}", encoded, sort, limit);
-    if let Some(c) = cursor {
-        url.push_str(&format!("&cursor={}", urlencoding::encode(c)));
+    let listing = Listing::new(sort, limit).offset(options.offset);
+    let url = Endpoint::Posts {
+        listing,
+        author: Some(author),
     }
-    let response: FeedResponse = client.get(&url).await?;
-    println!(
-        "\n{} {}",
-        "Posts by".bright_green().bold(),
-        author.bright_cyan().bold()
-    );
-    println!("{}", "=".repeat(60));
-    if response.posts.is_empty() {
-        display::info("No posts found.");
-    } else {
-        for (i, post) in response.posts.iter().enumerate() {
-            display::display_post(post, Some(i + 1));
-        }
-        if response.has_more.unwrap_or(false) {
-            if let Some(next) = &response.next_cursor {
-                display::print_next_cursor(next);
-            }
-        }
-    }
-    Ok(())
+    .path();
+    stream_feed::<FeedResponse>(
+        client,
+        url,
+        listing,
+        cursor,
+        options,
+        || {
+            println!(
+                "\n{} {}",
+                "Posts by".bright_green().bold(),
+                author.bright_cyan().bold()
+            );
+            println!("{}", "=".repeat(60));
+        },
+        || display::info("No posts found."),
+    )
+    .await
 }
 
 /// Fetches and displays global posts from the entire network.
@@ -113,27 +389,27 @@ pub async fn global_feed(
     sort: &str,
     limit: u64,
     cursor: Option<&str>,
+    options: ListingOptions,
 ) -> Result<(), ApiError> {
-    let mut url = format!("/posts?sort={}&limit={}", sort, limit);
-    if let Some(c) = cursor {
-        url.push_str(&format!("&cursor={}", urlencoding::encode(c)));
+    let listing = Listing::new(sort, limit).offset(options.offset);
+    let url = Endpoint::Posts {
+        listing,
+        author: None,
     }
-    let response: FeedResponse = client.get(&url).await?;
-    println!("\n{} ({})", "Global Feed".bright_green().bold(), sort);
-    println!("{}", "=".repeat(60));
-    if response.posts.is_empty() {
-        display::info("No posts found.");
-    } else {
-        for (i, post) in response.posts.iter().enumerate() {
-            display::display_post(post, Some(i + 1));
-        }
-        if response.has_more.unwrap_or(false) {
-            if let Some(next) = &response.next_cursor {
-                display::print_next_cursor(next);
-            }
-        }
-    }
-    Ok(())
+    .path();
+    stream_feed::<FeedResponse>(
+        client,
+        url,
+        listing,
+        cursor,
+        options,
+        || {
+            println!("\n{} ({})", "Global Feed".bright_green().bold(), sort);
+            println!("{}", "=".repeat(60));
+        },
+        || display::info("No posts found."),
+    )
+    .await
 }
 
 /// Orchestrates the post creation process, handling both interactive and one-shot modes.
@@ -151,6 +427,10 @@ pub async fn create_post(client: &MoltbookClient, params: PostParams) -> Result<
 
     let (final_title, final_submolt, final_content, final_url) = if !has_args {
         // Interactive Mode
+        require_tty(
+            "A post title",
+            "arguments, e.g. `moltbook post \"Title\" --submolt general --content \"...\"`",
+        )?;
         let t = Input::<String>::with_theme(&ColorfulTheme::default())
             .with_prompt("Post Title")
             .interact_text()
@@ -158,7 +438,7 @@ pub async fn create_post(client: &MoltbookClient, params: PostParams) -> Result<
 
         let s = Input::<String>::with_theme(&ColorfulTheme::default())
             .with_prompt("Submolt")
-            .default("general".into())
+            .default(params.default_submolt.clone())
             .interact_text()
             .map_err(|e| ApiError::IoError(std::io::Error::other(e)))?;
 
@@ -183,7 +463,7 @@ pub async fn create_post(client: &MoltbookClient, params: PostParams) -> Result<
         let f_submolt = params
             .submolt
             .or(params.submolt_pos)
-            .unwrap_or_else(|| "general".to_string());
+            .unwrap_or(params.default_submolt);
         let f_content = params.content.or(params.content_pos);
         let f_url = params.url.or(params.url_pos);
 
@@ -206,7 +486,8 @@ pub async fn create_post(client: &MoltbookClient, params: PostParams) -> Result<
         body["url"] = json!(u);
     }
 
-    let result: serde_json::Value = client.post("/posts", &body).await?;
+    let result = post_tracked(client, &Endpoint::CreatePost.path(), &body).await;
+    let result: serde_json::Value = explain_restriction(result, "post")?;
 
     if !crate::cli::verification::handle_verification(&result, "post")
         && result["success"].as_bool().unwrap_or(false)
@@ -214,6 +495,7 @@ pub async fn create_post(client: &MoltbookClient, params: PostParams) -> Result<
         display::success("Post created successfully! 🦞");
         if let Some(post_id) = result["post"]["id"].as_str() {
             println!("Post ID: {}", post_id.dimmed());
+            alias::remember_last(AliasTarget::new(ItemKind::Post, post_id));
         }
         // Surface label suggestions when the submolt has labels defined
         if let Some(labels_json) = result["consider_labels"].as_array() {
@@ -227,19 +509,83 @@ pub async fn create_post(client: &MoltbookClient, params: PostParams) -> Result<
     Ok(())
 }
 
-pub async fn view_post(client: &MoltbookClient, post_id: &str) -> Result<(), ApiError> {
-    let response: serde_json::Value = client.get(&format!("/posts/{}", post_id)).await?;
-    let post: Post = if let Some(p) = response.get("post") {
-        serde_json::from_value(p.clone())?
-    } else {
-        serde_json::from_value(response)?
+/// Prints the countdown and read-only suggestions for a new-agent cooldown.
+fn explain_restriction<T>(result: Result<T, ApiError>, action: &str) -> Result<T, ApiError> {
+    if let Err(ApiError::NewAgentRestricted(wait)) = &result {
+        display::display_new_agent_restriction(*wait, action);
+    }
+    result
+}
+
+/// Shows a post and, with `comments` set to `(limit, sort)`, its top
+/// comments below it.
+///
+/// Both requests go out together, so this takes about as long as fetching
+/// the post alone. A failed comment request is reported without hiding the
+/// post. Viewing the comments counts as a visit for `comments --new-only`.
+pub async fn view_post(
+    client: &MoltbookClient,
+    post_id: &str,
+    comments: Option<(u64, &str)>,
+    depth: usize,
+) -> Result<(), ApiError> {
+    let post_url = Endpoint::Post(post_id).path();
+    let fetch_comments = async {
+        let (limit, sort) = comments?;
+        let url = Endpoint::PostComments {
+            post_id,
+            listing: Listing::new(sort, limit),
+        }
+        .path();
+        Some(client.get::<serde_json::Value>(&url).await)
     };
+    let (post, comments_response) = tokio::join!(
+        client.get_enveloped::<Post>(&post_url, "post"),
+        fetch_comments
+    );
+    let post = post?;
+    client.report_unknown_fields([&post]);
     display::display_post(&post, None);
+    alias::remember_last(AliasTarget::new(ItemKind::Post, &post.id));
+
+    let Some(response) = comments_response else {
+        return Ok(());
+    };
+    let response = match response {
+        Ok(response) => response,
+        Err(e) => {
+            display::warn(&format!("Could not load comments: {}", e));
+            return Ok(());
+        }
+    };
+    let mut thread = build_thread(response["comments"].as_array().cloned().unwrap_or_default());
+    if let Some((_, sort)) = comments {
+        ordering::sort_comments(&mut thread, sort);
+    }
+    let _ = VisitStore::update(|visits| visits.record(&post.id, chrono::Utc::now()));
+
+    println!(
+        "{} ({})",
+        "Comments".bright_green().bold(),
+        post.comment_count.unwrap_or(0)
+    );
+    println!("{}", "=".repeat(60));
+    if thread.is_empty() {
+        display::info("No comments yet. Be the first!");
+        return Ok(());
+    }
+    display_thread(&thread, depth);
+    if response["has_more"].as_bool().unwrap_or(false) {
+        println!(
+            "{}",
+            format!("More in `moltbook comments {}`", post.id).dimmed()
+        );
+    }
     Ok(())
 }
 
 pub async fn delete_post(client: &MoltbookClient, post_id: &str) -> Result<(), ApiError> {
-    let result: serde_json::Value = client.delete(&format!("/posts/{}", post_id)).await?;
+    let result: serde_json::Value = client.delete(&Endpoint::Post(post_id).path()).await?;
     if !crate::cli::verification::handle_verification(&result, "post deletion")
         && result["success"].as_bool().unwrap_or(false)
     {
@@ -250,7 +596,7 @@ pub async fn delete_post(client: &MoltbookClient, post_id: &str) -> Result<(), A
 
 pub async fn upvote_post(client: &MoltbookClient, post_id: &str) -> Result<(), ApiError> {
     let result: serde_json::Value = client
-        .post(&format!("/posts/{}/upvote", post_id), &json!({}))
+        .post(&Endpoint::PostUpvote(post_id).path(), &json!({}))
         .await?;
     if !crate::cli::verification::handle_verification(&result, "upvote")
         && result["success"].as_bool().unwrap_or(false)
@@ -265,7 +611,7 @@ pub async fn upvote_post(client: &MoltbookClient, post_id: &str) -> Result<(), A
 
 pub async fn downvote_post(client: &MoltbookClient, post_id: &str) -> Result<(), ApiError> {
     let result: serde_json::Value = client
-        .post(&format!("/posts/{}/downvote", post_id), &json!({}))
+        .post(&Endpoint::PostDownvote(post_id).path(), &json!({}))
         .await?;
     if !crate::cli::verification::handle_verification(&result, "downvote")
         && result["success"].as_bool().unwrap_or(false)
@@ -275,20 +621,249 @@ pub async fn downvote_post(client: &MoltbookClient, post_id: &str) -> Result<(),
     Ok(())
 }
 
+/// Retracts the agent's upvote or downvote on a post.
+pub async fn unvote_post(client: &MoltbookClient, post_id: &str) -> Result<(), ApiError> {
+    let result: serde_json::Value = client.delete(&Endpoint::PostVote(post_id).path()).await?;
+    if !crate::cli::verification::handle_verification(&result, "vote removal")
+        && result["success"].as_bool().unwrap_or(false)
+    {
+        display::success("Vote removed");
+    }
+    Ok(())
+}
+
+/// Upvotes a post and comments on it in one go, then prints one summary.
+///
+/// The comment's length is checked before anything is sent. Both steps run
+/// even if the first fails; challenges from either are shown together after
+/// the summary, and a failed step makes the command fail.
+pub async fn engage(
+    client: &MoltbookClient,
+    post_id: &str,
+    upvote: bool,
+    comment: Option<String>,
+) -> Result<(), ApiError> {
+    let comment = comment.map(|c| c.trim().to_string());
+    if let Some(content) = &comment {
+        let length = draft::char_count(content);
+        if length > COMMENT_CHAR_LIMIT {
+            return Err(ApiError::TooLong {
+                what: "Comment".to_string(),
+                length,
+                limit: COMMENT_CHAR_LIMIT,
+            });
+        }
+    }
+
+    let mut steps: Vec<(&str, Result<serde_json::Value, ApiError>)> = Vec::new();
+    if upvote {
+        let result = client
+            .post(&Endpoint::PostUpvote(post_id).path(), &json!({}))
+            .await;
+        steps.push(("upvote", result));
+    }
+    if let Some(content) = comment {
+        let body = json!({ "content": content });
+        let result = post_tracked(client, &Endpoint::CreateComment(post_id).path(), &body).await;
+        steps.push(("comment", explain_restriction(result, "comment")));
+    }
+    alias::remember_last(AliasTarget::new(ItemKind::Post, post_id));
+
+    println!("\n{} {}", "Engaged with".bright_green().bold(), post_id);
+    let mut failed = 0;
+    for (action, result) in &steps {
+        match result {
+            Ok(value) if crate::verification::find_challenge(value).is_some() => {
+                println!("  {} {} needs verification", "?".yellow(), action)
+            }
+            Ok(value) if value["success"].as_bool().unwrap_or(false) => {
+                println!("  {} {}", "✓".green(), action)
+            }
+            Ok(value) => {
+                failed += 1;
+                let error = value["error"]
+                    .as_str()
+                    .unwrap_or("not confirmed by the API");
+                println!("  {} {} {}", "✗".red(), action, error.dimmed());
+            }
+            Err(e) => {
+                failed += 1;
+                println!("  {} {} {}", "✗".red(), action, e.to_string().dimmed());
+            }
+        }
+    }
+    for (action, result) in &steps {
+        if let Ok(value) = result {
+            crate::cli::verification::handle_verification(value, action);
+            if let Some(comment_id) = value["comment"]["id"].as_str() {
+                alias::remember_last(AliasTarget::new(ItemKind::Comment, comment_id));
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(ApiError::MoltbookError(
+            format!("{} of {} steps failed", failed, steps.len()),
+            "See the summary above; the others went through".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Votes on every post listed in `path` (`-` for stdin), one request at a time.
+///
+/// Requests are spaced `delay` apart. Rate limits short enough to wait out are
+/// retried; a longer one stops the batch and the remaining IDs are reported
+/// as skipped.
+pub async fn vote_batch(
+    client: &MoltbookClient,
+    path: &Path,
+    upvote: bool,
+    delay: Duration,
+) -> Result<(), ApiError> {
+    let ids = parse_ids(&read_input(path)?);
+    if ids.is_empty() {
+        display::warn("No IDs found in the batch file.");
+        return Ok(());
+    }
+
+    let action = if upvote { "upvote" } else { "downvote" };
+    let total = ids.len();
+    let mut summary = BatchSummary::default();
+    let mut queue = ids.into_iter().enumerate().peekable();
+    let bar = display::progress::items(total as u64, client.progress_enabled());
+
+    while let Some((i, id)) = queue.next() {
+        if interrupt::requested() {
+            summary.skipped.push(id);
+            summary.skipped.extend(queue.by_ref().map(|(_, id)| id));
+            break;
+        }
+        let endpoint = if upvote {
+            Endpoint::PostUpvote(&id)
+        } else {
+            Endpoint::PostDownvote(&id)
+        };
+        let progress = format!("[{}/{}]", i + 1, total).dimmed();
+        bar.set_message(id.clone());
+
+        let mut result = client
+            .post::<serde_json::Value>(&endpoint.path(), &json!({}))
+            .await;
+        while let Err(ApiError::RateLimited(hint)) = &result {
+            summary.rate_limited += 1;
+            match retry_after(hint).filter(|wait| *wait <= MAX_RATE_LIMIT_WAIT) {
+                Some(wait) => {
+                    bar.suspend(|| {
+                        display::warn(&format!("Rate limited; waiting {}s", wait.as_secs()))
+                    });
+                    interrupt::sleep(wait).await;
+                    if interrupt::requested() {
+                        break;
+                    }
+                    result = client.post(&endpoint.path(), &json!({})).await;
+                }
+                None => break,
+            }
+        }
+
+        let verify = |value: &serde_json::Value| {
+            bar.suspend(|| crate::cli::verification::handle_verification(value, action))
+        };
+        match result {
+            Ok(value) if verify(&value) => {
+                bar.suspend(|| println!("{} {} {}", progress, "?".yellow(), id));
+                summary.verification.push(id);
+            }
+            Ok(_) => {
+                bar.suspend(|| println!("{} {} {}", progress, "✓".green(), id));
+                summary.succeeded += 1;
+            }
+            Err(ApiError::RateLimited(hint)) => {
+                bar.suspend(|| {
+                    display::error(&format!("Rate limited for {}; stopping the batch", hint))
+                });
+                summary.skipped.push(id);
+                summary.skipped.extend(queue.by_ref().map(|(_, id)| id));
+            }
+            // Every remaining vote would be refused the same way.
+            Err(
+                e @ (ApiError::BudgetExhausted { .. }
+                | ApiError::PolicyViolation { .. }
+                | ApiError::NewAgentRestricted(_)),
+            ) => {
+                bar.suspend(|| display::error(&format!("{}; stopping the batch", e)));
+                summary.skipped.push(id);
+                summary.skipped.extend(queue.by_ref().map(|(_, id)| id));
+            }
+            Err(e) => {
+                bar.suspend(|| {
+                    println!(
+                        "{} {} {} {}",
+                        progress,
+                        "✗".red(),
+                        id,
+                        e.to_string().dimmed()
+                    )
+                });
+                summary.failed.push((id, e.to_string()));
+            }
+        }
+        bar.inc(1);
+
+        if queue.peek().is_some() {
+            interrupt::sleep(delay).await;
+            let _ = interrupt::or_cancel(client.jitter()).await;
+        }
+    }
+
+    bar.finish_and_clear();
+    display::display_batch_summary(&summary, action);
+    if interrupt::requested() {
+        return Err(ApiError::Interrupted);
+    }
+    Ok(())
+}
+
+/// Paging and output options for `search`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SearchOptions {
+    /// 1-based page to start from, reached by following cursors from the first page.
+    pub page: Option<usize>,
+    /// Results to skip before the first one listed, sent to the API.
+    pub offset: Option<u64>,
+    /// Keep following cursors until the results are exhausted.
+    pub all: bool,
+    /// Print why each result matched.
+    pub explain: bool,
+    /// Render results, or write them as JSON lines.
+    pub format: OutputFormat,
+    /// Show karma and owner next to each result's author.
+    pub hydrate: bool,
+}
+
 /// Performs an AI-powered semantic search across the network.
+///
+/// Results repeated on later pages are shown only once.
 pub async fn search(
     client: &MoltbookClient,
     query: &str,
     type_filter: &str,
     limit: u64,
     cursor: Option<&str>,
+    options: SearchOptions,
 ) -> Result<(), ApiError> {
-    let encoded = urlencoding::encode(query);
-    let mut url = format!("/search?q={}&type={}&limit={}", encoded, type_filter, limit);
-    if let Some(c) = cursor {
-        url.push_str(&format!("&cursor={}", urlencoding::encode(c)));
+    let url = Endpoint::Search {
+        query,
+        type_filter,
+        listing: Listing::limit(limit).offset(options.offset),
+    }
+    .path();
+    let mut pages = Pages::<SearchResponse>::new(client, url, cursor.map(String::from))
+        .with_timeout(page_timeout(options.all));
+    if options.format == OutputFormat::Jsonl {
+        return search_jsonl(pages, options).await;
     }
-    let response: SearchResponse = client.get(&url).await?;
 
     println!(
         "\n{} '{}'",
@@ -296,109 +871,509 @@ pub async fn search(
         query.bright_cyan()
     );
     println!("{}", "=".repeat(60));
-    if response.results.is_empty() {
-        display::info("No results found.");
-    } else {
-        for (i, res) in response.results.iter().enumerate() {
-            display::display_search_result(res, i + 1);
+
+    let terms = crate::search::terms(query);
+    let mut seen = HashSet::new();
+    let mut page_no = 0;
+    let mut fetched = 0;
+    let mut shown = 0;
+    let mut listed = Vec::new();
+    let mut repeated = 0;
+    let mut total = None;
+    let start_page = options.page.unwrap_or(1);
+
+    let multi_page = options.all || start_page > 1;
+    let mut interrupted = false;
+    loop {
+        let spinner = display::progress::spinner(
+            format!("Fetching page {}…", page_no + 1),
+            multi_page && client.progress_enabled(),
+        );
+        let next = interrupt::or_cancel(pages.next()).await;
+        spinner.finish_and_clear();
+        let Ok(Some(page)) = next else {
+            interrupted = next.is_err();
+            break;
+        };
+        let mut page = page?;
+        page_no += 1;
+        if page_no < start_page {
+            continue;
         }
-        if response.has_more.unwrap_or(false) {
-            if let Some(next) = &response.next_cursor {
-                display::print_next_cursor(next);
+        fetched += 1;
+        total = total.or(page.total);
+        ordering::sort_search_results(&mut page.results);
+        let authors = if options.hydrate {
+            let names = page.results.iter().map(|r| r.author.name.as_str());
+            crate::cli::hydrate::hydrate(client, names).await
+        } else {
+            HashMap::new()
+        };
+
+        let best = page
+            .results
+            .iter()
+            .filter_map(|r| r.similarity)
+            .reduce(f64::max);
+        for res in &page.results {
+            if !seen.insert(res.id.clone()) {
+                repeated += 1;
+                continue;
+            }
+            shown += 1;
+            let kind = if res.result_type == "comment" {
+                ItemKind::Comment
+            } else {
+                ItemKind::Post
+            };
+            listed.push(AliasTarget::new(kind, &res.id));
+            display::display_search_result(res, shown, &terms, authors.get(&res.author.name));
+            if options.explain {
+                let explanation = crate::search::Explanation::new(res, &terms, best);
+                display::display_search_explanation(&explanation, &terms);
             }
         }
+
+        if !options.all || fetched >= MAX_ALL_PAGES {
+            break;
+        }
+    }
+
+    if !listed.is_empty() {
+        alias::remember_listing(listed);
+    }
+    if shown == 0 && !interrupted {
+        if page_no < start_page {
+            display::info(&format!("There are only {} page(s) of results.", page_no));
+        } else {
+            display::info("No results found.");
+        }
+    }
+    match total {
+        Some(total) => {
+            println!(
+                "{}",
+                format!("Showing {} of {} results", shown, total).dimmed()
+            )
+        }
+        None if options.all && shown > 0 => println!("{}", format!("{} results", shown).dimmed()),
+        None => {}
+    }
+    if repeated > 0 {
+        println!(
+            "{}",
+            format!("{} result(s) repeated across pages were hidden", repeated).dimmed()
+        );
+    }
+    if let Some(next) = pages.cursor() {
+        display::print_next_cursor(next);
+    }
+    let (page, page_count) = page_position(options.offset.unwrap_or(0), limit, total);
+    let page = page + start_page as u64 - 1;
+    if !options.all
+        && fetched > 0
+        && page_count.map_or(pages.cursor().is_some(), |count| page < count)
+    {
+        display::print_page_hint(page, page_count);
+    }
+    if interrupted {
+        return Err(ApiError::Interrupted);
+    }
+    Ok(())
+}
+
+/// Writes search results as JSON lines, skipping results repeated across
+/// pages; the continuation cursor goes to stderr.
+async fn search_jsonl(
+    mut pages: Pages<'_, SearchResponse>,
+    options: SearchOptions,
+) -> Result<(), ApiError> {
+    let start_page = options.page.unwrap_or(1);
+    let mut seen = HashSet::new();
+    let mut page_no = 0;
+    let mut fetched = 0;
+    let mut interrupted = false;
+    loop {
+        let next = interrupt::or_cancel(pages.next()).await;
+        let Ok(Some(page)) = next else {
+            interrupted = next.is_err();
+            break;
+        };
+        let mut page = page?;
+        page_no += 1;
+        if page_no < start_page {
+            continue;
+        }
+        fetched += 1;
+        ordering::sort_search_results(&mut page.results);
+        for result in page.results.iter().filter(|r| seen.insert(r.id.clone())) {
+            display::write_jsonl(&Stamped::search_result(result))?;
+        }
+        if !options.all || fetched >= MAX_ALL_PAGES {
+            break;
+        }
+    }
+    if let Some(next) = pages.cursor() {
+        eprintln!("next page cursor: {}", next);
+    }
+    if interrupted {
+        return Err(ApiError::Interrupted);
     }
     Ok(())
 }
 
+/// Lists a post's comments and records the visit.
+///
+/// Replies are nested under their parents and shown `depth` levels deep.
+/// With `new_only`, only comments and replies created since the previous
+/// visit are shown, oldest first, below a marker for the old read position.
 pub async fn comments(
     client: &MoltbookClient,
     post_id: &str,
     sort: &str,
     limit: u64,
     cursor: Option<&str>,
+    new_only: bool,
+    depth: usize,
 ) -> Result<(), ApiError> {
-    let mut url = format!("/posts/{}/comments?sort={}&limit={}", post_id, sort, limit);
-    if let Some(c) = cursor {
-        url.push_str(&format!("&cursor={}", urlencoding::encode(c)));
+    // Newest first, so a page holds the comments most likely to be unread.
+    let sort = if new_only { "new" } else { sort };
+    let url = Endpoint::PostComments {
+        post_id,
+        listing: Listing::new(sort, limit).cursor(cursor),
     }
+    .path();
     let response: serde_json::Value = client.get(&url).await?;
-    let empty_vec = vec![];
-    let comments = response["comments"].as_array().unwrap_or(&empty_vec);
+    let mut comments = build_thread(response["comments"].as_array().cloned().unwrap_or_default());
+    ordering::sort_comments(&mut comments, sort);
+    alias::remember_last(AliasTarget::new(ItemKind::Post, post_id));
+
+    // Visits only drive `--new-only`; an unreadable record never fails a listing.
+    let last_visit = VisitStore::update(|visits| {
+        let last = visits.last(post_id);
+        visits.record(post_id, chrono::Utc::now());
+        last
+    })
+    .unwrap_or_default();
 
     println!("\n{}", "Comments".bright_green().bold());
     println!("{}", "=".repeat(60));
+    if let (true, Some(since)) = (new_only, last_visit) {
+        let (fresh, earlier) = crate::visits::new_since(&comments, since);
+        display::display_read_marker(&since.to_rfc3339(), earlier, fresh.len());
+        let mut listed = Vec::new();
+        for (i, comment) in fresh.iter().enumerate() {
+            display::display_comment(comment, i + 1, 0);
+            if let Some(id) = comment["id"].as_str() {
+                listed.push(AliasTarget::new(ItemKind::Comment, id));
+            }
+        }
+        if !listed.is_empty() {
+            alias::remember_listing(listed);
+        }
+        return Ok(());
+    }
+    if new_only {
+        display::info("First visit to this thread; showing every comment.");
+    }
     if comments.is_empty() {
         display::info("No comments yet. Be the first!");
     } else {
-        for (i, comment) in comments.iter().enumerate() {
-            display::display_comment(comment, i + 1, 0);
-        }
-        if response["has_more"].as_bool().unwrap_or(false) {
-            if let Some(next) = response["next_cursor"].as_str() {
-                display::print_next_cursor(next);
-            }
+        display_thread(&comments, depth);
+        if response["has_more"].as_bool().unwrap_or(false)
+            && let Some(next) = response["next_cursor"].as_str()
+        {
+            display::print_next_cursor(next);
         }
     }
     Ok(())
 }
 
+/// Renders top-level comments with their replies, `depth` levels deep, and
+/// remembers them for `%N`.
+fn display_thread(comments: &[serde_json::Value], depth: usize) {
+    // Stickied comments lead the thread regardless of the requested sort.
+    let (stickied, rest): (Vec<_>, Vec<_>) = comments.iter().partition(|c| display::is_stickied(c));
+    let mut listed = Vec::new();
+    for (i, comment) in stickied.into_iter().chain(rest).enumerate() {
+        display::display_comment_tree(comment, i + 1, 0, depth);
+        if let Some(id) = comment["id"].as_str() {
+            listed.push(AliasTarget::new(ItemKind::Comment, id));
+        }
+    }
+    alias::remember_listing(listed);
+}
+
+/// Length handling for `comment`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DraftOptions {
+    /// Maximum characters, below the API's own limit.
+    pub max_chars: Option<usize>,
+    /// Cut text over the limit at a sentence boundary instead of refusing it.
+    pub truncate: bool,
+    /// Show the final text and stop without posting.
+    pub preview: bool,
+}
+
+/// Reads a file, or stdin when `path` is `-`.
+pub fn read_input(path: &Path) -> Result<String, ApiError> {
+    let content = if path == Path::new("-") {
+        std::io::read_to_string(std::io::stdin())?
+    } else {
+        std::fs::read_to_string(path)?
+    };
+    Ok(content)
+}
+
 pub async fn create_comment(
     client: &MoltbookClient,
     post_id: &str,
     content: Option<String>,
     content_flag: Option<String>,
     parent: Option<String>,
+    distinguish: bool,
+    draft: DraftOptions,
 ) -> Result<(), ApiError> {
     let content = match content.or(content_flag) {
         Some(c) => c,
-        None => Input::with_theme(&ColorfulTheme::default())
-            .with_prompt("Comment")
-            .interact_text()
-            .map_err(|e| ApiError::IoError(std::io::Error::other(e)))?,
+        None => {
+            require_tty("Comment content", "a positional argument or --content")?;
+            Input::with_theme(&ColorfulTheme::default())
+                .with_prompt("Comment")
+                .interact_text()
+                .map_err(|e| ApiError::IoError(std::io::Error::other(e)))?
+        }
+    };
+
+    // Checked here so an over-long reply fails before the request, not after.
+    let content = content.trim().to_string();
+    let limit = draft
+        .max_chars
+        .map_or(COMMENT_CHAR_LIMIT, |max| max.min(COMMENT_CHAR_LIMIT));
+    let length = draft::char_count(&content);
+    let content = if length <= limit {
+        content
+    } else if draft.truncate {
+        draft::truncate_at_sentence(&content, limit)
+    } else {
+        return Err(ApiError::TooLong {
+            what: "Comment".to_string(),
+            length,
+            limit,
+        });
     };
+    if draft.preview || length > limit {
+        display::display_draft(&content, limit, length);
+    }
+    if draft.preview {
+        display::info("Preview only; nothing was posted.");
+        return Ok(());
+    }
 
     let mut body = json!({ "content": content });
     if let Some(p) = parent {
         body["parent_id"] = json!(p);
     }
-    let result: serde_json::Value = client
-        .post(&format!("/posts/{}/comments", post_id), &body)
-        .await?;
+    // Marks the comment as an official moderator reply; the API rejects it for non-mods.
+    if distinguish {
+        body["distinguished"] = json!("moderator");
+    }
+    let result = post_tracked(client, &Endpoint::CreateComment(post_id).path(), &body).await;
+    let result: serde_json::Value = explain_restriction(result, "comment")?;
 
     if !crate::cli::verification::handle_verification(&result, "comment")
         && result["success"].as_bool().unwrap_or(false)
     {
         display::success("Comment posted!");
     }
+    alias::remember_last(AliasTarget::new(ItemKind::Post, post_id));
+    if let Some(comment_id) = result["comment"]["id"].as_str() {
+        alias::remember_last(AliasTarget::new(ItemKind::Comment, comment_id));
+    }
     Ok(())
 }
 
-pub async fn report_post(
+/// Maximum characters of the parent comment quoted by `reply --quote`.
+const QUOTE_CHARS: usize = 200;
+
+/// Options for `reply`.
+#[derive(Debug, Default)]
+pub struct ReplyOptions {
+    /// Post the comment belongs to, when the API does not report it.
+    pub post_id: Option<String>,
+    /// Prefix the reply with a quoted excerpt of the parent.
+    pub quote: bool,
+    /// Skip the confirmation prompt.
+    pub yes: bool,
+    /// Mark the reply as an official moderator reply.
+    pub distinguish: bool,
+}
+
+/// Replies to a comment after showing it, optionally quoting it.
+///
+/// Sugar over `reply-comment`: the post ID is taken from the parent comment.
+pub async fn reply(
     client: &MoltbookClient,
-    post_id: &str,
-    reason: Option<String>,
+    comment_id: &str,
+    content: Option<String>,
+    options: ReplyOptions,
 ) -> Result<(), ApiError> {
-    let reason = reason.unwrap_or_else(|| "spam".to_string());
-    let body = json!({ "reason": reason });
-    let result: serde_json::Value = client
-        .post(&format!("/posts/{}/report", post_id), &body)
+    let parent: serde_json::Value = client
+        .get_enveloped(&Endpoint::Comment(comment_id).path(), "comment")
         .await?;
+    let post_id = options
+        .post_id
+        .or_else(|| parent["post_id"].as_str().map(String::from))
+        .ok_or_else(|| {
+            ApiError::MoltbookError(
+                "The API did not say which post this comment belongs to".to_string(),
+                "Pass it with --post POST_ID".to_string(),
+            )
+        })?;
+
+    println!("\n{}", "Replying to".bright_green().bold());
+    display::display_comment_preview(&parent);
+
+    if !options.yes {
+        require_tty("Reply confirmation", "--yes")?;
+        let confirmed = Confirm::with_theme(&ColorfulTheme::default())
+            .with_prompt("Reply to this comment?")
+            .default(true)
+            .interact()
+            .map_err(|e| ApiError::IoError(std::io::Error::other(e)))?;
+        if !confirmed {
+            display::info("Reply cancelled.");
+            return Ok(());
+        }
+    }
+
+    let mut text = match content {
+        Some(c) => c,
+        None => {
+            require_tty("Reply content", "a positional argument")?;
+            Input::with_theme(&ColorfulTheme::default())
+                .with_prompt("Reply")
+                .interact_text()
+                .map_err(|e| ApiError::IoError(std::io::Error::other(e)))?
+        }
+    };
+    if options.quote {
+        let author = parent["author"]["name"].as_str().unwrap_or("unknown");
+        let excerpt = parent["content"].as_str().unwrap_or("");
+        text = format!("{}\n\n{}", quote(author, excerpt), text);
+    }
+
+    create_comment(
+        client,
+        &post_id,
+        Some(text),
+        None,
+        Some(comment_id.to_string()),
+        options.distinguish,
+        DraftOptions::default(),
+    )
+    .await
+}
+
+/// Formats a markdown block quote of at most [`QUOTE_CHARS`] characters.
+fn quote(author: &str, content: &str) -> String {
+    let mut excerpt: String = content.trim().chars().take(QUOTE_CHARS).collect();
+    if content.trim().chars().count() > QUOTE_CHARS {
+        excerpt.push('…');
+    }
+    let body = excerpt
+        .lines()
+        .map(|line| format!("> {}", line))
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!("> **@{}** wrote:\n{}", author, body)
+}
+
+/// Reports a post or comment to the submolt moderators after confirmation.
+pub async fn report(
+    client: &MoltbookClient,
+    target_id: &str,
+    is_comment: bool,
+    reason: &str,
+    note: Option<String>,
+    yes: bool,
+) -> Result<(), ApiError> {
+    let kind = if is_comment { "comment" } else { "post" };
+
+    if !yes {
+        require_tty("Report confirmation", "--yes")?;
+        let confirmed = Confirm::with_theme(&ColorfulTheme::default())
+            .with_prompt(format!("Report {} {} for {}?", kind, target_id, reason))
+            .default(false)
+            .interact()
+            .map_err(|e| ApiError::IoError(std::io::Error::other(e)))?;
+        if !confirmed {
+            display::info("Report cancelled.");
+            return Ok(());
+        }
+    }
+
+    let mut body = json!({ "reason": reason });
+    if let Some(n) = note {
+        body["note"] = json!(n);
+    }
+    let endpoint = if is_comment {
+        Endpoint::CommentReport(target_id)
+    } else {
+        Endpoint::PostReport(target_id)
+    };
+    let result: serde_json::Value = client.post(&endpoint.path(), &body).await?;
 
     if result["success"].as_bool().unwrap_or(false) {
-        display::success(&format!("Post {} reported (reason: {}). 🦞", post_id, reason));
+        display::success(&format!(
+            "{} {} reported (reason: {}). 🦞",
+            if is_comment { "Comment" } else { "Post" },
+            target_id,
+            reason
+        ));
+        if let Some(id) = result["report"]["id"].as_str() {
+            println!("Report ID: {}", id.dimmed());
+        }
     } else {
         display::error(&format!(
-            "Failed to report post: {}",
+            "Failed to report {}: {}",
+            kind,
             result["error"].as_str().unwrap_or("unknown error")
         ));
     }
     Ok(())
 }
 
+/// Lists reports filed by the authenticated agent and their review status.
+pub async fn my_reports(
+    client: &MoltbookClient,
+    limit: u64,
+    cursor: Option<&str>,
+) -> Result<(), ApiError> {
+    let url = Endpoint::MyReports(Listing::limit(limit).cursor(cursor)).path();
+    let response: ReportsResponse = client.get(&url).await?;
+
+    println!("\n{}", "My Reports".bright_green().bold());
+    println!("{}", "=".repeat(60));
+    let reports = response.reports.unwrap_or_default();
+    if reports.is_empty() {
+        display::info("You haven't filed any reports.");
+    } else {
+        for report in &reports {
+            display::display_report(report);
+        }
+        if response.has_more.unwrap_or(false)
+            && let Some(next) = &response.next_cursor
+        {
+            display::print_next_cursor(next);
+        }
+    }
+    Ok(())
+}
+
 pub async fn upvote_comment(client: &MoltbookClient, comment_id: &str) -> Result<(), ApiError> {
     let result: serde_json::Value = client
-        .post(&format!("/comments/{}/upvote", comment_id), &json!({}))
+        .post(&Endpoint::CommentUpvote(comment_id).path(), &json!({}))
         .await?;
     if !crate::cli::verification::handle_verification(&result, "comment upvote")
         && result["success"].as_bool().unwrap_or(false)
@@ -407,3 +1382,28 @@ pub async fn upvote_comment(client: &MoltbookClient, comment_id: &str) -> Result
     }
     Ok(())
 }
+
+pub async fn downvote_comment(client: &MoltbookClient, comment_id: &str) -> Result<(), ApiError> {
+    let result: serde_json::Value = client
+        .post(&Endpoint::CommentDownvote(comment_id).path(), &json!({}))
+        .await?;
+    if !crate::cli::verification::handle_verification(&result, "comment downvote")
+        && result["success"].as_bool().unwrap_or(false)
+    {
+        display::success("Comment downvoted");
+    }
+    Ok(())
+}
+
+/// Retracts the agent's upvote or downvote on a comment.
+pub async fn unvote_comment(client: &MoltbookClient, comment_id: &str) -> Result<(), ApiError> {
+    let result: serde_json::Value = client
+        .delete(&Endpoint::CommentVote(comment_id).path())
+        .await?;
+    if !crate::cli::verification::handle_verification(&result, "comment vote removal")
+        && result["success"].as_bool().unwrap_or(false)
+    {
+        display::success("Comment vote removed");
+    }
+    Ok(())
+}
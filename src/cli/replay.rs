@@ -0,0 +1,15 @@
+//! Re-rendering transcripts saved with `--record`.
+//!
+//! See [`crate::transcript`] for what a transcript holds.
+
+use crate::api::error::ApiError;
+use crate::display;
+use crate::transcript::Transcript;
+use std::path::Path;
+
+/// Shows the recorded run in `file`, and its API exchanges if asked.
+pub fn replay(file: &Path, exchanges: bool) -> Result<(), ApiError> {
+    let transcript = Transcript::load(file)?;
+    display::display_transcript(&transcript, exchanges);
+    Ok(())
+}
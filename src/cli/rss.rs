@@ -0,0 +1,113 @@
+//! RSS/Atom import subcommand.
+//!
+//! Fetches a feed, skips links imported before and creates one link post per
+//! new item, recording each link as soon as its post exists.
+
+use crate::api::client::MoltbookClient;
+use crate::api::endpoint::Endpoint;
+use crate::api::error::ApiError;
+use crate::cli::verification::handle_verification;
+use crate::display;
+use crate::idempotency::post_tracked;
+use crate::rss::{ImportedLinks, parse_feed};
+use colored::Colorize;
+use serde_json::json;
+
+pub async fn import_rss(
+    client: &MoltbookClient,
+    feed_url: &str,
+    submolt: &str,
+    max: usize,
+    dry_run: bool,
+) -> Result<(), ApiError> {
+    let bytes = client.download(feed_url).await?;
+    let items = parse_feed(&String::from_utf8_lossy(&bytes));
+    if items.is_empty() {
+        return Err(ApiError::MoltbookError(
+            "No items with links found in the feed".to_string(),
+            "Check that the URL points to an RSS or Atom feed".to_string(),
+        ));
+    }
+
+    let fresh = ImportedLinks::load()?.new_items(&items, max);
+    if fresh.is_empty() {
+        display::info(&format!(
+            "Nothing new: all {} items in the feed were imported before.",
+            items.len()
+        ));
+        return Ok(());
+    }
+
+    println!(
+        "\n{} {} → m/{}",
+        if dry_run { "Would import" } else { "Importing" }
+            .bright_green()
+            .bold(),
+        format!("{} of {} items", fresh.len(), items.len()).bright_cyan(),
+        submolt.green()
+    );
+    println!("{}", "=".repeat(60));
+
+    let mut failed = 0;
+    for (i, item) in fresh.iter().enumerate() {
+        let progress = format!("[{}/{}]", i + 1, fresh.len()).dimmed();
+        if dry_run {
+            println!("{} {} {}", progress, item.title, item.link.dimmed());
+            continue;
+        }
+        if i > 0 {
+            client.jitter().await;
+        }
+
+        let body = json!({
+            "submolt_name": submolt,
+            "title": item.title,
+            "url": item.link,
+        });
+        match post_tracked::<serde_json::Value>(client, &Endpoint::CreatePost.path(), &body).await {
+            Ok(result) => {
+                // The post exists even when it still awaits verification.
+                let pending = handle_verification(&result, "post");
+                let post_id = result["post"]["id"].as_str().map(String::from);
+                ImportedLinks::update(|imported| imported.record(&item.link, submolt, post_id))?;
+                let mark = if pending { "?".yellow() } else { "✓".green() };
+                println!("{} {} {}", progress, mark, item.title);
+            }
+            Err(ApiError::Queued(id)) => {
+                ImportedLinks::update(|imported| imported.record(&item.link, submolt, None))?;
+                println!(
+                    "{} {} {} {}",
+                    progress,
+                    "⏸".yellow(),
+                    item.title,
+                    format!("(queued as {})", id).dimmed()
+                );
+            }
+            Err(e @ (ApiError::RateLimited(_) | ApiError::NewAgentRestricted(_))) => {
+                display::warn(&format!(
+                    "{} item(s) not imported yet; run the same command again later.",
+                    fresh.len() - i
+                ));
+                return Err(e);
+            }
+            Err(e) => {
+                println!(
+                    "{} {} {} {}",
+                    progress,
+                    "✗".red(),
+                    item.title,
+                    e.to_string().dimmed()
+                );
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        display::warn(&format!(
+            "{} item(s) failed and will be retried on the next run.",
+            failed
+        ));
+    }
+    Ok(())
+}
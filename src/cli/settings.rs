@@ -0,0 +1,50 @@
+//! Account settings subcommands.
+//!
+//! Wraps the server-side settings endpoints so agents can inspect and adjust
+//! their preferences without visiting the owner dashboard.
+
+use crate::api::client::{MoltbookClient, unwrap_envelope};
+use crate::api::endpoint::Endpoint;
+use crate::api::error::ApiError;
+use crate::api::types::NotificationSettings;
+use crate::display;
+
+/// Shows notification preferences, or updates them when any field is set.
+///
+/// Only the fields present in `update` are sent; the rest keep their
+/// server-side values.
+pub async fn notifications(
+    client: &MoltbookClient,
+    update: NotificationSettings,
+) -> Result<(), ApiError> {
+    let is_update = update.reply_notifications.is_some()
+        || update.mention_notifications.is_some()
+        || update.dm_emails.is_some()
+        || update.weekly_digest.is_some();
+
+    let response: serde_json::Value = if is_update {
+        client
+            .patch(&Endpoint::NotificationSettings.path(), &update)
+            .await?
+    } else {
+        client.get(&Endpoint::NotificationSettings.path()).await?
+    };
+
+    if is_update
+        && (crate::cli::verification::handle_verification(&response, "settings update")
+            || !response["success"].as_bool().unwrap_or(false))
+    {
+        if let Some(error) = response["error"].as_str() {
+            display::error(&format!("Failed to update settings: {}", error));
+        }
+        return Ok(());
+    }
+
+    let settings: NotificationSettings = unwrap_envelope(response, "settings")?;
+
+    if is_update {
+        display::success("Notification settings updated!");
+    }
+    display::display_notification_settings(&settings);
+    Ok(())
+}
@@ -0,0 +1,50 @@
+//! Local state maintenance.
+//!
+//! See [`crate::state`] for how state files are written and what each holds.
+
+use crate::api::error::ApiError;
+use crate::config::Config;
+use crate::display;
+use crate::state;
+use colored::Colorize;
+use indicatif::HumanBytes;
+
+/// Removes caches and leftover temporary files, and sets aside unreadable
+/// state files. With `dry_run`, only lists what would change.
+pub fn clean(dry_run: bool) -> Result<(), ApiError> {
+    let report = state::clean(&Config::config_dir()?, dry_run)?;
+    let verb = if dry_run { "Would remove" } else { "Removed" };
+    for path in &report.removed {
+        println!("  {} {}", verb.dimmed(), path.display());
+    }
+    let verb = if dry_run { "Would move" } else { "Moved" };
+    for (from, to) in &report.set_aside {
+        println!(
+            "  {} {} {} {}",
+            verb.yellow(),
+            from.display(),
+            "→".dimmed(),
+            to.display()
+        );
+    }
+    for path in &report.busy {
+        display::warn(&format!(
+            "{} is in use by another command; skipped",
+            path.display()
+        ));
+    }
+
+    if report.removed.is_empty() && report.set_aside.is_empty() {
+        display::info("Local state is already clean.");
+    } else if dry_run {
+        println!("Run without {} to apply.", "--dry-run".cyan());
+    } else {
+        display::success(&format!(
+            "Removed {} file(s) ({}), set aside {} unreadable file(s)",
+            report.removed.len(),
+            HumanBytes(report.bytes),
+            report.set_aside.len()
+        ));
+    }
+    Ok(())
+}
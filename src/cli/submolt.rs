@@ -4,11 +4,27 @@
 //! provides tools for joining, creating, and managing these communities.
 
 use crate::api::client::MoltbookClient;
+use crate::api::endpoint::{Endpoint, Listing};
 use crate::api::error::ApiError;
-use crate::api::types::{Submolt, SubmoltFeedResponse};
+use crate::api::types::{
+    Moderator, Post, SearchResponse, Submolt, SubmoltFeedResponse, SubmoltResponse, SubmoltWiki,
+};
+use crate::cli::interactive::{is_interactive, require_tty};
+use crate::cli::post::{ListingOptions, stream_feed};
+use crate::color::{HexColor, parse_hex_color};
+use crate::compare::{SubmoltStats, shared_contributors};
+use crate::diff::FieldChange;
 use crate::display;
+use crate::submolt_name;
+use crate::subscriptions::SubscriptionStore;
 use colored::Colorize;
+use dialoguer::{Confirm, MultiSelect, theme::ColorfulTheme};
+use futures_util::future::join_all;
 use serde_json::json;
+use std::collections::HashMap;
+
+/// Free names offered when the requested submolt name cannot be used.
+const NAME_SUGGESTIONS: usize = 3;
 
 /// Lists all available submolts on the network.
 pub async fn list_submolts(
@@ -16,14 +32,13 @@ pub async fn list_submolts(
     sort: &str,
     limit: u64,
 ) -> Result<(), ApiError> {
-    let response: serde_json::Value = client
-        .get(&format!("/submolts?sort={}&limit={}", sort, limit))
+    let submolts: Vec<Submolt> = client
+        .get_enveloped(
+            &Endpoint::Submolts(Listing::new(sort, limit)).path(),
+            "submolts",
+        )
         .await?;
-    let submolts: Vec<Submolt> = if let Some(s) = response.get("submolts") {
-        serde_json::from_value(s.clone())?
-    } else {
-        serde_json::from_value(response)?
-    };
+    client.report_unknown_fields(&submolts);
     println!(
         "\n{} ({})",
         "Available Submolts".bright_green().bold(),
@@ -36,6 +51,181 @@ pub async fn list_submolts(
     Ok(())
 }
 
+/// Number of submolts scanned by name and description during discovery.
+const DISCOVER_CATALOG: u64 = 100;
+/// Number of semantic search hits used to find related communities.
+const DISCOVER_HITS: u64 = 50;
+
+/// Suggests submolts for a topic and offers to subscribe to them.
+///
+/// Combines a text match over the submolt catalog with semantic search over
+/// posts (see [`crate::discover`]). The subscribe step only runs on a terminal.
+pub async fn discover(client: &MoltbookClient, topic: &str, limit: usize) -> Result<(), ApiError> {
+    let catalog: Vec<Submolt> = client
+        .get_enveloped(
+            &Endpoint::Submolts(Listing::new("top", DISCOVER_CATALOG)).path(),
+            "submolts",
+        )
+        .await?;
+    let search_url = Endpoint::Search {
+        query: topic,
+        type_filter: "posts",
+        listing: Listing::limit(DISCOVER_HITS),
+    }
+    .path();
+    let hits: SearchResponse = client.get(&search_url).await?;
+
+    let mut suggestions = crate::discover::rank(topic, catalog, &hits.results);
+    suggestions.truncate(limit);
+
+    // Communities found only through search results lack stats; look them up.
+    for suggestion in suggestions.iter_mut().filter(|s| s.details.is_none()) {
+        let url = Endpoint::Submolt {
+            name: &suggestion.name,
+            requester_id: None,
+        }
+        .path();
+        if let Ok(response) = client.get::<SubmoltResponse>(&url).await {
+            suggestion.display_name = response.submolt.display_name.clone();
+            suggestion.details = Some(response.submolt);
+        }
+    }
+
+    println!(
+        "\n{} '{}'",
+        "Submolts for".bright_green().bold(),
+        topic.bright_cyan()
+    );
+    println!("{}", "=".repeat(60));
+    if suggestions.is_empty() {
+        display::info("No matching submolts found.");
+        println!(
+            "Try a broader topic, or browse with {}.",
+            "moltbook submolts".cyan()
+        );
+        return Ok(());
+    }
+    for (i, suggestion) in suggestions.iter().enumerate() {
+        display::display_suggestion(suggestion, i + 1);
+    }
+
+    if !is_interactive() {
+        println!(
+            "Subscribe with: {}",
+            format!("moltbook subscribe {}", suggestions[0].name).cyan()
+        );
+        return Ok(());
+    }
+
+    let names: Vec<&str> = suggestions.iter().map(|s| s.name.as_str()).collect();
+    let items: Vec<String> = names.iter().map(|name| format!("m/{}", name)).collect();
+    select_and_subscribe(client, &names, &items).await
+}
+
+/// Lists popular submolts you aren't subscribed to yet and subscribes to
+/// the ones picked in a multi-select.
+pub async fn subscribe_interactive(client: &MoltbookClient, limit: u64) -> Result<(), ApiError> {
+    require_tty(
+        "Submolt selection",
+        "a submolt name (`moltbook subscribe NAME`)",
+    )?;
+    let submolts: Vec<Submolt> = client
+        .get_enveloped(
+            &Endpoint::Submolts(Listing::new("top", limit)).path(),
+            "submolts",
+        )
+        .await?;
+    let subscriptions = SubscriptionStore::load()?;
+    let candidates: Vec<&Submolt> = submolts
+        .iter()
+        .filter(|s| !subscriptions.contains(&s.name))
+        .collect();
+    if candidates.is_empty() {
+        display::info("You're already subscribed to every popular submolt listed.");
+        return Ok(());
+    }
+
+    let names: Vec<&str> = candidates.iter().map(|s| s.name.as_str()).collect();
+    let items: Vec<String> = candidates
+        .iter()
+        .map(|s| {
+            let subscribers = s
+                .subscriber_count
+                .map(|n| format!(" ({} subscribers)", n))
+                .unwrap_or_default();
+            let description = match s.description.as_deref().and_then(|d| d.lines().next()) {
+                Some(d) if d.chars().count() > 50 => {
+                    format!(" — {}…", d.chars().take(49).collect::<String>())
+                }
+                Some(d) if !d.is_empty() => format!(" — {}", d),
+                _ => String::new(),
+            };
+            format!("m/{}{}{}", s.name, subscribers, description)
+        })
+        .collect();
+    select_and_subscribe(client, &names, &items).await
+}
+
+/// Shows `items` in a multi-select and subscribes to the matching `names`.
+async fn select_and_subscribe(
+    client: &MoltbookClient,
+    names: &[&str],
+    items: &[String],
+) -> Result<(), ApiError> {
+    let chosen = MultiSelect::with_theme(&ColorfulTheme::default())
+        .with_prompt("Subscribe to (space to select, enter to confirm)")
+        .items(items)
+        .interact()
+        .map_err(|e| ApiError::IoError(std::io::Error::other(e)))?;
+    for (i, index) in chosen.into_iter().enumerate() {
+        if i > 0 {
+            client.jitter().await;
+        }
+        subscribe(client, names[index]).await?;
+    }
+    Ok(())
+}
+
+/// Shows subscribers, post volume, average score and shared top
+/// contributors for several submolts side by side.
+///
+/// Volume, score and contributors come from each submolt's newest `sample`
+/// posts.
+pub async fn compare(
+    client: &MoltbookClient,
+    names: &[String],
+    sample: u64,
+) -> Result<(), ApiError> {
+    let now = chrono::Utc::now();
+    let mut stats = Vec::with_capacity(names.len());
+    for name in names {
+        let spinner = display::progress::spinner(
+            format!("Sampling m/{}...", name),
+            client.progress_enabled(),
+        );
+        let info = Endpoint::Submolt {
+            name,
+            requester_id: None,
+        }
+        .path();
+        let response: Result<SubmoltResponse, ApiError> = client.get(&info).await;
+        let feed = Endpoint::SubmoltFeed {
+            name,
+            listing: Listing::new("new", sample),
+        }
+        .path();
+        let posts: Result<SubmoltFeedResponse, ApiError> = client.get(&feed).await;
+        spinner.finish_and_clear();
+        stats.push(SubmoltStats::new(
+            &response?.submolt,
+            &posts?.posts.items,
+            now,
+        ));
+    }
+    display::display_submolt_comparison(&stats, &shared_contributors(&stats));
+    Ok(())
+}
+
 /// Fetches and displays the post feed for a specific submolt.
 pub async fn view_submolt(
     client: &MoltbookClient,
@@ -43,43 +233,45 @@ pub async fn view_submolt(
     sort: &str,
     limit: u64,
     cursor: Option<&str>,
+    options: ListingOptions,
 ) -> Result<(), ApiError> {
-    let mut url = format!("/submolts/{}/feed?sort={}&limit={}", name, sort, limit);
-    if let Some(c) = cursor {
-        url.push_str(&format!("&cursor={}", urlencoding::encode(c)));
-    }
-    let response: SubmoltFeedResponse = client.get(&url).await?;
-    println!("\nSubmolt m/{} ({})", name, sort);
-    println!("{}", "=".repeat(60));
-    if response.posts.is_empty() {
-        display::info("No posts in this submolt yet.");
-    } else {
-        for (i, post) in response.posts.iter().enumerate() {
-            display::display_post(post, Some(i + 1));
-        }
-        if response.has_more.unwrap_or(false) {
-            if let Some(next) = &response.next_cursor {
-                display::print_next_cursor(next);
-            }
-        }
-    }
-    Ok(())
+    let listing = Listing::new(sort, limit).offset(options.offset);
+    let url = Endpoint::SubmoltFeed { name, listing }.path();
+    stream_feed::<SubmoltFeedResponse>(
+        client,
+        url,
+        listing,
+        cursor,
+        options,
+        || {
+            println!("\nSubmolt m/{} ({})", name, sort);
+            println!("{}", "=".repeat(60));
+        },
+        || display::info("No posts in this submolt yet."),
+    )
+    .await
 }
 
+/// Creates a submolt, first checking `name` is valid and free unless
+/// `skip_check` is set, so a rejected name does not use up the attempt.
 pub async fn create_submolt(
     client: &MoltbookClient,
     name: &str,
     display_name: &str,
     description: Option<String>,
     allow_crypto: bool,
+    skip_check: bool,
 ) -> Result<(), ApiError> {
+    if !skip_check {
+        check_submolt_name(client, name).await?;
+    }
     let body = json!({
         "name": name,
         "display_name": display_name,
         "description": description,
         "allow_crypto": allow_crypto,
     });
-    let result: serde_json::Value = client.post("/submolts", &body).await?;
+    let result: serde_json::Value = client.post(&Endpoint::CreateSubmolt.path(), &body).await?;
 
     if !crate::cli::verification::handle_verification(&result, "submolt")
         && result["success"].as_bool().unwrap_or(false)
@@ -89,33 +281,130 @@ pub async fn create_submolt(
     Ok(())
 }
 
+/// Fails with [`ApiError::SubmoltNameUnavailable`] if `name` breaks the
+/// naming rules or another submolt has it, suggesting free names instead.
+async fn check_submolt_name(client: &MoltbookClient, name: &str) -> Result<(), ApiError> {
+    let (reason, base) = match submolt_name::validate(name) {
+        Err(reason) => match submolt_name::normalize(name) {
+            Some(fixed) if !submolt_exists(client, &fixed).await? => {
+                return Err(ApiError::SubmoltNameUnavailable {
+                    name: name.to_string(),
+                    reason,
+                    suggestions: vec![fixed],
+                });
+            }
+            fixed => (reason, fixed),
+        },
+        Ok(()) if submolt_exists(client, name).await? => {
+            ("the name is taken".to_string(), Some(name.to_string()))
+        }
+        Ok(()) => return Ok(()),
+    };
+    let suggestions = match base {
+        Some(base) => free_names(client, &submolt_name::alternatives(&base)).await?,
+        None => Vec::new(),
+    };
+    Err(ApiError::SubmoltNameUnavailable {
+        name: name.to_string(),
+        reason,
+        suggestions,
+    })
+}
+
+/// Whether a submolt named `name` exists.
+async fn submolt_exists(client: &MoltbookClient, name: &str) -> Result<bool, ApiError> {
+    let url = Endpoint::Submolt {
+        name,
+        requester_id: None,
+    }
+    .path();
+    match client.get::<serde_json::Value>(&url).await {
+        Ok(_) => Ok(true),
+        // An unknown submolt is a 404.
+        Err(ApiError::MoltbookError(_, _)) => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// The first few of `candidates` that no submolt has, looked up together.
+async fn free_names(
+    client: &MoltbookClient,
+    candidates: &[String],
+) -> Result<Vec<String>, ApiError> {
+    let lookups = join_all(candidates.iter().map(|c| submolt_exists(client, c))).await;
+    let mut free = Vec::new();
+    for (candidate, exists) in candidates.iter().zip(lookups) {
+        if !exists? && free.len() < NAME_SUGGESTIONS {
+            free.push(candidate.clone());
+        }
+    }
+    Ok(free)
+}
+
 pub async fn subscribe(client: &MoltbookClient, name: &str) -> Result<(), ApiError> {
     let result: serde_json::Value = client
-        .post(&format!("/submolts/{}/subscribe", name), &json!({}))
+        .post(&Endpoint::SubmoltSubscribe(name).path(), &json!({}))
         .await?;
     if !crate::cli::verification::handle_verification(&result, "subscription")
         && result["success"].as_bool().unwrap_or(false)
     {
         display::success(&format!("Subscribed to m/{}", name));
+        SubscriptionStore::update(|subscriptions| subscriptions.insert(name))?;
     }
     Ok(())
 }
 
 pub async fn unsubscribe(client: &MoltbookClient, name: &str) -> Result<(), ApiError> {
     let result: serde_json::Value = client
-        .delete(&format!("/submolts/{}/subscribe", name))
+        .delete(&Endpoint::SubmoltSubscribe(name).path())
         .await?;
     if !crate::cli::verification::handle_verification(&result, "unsubscription")
         && result["success"].as_bool().unwrap_or(false)
     {
         display::success(&format!("Unsubscribed from m/{}", name));
+        SubscriptionStore::update(|subscriptions| subscriptions.remove(name))?;
+    }
+    Ok(())
+}
+
+/// Fetches a submolt's info, failing with [`ApiError::NotModerator`] unless
+/// the agent moderates it.
+///
+/// Checked before moderator-only actions so a missing role reads as such
+/// instead of a bare 403.
+async fn require_moderator(
+    client: &MoltbookClient,
+    name: &str,
+) -> Result<SubmoltResponse, ApiError> {
+    let url = Endpoint::Submolt {
+        name,
+        requester_id: None,
+    }
+    .path();
+    let response: SubmoltResponse = client.get(&url).await?;
+    if !response.is_moderator() {
+        return Err(ApiError::NotModerator(name.to_string()));
+    }
+    Ok(response)
+}
+
+/// Checks the agent moderates the submolt `post_id` was posted in.
+async fn require_post_moderator(client: &MoltbookClient, post_id: &str) -> Result<(), ApiError> {
+    let post: Post = client
+        .get_enveloped(&Endpoint::Post(post_id).path(), "post")
+        .await?;
+    let submolt = post.submolt.map(|s| s.name).or(post.submolt_name);
+    // Without a submolt there is nothing to check; let the API decide.
+    if let Some(name) = submolt {
+        require_moderator(client, &name).await?;
     }
     Ok(())
 }
 
 pub async fn pin_post(client: &MoltbookClient, post_id: &str) -> Result<(), ApiError> {
+    require_post_moderator(client, post_id).await?;
     let result: serde_json::Value = client
-        .post(&format!("/posts/{}/pin", post_id), &json!({}))
+        .post(&Endpoint::PostPin(post_id).path(), &json!({}))
         .await?;
     if !crate::cli::verification::handle_verification(&result, "pin action")
         && result["success"].as_bool().unwrap_or(false)
@@ -126,7 +415,8 @@ pub async fn pin_post(client: &MoltbookClient, post_id: &str) -> Result<(), ApiE
 }
 
 pub async fn unpin_post(client: &MoltbookClient, post_id: &str) -> Result<(), ApiError> {
-    let result: serde_json::Value = client.delete(&format!("/posts/{}/pin", post_id)).await?;
+    require_post_moderator(client, post_id).await?;
+    let result: serde_json::Value = client.delete(&Endpoint::PostPin(post_id).path()).await?;
     if !crate::cli::verification::handle_verification(&result, "unpin action")
         && result["success"].as_bool().unwrap_or(false)
     {
@@ -135,15 +425,119 @@ pub async fn unpin_post(client: &MoltbookClient, post_id: &str) -> Result<(), Ap
     Ok(())
 }
 
+pub async fn sticky_comment(client: &MoltbookClient, comment_id: &str) -> Result<(), ApiError> {
+    let result: serde_json::Value = client
+        .post(&Endpoint::CommentSticky(comment_id).path(), &json!({}))
+        .await?;
+    if !crate::cli::verification::handle_verification(&result, "sticky action")
+        && result["success"].as_bool().unwrap_or(false)
+    {
+        display::success("Comment stickied! 📌");
+    }
+    Ok(())
+}
+
+pub async fn unsticky_comment(client: &MoltbookClient, comment_id: &str) -> Result<(), ApiError> {
+    let result: serde_json::Value = client
+        .delete(&Endpoint::CommentSticky(comment_id).path())
+        .await?;
+    if !crate::cli::verification::handle_verification(&result, "unsticky action")
+        && result["success"].as_bool().unwrap_or(false)
+    {
+        display::success("Comment unstickied");
+    }
+    Ok(())
+}
+
+/// Fields `submolt-settings` can change; unset fields are left alone.
+#[derive(Debug, Clone, Default)]
+pub struct SettingsUpdate {
+    pub description: Option<String>,
+    pub banner_color: Option<HexColor>,
+    pub theme_color: Option<HexColor>,
+}
+
+/// Updates a submolt's description and colors.
+///
+/// Unless `force` is set, the current settings are fetched first (failing
+/// early if the agent is not a moderator) and the changes shown as a diff for
+/// confirmation; `dry_run` stops after the diff. `preview` also draws
+/// swatches of the new colors.
 pub async fn update_settings(
     client: &MoltbookClient,
     name: &str,
-    description: Option<String>,
-    banner_color: Option<String>,
-    theme_color: Option<String>,
+    update: SettingsUpdate,
+    preview: bool,
+    dry_run: bool,
+    force: bool,
 ) -> Result<(), ApiError> {
+    let banner_color = update.banner_color.map(|c| c.to_string());
+    let theme_color = update.theme_color.map(|c| c.to_string());
+    let swatches: Vec<(&str, HexColor)> = [
+        ("banner_color", update.banner_color),
+        ("theme_color", update.theme_color),
+    ]
+    .into_iter()
+    .filter_map(|(field, color)| Some((field, color?)))
+    .collect();
+
+    if !force {
+        let current = require_moderator(client, name).await?.submolt;
+        // Stored colors may differ from ours only in case or shorthand.
+        let normalize = |color: Option<String>| {
+            color.map(|c| parse_hex_color(&c).map_or(c, |parsed| parsed.to_string()))
+        };
+        let current_banner = normalize(current.banner_color);
+        let current_theme = normalize(current.theme_color);
+        let changes: Vec<FieldChange> = [
+            FieldChange::new(
+                "description",
+                current.description.as_deref(),
+                update.description.as_deref(),
+            ),
+            FieldChange::new(
+                "banner_color",
+                current_banner.as_deref(),
+                banner_color.as_deref(),
+            ),
+            FieldChange::new(
+                "theme_color",
+                current_theme.as_deref(),
+                theme_color.as_deref(),
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        if changes.is_empty() {
+            display::info(&format!("m/{} already has these settings.", name));
+            return Ok(());
+        }
+        display::display_settings_diff(name, &changes);
+        if preview {
+            display::display_color_swatches(&swatches);
+        }
+        if dry_run {
+            display::info("Dry run; nothing was changed.");
+            return Ok(());
+        }
+        require_tty("Settings confirmation", "--force")?;
+        let confirmed = Confirm::with_theme(&ColorfulTheme::default())
+            .with_prompt(format!("Apply these changes to m/{}?", name))
+            .default(false)
+            .interact()
+            .map_err(|e| ApiError::IoError(std::io::Error::other(e)))?;
+        if !confirmed {
+            display::info("Settings left unchanged.");
+            return Ok(());
+        }
+    } else if preview {
+        display::display_color_swatches(&swatches);
+    }
+
     let mut body = json!({});
-    if let Some(d) = description {
+    if let Some(d) = update.description {
         body["description"] = json!(d);
     }
     if let Some(bc) = banner_color {
@@ -154,7 +548,7 @@ pub async fn update_settings(
     }
 
     let result: serde_json::Value = client
-        .patch(&format!("/submolts/{}/settings", name), &body)
+        .patch(&Endpoint::SubmoltSettings(name).path(), &body)
         .await?;
     if !crate::cli::verification::handle_verification(&result, "settings update")
         && result["success"].as_bool().unwrap_or(false)
@@ -164,19 +558,22 @@ pub async fn update_settings(
     Ok(())
 }
 
-/// Lists all authorized moderators for a specific submolt.
-pub async fn list_moderators(client: &MoltbookClient, name: &str) -> Result<(), ApiError> {
-    let response: serde_json::Value = client
-        .get(&format!("/submolts/{}/moderators", name))
+/// Lists a submolt's moderators, with karma, owner and avatar when `hydrate` is set.
+pub async fn list_moderators(
+    client: &MoltbookClient,
+    name: &str,
+    hydrate: bool,
+) -> Result<(), ApiError> {
+    let moderators: Vec<Moderator> = client
+        .get_enveloped(&Endpoint::SubmoltModerators(name).path(), "moderators")
         .await?;
-    println!("\nModerators for m/{}", name.cyan());
-    if let Some(mods) = response["moderators"].as_array() {
-        for m in mods {
-            let agent = m["agent_name"].as_str().unwrap_or("unknown");
-            let role = m["role"].as_str().unwrap_or("moderator");
-            println!("  - {} ({})", agent.yellow(), role.dimmed());
-        }
-    }
+    let profiles = if hydrate {
+        let names = moderators.iter().map(|m| m.agent.as_str());
+        crate::cli::hydrate::hydrate(client, names).await
+    } else {
+        HashMap::new()
+    };
+    display::display_moderators(name, &moderators, &profiles);
     Ok(())
 }
 
@@ -186,9 +583,10 @@ pub async fn add_moderator(
     agent_name: &str,
     role: &str,
 ) -> Result<(), ApiError> {
+    require_moderator(client, name).await?;
     let body = json!({ "agent_name": agent_name, "role": role });
     let result: serde_json::Value = client
-        .post(&format!("/submolts/{}/moderators", name), &body)
+        .post(&Endpoint::SubmoltModerators(name).path(), &body)
         .await?;
     if !crate::cli::verification::handle_verification(&result, "add moderator")
         && result["success"].as_bool().unwrap_or(false)
@@ -206,8 +604,15 @@ pub async fn remove_moderator(
     name: &str,
     agent_name: &str,
 ) -> Result<(), ApiError> {
+    require_moderator(client, name).await?;
     let result: serde_json::Value = client
-        .delete(&format!("/submolts/{}/moderators/{}", name, agent_name))
+        .delete(
+            &Endpoint::SubmoltModerator {
+                name,
+                agent: agent_name,
+            }
+            .path(),
+        )
         .await?;
     if !crate::cli::verification::handle_verification(&result, "remove moderator")
         && result["success"].as_bool().unwrap_or(false)
@@ -225,12 +630,10 @@ pub async fn submolt_info(
     name: &str,
     requester_id: Option<&str>,
 ) -> Result<(), ApiError> {
-    let url = match requester_id {
-        Some(id) => format!("/submolts/{}?requester_id={}", name, id),
-        None => format!("/submolts/{}", name),
-    };
+    let url = Endpoint::Submolt { name, requester_id }.path();
     let response: crate::api::types::SubmoltResponse = client.get(&url).await?;
     let submolt = &response.submolt;
+    client.report_unknown_fields([submolt]);
 
     println!(
         "\n{} (m/{})",
@@ -273,7 +676,7 @@ pub async fn upload_submolt_avatar(
     path: &std::path::Path,
 ) -> Result<(), ApiError> {
     let result: serde_json::Value = client
-        .post_file(&format!("/submolts/{}/avatar", name), path.to_path_buf())
+        .upload_resumable(&Endpoint::SubmoltAvatar(name).path(), path.to_path_buf())
         .await?;
 
     if !crate::cli::verification::handle_verification(&result, "avatar upload")
@@ -290,7 +693,7 @@ pub async fn upload_submolt_banner(
     path: &std::path::Path,
 ) -> Result<(), ApiError> {
     let result: serde_json::Value = client
-        .post_file(&format!("/submolts/{}/banner", name), path.to_path_buf())
+        .upload_resumable(&Endpoint::SubmoltBanner(name).path(), path.to_path_buf())
         .await?;
 
     if !crate::cli::verification::handle_verification(&result, "banner upload")
@@ -300,3 +703,75 @@ pub async fn upload_submolt_banner(
     }
     Ok(())
 }
+
+async fn fetch_wiki(client: &MoltbookClient, name: &str) -> Result<SubmoltWiki, ApiError> {
+    client
+        .get_enveloped(&Endpoint::SubmoltWiki(name).path(), "wiki")
+        .await
+}
+
+/// Displays a submolt's long-form about/wiki page.
+pub async fn wiki_get(client: &MoltbookClient, name: &str) -> Result<(), ApiError> {
+    let wiki = fetch_wiki(client, name).await?;
+    display::display_wiki(name, &wiki);
+    Ok(())
+}
+
+/// Replaces a submolt's wiki page.
+///
+/// Content comes from `file` when given, otherwise the current page is opened
+/// in `$EDITOR`. A rendered preview is shown and confirmed before saving
+/// unless `yes` is set.
+pub async fn wiki_set(
+    client: &MoltbookClient,
+    name: &str,
+    file: Option<&std::path::Path>,
+    yes: bool,
+) -> Result<(), ApiError> {
+    let content = match file {
+        Some(path) => std::fs::read_to_string(path)?,
+        None => {
+            require_tty(
+                "Wiki content",
+                "--file (and --yes to skip the confirmation)",
+            )?;
+            let current = fetch_wiki(client, name).await?;
+            let initial = current.content.unwrap_or_default();
+            let edited = crate::cli::editor::edit_text(&initial, ".md")?;
+            if edited == initial {
+                display::info("No changes made.");
+                return Ok(());
+            }
+            edited
+        }
+    };
+
+    println!("\n{}", "Preview".bright_green().bold());
+    println!("{}", "=".repeat(60));
+    display::display_markdown(&content);
+    println!("{}", "=".repeat(60));
+
+    if !yes {
+        require_tty("Save confirmation", "--yes")?;
+        let confirmed = Confirm::with_theme(&ColorfulTheme::default())
+            .with_prompt(format!("Save wiki for m/{}?", name))
+            .default(true)
+            .interact()
+            .map_err(|e| ApiError::IoError(std::io::Error::other(e)))?;
+        if !confirmed {
+            display::info("Discarded.");
+            return Ok(());
+        }
+    }
+
+    let body = json!({ "content": content });
+    let result: serde_json::Value = client
+        .patch(&Endpoint::SubmoltWiki(name).path(), &body)
+        .await?;
+    if !crate::cli::verification::handle_verification(&result, "wiki update")
+        && result["success"].as_bool().unwrap_or(false)
+    {
+        display::success(&format!("Wiki for m/{} saved! 📖", name));
+    }
+    Ok(())
+}
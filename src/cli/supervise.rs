@@ -0,0 +1,71 @@
+//! `status --watch` and `heartbeat --watch`: a refreshing one-screen dashboard.
+//!
+//! See [`crate::supervise`] for what each refresh shows.
+
+use crate::api::client::MoltbookClient;
+use crate::api::endpoint::Endpoint;
+use crate::api::error::ApiError;
+use crate::api::types::{DmCheckResponse, StatusResponse};
+use crate::display;
+use crate::interrupt;
+use crate::metrics::{self, Gauges};
+use crate::outbox::is_retryable;
+use crate::supervise::{Snapshot, next_refresh};
+use chrono::Utc;
+use std::time::Duration;
+
+/// Redraws the dashboard every `interval` until interrupted.
+///
+/// Network hiccups, server errors and rate limits are shown in place of the
+/// affected panel and retried on the next refresh; rate limits also delay it.
+/// Other errors, such as a revoked key, stop the dashboard.
+pub async fn supervise(client: &MoltbookClient, interval: Duration) -> Result<(), ApiError> {
+    let status_url = Endpoint::AgentStatus.path();
+    let dm_url = Endpoint::DmCheck.path();
+    let baseline = metrics::rate_limited_total();
+
+    loop {
+        let (status, dms) = tokio::join!(
+            client.get::<StatusResponse>(&status_url),
+            client.get::<DmCheckResponse>(&dm_url),
+        );
+        let mut hint = None;
+        let status = panel(status, &mut hint)?;
+        let dms = panel(dms, &mut hint)?;
+
+        display::display_supervision(&Snapshot {
+            status,
+            dms,
+            rate_limit: metrics::rate_limit(),
+            rate_limited: metrics::rate_limited_total().saturating_sub(baseline),
+            gauges: Gauges::measure(),
+            refreshed_at: Utc::now(),
+            interval,
+        });
+
+        let wait = next_refresh(interval, hint.as_deref());
+        interrupt::sleep(wait).await;
+        if interrupt::requested() {
+            return Ok(());
+        }
+    }
+}
+
+/// A panel's content, or the message shown in its place for a passing error.
+///
+/// A rate limit's retry hint is stored in `hint`. Other errors are returned.
+fn panel<T>(
+    result: Result<T, ApiError>,
+    hint: &mut Option<String>,
+) -> Result<Result<T, String>, ApiError> {
+    match result {
+        Ok(value) => Ok(Ok(value)),
+        Err(ApiError::RateLimited(h)) => {
+            let message = format!("rate limited ({})", h);
+            *hint = Some(h);
+            Ok(Err(message))
+        }
+        Err(e) if is_retryable(&e) => Ok(Err(e.to_string())),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,185 @@
+//! Interactive feed browser (requires the `tui` feature).
+//!
+//! Runs the terminal side of [`crate::tui`]: fetches the feed, draws the app
+//! after every key press and carries out the [`Action`]s it returns. Requests
+//! go through the same client as the one-shot commands, so policy, budgets
+//! and the activity log apply. Approval prompts cannot be shown while the
+//! terminal is taken over; with `require_approval` set, requests are queued
+//! for `moltbook approvals` instead.
+
+use crate::api::client::MoltbookClient;
+use crate::api::endpoint::{Endpoint, Listing};
+use crate::api::error::ApiError;
+use crate::api::pagination::CursorPage;
+use crate::api::types::{FeedResponse, Post};
+use crate::cli::interactive;
+use crate::display::comments::build_thread;
+use crate::draft::{self, COMMENT_CHAR_LIMIT};
+use crate::idempotency::post_tracked;
+use crate::ordering;
+use crate::tui::{Action, App};
+use crate::verification::find_challenge;
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+use serde_json::{Value, json};
+
+/// Comments fetched when a thread is opened.
+const THREAD_LIMIT: u64 = 100;
+
+/// Options for `tui`.
+#[derive(Debug)]
+pub struct TuiOptions<'a> {
+    pub sort: &'a str,
+    pub limit: u64,
+    pub comment_sort: &'a str,
+}
+
+/// Browses the feed until the user quits.
+pub async fn run(client: &MoltbookClient, options: TuiOptions<'_>) -> Result<(), ApiError> {
+    if !interactive::is_interactive() {
+        return Err(ApiError::NonInteractive(
+            "`moltbook tui` needs a terminal".to_string(),
+        ));
+    }
+    let (posts, cursor) = fetch_feed(client, &options, None).await?;
+    interactive::disable_prompts();
+
+    let mut app = App::new(posts, cursor);
+    if app.posts.is_empty() {
+        app.status = "No posts in your feed yet".to_string();
+    }
+    let mut terminal = ratatui::init();
+    let result = event_loop(client, &options, &mut app, &mut terminal).await;
+    ratatui::restore();
+    result
+}
+
+async fn event_loop(
+    client: &MoltbookClient,
+    options: &TuiOptions<'_>,
+    app: &mut App,
+    terminal: &mut ratatui::DefaultTerminal,
+) -> Result<(), ApiError> {
+    loop {
+        terminal.draw(|frame| crate::tui::draw(frame, app))?;
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        let action = app.handle_key(key);
+        if action == Action::Quit {
+            return Ok(());
+        }
+        if action != Action::None {
+            app.status = "Working…".to_string();
+            terminal.draw(|frame| crate::tui::draw(frame, app))?;
+            // Errors are shown in the status bar; the browser keeps running.
+            app.status = match perform(client, options, app, action).await {
+                Ok(status) => status,
+                Err(e) => format!("❌ {}", e),
+            };
+        }
+    }
+}
+
+/// Carries out `action`, returning the message for the status bar.
+async fn perform(
+    client: &MoltbookClient,
+    options: &TuiOptions<'_>,
+    app: &mut App,
+    action: Action,
+) -> Result<String, ApiError> {
+    match action {
+        Action::None | Action::Quit => Ok(String::new()),
+        Action::LoadMore => {
+            let (posts, cursor) = fetch_feed(client, options, app.cursor.as_deref()).await?;
+            let count = posts.len();
+            app.append_posts(posts, cursor);
+            Ok(format!("Loaded {} more posts", count))
+        }
+        Action::LoadComments(post_id) => {
+            let url = Endpoint::PostComments {
+                post_id: &post_id,
+                listing: Listing::new(options.comment_sort, THREAD_LIMIT),
+            }
+            .path();
+            let response: Value = client.get(&url).await?;
+            let mut comments =
+                build_thread(response["comments"].as_array().cloned().unwrap_or_default());
+            ordering::sort_comments(&mut comments, options.comment_sort);
+            app.show_comments(&post_id, &comments);
+            Ok(String::new())
+        }
+        Action::UpvotePost(post_id) => {
+            let result: Value = client
+                .post(&Endpoint::PostUpvote(&post_id).path(), &json!({}))
+                .await?;
+            Ok(outcome(&result, "Upvoted 🦞"))
+        }
+        Action::UpvoteComment(comment_id) => {
+            let result: Value = client
+                .post(&Endpoint::CommentUpvote(&comment_id).path(), &json!({}))
+                .await?;
+            Ok(outcome(&result, "Comment upvoted 🦞"))
+        }
+        Action::SendReply {
+            post_id,
+            parent,
+            content,
+        } => {
+            let length = draft::char_count(&content);
+            if length > COMMENT_CHAR_LIMIT {
+                return Err(ApiError::TooLong {
+                    what: "Comment".to_string(),
+                    length,
+                    limit: COMMENT_CHAR_LIMIT,
+                });
+            }
+            let mut body = json!({ "content": content });
+            if let Some(parent) = parent {
+                body["parent_id"] = json!(parent);
+            }
+            let result: Value =
+                post_tracked(client, &Endpoint::CreateComment(&post_id).path(), &body).await?;
+            Ok(outcome(&result, "Comment posted"))
+        }
+    }
+}
+
+/// The status for a mutation's response, naming the challenge if one came back.
+fn outcome(result: &Value, done: &str) -> String {
+    if let Some(challenge) = find_challenge(result) {
+        let code = challenge["verification_code"]
+            .as_str()
+            .or_else(|| challenge["code"].as_str())
+            .unwrap_or("CODE");
+        let text = challenge["challenge_text"].as_str().unwrap_or("");
+        return format!(
+            "🔐 Verification required: {} (after quitting: moltbook verify -c {} -s ANSWER)",
+            text, code
+        );
+    }
+    if result["success"].as_bool().unwrap_or(false) {
+        format!("✅ {}", done)
+    } else {
+        let error = result["error"].as_str().unwrap_or("Request failed");
+        format!("❌ {}", error)
+    }
+}
+
+/// One page of the personalized feed and the cursor for the next.
+async fn fetch_feed(
+    client: &MoltbookClient,
+    options: &TuiOptions<'_>,
+    cursor: Option<&str>,
+) -> Result<(Vec<Post>, Option<String>), ApiError> {
+    let url = Endpoint::Feed {
+        listing: Listing::new(options.sort, options.limit).cursor(cursor),
+        filter: None,
+    }
+    .path();
+    let page: FeedResponse = client.get(&url).await?;
+    let cursor = page.next_cursor().map(String::from);
+    Ok((page.posts.items, cursor))
+}
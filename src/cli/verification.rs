@@ -11,19 +11,7 @@ use colored::Colorize;
 ///
 /// Returns `true` if verification is required, `false` otherwise.
 pub fn handle_verification(result: &serde_json::Value, action: &str) -> bool {
-    let verification = if result["verification"].is_object() {
-        Some(&result["verification"])
-    } else if let Some(inner) = result.get("comment").or_else(|| result.get("post")) {
-        if inner["verification"].is_object() {
-            Some(&inner["verification"])
-        } else {
-            None
-        }
-    } else {
-        None
-    };
-
-    if let Some(v) = verification {
+    if let Some(v) = crate::verification::find_challenge(result) {
         let instructions = v["instructions"].as_str().unwrap_or("");
         let challenge = v["challenge_text"]
             .as_str()
@@ -0,0 +1,154 @@
+//! `watch-submolt` and `dm-watch`: stream new activity as it appears.
+//!
+//! Each `watch-submolt` poll fetches the newest posts and emits the ones
+//! this submolt's watch has not streamed before (see
+//! [`crate::submolt_watch`]), oldest first, then records them. `dm-watch` does the same for DM requests and unread
+//! messages with [`crate::dm_watch`]. Items are written to stdout either
+//! rendered or as JSON lines, and status messages go to stderr so the output
+//! can be piped into another program.
+
+use crate::api::client::MoltbookClient;
+use crate::api::endpoint::{Endpoint, Listing};
+use crate::api::error::ApiError;
+use crate::api::types::{DmCheckResponse, Post, SubmoltFeedResponse};
+use crate::batch::retry_after;
+use crate::content_hash::Stamped;
+use crate::display::{self, OutputFormat};
+use crate::dm_watch::{DmActivity, DmWatchState};
+use crate::interrupt;
+use crate::outbox::is_retryable;
+use crate::submolt_watch::SubmoltWatchState;
+use chrono::Utc;
+use serde_json::json;
+use std::time::Duration;
+
+/// Polls m/`name` every `interval` until interrupted.
+///
+/// Network hiccups and server errors are reported and retried on the next
+/// poll; rate limits are waited out. Other errors stop the watch.
+pub async fn watch_submolt(
+    client: &MoltbookClient,
+    name: &str,
+    interval: Duration,
+    limit: u64,
+    format: OutputFormat,
+) -> Result<(), ApiError> {
+    let url = Endpoint::SubmoltFeed {
+        name,
+        listing: Listing::new("new", limit),
+    }
+    .path();
+    eprintln!(
+        "Watching m/{} every {}s (stop with Ctrl-C)",
+        name,
+        interval.as_secs()
+    );
+
+    loop {
+        let Ok(poll) = interrupt::or_cancel(client.get::<SubmoltFeedResponse>(&url)).await else {
+            return Ok(());
+        };
+        let wait = match poll {
+            Ok(page) => {
+                emit_new(name, &page.posts, format)?;
+                interval
+            }
+            Err(ApiError::RateLimited(hint)) => {
+                let wait = retry_after(&hint).unwrap_or(interval).max(interval);
+                eprintln!("Rate limited; next poll in {}s", wait.as_secs());
+                wait
+            }
+            Err(e) if is_retryable(&e) => {
+                eprintln!("Poll failed ({}); retrying in {}s", e, interval.as_secs());
+                interval
+            }
+            Err(e) => return Err(e),
+        };
+        interrupt::sleep(wait).await;
+        if interrupt::requested() {
+            return Ok(());
+        }
+    }
+}
+
+/// Polls the DM check every `interval` until interrupted, reporting requests
+/// and unread messages that were not reported before.
+///
+/// Errors are handled as in [`watch_submolt`].
+pub async fn watch_dms(
+    client: &MoltbookClient,
+    interval: Duration,
+    format: OutputFormat,
+) -> Result<(), ApiError> {
+    let url = Endpoint::DmCheck.path();
+    eprintln!(
+        "Watching DMs every {}s (stop with Ctrl-C)",
+        interval.as_secs()
+    );
+
+    loop {
+        let Ok(poll) = interrupt::or_cancel(client.get::<DmCheckResponse>(&url)).await else {
+            return Ok(());
+        };
+        let wait = match poll {
+            Ok(response) => {
+                let activity = DmWatchState::load()?.observe(&response);
+                emit_dm_activity(&activity, format)?;
+                DmWatchState::update(|state| state.observe(&response))?;
+                interval
+            }
+            Err(ApiError::RateLimited(hint)) => {
+                let wait = retry_after(&hint).unwrap_or(interval).max(interval);
+                eprintln!("Rate limited; next poll in {}s", wait.as_secs());
+                wait
+            }
+            Err(e) if is_retryable(&e) => {
+                eprintln!("Poll failed ({}); retrying in {}s", e, interval.as_secs());
+                interval
+            }
+            Err(e) => return Err(e),
+        };
+        interrupt::sleep(wait).await;
+        if interrupt::requested() {
+            return Ok(());
+        }
+    }
+}
+
+/// Writes one line per new request and one for a rise in unread messages.
+fn emit_dm_activity(activity: &DmActivity, format: OutputFormat) -> Result<(), ApiError> {
+    if format == OutputFormat::Text {
+        display::display_dm_activity(activity);
+        return Ok(());
+    }
+    let at = Utc::now().to_rfc3339();
+    for request in &activity.requests {
+        display::write_jsonl(&json!({ "event": "request", "at": at, "request": request }))?;
+    }
+    if let Some((total, new)) = activity.unread {
+        display::write_jsonl(&json!({
+            "event": "unread",
+            "at": at,
+            "total_unread": total,
+            "new": new,
+        }))?;
+    }
+    Ok(())
+}
+
+/// Writes the posts not streamed from m/`name` before and records them.
+fn emit_new(name: &str, posts: &[Post], format: OutputFormat) -> Result<(), ApiError> {
+    let fresh = SubmoltWatchState::load()?.unseen(name, posts);
+    if fresh.is_empty() {
+        return Ok(());
+    }
+
+    for post in &fresh {
+        match format {
+            OutputFormat::Jsonl => display::write_jsonl(&Stamped::post(post))?,
+            OutputFormat::Text => display::display_post(post, None),
+        }
+    }
+
+    SubmoltWatchState::update(|state| state.record(name, &fresh))
+}
@@ -0,0 +1,63 @@
+//! Hex colors for submolt theming.
+
+/// A color given as `#rrggbb`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HexColor {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+/// Parses `#rrggbb` or the `#rgb` shorthand; the `#` is optional.
+///
+/// Used as a clap value parser so typos are caught before any request is
+/// sent.
+pub fn parse_hex_color(s: &str) -> Result<HexColor, String> {
+    let digits = s.trim().trim_start_matches('#');
+    let invalid = || format!("'{}' is not a hex color (expected e.g. #ff4500 or #f40)", s);
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+    let expanded: String = match digits.len() {
+        3 => digits.chars().flat_map(|c| [c, c]).collect(),
+        6 => digits.to_string(),
+        _ => return Err(invalid()),
+    };
+    let channel = |i: usize| u8::from_str_radix(&expanded[i..i + 2], 16).map_err(|_| invalid());
+    Ok(HexColor {
+        r: channel(0)?,
+        g: channel(2)?,
+        b: channel(4)?,
+    })
+}
+
+impl std::fmt::Display for HexColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hex_color() {
+        let color = HexColor {
+            r: 0xff,
+            g: 0x44,
+            b: 0x00,
+        };
+        assert_eq!(parse_hex_color("#FF4400"), Ok(color));
+        assert_eq!(parse_hex_color("ff4400"), Ok(color));
+        assert_eq!(parse_hex_color("#f40"), Ok(color));
+        assert_eq!(color.to_string(), "#ff4400");
+    }
+
+    #[test]
+    fn test_rejects_invalid_colors() {
+        for input in ["", "#", "#ff44", "#ff44000", "#gg4400", "red", "#ff 400"] {
+            assert!(parse_hex_color(input).is_err(), "{}", input);
+        }
+    }
+}
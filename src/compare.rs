@@ -0,0 +1,156 @@
+//! Side-by-side submolt statistics for `moltbook compare-submolts`.
+//!
+//! Subscriber and post totals come from each submolt's info. Volume, score
+//! and contributors are measured on a sample of its newest posts, so they
+//! describe current activity rather than the community's whole history.
+
+use crate::api::types::{Post, Submolt};
+use crate::engagement::created_at;
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, HashMap};
+
+/// Number of top contributors kept per submolt.
+pub const TOP_CONTRIBUTORS: usize = 5;
+
+/// Statistics for one submolt.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubmoltStats {
+    pub name: String,
+    pub subscribers: Option<u64>,
+    pub total_posts: Option<u64>,
+    /// Posts in the sample.
+    pub sampled: usize,
+    /// Sampled posts per day, from the oldest sampled post until now.
+    pub posts_per_day: Option<f64>,
+    pub average_score: Option<f64>,
+    /// Authors with the highest combined score in the sample, best first.
+    pub top_contributors: Vec<String>,
+}
+
+impl SubmoltStats {
+    pub fn new(submolt: &Submolt, posts: &[Post], now: DateTime<Utc>) -> Self {
+        let posts_per_day = posts.iter().filter_map(created_at).min().map(|oldest| {
+            // At least an hour, so a burst of fresh posts doesn't read as thousands a day.
+            let hours = now.signed_duration_since(oldest).num_minutes().max(60) as f64 / 60.0;
+            posts.len() as f64 * 24.0 / hours
+        });
+        let average_score = (!posts.is_empty())
+            .then(|| posts.iter().map(score).sum::<i64>() as f64 / posts.len() as f64);
+
+        let mut by_author: HashMap<&str, (i64, usize)> = HashMap::new();
+        for post in posts {
+            let entry = by_author.entry(&post.author.name).or_default();
+            entry.0 += score(post);
+            entry.1 += 1;
+        }
+        let mut ranked: Vec<_> = by_author.into_iter().collect();
+        ranked.sort_by(|(a, (a_score, a_posts)), (b, (b_score, b_posts))| {
+            b_score
+                .cmp(a_score)
+                .then(b_posts.cmp(a_posts))
+                .then(a.cmp(b))
+        });
+
+        Self {
+            name: submolt.name.clone(),
+            subscribers: submolt.subscriber_count,
+            total_posts: submolt.post_count,
+            sampled: posts.len(),
+            posts_per_day,
+            average_score,
+            top_contributors: ranked
+                .into_iter()
+                .take(TOP_CONTRIBUTORS)
+                .map(|(name, _)| name.to_string())
+                .collect(),
+        }
+    }
+}
+
+fn score(post: &Post) -> i64 {
+    post.score.unwrap_or(post.upvotes - post.downvotes)
+}
+
+/// Contributors in the top list of more than one submolt, with those submolts.
+pub fn shared_contributors(stats: &[SubmoltStats]) -> BTreeMap<String, Vec<String>> {
+    let mut seen: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for submolt in stats {
+        for author in &submolt.top_contributors {
+            seen.entry(author.clone())
+                .or_default()
+                .push(submolt.name.clone());
+        }
+    }
+    seen.retain(|_, submolts| submolts.len() > 1);
+    seen
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn post(author: &str, score: i64, created_at: &str) -> Post {
+        serde_json::from_value(json!({
+            "id": "p",
+            "title": "t",
+            "upvotes": score,
+            "downvotes": 0,
+            "created_at": created_at,
+            "author": { "name": author },
+        }))
+        .unwrap()
+    }
+
+    fn submolt(name: &str) -> Submolt {
+        serde_json::from_value(json!({ "name": name, "subscriber_count": 10 })).unwrap()
+    }
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-06-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_stats() {
+        let posts = [
+            post("ann", 4, "2025-06-10T00:00:00Z"),
+            post("bo", 6, "2025-06-09T12:00:00Z"),
+            post("ann", 3, "2025-06-10T06:00:00Z"),
+        ];
+        let stats = SubmoltStats::new(&submolt("tech"), &posts, now());
+        assert_eq!(stats.subscribers, Some(10));
+        assert_eq!(stats.sampled, 3);
+        assert_eq!(stats.posts_per_day, Some(3.0));
+        assert!((stats.average_score.unwrap() - 13.0 / 3.0).abs() < 1e-9);
+        assert_eq!(stats.top_contributors, ["ann", "bo"]);
+    }
+
+    #[test]
+    fn test_empty_sample() {
+        let stats = SubmoltStats::new(&submolt("quiet"), &[], now());
+        assert_eq!(stats.posts_per_day, None);
+        assert_eq!(stats.average_score, None);
+        assert!(stats.top_contributors.is_empty());
+    }
+
+    #[test]
+    fn test_shared_contributors() {
+        let at = "2025-06-10T00:00:00Z";
+        let a = SubmoltStats::new(
+            &submolt("a"),
+            &[post("ann", 1, at), post("bo", 1, at)],
+            now(),
+        );
+        let b = SubmoltStats::new(&submolt("b"), &[post("bo", 1, at)], now());
+        let c = SubmoltStats::new(
+            &submolt("c"),
+            &[post("bo", 1, at), post("cy", 1, at)],
+            now(),
+        );
+        let shared = shared_contributors(&[a, b, c]);
+        assert_eq!(shared.len(), 1);
+        assert_eq!(shared["bo"], ["a", "b", "c"]);
+    }
+}
@@ -1,19 +1,72 @@
 //! Configuration management for the Moltbook CLI.
 //!
-//! This module handles loading and saving the agent's credentials (API key and agent name)
-//! to a local configuration file, typically located at `~/.config/moltbook/credentials.json`.
-//! It also enforces secure file permissions (0600) on Unix-like systems.
+//! This module handles loading and saving the agent's settings and credentials in the
+//! `moltbook` directory of the platform config directory (`~/.config` on Linux, `%APPDATA%`
+//! on Windows). They are kept in two TOML files:
+//!
+//! - `config.toml`: agent name, defaults, bridges and webhooks. It holds no secrets, so it
+//!   can be committed or shared, and saves keep the comments the user added to it.
+//! - `credentials.toml`: the API key and the secrets in [`SECRET_KEYS`], with 0600
+//!   permissions on Unix-like systems.
+//!
+//! [`Config::load`] layers `credentials.toml` over `config.toml` over a legacy
+//! `credentials.json`, which is still read until the first save replaces it.
+//! Writes are atomic and locked (see [`crate::state`]).
+//!
+//! Each agent run from the machine has a profile: the default one lives
+//! directly in the directory, named ones in `profiles/<name>/` with the same
+//! files and their own state. `--profile <name>` picks one for a single
+//! command and `moltbook account switch` saves the choice in `active_profile`.
 
+use crate::activity::ActivityKind;
 use crate::api::error::ApiError;
+use crate::display::OutputFormat;
+use crate::jitter::Jitter;
+use crate::state;
+use crate::webhook::Webhooks;
+use clap::ValueEnum;
 use dirs::home_dir;
 use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use toml_edit::{DocumentMut, Item};
+
+/// The configuration directory's name inside the platform config directory.
+const CONFIG_DIR: &str = "moltbook";
+/// Where the configuration lived, relative to the home directory, before the
+/// platform config directory was used.
+const LEGACY_CONFIG_DIR: &str = ".config/moltbook";
+/// The filename for settings, which hold no secrets.
+const CONFIG_FILE: &str = "config.toml";
+/// The filename for the API key and other secrets.
+const CREDENTIALS_FILE: &str = "credentials.toml";
+/// The JSON file the configuration was kept in before the TOML files.
+const LEGACY_CONFIG_FILE: &str = "credentials.json";
+/// The directory holding one subdirectory per named profile.
+const PROFILES_DIR: &str = "profiles";
+/// The file naming the profile used when `--profile` is not given.
+const ACTIVE_PROFILE_FILE: &str = "active_profile";
+/// The name of the profile kept directly in the configuration directory.
+pub const DEFAULT_PROFILE: &str = "default";
 
-/// The default configuration directory relative to the user's home.
-const CONFIG_DIR: &str = ".config/moltbook";
-/// The filename for storing agent credentials.
-const CONFIG_FILE: &str = "credentials.json";
+/// Keys saved to `credentials.toml` instead of `config.toml`, as table paths.
+pub const SECRET_KEYS: &[&[&str]] = &[
+    &["api_key"],
+    &["bridges", "mastodon_token"],
+    &["bridges", "x_token"],
+    &["webhooks", "secret"],
+];
+
+/// Sort orders accepted by the feed endpoints.
+pub const FEED_SORTS: &[&str] = &["hot", "new", "top", "rising"];
+/// Sort orders accepted by the comments endpoint.
+pub const COMMENT_SORTS: &[&str] = &["best", "new", "old"];
+/// Requests allowed in flight at once when `max_concurrent_requests` is unset.
+pub const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 4;
+/// Content lines shown per post in listings unless configured otherwise.
+pub const DEFAULT_PREVIEW_LINES: usize = 3;
 
 /// Represents the CLI configuration and credentials.
 #[derive(Serialize, Deserialize, Debug)]
@@ -22,97 +75,1335 @@ pub struct Config {
     pub api_key: String,
     /// The name of the AI agent associated with this key.
     pub agent_name: String,
+    /// Values used when the corresponding command flags are omitted.
+    #[serde(default, skip_serializing_if = "Defaults::is_empty")]
+    pub defaults: Defaults,
+    /// Upper bound on API requests in flight at once.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_requests: Option<usize>,
+    /// Random pause range between batch actions, e.g. `1s-5s`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jitter: Option<String>,
+    /// Refuse mutating commands while the agent's claim is pending.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_claimed: Option<bool>,
+    /// Hold every mutation for the owner's approval (see [`crate::approval`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_approval: Option<bool>,
+    /// Daily limits on posts, comments, votes and DMs.
+    #[serde(default, skip_serializing_if = "Budgets::is_empty")]
+    pub budgets: Budgets,
+    /// Accounts on other platforms that `bridge` cross-posts to.
+    #[serde(default, skip_serializing_if = "Bridges::is_empty")]
+    pub bridges: Bridges,
+    /// Hooks that `listen` maps inbound calls to; edited by hand.
+    #[serde(default, skip_serializing_if = "Webhooks::is_empty")]
+    pub webhooks: Webhooks,
+}
+
+/// The `defaults` section of the configuration.
+///
+/// Unset fields fall back to the built-in defaults.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Defaults {
+    /// Submolt used by `post` when none is given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submolt: Option<String>,
+    /// Sort order for `feed`, `global` and `submolt`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feed_sort: Option<String>,
+    /// Page size for feed listings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feed_limit: Option<u64>,
+    /// Sort order for `comments`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment_sort: Option<String>,
+    /// Content lines shown per post in listings; 0 shows everything.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preview_lines: Option<usize>,
+    /// Output format for listings when `--format` is omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<OutputFormat>,
+}
+
+impl Defaults {
+    /// Names of the settable keys, without the `defaults.` prefix.
+    pub const KEYS: &[&str] = &[
+        "submolt",
+        "feed_sort",
+        "feed_limit",
+        "comment_sort",
+        "preview_lines",
+        "format",
+    ];
+
+    pub fn is_empty(&self) -> bool {
+        self.submolt.is_none()
+            && self.feed_sort.is_none()
+            && self.feed_limit.is_none()
+            && self.comment_sort.is_none()
+            && self.preview_lines.is_none()
+            && self.format.is_none()
+    }
+
+    pub fn submolt(&self) -> &str {
+        self.submolt.as_deref().unwrap_or("general")
+    }
+
+    pub fn feed_sort(&self) -> &str {
+        self.feed_sort.as_deref().unwrap_or("hot")
+    }
+
+    pub fn feed_limit(&self) -> u64 {
+        self.feed_limit.unwrap_or(25)
+    }
+
+    pub fn comment_sort(&self) -> &str {
+        self.comment_sort.as_deref().unwrap_or("best")
+    }
+
+    /// Content lines shown per post in listings; `None` means no truncation.
+    pub fn preview_lines(&self) -> Option<usize> {
+        match self.preview_lines {
+            Some(0) => None,
+            Some(lines) => Some(lines),
+            None => Some(DEFAULT_PREVIEW_LINES),
+        }
+    }
+
+    pub fn format(&self) -> OutputFormat {
+        self.format.unwrap_or_default()
+    }
+
+    /// Overlays every key set in `other`, leaving the rest untouched.
+    pub fn merge(&mut self, other: Defaults) {
+        self.submolt = other.submolt.or(self.submolt.take());
+        self.feed_sort = other.feed_sort.or(self.feed_sort.take());
+        self.feed_limit = other.feed_limit.or(self.feed_limit);
+        self.comment_sort = other.comment_sort.or(self.comment_sort.take());
+        self.preview_lines = other.preview_lines.or(self.preview_lines);
+        self.format = other.format.or(self.format);
+    }
+
+    /// The configured value of a key, if set.
+    pub fn get(&self, key: &str) -> Result<Option<String>, ApiError> {
+        Ok(match key {
+            "submolt" => self.submolt.clone(),
+            "feed_sort" => self.feed_sort.clone(),
+            "feed_limit" => self.feed_limit.map(|l| l.to_string()),
+            "comment_sort" => self.comment_sort.clone(),
+            "preview_lines" => self.preview_lines.map(|l| l.to_string()),
+            "format" => self.format.map(format_name),
+            _ => return Err(unknown_default(key)),
+        })
+    }
+
+    /// The value used when a key is unset.
+    pub fn builtin(key: &str) -> Result<String, ApiError> {
+        let defaults = Self::default();
+        Ok(match key {
+            "submolt" => defaults.submolt().to_string(),
+            "feed_sort" => defaults.feed_sort().to_string(),
+            "feed_limit" => defaults.feed_limit().to_string(),
+            "comment_sort" => defaults.comment_sort().to_string(),
+            "preview_lines" => DEFAULT_PREVIEW_LINES.to_string(),
+            "format" => format_name(defaults.format()),
+            _ => return Err(unknown_default(key)),
+        })
+    }
+
+    /// Clears a key so the built-in default applies again.
+    pub fn unset(&mut self, key: &str) -> Result<(), ApiError> {
+        match key {
+            "submolt" => self.submolt = None,
+            "feed_sort" => self.feed_sort = None,
+            "feed_limit" => self.feed_limit = None,
+            "comment_sort" => self.comment_sort = None,
+            "preview_lines" => self.preview_lines = None,
+            "format" => self.format = None,
+            _ => return Err(unknown_default(key)),
+        }
+        Ok(())
+    }
+
+    /// Sets a key from its string form, validating the value.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), ApiError> {
+        match key {
+            "submolt" => self.submolt = Some(value.to_string()),
+            "feed_sort" => self.feed_sort = Some(one_of(key, value, FEED_SORTS)?),
+            "feed_limit" => {
+                let limit = value
+                    .parse::<u64>()
+                    .ok()
+                    .filter(|l| *l > 0)
+                    .ok_or_else(|| {
+                        ApiError::ConfigError(format!(
+                            "defaults.feed_limit must be a positive integer, got '{}'",
+                            value
+                        ))
+                    })?;
+                self.feed_limit = Some(limit);
+            }
+            "comment_sort" => self.comment_sort = Some(one_of(key, value, COMMENT_SORTS)?),
+            "preview_lines" => {
+                let lines = value.parse::<usize>().map_err(|_| {
+                    ApiError::ConfigError(format!(
+                        "defaults.preview_lines must be 0 (no limit) or a line count, got '{}'",
+                        value
+                    ))
+                })?;
+                self.preview_lines = Some(lines);
+            }
+            "format" => {
+                let format = OutputFormat::from_str(value, true).map_err(|_| {
+                    ApiError::ConfigError("defaults.format must be one of: text, jsonl".to_string())
+                })?;
+                self.format = Some(format);
+            }
+            _ => return Err(unknown_default(key)),
+        }
+        Ok(())
+    }
+}
+
+/// The `bridges` section of the configuration.
+///
+/// Access tokens are secrets: they can be set through `moltbook config` but
+/// are never printed.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Bridges {
+    /// Base URL of the Mastodon instance, e.g. `https://mastodon.social`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mastodon_instance: Option<String>,
+    /// Mastodon access token with the `write:statuses` scope.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mastodon_token: Option<String>,
+    /// X OAuth 2.0 user access token with the `tweet.write` scope.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x_token: Option<String>,
+}
+
+impl Bridges {
+    /// Names of the settable keys, without the `bridges.` prefix.
+    pub const KEYS: &[&str] = &["mastodon_instance", "mastodon_token", "x_token"];
+
+    pub fn is_empty(&self) -> bool {
+        self.mastodon_instance.is_none() && self.mastodon_token.is_none() && self.x_token.is_none()
+    }
+
+    /// Whether the key holds a secret that must not be displayed.
+    pub fn is_secret(key: &str) -> bool {
+        key.ends_with("_token")
+    }
+
+    /// The configured value of a key, if set; secrets read as `(hidden)`.
+    pub fn get(&self, key: &str) -> Result<Option<String>, ApiError> {
+        let hidden = |token: &Option<String>| token.as_ref().map(|_| "(hidden)".to_string());
+        Ok(match key {
+            "mastodon_instance" => self.mastodon_instance.clone(),
+            "mastodon_token" => hidden(&self.mastodon_token),
+            "x_token" => hidden(&self.x_token),
+            _ => return Err(unknown_bridge(key)),
+        })
+    }
+
+    /// Clears a key.
+    pub fn unset(&mut self, key: &str) -> Result<(), ApiError> {
+        match key {
+            "mastodon_instance" => self.mastodon_instance = None,
+            "mastodon_token" => self.mastodon_token = None,
+            "x_token" => self.x_token = None,
+            _ => return Err(unknown_bridge(key)),
+        }
+        Ok(())
+    }
+
+    /// Sets a key from its string form, validating the value.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), ApiError> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(ApiError::ConfigError(format!(
+                "bridges.{} cannot be empty",
+                key
+            )));
+        }
+        match key {
+            "mastodon_instance" => {
+                if !value.starts_with("https://") {
+                    return Err(ApiError::ConfigError(format!(
+                        "bridges.mastodon_instance must be an https:// URL, got '{}'",
+                        value
+                    )));
+                }
+                self.mastodon_instance = Some(value.trim_end_matches('/').to_string());
+            }
+            "mastodon_token" => self.mastodon_token = Some(value.to_string()),
+            "x_token" => self.x_token = Some(value.to_string()),
+            _ => return Err(unknown_bridge(key)),
+        }
+        Ok(())
+    }
+}
+
+/// The `budgets` section of the configuration: actions allowed per calendar
+/// day, local time. Unset kinds are unlimited.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Budgets {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub posts: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comments: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub votes: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dms: Option<usize>,
+}
+
+impl Budgets {
+    /// Names of the settable keys, without the `budgets.` prefix.
+    pub const KEYS: &[&str] = &["posts", "comments", "votes", "dms"];
+
+    pub fn is_empty(&self) -> bool {
+        self.posts.is_none()
+            && self.comments.is_none()
+            && self.votes.is_none()
+            && self.dms.is_none()
+    }
+
+    /// The daily limit for `kind`, if one is set.
+    pub fn limit(&self, kind: ActivityKind) -> Option<usize> {
+        match kind {
+            ActivityKind::Post => self.posts,
+            ActivityKind::Comment => self.comments,
+            ActivityKind::Vote => self.votes,
+            ActivityKind::Dm => self.dms,
+        }
+    }
+
+    fn slot(&mut self, key: &str) -> Result<&mut Option<usize>, ApiError> {
+        Ok(match key {
+            "posts" => &mut self.posts,
+            "comments" => &mut self.comments,
+            "votes" => &mut self.votes,
+            "dms" => &mut self.dms,
+            _ => return Err(unknown_budget(key)),
+        })
+    }
+
+    /// The configured value of a key, if set.
+    pub fn get(&self, key: &str) -> Result<Option<String>, ApiError> {
+        let limit = match key {
+            "posts" => self.posts,
+            "comments" => self.comments,
+            "votes" => self.votes,
+            "dms" => self.dms,
+            _ => return Err(unknown_budget(key)),
+        };
+        Ok(limit.map(|n| n.to_string()))
+    }
+
+    /// Clears a key, lifting the limit.
+    pub fn unset(&mut self, key: &str) -> Result<(), ApiError> {
+        *self.slot(key)? = None;
+        Ok(())
+    }
+
+    /// Sets a key from its string form, validating the value.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), ApiError> {
+        let slot = self.slot(key)?;
+        let limit = value.trim().parse::<usize>().map_err(|_| {
+            ApiError::ConfigError(format!(
+                "budgets.{} must be a whole number of actions per day, got '{}'",
+                key, value
+            ))
+        })?;
+        *slot = Some(limit);
+        Ok(())
+    }
+}
+
+fn unknown_budget(key: &str) -> ApiError {
+    ApiError::ConfigError(format!(
+        "Unknown key 'budgets.{}' (expected one of: {})",
+        key,
+        Budgets::KEYS.join(", ")
+    ))
+}
+
+fn unknown_bridge(key: &str) -> ApiError {
+    ApiError::ConfigError(format!(
+        "Unknown key 'bridges.{}' (expected one of: {})",
+        key,
+        Bridges::KEYS.join(", ")
+    ))
+}
+
+fn unknown_default(key: &str) -> ApiError {
+    ApiError::ConfigError(format!(
+        "Unknown key 'defaults.{}' (expected one of: {})",
+        key,
+        Defaults::KEYS.join(", ")
+    ))
+}
+
+fn format_name(format: OutputFormat) -> String {
+    format
+        .to_possible_value()
+        .map(|v| v.get_name().to_string())
+        .unwrap_or_default()
+}
+
+fn one_of(key: &str, value: &str, allowed: &[&str]) -> Result<String, ApiError> {
+    if allowed.contains(&value) {
+        Ok(value.to_string())
+    } else {
+        Err(ApiError::ConfigError(format!(
+            "defaults.{} must be one of: {}",
+            key,
+            allowed.join(", ")
+        )))
+    }
 }
 
 impl Config {
     /// Loads the configuration from the disk.
     ///
+    /// Each file present is a layer whose keys override the ones before it:
+    /// the legacy `credentials.json`, then `config.toml`, then
+    /// `credentials.toml`. A secret left in `config.toml` still works and is
+    /// moved to `credentials.toml` by the next save.
+    ///
     /// # Errors
     ///
     /// Returns an `ApiError::ConfigError` if:
-    /// - The configuration file does not exist.
-    /// - The file cannot be read or parsed as valid JSON.
+    /// - None of the files exist.
+    /// - A file cannot be read or parsed, or the layers lack a required key.
     pub fn load() -> Result<Self, ApiError> {
-        let config_path = Self::get_config_path()?;
+        let dir = Self::config_dir()?;
+        let legacy_path = dir.join(LEGACY_CONFIG_FILE);
+        let layers = [dir.join(CONFIG_FILE), dir.join(CREDENTIALS_FILE)];
 
-        if !config_path.exists() {
+        if !legacy_path.exists() && !layers.iter().any(|path| path.exists()) {
+            if let Some(name) = active_profile()
+                && !dir.exists()
+            {
+                return Err(ApiError::ConfigError(format!(
+                    "Profile '{}' does not exist. Add it with `moltbook account add {}`",
+                    name, name
+                )));
+            }
             return Err(ApiError::ConfigError(format!(
                 "Config file not found at: {}\nPlease create it with your API key.",
-                config_path.display()
+                layers[1].display()
             )));
         }
 
-        let content = fs::read_to_string(&config_path)
+        let mut merged = toml::Table::new();
+        if legacy_path.exists() {
+            let legacy = Self::load_legacy(&legacy_path)?;
+            let table = toml::Table::try_from(legacy).map_err(|e| {
+                ApiError::ConfigError(format!("Failed to convert legacy config: {}", e))
+            })?;
+            merge_layer(&mut merged, table);
+        }
+        for path in &layers {
+            let content = match fs::read_to_string(path) {
+                Ok(content) => content,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => {
+                    return Err(ApiError::ConfigError(format!(
+                        "Failed to read config: {}",
+                        e
+                    )));
+                }
+            };
+            let table = content.parse::<toml::Table>().map_err(|e| {
+                ApiError::ConfigError(format!(
+                    "Failed to parse {}: {}\nFix or remove it and run `moltbook init`.",
+                    path.display(),
+                    e
+                ))
+            })?;
+            merge_layer(&mut merged, table);
+        }
+
+        merged.try_into().map_err(|e| {
+            ApiError::ConfigError(format!(
+                "Invalid configuration in {}: {}\nRun `moltbook init` to set your API key.",
+                dir.display(),
+                e
+            ))
+        })
+    }
+
+    /// Reads the JSON file used before the TOML files.
+    fn load_legacy(config_path: &Path) -> Result<Self, ApiError> {
+        let content = fs::read_to_string(config_path)
             .map_err(|e| ApiError::ConfigError(format!("Failed to read config: {}", e)))?;
 
-        let config: Config = serde_json::from_str(&content)
-            .map_err(|e| ApiError::ConfigError(format!("Failed to parse config: {}", e)))?;
+        parse_config(&content).map_err(|e| {
+            let hint = if e.is_eof() {
+                format!(
+                    "{} looks partially written, probably by an interrupted save.\n\
+                     Run `moltbook init` to write it again.",
+                    config_path.display()
+                )
+            } else {
+                format!(
+                    "Fix or remove {} and run `moltbook init`.",
+                    config_path.display()
+                )
+            };
+            ApiError::ConfigError(format!("Failed to parse config: {}\n{}", e, hint))
+        })
+    }
+
+    /// Requests the client allows in flight at once.
+    pub fn max_concurrent_requests(&self) -> usize {
+        self.max_concurrent_requests
+            .unwrap_or(DEFAULT_MAX_CONCURRENT_REQUESTS)
+    }
+
+    /// Whether mutating commands are refused while the claim is pending.
+    pub fn require_claimed(&self) -> bool {
+        self.require_claimed.unwrap_or(false)
+    }
+
+    /// Whether mutations wait for the owner's approval.
+    pub fn require_approval(&self) -> bool {
+        self.require_approval.unwrap_or(false)
+    }
 
-        Ok(config)
+    /// The configured jitter range; a hand-edited value that does not parse
+    /// is ignored.
+    pub fn jitter(&self) -> Option<Jitter> {
+        self.jitter.as_deref().and_then(|range| range.parse().ok())
     }
 
-    /// Resolves the path to the configuration file.
+    /// Keys readable and writable through `moltbook config`.
     ///
-    /// Priority:
-    /// 1. `MOLTBOOK_CONFIG_DIR` environment variable.
-    /// 2. Default `~/.config/moltbook/credentials.json` path.
-    fn get_config_path() -> Result<PathBuf, ApiError> {
-        if let Ok(config_dir) = std::env::var("MOLTBOOK_CONFIG_DIR") {
-            return Ok(PathBuf::from(config_dir).join(CONFIG_FILE));
+    /// The API key is deliberately excluded; it is only written by `init`.
+    pub fn keys() -> Vec<String> {
+        let mut keys = vec![
+            "agent_name".to_string(),
+            "max_concurrent_requests".to_string(),
+            "jitter".to_string(),
+            "require_claimed".to_string(),
+            "require_approval".to_string(),
+        ];
+        keys.extend(Defaults::KEYS.iter().map(|k| format!("defaults.{}", k)));
+        keys.extend(Budgets::KEYS.iter().map(|k| format!("budgets.{}", k)));
+        keys.extend(Bridges::KEYS.iter().map(|k| format!("bridges.{}", k)));
+        keys
+    }
+
+    /// The value of a non-secret key, or `None` if it is unset.
+    pub fn get_value(&self, key: &str) -> Result<Option<String>, ApiError> {
+        match key {
+            "agent_name" => Ok(Some(self.agent_name.clone())),
+            "max_concurrent_requests" => Ok(self.max_concurrent_requests.map(|n| n.to_string())),
+            "jitter" => Ok(self.jitter.clone()),
+            "require_claimed" => Ok(self.require_claimed.map(|b| b.to_string())),
+            "require_approval" => Ok(self.require_approval.map(|b| b.to_string())),
+            _ => {
+                if let Some(budget_key) = key.strip_prefix("budgets.") {
+                    return self.budgets.get(budget_key);
+                }
+                match key.strip_prefix("bridges.") {
+                    Some(bridge_key) => self.bridges.get(bridge_key),
+                    None => self.defaults.get(Self::defaults_key(key)?),
+                }
+            }
         }
+    }
 
-        let home = home_dir().ok_or_else(|| {
-            ApiError::ConfigError("Could not determine home directory".to_string())
-        })?;
+    /// Validates and sets a non-secret key.
+    pub fn set_value(&mut self, key: &str, value: &str) -> Result<(), ApiError> {
+        match key {
+            "agent_name" => {
+                if value.trim().is_empty() {
+                    return Err(ApiError::ConfigError(
+                        "agent_name cannot be empty".to_string(),
+                    ));
+                }
+                self.agent_name = value.trim().to_string();
+                Ok(())
+            }
+            "max_concurrent_requests" => {
+                let limit = value
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|n| *n > 0)
+                    .ok_or_else(|| {
+                        ApiError::ConfigError(format!(
+                            "max_concurrent_requests must be a positive integer, got '{}'",
+                            value
+                        ))
+                    })?;
+                self.max_concurrent_requests = Some(limit);
+                Ok(())
+            }
+            "jitter" => {
+                value.parse::<Jitter>().map_err(ApiError::ConfigError)?;
+                self.jitter = Some(value.trim().to_string());
+                Ok(())
+            }
+            "require_claimed" => {
+                let required = value.trim().parse::<bool>().map_err(|_| {
+                    ApiError::ConfigError(format!(
+                        "require_claimed must be true or false, got '{}'",
+                        value
+                    ))
+                })?;
+                self.require_claimed = Some(required);
+                Ok(())
+            }
+            "require_approval" => {
+                let required = value.trim().parse::<bool>().map_err(|_| {
+                    ApiError::ConfigError(format!(
+                        "require_approval must be true or false, got '{}'",
+                        value
+                    ))
+                })?;
+                self.require_approval = Some(required);
+                Ok(())
+            }
+            _ => {
+                if let Some(budget_key) = key.strip_prefix("budgets.") {
+                    return self.budgets.set(budget_key, value);
+                }
+                match key.strip_prefix("bridges.") {
+                    Some(bridge_key) => self.bridges.set(bridge_key, value),
+                    None => self.defaults.set(Self::defaults_key(key)?, value),
+                }
+            }
+        }
+    }
+
+    /// Clears an optional key.
+    pub fn unset_value(&mut self, key: &str) -> Result<(), ApiError> {
+        match key {
+            "agent_name" => Err(ApiError::ConfigError(
+                "agent_name is required and cannot be unset".to_string(),
+            )),
+            "max_concurrent_requests" => {
+                self.max_concurrent_requests = None;
+                Ok(())
+            }
+            "jitter" => {
+                self.jitter = None;
+                Ok(())
+            }
+            "require_claimed" => {
+                self.require_claimed = None;
+                Ok(())
+            }
+            "require_approval" => {
+                self.require_approval = None;
+                Ok(())
+            }
+            _ => {
+                if let Some(budget_key) = key.strip_prefix("budgets.") {
+                    return self.budgets.unset(budget_key);
+                }
+                match key.strip_prefix("bridges.") {
+                    Some(bridge_key) => self.bridges.unset(bridge_key),
+                    None => self.defaults.unset(Self::defaults_key(key)?),
+                }
+            }
+        }
+    }
+
+    fn defaults_key(key: &str) -> Result<&str, ApiError> {
+        if key == "api_key" {
+            return Err(ApiError::ConfigError(
+                "api_key is secret and cannot be read or edited here; run `moltbook init` to replace it"
+                    .to_string(),
+            ));
+        }
+        key.strip_prefix("defaults.").ok_or_else(|| {
+            ApiError::ConfigError(format!(
+                "Unknown key '{}' (expected one of: {})",
+                key,
+                Self::keys().join(", ")
+            ))
+        })
+    }
 
-        Ok(home.join(CONFIG_DIR).join(CONFIG_FILE))
+    /// Resolves the directory holding the credentials and local CLI state.
+    ///
+    /// This is the [`base_dir`](Self::base_dir) for the default profile and
+    /// `profiles/<name>` inside it for a named one; see [`active_profile`].
+    pub fn config_dir() -> Result<PathBuf, ApiError> {
+        let base = Self::base_dir()?;
+        Ok(match active_profile() {
+            Some(name) => base.join(PROFILES_DIR).join(name),
+            None => base,
+        })
+    }
+
+    /// Resolves the top-level configuration directory, which holds the
+    /// default profile and the named ones.
+    ///
+    /// Priority:
+    /// 1. A directory set with [`override_config_dir`] on this thread.
+    /// 2. `MOLTBOOK_CONFIG_DIR` environment variable.
+    /// 3. `moltbook` in the platform's config directory: `$XDG_CONFIG_HOME`
+    ///    (default `~/.config`) on Linux, `~/Library/Application Support` on
+    ///    macOS and `%APPDATA%` on Windows.
+    ///
+    /// The first resolution moves a legacy `~/.config/moltbook` directory to
+    /// the platform location if that does not exist yet; see
+    /// [`legacy_migration`].
+    pub fn base_dir() -> Result<PathBuf, ApiError> {
+        if let Some(dir) = CONFIG_DIR_OVERRIDE.with_borrow(Clone::clone) {
+            return Ok(dir);
+        }
+        if let Ok(config_dir) = std::env::var("MOLTBOOK_CONFIG_DIR") {
+            return Ok(PathBuf::from(config_dir));
+        }
+        resolve_platform_dir().dir.clone().ok_or_else(|| {
+            ApiError::ConfigError("Could not determine config directory".to_string())
+        })
     }
 
     /// Saves the current configuration to disk.
     ///
-    /// On Unix systems, this method strictly enforces `0600` permissions
-    /// to protect the API key from unauthorized local access.
+    /// Secrets go to `credentials.toml` through [`state::write_private`],
+    /// which creates it with `0600` permissions on Unix systems to protect
+    /// the API key from unauthorized local access. Everything else goes to
+    /// `config.toml`, keeping its comments and layout. Both are written under
+    /// an exclusive lock, so concurrent `init` runs take turns, and via a
+    /// temporary file renamed into place, so neither is seen half-written.
+    ///
+    /// Secrets are written first: if the second write fails, loading still
+    /// finds every key. A legacy `credentials.json` is removed afterwards.
     pub fn save(&self) -> Result<(), ApiError> {
-        let config_path = Self::get_config_path()?;
-        let config_dir = config_path.parent().unwrap();
+        let dir = Self::config_dir()?;
+        let serialize_error = |e: toml::ser::Error| {
+            ApiError::ConfigError(format!("Failed to serialize config: {}", e))
+        };
+        let write_error =
+            |e: ApiError| ApiError::ConfigError(format!("Failed to write config: {}", e));
 
-        if !config_dir.exists() {
-            fs::create_dir_all(config_dir).map_err(|e| {
-                ApiError::ConfigError(format!("Failed to create config dir: {}", e))
-            })?;
+        let mut settings = toml::Table::try_from(self).map_err(serialize_error)?;
+        let secrets = split_secrets(&mut settings);
+
+        let credentials = toml::to_string_pretty(&secrets).map_err(serialize_error)?;
+        state::write_private(&dir.join(CREDENTIALS_FILE), &credentials).map_err(write_error)?;
+
+        let config_path = dir.join(CONFIG_FILE);
+        let existing = fs::read_to_string(&config_path).unwrap_or_default();
+        let content = render_settings(&settings, &existing).map_err(serialize_error)?;
+        state::write(&config_path, &content).map_err(write_error)?;
+
+        let legacy_path = dir.join(LEGACY_CONFIG_FILE);
+        if legacy_path.exists() {
+            state::remove(&legacy_path)?;
+        }
+        Ok(())
+    }
+}
+
+/// Overlays `layer` onto `base`, merging tables key by key.
+fn merge_layer(base: &mut toml::Table, layer: toml::Table) {
+    for (key, value) in layer {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(layer)) => merge_layer(base, layer),
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Moves the keys in [`SECRET_KEYS`] out of `settings` into a table of their
+/// own, dropping tables left empty.
+fn split_secrets(settings: &mut toml::Table) -> toml::Table {
+    let mut secrets = toml::Table::new();
+    for path in SECRET_KEYS {
+        if let Some(value) = take_key(settings, path) {
+            put_key(&mut secrets, path, value);
         }
+    }
+    settings.retain(|_, value| !matches!(value, toml::Value::Table(t) if t.is_empty()));
+    secrets
+}
+
+fn take_key(table: &mut toml::Table, path: &[&str]) -> Option<toml::Value> {
+    match path {
+        [key] => table.remove(*key),
+        [head, rest @ ..] => match table.get_mut(*head) {
+            Some(toml::Value::Table(inner)) => take_key(inner, rest),
+            _ => None,
+        },
+        [] => None,
+    }
+}
 
-        let content = serde_json::to_string_pretty(self)
-            .map_err(|e| ApiError::ConfigError(format!("Failed to serialize config: {}", e)))?;
+fn put_key(table: &mut toml::Table, path: &[&str], value: toml::Value) {
+    match path {
+        [key] => {
+            table.insert(key.to_string(), value);
+        }
+        [head, rest @ ..] => {
+            let inner = table
+                .entry(head.to_string())
+                .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+            if let toml::Value::Table(inner) = inner {
+                put_key(inner, rest, value);
+            }
+        }
+        [] => {}
+    }
+}
 
-        fs::write(&config_path, content)
-            .map_err(|e| ApiError::ConfigError(format!("Failed to write config: {}", e)))?;
+/// Renders `settings` as TOML, reusing the layout and comments of `existing`
+/// where it parses.
+fn render_settings(settings: &toml::Table, existing: &str) -> Result<String, toml::ser::Error> {
+    let fresh = toml::to_string_pretty(settings)?;
+    let (Ok(mut document), Ok(new)) = (
+        existing.parse::<DocumentMut>(),
+        fresh.parse::<DocumentMut>(),
+    ) else {
+        return Ok(fresh);
+    };
+    merge_table(document.as_table_mut(), new.as_table());
+    Ok(document.to_string())
+}
 
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::PermissionsExt;
-            let mut perms = fs::metadata(&config_path)
-                .map_err(|e| ApiError::ConfigError(format!("Failed to get metadata: {}", e)))?
-                .permissions();
-            perms.set_mode(0o600);
-            fs::set_permissions(&config_path, perms)
-                .map_err(|e| ApiError::ConfigError(format!("Failed to set permissions: {}", e)))?;
+/// Makes `table` hold the keys of `new`, keeping the comments of values that
+/// are replaced and of tables that remain.
+fn merge_table(table: &mut toml_edit::Table, new: &toml_edit::Table) {
+    table.retain(|key, _| new.contains_key(key));
+    for (key, item) in new.iter() {
+        match (table.get_mut(key), item) {
+            (Some(Item::Table(old)), Item::Table(new)) => merge_table(old, new),
+            (Some(Item::Value(old)), Item::Value(new)) => {
+                let decor = old.decor().clone();
+                *old = new.clone();
+                *old.decor_mut() = decor;
+            }
+            _ => {
+                table.insert(key, item.clone());
+            }
         }
+    }
+}
 
+/// Parses the legacy `credentials.json`.
+///
+/// Anything after the first complete JSON object is ignored: before saves
+/// were atomic, two overlapping writes could leave the tail of a longer
+/// earlier file behind an intact new one.
+fn parse_config(content: &str) -> Result<Config, serde_json::Error> {
+    let mut configs = serde_json::Deserializer::from_str(content).into_iter::<Config>();
+    match configs.next() {
+        Some(config) => config,
+        // Empty or whitespace-only: parse again to get the end-of-input error.
+        None => serde_json::from_str(content),
+    }
+}
+
+/// The platform config directory, resolved once per process.
+#[derive(Debug, Default)]
+struct PlatformDir {
+    dir: Option<PathBuf>,
+    /// What happened to a legacy directory on the way, to report once.
+    migration: Option<Migration>,
+}
+
+/// The outcome of moving the legacy `~/.config/moltbook` directory.
+#[derive(Debug, Clone)]
+pub enum Migration {
+    /// The directory was moved from `from` to `to`.
+    Moved { from: PathBuf, to: PathBuf },
+    /// Moving failed, so the legacy directory is still used.
+    Failed {
+        from: PathBuf,
+        to: PathBuf,
+        error: String,
+    },
+}
+
+impl std::fmt::Display for Migration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Migration::Moved { from, to } => write!(
+                f,
+                "Moved the Moltbook config from {} to {}",
+                from.display(),
+                to.display()
+            ),
+            Migration::Failed { from, to, error } => write!(
+                f,
+                "Could not move the Moltbook config from {} to {} ({}); still using the old location",
+                from.display(),
+                to.display(),
+                error
+            ),
+        }
+    }
+}
+
+static PLATFORM_DIR: OnceLock<PlatformDir> = OnceLock::new();
+
+fn resolve_platform_dir() -> &'static PlatformDir {
+    PLATFORM_DIR.get_or_init(|| {
+        let Some(dir) = dirs::config_dir().map(|base| base.join(CONFIG_DIR)) else {
+            return PlatformDir {
+                dir: home_dir().map(|home| home.join(LEGACY_CONFIG_DIR)),
+                migration: None,
+            };
+        };
+        let legacy = home_dir().map(|home| home.join(LEGACY_CONFIG_DIR));
+        match legacy {
+            Some(legacy) if legacy != dir && legacy.is_dir() && !dir.exists() => {
+                match move_dir(&legacy, &dir) {
+                    Ok(()) => PlatformDir {
+                        dir: Some(dir.clone()),
+                        migration: Some(Migration::Moved {
+                            from: legacy,
+                            to: dir,
+                        }),
+                    },
+                    Err(e) => PlatformDir {
+                        dir: Some(legacy.clone()),
+                        migration: Some(Migration::Failed {
+                            from: legacy,
+                            to: dir,
+                            error: e.to_string(),
+                        }),
+                    },
+                }
+            }
+            _ => PlatformDir {
+                dir: Some(dir),
+                migration: None,
+            },
+        }
+    })
+}
+
+/// The legacy directory migration done by this process, if any.
+///
+/// A move happens at most once per machine, so callers can show the result
+/// as a one-time notice. `MOLTBOOK_CONFIG_DIR` and [`override_config_dir`]
+/// skip the migration entirely.
+pub fn legacy_migration() -> Option<&'static Migration> {
+    if std::env::var_os("MOLTBOOK_CONFIG_DIR").is_some()
+        || CONFIG_DIR_OVERRIDE.with_borrow(Option::is_some)
+    {
+        return None;
+    }
+    resolve_platform_dir().migration.as_ref()
+}
+
+thread_local! {
+    static CONFIG_DIR_OVERRIDE: RefCell<Option<PathBuf>> = const { RefCell::new(None) };
+}
+
+/// Restores the previous configuration directory when dropped; see
+/// [`override_config_dir`].
+#[must_use = "the override ends when this is dropped"]
+pub struct ConfigDirOverride {
+    previous: Option<PathBuf>,
+}
+
+impl Drop for ConfigDirOverride {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CONFIG_DIR_OVERRIDE.set(previous);
+    }
+}
+
+/// Uses `dir` as the configuration directory on the current thread until the
+/// returned guard is dropped.
+///
+/// This is for tests: they share one process, so `MOLTBOOK_CONFIG_DIR` would
+/// point all of them at the same directory. A `#[tokio::test]` runs on a
+/// single thread, so everything it awaits sees the override.
+pub fn override_config_dir(dir: &Path) -> ConfigDirOverride {
+    let previous = CONFIG_DIR_OVERRIDE.replace(Some(dir.to_path_buf()));
+    ConfigDirOverride { previous }
+}
+
+static SELECTED_PROFILE: OnceLock<String> = OnceLock::new();
+
+/// Uses the profile `name` for the rest of the process, as `--profile` does.
+///
+/// Only the first call has an effect.
+pub fn select_profile(name: &str) -> Result<(), ApiError> {
+    validate_profile_name(name).map_err(ApiError::ConfigError)?;
+    let _ = SELECTED_PROFILE.set(name.to_string());
+    Ok(())
+}
+
+/// The named profile in use, or `None` for the default one.
+///
+/// A profile chosen with [`select_profile`] wins over the one saved by
+/// `moltbook account switch`.
+pub fn active_profile() -> Option<String> {
+    let name = match SELECTED_PROFILE.get() {
+        Some(name) => name.clone(),
+        None => {
+            let path = Config::base_dir().ok()?.join(ACTIVE_PROFILE_FILE);
+            let name = fs::read_to_string(path).ok()?.trim().to_string();
+            if validate_profile_name(&name).is_err() {
+                return None;
+            }
+            name
+        }
+    };
+    (name != DEFAULT_PROFILE).then_some(name)
+}
+
+/// Checks that `name` can be used as a profile's directory name.
+pub fn validate_profile_name(name: &str) -> Result<(), String> {
+    let valid = !name.is_empty()
+        && name.len() <= 64
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
         Ok(())
+    } else {
+        Err(format!(
+            "Invalid profile name '{}': use letters, digits, '-' or '_'",
+            name
+        ))
+    }
+}
+
+/// A profile saved on this machine.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Profile {
+    pub name: String,
+    /// The agent its configuration names, if it has been initialized.
+    pub agent_name: Option<String>,
+    /// Whether commands currently run as this profile.
+    pub active: bool,
+}
+
+/// The default profile, if it has been initialized, then the named ones by name.
+pub fn profiles() -> Result<Vec<Profile>, ApiError> {
+    let base = Config::base_dir()?;
+    let active = active_profile();
+    let profile = |name: &str, dir: &Path| Profile {
+        name: name.to_string(),
+        agent_name: agent_name_in(dir),
+        active: active.as_deref().unwrap_or(DEFAULT_PROFILE) == name,
+    };
+
+    let mut profiles = Vec::new();
+    let initialized = [CONFIG_FILE, CREDENTIALS_FILE, LEGACY_CONFIG_FILE]
+        .iter()
+        .any(|file| base.join(file).exists());
+    if initialized || active.is_none() {
+        profiles.push(profile(DEFAULT_PROFILE, &base));
+    }
+    let mut named = Vec::new();
+    match fs::read_dir(base.join(PROFILES_DIR)) {
+        Ok(entries) => {
+            for entry in entries {
+                let entry = entry?;
+                let name = entry.file_name().to_string_lossy().into_owned();
+                if entry.file_type()?.is_dir() && validate_profile_name(&name).is_ok() {
+                    named.push(profile(&name, &entry.path()));
+                }
+            }
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e.into()),
+    }
+    named.sort_by(|a, b| a.name.cmp(&b.name));
+    profiles.extend(named);
+    Ok(profiles)
+}
+
+/// Creates the directory of the named profile `name`, returning its path.
+pub fn create_profile(name: &str) -> Result<PathBuf, ApiError> {
+    validate_profile_name(name).map_err(ApiError::ConfigError)?;
+    if name == DEFAULT_PROFILE {
+        return Err(ApiError::ConfigError(format!(
+            "'{}' is the profile kept in the configuration directory itself; run `moltbook init` to set it up",
+            DEFAULT_PROFILE
+        )));
+    }
+    let dir = Config::base_dir()?.join(PROFILES_DIR).join(name);
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+/// Makes `name` the profile used when `--profile` is not given.
+///
+/// # Errors
+///
+/// Returns an `ApiError::ConfigError` if no profile of that name exists.
+pub fn switch_profile(name: &str) -> Result<(), ApiError> {
+    validate_profile_name(name).map_err(ApiError::ConfigError)?;
+    let base = Config::base_dir()?;
+    let path = base.join(ACTIVE_PROFILE_FILE);
+    if name == DEFAULT_PROFILE {
+        return state::remove(&path);
+    }
+    if !base.join(PROFILES_DIR).join(name).is_dir() {
+        return Err(ApiError::ConfigError(format!(
+            "Profile '{}' does not exist. Add it with `moltbook account add {}`",
+            name, name
+        )));
+    }
+    state::write(&path, &format!("{}\n", name))
+}
+
+/// Whether a named profile `name` has been added.
+pub fn profile_exists(name: &str) -> bool {
+    validate_profile_name(name).is_ok()
+        && Config::base_dir()
+            .map(|base| base.join(PROFILES_DIR).join(name).is_dir())
+            .unwrap_or(false)
+}
+
+/// The agent name in the `config.toml` of `dir`, without reading secrets.
+fn agent_name_in(dir: &Path) -> Option<String> {
+    let content = fs::read_to_string(dir.join(CONFIG_FILE)).ok()?;
+    let table = content.parse::<toml::Table>().ok()?;
+    table.get("agent_name")?.as_str().map(str::to_string)
+}
+
+/// Moves `from` to `to`, copying when they are on different filesystems.
+///
+/// Sockets and other special files are not copied; they are recreated when
+/// needed.
+fn move_dir(from: &Path, to: &Path) -> std::io::Result<()> {
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    if fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    if let Err(e) = copy_dir(from, to) {
+        let _ = fs::remove_dir_all(to);
+        return Err(e);
+    }
+    fs::remove_dir_all(from)
+}
+
+fn copy_dir(from: &Path, to: &Path) -> std::io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let kind = entry.file_type()?;
+        let target = to.join(entry.file_name());
+        if kind.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else if kind.is_file() {
+            fs::copy(entry.path(), target)?;
+        }
     }
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_validate_profile_name() {
+        assert!(validate_profile_name("research-bot_2").is_ok());
+        assert!(validate_profile_name("").is_err());
+        assert!(validate_profile_name("../escape").is_err());
+        assert!(validate_profile_name("a b").is_err());
+        assert!(validate_profile_name(&"x".repeat(65)).is_err());
+    }
+
     #[test]
     fn test_config_deserialization() {
         let json = r#"{"api_key": "test_key", "agent_name": "test_agent"}"#;
         let config: Config = serde_json::from_str(json).unwrap();
         assert_eq!(config.api_key, "test_key");
         assert_eq!(config.agent_name, "test_agent");
+        assert!(config.defaults.is_empty());
+    }
+
+    #[test]
+    fn test_parse_config_tolerates_leftover_tail() {
+        let torn = r#"{"api_key": "new", "agent_name": "bot"}ame": "old_bot"}"#;
+        assert_eq!(parse_config(torn).unwrap().api_key, "new");
+
+        assert!(
+            parse_config(r#"{"api_key": "new", "agent"#)
+                .unwrap_err()
+                .is_eof()
+        );
+        assert!(parse_config("").unwrap_err().is_eof());
+    }
+
+    #[test]
+    fn test_render_settings_keeps_comments() {
+        let mut config: Config = toml::from_str(
+            "api_key = \"key\"\nagent_name = \"bot\"\n\n[defaults]\nsubmolt = \"rust\"\n",
+        )
+        .unwrap();
+        let existing = "# Moltbook settings\nagent_name = \"bot\"\n\n\
+                        # Where my posts go\n[defaults]\nsubmolt = \"rust\" # for now\n";
+        let render = |config: &Config, existing: &str| {
+            let mut settings = toml::Table::try_from(config).unwrap();
+            split_secrets(&mut settings);
+            render_settings(&settings, existing).unwrap()
+        };
+
+        config.defaults.submolt = Some("ml".to_string());
+        config.defaults.feed_limit = Some(10);
+        config.jitter = Some("1s-3s".to_string());
+        let rendered = render(&config, existing);
+        assert!(rendered.starts_with("# Moltbook settings\n"));
+        assert!(rendered.contains("# Where my posts go\n[defaults]"));
+        assert!(rendered.contains("submolt = \"ml\" # for now"));
+        assert!(!rendered.contains("api_key"));
+
+        let reloaded: toml::Table = rendered.parse().unwrap();
+        assert_eq!(reloaded["defaults"]["feed_limit"].as_integer(), Some(10));
+        assert_eq!(reloaded["jitter"].as_str(), Some("1s-3s"));
+
+        // Unset keys disappear rather than lingering from the old file.
+        config.defaults.submolt = None;
+        let rendered = render(&config, &rendered);
+        assert!(!rendered.contains("submolt"));
+        assert!(render(&config, "").parse::<toml::Table>().is_ok());
+    }
+
+    #[test]
+    fn test_secrets_split_and_layer_back() {
+        let config: Config = toml::from_str(
+            "api_key = \"key\"\nagent_name = \"bot\"\n\n\
+             [bridges]\nmastodon_instance = \"https://mastodon.social\"\nx_token = \"xt\"\n\n\
+             [webhooks]\nsecret = \"hush\"\n",
+        )
+        .unwrap();
+        let mut settings = toml::Table::try_from(&config).unwrap();
+        let secrets = split_secrets(&mut settings);
+
+        assert_eq!(secrets["api_key"].as_str(), Some("key"));
+        assert_eq!(secrets["bridges"]["x_token"].as_str(), Some("xt"));
+        assert_eq!(secrets["webhooks"]["secret"].as_str(), Some("hush"));
+        assert!(!settings.contains_key("api_key"));
+        assert!(!settings.contains_key("webhooks"));
+        assert!(settings["bridges"].get("x_token").is_none());
+
+        let mut merged = settings;
+        merge_layer(&mut merged, secrets);
+        let loaded: Config = merged.try_into().unwrap();
+        assert_eq!(loaded.api_key, "key");
+        assert_eq!(loaded.bridges.x_token.as_deref(), Some("xt"));
+        assert_eq!(
+            loaded.bridges.mastodon_instance.as_deref(),
+            Some("https://mastodon.social")
+        );
+        assert_eq!(loaded.webhooks.secret.as_deref(), Some("hush"));
+    }
+
+    #[test]
+    fn test_move_dir() {
+        let root = tempfile::tempdir().unwrap();
+        let legacy = root.path().join("legacy");
+        fs::create_dir_all(legacy.join("media")).unwrap();
+        fs::write(legacy.join(CONFIG_FILE), "{}").unwrap();
+        fs::write(legacy.join("media").join("a.png"), "png").unwrap();
+
+        let target = root.path().join("platform").join(CONFIG_DIR);
+        copy_dir(&legacy, &target).unwrap();
+        assert!(target.join("media").join("a.png").exists());
+
+        let moved = root.path().join("moved");
+        move_dir(&legacy, &moved).unwrap();
+        assert!(!legacy.exists());
+        assert_eq!(fs::read_to_string(moved.join(CONFIG_FILE)).unwrap(), "{}");
+    }
+
+    #[test]
+    fn test_defaults_set_validates() {
+        let mut defaults = Defaults::default();
+        assert_eq!(defaults.feed_sort(), "hot");
+        defaults.set("feed_sort", "new").unwrap();
+        defaults.set("feed_limit", "10").unwrap();
+        assert_eq!(defaults.feed_sort(), "new");
+        assert_eq!(defaults.feed_limit(), 10);
+        assert!(defaults.set("feed_sort", "sideways").is_err());
+        assert!(defaults.set("feed_limit", "0").is_err());
+        assert!(defaults.set("colour", "red").is_err());
+
+        assert_eq!(defaults.preview_lines(), Some(DEFAULT_PREVIEW_LINES));
+        defaults.set("preview_lines", "0").unwrap();
+        assert_eq!(defaults.preview_lines(), None);
+        assert!(defaults.set("preview_lines", "-1").is_err());
+    }
+
+    #[test]
+    fn test_config_keys_hide_api_key() {
+        let mut config: Config =
+            serde_json::from_str(r#"{"api_key": "secret", "agent_name": "bot"}"#).unwrap();
+        assert!(config.get_value("api_key").is_err());
+        assert!(config.set_value("api_key", "x").is_err());
+        assert!(!Config::keys().contains(&"api_key".to_string()));
+
+        config.set_value("defaults.submolt", "rust").unwrap();
+        assert_eq!(
+            config.get_value("defaults.submolt").unwrap().as_deref(),
+            Some("rust")
+        );
+        config.unset_value("defaults.submolt").unwrap();
+        assert_eq!(config.get_value("defaults.submolt").unwrap(), None);
+        assert!(config.get_value("nope").is_err());
+
+        assert_eq!(
+            config.max_concurrent_requests(),
+            DEFAULT_MAX_CONCURRENT_REQUESTS
+        );
+        config.set_value("max_concurrent_requests", "2").unwrap();
+        assert_eq!(config.max_concurrent_requests(), 2);
+        assert!(config.set_value("max_concurrent_requests", "0").is_err());
+
+        assert!(!config.require_claimed());
+        config.set_value("require_claimed", "true").unwrap();
+        assert!(config.require_claimed());
+        assert!(config.set_value("require_claimed", "yes").is_err());
+    }
+
+    #[test]
+    fn test_bridge_tokens_are_hidden() {
+        let mut config: Config =
+            serde_json::from_str(r#"{"api_key": "secret", "agent_name": "bot"}"#).unwrap();
+        config.set_value("bridges.x_token", "tok").unwrap();
+        config
+            .set_value("bridges.mastodon_instance", "https://mastodon.social/")
+            .unwrap();
+        assert_eq!(config.bridges.x_token.as_deref(), Some("tok"));
+        assert_eq!(
+            config.get_value("bridges.x_token").unwrap().as_deref(),
+            Some("(hidden)")
+        );
+        assert_eq!(
+            config
+                .get_value("bridges.mastodon_instance")
+                .unwrap()
+                .as_deref(),
+            Some("https://mastodon.social")
+        );
+        assert!(
+            config
+                .set_value("bridges.mastodon_instance", "mastodon.social")
+                .is_err()
+        );
+        assert!(config.set_value("bridges.bluesky_token", "x").is_err());
+
+        config.set_value("budgets.posts", "10").unwrap();
+        assert_eq!(config.budgets.limit(ActivityKind::Post), Some(10));
+        assert_eq!(config.get_value("budgets.votes").unwrap(), None);
+        assert!(config.set_value("budgets.votes", "-1").is_err());
+        assert!(config.set_value("budgets.follows", "5").is_err());
+        config.unset_value("budgets.posts").unwrap();
+        assert!(config.budgets.is_empty());
     }
 
     #[test]
@@ -0,0 +1,180 @@
+//! Content hashes for change detection.
+//!
+//! Each post, comment or search result gets a short hash of its title,
+//! content and a coarse score bucket. Downstream caches compare hashes to
+//! spot edits without diffing bodies, and `--changed-since` keeps the hashes
+//! of the previous run in a state file to list only new or changed posts.
+//!
+//! The score is bucketed by powers of two so a single vote does not count as
+//! a change, while a post that takes off does.
+
+use crate::api::error::ApiError;
+use crate::api::types::{Post, SearchResult};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Hex digits kept from the SHA-256 digest.
+const HASH_LEN: usize = 16;
+
+/// The bucket of a net score: 0 for 0, otherwise ±(bit length of |score|),
+/// so 1, 2–3, 4–7, 8–15, … each share a bucket.
+pub fn score_bucket(score: i64) -> i64 {
+    let bits = 64 - score.unsigned_abs().leading_zeros() as i64;
+    bits * score.signum()
+}
+
+fn hash(title: &str, content: &str, score: i64) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(title.as_bytes());
+    hasher.update([0x1f]);
+    hasher.update(content.as_bytes());
+    hasher.update([0x1f]);
+    hasher.update(score_bucket(score).to_string().as_bytes());
+    let digest = format!("{:x}", hasher.finalize());
+    digest[..HASH_LEN].to_string()
+}
+
+pub fn post_hash(post: &Post) -> String {
+    let score = post.score.unwrap_or(post.upvotes - post.downvotes);
+    hash(&post.title, post.content.as_deref().unwrap_or(""), score)
+}
+
+/// Hashes a comment as returned by the API (raw JSON); comments have no title.
+pub fn comment_hash(comment: &Value) -> String {
+    let score =
+        comment["upvotes"].as_i64().unwrap_or(0) - comment["downvotes"].as_i64().unwrap_or(0);
+    hash("", comment["content"].as_str().unwrap_or(""), score)
+}
+
+pub fn search_result_hash(result: &SearchResult) -> String {
+    hash(
+        result.title.as_deref().unwrap_or(""),
+        result.content.as_deref().unwrap_or(""),
+        result.upvotes - result.downvotes,
+    )
+}
+
+/// An item serialized with its content hash alongside its own fields.
+#[derive(Serialize)]
+pub struct Stamped<'a, T: Serialize> {
+    #[serde(flatten)]
+    pub item: &'a T,
+    pub content_hash: String,
+}
+
+impl<'a> Stamped<'a, Post> {
+    pub fn post(post: &'a Post) -> Self {
+        Self {
+            item: post,
+            content_hash: post_hash(post),
+        }
+    }
+}
+
+impl<'a> Stamped<'a, SearchResult> {
+    pub fn search_result(result: &'a SearchResult) -> Self {
+        Self {
+            item: result,
+            content_hash: search_result_hash(result),
+        }
+    }
+}
+
+/// Post hashes from earlier runs, kept in the file given to `--changed-since`.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct HashState {
+    pub posts: BTreeMap<String, String>,
+}
+
+impl HashState {
+    /// Loads the state file, returning an empty state if it does not exist yet.
+    pub fn load(path: &Path) -> Result<Self, ApiError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)?;
+        serde_json::from_str(&content).map_err(|e| {
+            ApiError::ConfigError(format!(
+                "Failed to parse change state {}: {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ApiError> {
+        if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Whether `post` is new or its hash differs from the recorded one.
+    pub fn changed(&self, post: &Post) -> bool {
+        self.posts.get(&post.id) != Some(&post_hash(post))
+    }
+
+    pub fn record(&mut self, posts: &[Post]) {
+        for post in posts {
+            self.posts.insert(post.id.clone(), post_hash(post));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn post(title: &str, upvotes: i64) -> Post {
+        serde_json::from_value(json!({
+            "id": "p1",
+            "title": title,
+            "content": "body",
+            "upvotes": upvotes,
+            "author": { "name": "bot" }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_score_bucket() {
+        assert_eq!(score_bucket(0), 0);
+        assert_eq!(score_bucket(1), 1);
+        assert_eq!(score_bucket(3), 2);
+        assert_eq!(score_bucket(4), 3);
+        assert_eq!(score_bucket(-5), -3);
+    }
+
+    #[test]
+    fn test_hash_tracks_edits_not_single_votes() {
+        let original = post_hash(&post("Hello", 4));
+        assert_eq!(original.len(), HASH_LEN);
+        assert_eq!(post_hash(&post("Hello", 5)), original);
+        assert_ne!(post_hash(&post("Hello", 8)), original);
+        assert_ne!(post_hash(&post("Hello!", 4)), original);
+    }
+
+    #[test]
+    fn test_state_detects_changes() {
+        let mut state = HashState::default();
+        let first = post("Hello", 1);
+        assert!(state.changed(&first));
+        state.record(std::slice::from_ref(&first));
+        assert!(!state.changed(&first));
+        assert!(state.changed(&post("Hello, edited", 1)));
+    }
+
+    #[test]
+    fn test_stamped_post_keeps_fields() {
+        let post = post("Hello", 1);
+        let value = serde_json::to_value(Stamped::post(&post)).unwrap();
+        assert_eq!(value["title"], "Hello");
+        assert_eq!(value["content_hash"], post_hash(&post));
+    }
+}
@@ -0,0 +1,250 @@
+//! Near-duplicate detection for feed listings.
+//!
+//! Bot spam waves tend to repost the same title or link many times in a row.
+//! This module groups such posts within a fetched batch and against a small
+//! local store of recently seen posts (`seen_posts.json` in the config
+//! directory), so each wave is rendered once with a "×N similar" marker.
+
+use crate::api::error::ApiError;
+use crate::api::types::Post;
+use crate::state;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+/// The filename for the seen-post store.
+const SEEN_FILE: &str = "seen_posts.json";
+/// Maximum number of entries kept in the seen-post store.
+const MAX_SEEN: usize = 1000;
+
+/// A post recorded in the seen-post store.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SeenPost {
+    /// The ID of the post.
+    pub id: String,
+    /// Normalized title key.
+    pub title_key: String,
+    /// Normalized URL key, if the post links somewhere.
+    pub url_key: Option<String>,
+}
+
+/// Fingerprints of recently displayed posts, persisted between invocations.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct SeenStore {
+    pub posts: Vec<SeenPost>,
+}
+
+/// A post selected for display together with every post it stands in for.
+#[derive(Debug)]
+pub struct PostGroup<'a> {
+    /// The first post of the group in feed order.
+    pub post: &'a Post,
+    /// IDs of all similar posts (including `post` itself).
+    pub similar_ids: Vec<String>,
+}
+
+impl PostGroup<'_> {
+    /// Number of similar posts collapsed into this entry.
+    pub fn similar_count(&self) -> usize {
+        self.similar_ids.len()
+    }
+}
+
+impl SeenStore {
+    /// Loads the seen-post store, returning an empty store if none exists yet.
+    pub fn load() -> Result<Self, ApiError> {
+        state::load(&Self::path()?, "seen posts")
+    }
+
+    /// Applies `f` to the store on disk, locked against concurrent invocations,
+    /// keeping only the most recent `MAX_SEEN` entries.
+    pub fn update<R>(f: impl FnOnce(&mut Self) -> R) -> Result<R, ApiError> {
+        state::update(&Self::path()?, "seen posts", |store: &mut Self| {
+            let result = f(store);
+            let excess = store.posts.len().saturating_sub(MAX_SEEN);
+            store.posts.drain(..excess);
+            result
+        })
+    }
+
+    /// Whether the post with `id` was recorded before.
+    pub fn contains(&self, id: &str) -> bool {
+        self.posts.iter().any(|p| p.id == id)
+    }
+
+    /// Records every post in the batch that is not already known.
+    pub fn record(&mut self, posts: &[Post]) {
+        for post in posts {
+            if self.contains(&post.id) {
+                continue;
+            }
+            self.posts.push(SeenPost {
+                id: post.id.clone(),
+                title_key: title_key(&post.title),
+                url_key: post.url.as_deref().map(url_key),
+            });
+        }
+    }
+
+    fn path() -> Result<PathBuf, ApiError> {
+        state::path(SEEN_FILE)
+    }
+}
+
+/// Normalizes a title for comparison: lowercase letters and digits only,
+/// single-spaced.
+///
+/// Punctuation and emoji are dropped since spam waves commonly vary them to
+/// dodge exact-match filters. Digits are kept: "Part 1" and "Part 2" are
+/// different posts.
+pub fn title_key(title: &str) -> String {
+    title
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Normalizes a URL for comparison by dropping scheme, `www.`, fragment and trailing slash.
+pub fn url_key(url: &str) -> String {
+    let url = url.trim().to_lowercase();
+    let url = url.split('#').next().unwrap_or_default();
+    let url = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(url);
+    let url = url.strip_prefix("www.").unwrap_or(url);
+    url.trim_end_matches('/').to_string()
+}
+
+fn is_similar(a_title: &str, a_url: Option<&str>, b_title: &str, b_url: Option<&str>) -> bool {
+    (!a_title.is_empty() && a_title == b_title) || (a_url.is_some() && a_url == b_url)
+}
+
+/// Groups near-duplicate posts in feed order.
+///
+/// Posts are similar when their normalized titles or URLs match. Entries in
+/// `seen` that match a group but were not part of this batch are counted as
+/// well, so a wave spread across several fetches keeps growing its marker.
+pub fn group_posts<'a>(posts: &'a [Post], seen: &SeenStore) -> Vec<PostGroup<'a>> {
+    let mut groups: Vec<(String, Option<String>, PostGroup<'a>)> = Vec::new();
+
+    for post in posts {
+        let t_key = title_key(&post.title);
+        let u_key = post.url.as_deref().map(url_key);
+
+        if let Some((_, _, group)) = groups
+            .iter_mut()
+            .find(|(t, u, _)| is_similar(t, u.as_deref(), &t_key, u_key.as_deref()))
+        {
+            if !group.similar_ids.contains(&post.id) {
+                group.similar_ids.push(post.id.clone());
+            }
+            continue;
+        }
+
+        groups.push((
+            t_key,
+            u_key,
+            PostGroup {
+                post,
+                similar_ids: vec![post.id.clone()],
+            },
+        ));
+    }
+
+    groups
+        .into_iter()
+        .map(|(t_key, u_key, mut group)| {
+            let mut known: HashSet<String> = group.similar_ids.iter().cloned().collect();
+            for entry in &seen.posts {
+                if is_similar(
+                    &t_key,
+                    u_key.as_deref(),
+                    &entry.title_key,
+                    entry.url_key.as_deref(),
+                ) && known.insert(entry.id.clone())
+                {
+                    group.similar_ids.push(entry.id.clone());
+                }
+            }
+            group
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn post(id: &str, title: &str, url: Option<&str>) -> Post {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "title": title,
+            "url": url,
+            "upvotes": 0,
+            "downvotes": 0,
+            "created_at": "2024-01-01T00:00:00Z",
+            "author": {"name": "Bot"}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_title_key_ignores_case_and_punctuation() {
+        assert_eq!(title_key("FREE Tokens!!! 🚀"), "free tokens");
+        assert_eq!(title_key("free   tokens"), "free tokens");
+        assert_eq!(title_key("Weekly update #12"), "weekly update 12");
+    }
+
+    #[test]
+    fn test_numbered_titles_are_not_duplicates() {
+        let posts = vec![
+            post("1", "Weekly update 12", None),
+            post("2", "Weekly update 13", None),
+            post("3", "Part 1", None),
+            post("4", "Part 2", None),
+        ];
+        let groups = group_posts(&posts, &SeenStore::default());
+        assert!(groups.iter().all(|g| g.similar_count() == 1));
+        assert_eq!(groups.len(), 4);
+    }
+
+    #[test]
+    fn test_url_key_normalization() {
+        assert_eq!(
+            url_key("https://www.Example.com/page/#top"),
+            url_key("http://example.com/page")
+        );
+    }
+
+    #[test]
+    fn test_group_posts_collapses_batch_duplicates() {
+        let posts = vec![
+            post("1", "Buy now!", None),
+            post("2", "Real discussion", None),
+            post("3", "BUY NOW 🚀", None),
+            post("4", "Different title", Some("https://spam.example/x")),
+            post("5", "Another title", Some("http://www.spam.example/x/")),
+        ];
+        let groups = group_posts(&posts, &SeenStore::default());
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0].post.id, "1");
+        assert_eq!(groups[0].similar_count(), 2);
+        assert_eq!(groups[1].similar_count(), 1);
+        assert_eq!(groups[2].similar_ids, vec!["4", "5"]);
+    }
+
+    #[test]
+    fn test_group_posts_counts_seen_store_matches() {
+        let mut seen = SeenStore::default();
+        seen.record(&[post("old", "Buy now!", None), post("1", "Buy now", None)]);
+        assert!(seen.contains("old") && !seen.contains("2"));
+        let posts = vec![post("1", "Buy now", None)];
+        let groups = group_posts(&posts, &seen);
+        assert_eq!(groups[0].similar_ids, vec!["1", "old"]);
+    }
+}
@@ -0,0 +1,112 @@
+//! Line diffs for previewing edits before they are sent.
+
+/// A field whose value an edit will change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: &'static str,
+    /// The current value; empty when unset.
+    pub old: String,
+    pub new: String,
+}
+
+impl FieldChange {
+    /// A change to `field`, or `None` when `new` matches the current value.
+    pub fn new(field: &'static str, old: Option<&str>, new: Option<&str>) -> Option<Self> {
+        let old = old.unwrap_or_default();
+        let new = new?;
+        (old != new).then(|| Self {
+            field,
+            old: old.to_string(),
+            new: new.to_string(),
+        })
+    }
+}
+
+/// One line of a diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffLine<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Diffs `old` against `new` line by line, keeping the longest run of
+/// unchanged lines in common.
+///
+/// Texts here are short (descriptions, wiki pages), so the quadratic table is
+/// fine.
+pub fn line_diff<'a>(old: &'a str, new: &'a str) -> Vec<DiffLine<'a>> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // common[i][j]: length of the longest common subsequence of old[i..] and new[j..].
+    let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::with_capacity(old.len().max(new.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            lines.push(DiffLine::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if common[i + 1][j] >= common[i][j + 1] {
+            lines.push(DiffLine::Removed(old[i]));
+            i += 1;
+        } else {
+            lines.push(DiffLine::Added(new[j]));
+            j += 1;
+        }
+    }
+    lines.extend(old[i..].iter().map(|line| DiffLine::Removed(line)));
+    lines.extend(new[j..].iter().map(|line| DiffLine::Added(line)));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use DiffLine::*;
+
+    #[test]
+    fn test_line_diff() {
+        let old = "Rust news\nNo memes\nBe kind";
+        let new = "Rust news\nMemes on Fridays\nBe kind\nEnglish only";
+        assert_eq!(
+            line_diff(old, new),
+            [
+                Same("Rust news"),
+                Removed("No memes"),
+                Added("Memes on Fridays"),
+                Same("Be kind"),
+                Added("English only"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_field_change() {
+        assert_eq!(FieldChange::new("theme_color", Some("#fff"), None), None);
+        assert_eq!(
+            FieldChange::new("theme_color", Some("#fff"), Some("#fff")),
+            None
+        );
+        let change = FieldChange::new("theme_color", None, Some("#000")).unwrap();
+        assert_eq!((change.old.as_str(), change.new.as_str()), ("", "#000"));
+    }
+
+    #[test]
+    fn test_empty_sides() {
+        assert_eq!(line_diff("", "new"), [Added("new")]);
+        assert_eq!(line_diff("old", ""), [Removed("old")]);
+        assert!(line_diff("", "").is_empty());
+    }
+}
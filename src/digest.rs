@@ -0,0 +1,145 @@
+//! Locally scheduled submolt digests.
+//!
+//! Subscriptions are kept in `digests.json` in the config directory. Each one
+//! records the submolt, how often a digest is due, and when it last ran, so
+//! `moltbook digest run --due` can be wired into cron or an agent heartbeat.
+
+use crate::api::error::ApiError;
+use crate::state;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// The filename for the digest subscription store.
+const DIGEST_FILE: &str = "digests.json";
+
+/// How often a digest should be generated.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DigestFrequency {
+    Daily,
+    Weekly,
+}
+
+impl DigestFrequency {
+    /// The length of one digest period.
+    pub fn period(self) -> Duration {
+        match self {
+            DigestFrequency::Daily => Duration::days(1),
+            DigestFrequency::Weekly => Duration::weeks(1),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DigestFrequency::Daily => "daily",
+            DigestFrequency::Weekly => "weekly",
+        }
+    }
+}
+
+/// A digest subscription for a single submolt.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DigestSubscription {
+    /// The submolt slug.
+    pub submolt: String,
+    /// How often the digest is due.
+    pub frequency: DigestFrequency,
+    /// When the digest was last generated (RFC 3339).
+    pub last_run: Option<String>,
+}
+
+impl DigestSubscription {
+    /// Start of the reporting window: the last run, or one period ago.
+    pub fn window_start(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        self.last_run
+            .as_deref()
+            .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+            .map(|t| t.with_timezone(&Utc))
+            .unwrap_or_else(|| now - self.frequency.period())
+    }
+
+    /// Whether a full period has elapsed since the last run.
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        match self.last_run.as_deref() {
+            None => true,
+            Some(_) => now - self.window_start(now) >= self.frequency.period(),
+        }
+    }
+}
+
+/// All digest subscriptions, persisted between invocations.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct DigestStore {
+    pub subscriptions: Vec<DigestSubscription>,
+}
+
+impl DigestStore {
+    /// Loads the digest store, returning an empty store if none exists yet.
+    pub fn load() -> Result<Self, ApiError> {
+        state::load(&Self::path()?, "digests")
+    }
+
+    /// Applies `f` to the digest store on disk, locked against concurrent
+    /// invocations.
+    pub fn update<R>(f: impl FnOnce(&mut Self) -> R) -> Result<R, ApiError> {
+        state::update(&Self::path()?, "digests", f)
+    }
+
+    /// Adds a subscription or updates the frequency of an existing one.
+    pub fn subscribe(&mut self, submolt: &str, frequency: DigestFrequency) {
+        if let Some(sub) = self.subscriptions.iter_mut().find(|s| s.submolt == submolt) {
+            sub.frequency = frequency;
+        } else {
+            self.subscriptions.push(DigestSubscription {
+                submolt: submolt.to_string(),
+                frequency,
+                last_run: None,
+            });
+        }
+    }
+
+    /// Removes a subscription, returning whether one existed.
+    pub fn unsubscribe(&mut self, submolt: &str) -> bool {
+        let before = self.subscriptions.len();
+        self.subscriptions.retain(|s| s.submolt != submolt);
+        self.subscriptions.len() != before
+    }
+
+    fn path() -> Result<PathBuf, ApiError> {
+        state::path(DIGEST_FILE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscribe_updates_existing_frequency() {
+        let mut store = DigestStore::default();
+        store.subscribe("rust", DigestFrequency::Daily);
+        store.subscribe("rust", DigestFrequency::Weekly);
+        assert_eq!(store.subscriptions.len(), 1);
+        assert_eq!(store.subscriptions[0].frequency, DigestFrequency::Weekly);
+        assert!(store.unsubscribe("rust"));
+        assert!(!store.unsubscribe("rust"));
+    }
+
+    #[test]
+    fn test_is_due_after_full_period() {
+        let now = Utc::now();
+        let mut sub = DigestSubscription {
+            submolt: "rust".to_string(),
+            frequency: DigestFrequency::Weekly,
+            last_run: None,
+        };
+        assert!(sub.is_due(now));
+
+        sub.last_run = Some((now - Duration::days(3)).to_rfc3339());
+        assert!(!sub.is_due(now));
+
+        sub.last_run = Some((now - Duration::days(8)).to_rfc3339());
+        assert!(sub.is_due(now));
+    }
+}
@@ -0,0 +1,149 @@
+//! Topic-based submolt discovery.
+//!
+//! Communities are ranked by two signals: how many of the topic's terms
+//! appear in a submolt's name and description, and how strongly the API's
+//! semantic search ties the topic to posts inside it. Either signal alone is
+//! enough to surface a community, so niche submolts with sparse descriptions
+//! are still found through their content.
+
+use crate::api::types::{SearchResult, Submolt};
+use crate::search::terms;
+use std::collections::HashMap;
+
+/// A submolt suggested for a topic.
+#[derive(Debug, Clone)]
+pub struct Suggestion {
+    /// The submolt slug.
+    pub name: String,
+    pub display_name: String,
+    /// Full community details, when known.
+    pub details: Option<Submolt>,
+    /// Combined relevance; higher is better.
+    pub score: f64,
+    /// Topic terms found in the name or description.
+    pub matched_terms: Vec<String>,
+    /// Number of semantically related posts found in the submolt.
+    pub related_posts: usize,
+}
+
+/// Ranks submolts from `catalog` and the communities of semantic `hits`.
+///
+/// Submolts with no matching term and no related post are dropped.
+pub fn rank(topic: &str, catalog: Vec<Submolt>, hits: &[SearchResult]) -> Vec<Suggestion> {
+    let terms = terms(topic);
+    let mut by_name: HashMap<String, Suggestion> = HashMap::new();
+
+    for submolt in catalog {
+        let haystack = format!(
+            "{} {} {}",
+            submolt.name,
+            submolt.display_name,
+            submolt.description.as_deref().unwrap_or("")
+        )
+        .to_lowercase();
+        let matched_terms: Vec<String> = terms
+            .iter()
+            .filter(|t| haystack.contains(t.as_str()))
+            .cloned()
+            .collect();
+        let score = if terms.is_empty() {
+            0.0
+        } else {
+            matched_terms.len() as f64 / terms.len() as f64
+        };
+        by_name.insert(
+            submolt.name.to_lowercase(),
+            Suggestion {
+                name: submolt.name.clone(),
+                display_name: submolt.display_name.clone(),
+                details: Some(submolt),
+                score,
+                matched_terms,
+                related_posts: 0,
+            },
+        );
+    }
+
+    let mut semantic: HashMap<String, f64> = HashMap::new();
+    for hit in hits {
+        let Some(info) = &hit.submolt else { continue };
+        let key = info.name.to_lowercase();
+        let entry = by_name.entry(key.clone()).or_insert_with(|| Suggestion {
+            name: info.name.clone(),
+            display_name: info.display_name.clone(),
+            details: None,
+            score: 0.0,
+            matched_terms: Vec::new(),
+            related_posts: 0,
+        });
+        entry.related_posts += 1;
+        // Relevance is usually 0..1; larger raw scores are capped so a single
+        // strong hit cannot outweigh the name/description match.
+        *semantic.entry(key).or_default() += hit.similarity.unwrap_or(0.5).clamp(0.0, 1.0);
+    }
+    for (key, weight) in semantic {
+        if let Some(s) = by_name.get_mut(&key) {
+            s.score += weight.min(1.0);
+        }
+    }
+
+    let mut ranked: Vec<Suggestion> = by_name.into_values().filter(|s| s.score > 0.0).collect();
+    ranked.sort_by(|a, b| {
+        b.score.total_cmp(&a.score).then_with(|| {
+            let subscribers = |s: &Suggestion| {
+                s.details
+                    .as_ref()
+                    .and_then(|d| d.subscriber_count)
+                    .unwrap_or(0)
+            };
+            subscribers(b).cmp(&subscribers(a))
+        })
+    });
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn submolt(name: &str, description: &str, subscribers: u64) -> Submolt {
+        serde_json::from_value(json!({
+            "name": name,
+            "display_name": name,
+            "description": description,
+            "subscriber_count": subscribers
+        }))
+        .unwrap()
+    }
+
+    fn hit(submolt: &str, similarity: f64) -> SearchResult {
+        serde_json::from_value(json!({
+            "id": "p1",
+            "type": "post",
+            "upvotes": 1,
+            "downvotes": 0,
+            "similarity": similarity,
+            "author": { "name": "Bot" },
+            "submolt": { "name": submolt }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_rank_combines_text_and_semantic_hits() {
+        let catalog = vec![
+            submolt("rl", "Reinforcement learning research", 10),
+            submolt("cooking", "Recipes", 500),
+            submolt("ml", "Machine learning", 200),
+        ];
+        let hits = vec![hit("ml", 0.9), hit("agents", 0.8), hit("ml", 0.7)];
+        let ranked = rank("reinforcement learning", catalog, &hits);
+
+        let names: Vec<&str> = ranked.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["ml", "rl", "agents"]);
+        assert_eq!(ranked[0].related_posts, 2);
+        assert_eq!(ranked[1].matched_terms, ["reinforcement", "learning"]);
+        assert!(ranked[2].details.is_none());
+    }
+}
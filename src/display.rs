@@ -1,19 +1,77 @@
+pub mod account;
+pub mod alias;
+pub mod approvals;
+pub mod batch;
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod best_time;
+pub mod budget;
+pub mod cache;
+pub mod comments;
+pub mod digest;
 pub mod dm;
+pub mod drafts;
+pub mod engagement;
+pub mod follows;
 pub mod home;
+pub mod jsonl;
 pub mod label;
 pub mod notification;
+pub mod outbox;
+pub mod pending;
 pub mod post;
 pub mod profile;
+pub mod progress;
+pub mod report;
 pub mod search;
 pub mod submolt;
+pub mod supervise;
+pub mod transcript;
 pub mod utils;
+pub mod wiki;
 
-pub use dm::{display_conversation, display_dm_check, display_dm_request, display_message};
+pub use account::display_profiles;
+pub use alias::display_aliases;
+pub use approvals::{display_approval_request, display_approvals};
+pub use batch::display_batch_summary;
+#[cfg(feature = "bench")]
+pub use bench::display_render_stats;
+pub use best_time::display_best_time;
+pub use budget::display_budget;
+pub use cache::display_cache_stats;
+pub use digest::{display_digest, display_digest_subscriptions};
+pub use dm::{
+    display_conversation, display_dm_activity, display_dm_check, display_dm_list_summary,
+    display_dm_request, display_message, display_older_messages_hint,
+};
+pub use drafts::display_drafts;
+pub use engagement::display_engagement_report;
+pub use follows::display_follow_audit;
 pub use home::{display_home, display_home_fallback};
+pub use jsonl::{OutputFormat, write_jsonl};
 pub use label::{display_consider_labels, display_labels, display_roles};
-pub use notification::display_notifications;
-pub use post::{display_comment, display_post};
-pub use profile::{display_profile, display_status};
-pub use search::display_search_result;
-pub use submolt::display_submolt;
-pub use utils::{error, get_term_width, info, print_next_cursor, relative_time, success, warn};
+pub use notification::{display_notification_settings, display_notifications};
+pub use outbox::display_outbox;
+pub use pending::display_pending_actions;
+pub use post::{
+    display_comment, display_comment_preview, display_comment_tree, display_draft, display_post,
+    display_post_group, display_read_marker, is_stickied, write_comment, write_post,
+};
+pub use profile::{
+    display_capabilities, display_claim_reminder, display_dashboard_link,
+    display_new_agent_restriction, display_owner_status, display_profile, display_profile_check,
+    display_status,
+};
+pub use report::display_report;
+pub use search::{display_search_explanation, display_search_result};
+pub use submolt::{
+    display_color_swatches, display_moderators, display_settings_diff, display_submolt,
+    display_submolt_comparison, display_suggestion,
+};
+pub use supervise::display_supervision;
+pub use transcript::display_transcript;
+pub use utils::{
+    error, get_term_width, info, print_next_cursor, print_page_hint, relative_time, success, warn,
+    warn_skipped,
+};
+pub use wiki::{display_markdown, display_wiki};
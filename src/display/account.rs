@@ -0,0 +1,29 @@
+use crate::config::Profile;
+use crate::display::utils::{get_term_width, info};
+use colored::*;
+
+pub fn display_profiles(profiles: &[Profile]) {
+    let width = get_term_width();
+    println!("\n{}", "Profiles".bright_green().bold());
+    println!("{}", "=".repeat(60));
+
+    if profiles.is_empty() {
+        info("No profiles yet. Run `moltbook init` or `moltbook account add <name>`.");
+        return;
+    }
+
+    for profile in profiles {
+        let marker = if profile.active { "*" } else { " " };
+        let agent = match &profile.agent_name {
+            Some(agent) => agent.cyan(),
+            None => "not initialized".dimmed(),
+        };
+        println!(
+            "{} {:<20} {}",
+            marker.bright_green().bold(),
+            profile.name.yellow(),
+            agent
+        );
+    }
+    println!("{}", "─".repeat(width.min(60)).dimmed());
+}
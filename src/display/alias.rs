@@ -0,0 +1,51 @@
+use crate::alias::AliasStore;
+use crate::display::utils::{get_term_width, info};
+use colored::*;
+
+pub fn display_aliases(store: &AliasStore) {
+    let width = get_term_width();
+    println!("\n{}", "Aliases".bright_green().bold());
+    println!("{}", "=".repeat(60));
+
+    if store.names.is_empty()
+        && store.agents.is_empty()
+        && store.positions.is_empty()
+        && store.last.is_empty()
+    {
+        info("No aliases yet. Listings such as `feed` and `dm-list` record %N and @agent.");
+        return;
+    }
+
+    for (name, target) in &store.names {
+        println!(
+            "  {:<20} {:<13} {}",
+            name.yellow(),
+            target.kind.as_str().cyan(),
+            target.id.dimmed()
+        );
+    }
+    for (agent, id) in &store.agents {
+        println!(
+            "  {:<20} {:<13} {}",
+            format!("@{}", agent).yellow(),
+            "conversation".cyan(),
+            id.dimmed()
+        );
+    }
+    for target in &store.last {
+        println!(
+            "  {:<20} {:<13} {}",
+            "last".yellow(),
+            target.kind.as_str().cyan(),
+            target.id.dimmed()
+        );
+    }
+    if !store.positions.is_empty() {
+        println!(
+            "  {:<20} {}",
+            format!("%1..%{}", store.positions.len()).yellow(),
+            "items of the last listing".dimmed()
+        );
+    }
+    println!("{}", "─".repeat(width.min(60)).dimmed());
+}
@@ -0,0 +1,50 @@
+use crate::approval::ApprovalRequest;
+use crate::display::utils::{get_term_width, info, relative_time};
+use colored::*;
+
+pub fn display_approvals(requests: &[ApprovalRequest]) {
+    let width = get_term_width();
+    println!("\n{}", "Awaiting Approval".bright_green().bold());
+    println!("{}", "=".repeat(60));
+
+    if requests.is_empty() {
+        info("No requests awaiting approval.");
+        return;
+    }
+
+    for request in requests {
+        println!(
+            "  {} {} {:<40} {}",
+            request.short_id().yellow(),
+            request.method.cyan(),
+            request.endpoint,
+            relative_time(&request.requested_at).dimmed()
+        );
+        println!("           {}", request.command.dimmed());
+    }
+    println!("{}", "─".repeat(width.min(60)).dimmed());
+}
+
+/// Shows everything a queued request would send, before the owner decides.
+pub fn display_approval_request(request: &ApprovalRequest) {
+    println!(
+        "\n{} {}",
+        request.short_id().yellow(),
+        request.summary().bright_white().bold()
+    );
+    println!(
+        "  {} {}",
+        "Queued:".dimmed(),
+        relative_time(&request.requested_at)
+    );
+    println!("  {} {}", "Command:".dimmed(), request.command);
+    if let Some(file) = &request.file {
+        println!("  {} {}", "File:".dimmed(), file.display());
+    }
+    if let Some(body) = &request.body {
+        let body = serde_json::to_string_pretty(body).unwrap_or_default();
+        for line in body.lines() {
+            println!("  {}", line);
+        }
+    }
+}
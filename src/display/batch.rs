@@ -0,0 +1,50 @@
+use crate::batch::BatchSummary;
+use colored::*;
+
+pub fn display_batch_summary(summary: &BatchSummary, action: &str) {
+    println!(
+        "\n{}",
+        format!("Batch {} summary", action).bright_green().bold()
+    );
+    println!("{}", "=".repeat(60));
+    println!(
+        "  {:<14} {}",
+        "Succeeded:",
+        summary.succeeded.to_string().green()
+    );
+    println!(
+        "  {:<14} {}",
+        "Rate limits:",
+        summary.rate_limited.to_string().yellow()
+    );
+    println!(
+        "  {:<14} {}",
+        "Failed:",
+        summary.failed.len().to_string().red()
+    );
+
+    for (id, error) in &summary.failed {
+        println!("    {} {}", id, error.dimmed());
+    }
+    if !summary.verification.is_empty() {
+        println!(
+            "  {:<14} {}",
+            "Need verify:",
+            summary.verification.len().to_string().yellow()
+        );
+        for id in &summary.verification {
+            println!("    {}", id);
+        }
+    }
+    if !summary.skipped.is_empty() {
+        println!(
+            "  {:<14} {} {}",
+            "Skipped:",
+            summary.skipped.len().to_string().yellow(),
+            "(re-run with these IDs later)".dimmed()
+        );
+        for id in &summary.skipped {
+            println!("    {}", id);
+        }
+    }
+}
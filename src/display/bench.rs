@@ -0,0 +1,36 @@
+use crate::bench::RenderStats;
+use colored::*;
+
+/// Shows the results of `moltbook bench render`.
+pub fn display_render_stats(stats: &RenderStats) {
+    let per_post = |total: u64| total as f64 / stats.posts.max(1) as f64;
+
+    println!("\n{}", "Render Benchmark".bright_green().bold());
+    println!("{}", "=".repeat(60));
+    println!("  {:<16} {}", "Posts:".cyan(), stats.posts);
+    println!("  {:<16} {:.2?}", "Elapsed:".cyan(), stats.elapsed);
+    println!(
+        "  {:<16} {:.0} posts/s",
+        "Throughput:".cyan(),
+        stats.posts_per_second()
+    );
+    println!(
+        "  {:<16} {} ({:.1} per post)",
+        "Allocations:".cyan(),
+        stats.allocations,
+        per_post(stats.allocations)
+    );
+    println!(
+        "  {:<16} {} ({:.0} B per post)",
+        "Allocated:".cyan(),
+        indicatif::HumanBytes(stats.allocated_bytes),
+        per_post(stats.allocated_bytes)
+    );
+    if stats.output_bytes > 0 {
+        println!(
+            "  {:<16} {}",
+            "Output:".cyan(),
+            indicatif::HumanBytes(stats.output_bytes as u64)
+        );
+    }
+}
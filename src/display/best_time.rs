@@ -0,0 +1,80 @@
+use crate::best_time::{Heatmap, MIN_BUCKET_POSTS, Window};
+use colored::*;
+
+const DAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+/// Shades from no posts to the best bucket.
+const SHADES: [&str; 5] = ["··", "░░", "▒▒", "▓▓", "██"];
+
+/// Renders the weekday × hour heatmap and the suggested windows.
+///
+/// `scope` names what was sampled and `zone` the UTC offset hours are shown in.
+pub fn display_best_time(heatmap: &Heatmap, windows: &[Window], scope: &str, zone: &str) {
+    println!(
+        "\n{} {}",
+        "Best Time to Post".bright_green().bold(),
+        format!("({}, {} posts, UTC{})", scope, heatmap.total_posts(), zone).dimmed()
+    );
+    println!("{}", "=".repeat(60));
+
+    let hours: String = (0..24)
+        .map(|h| {
+            if h % 3 == 0 {
+                format!("{:<2}", h)
+            } else {
+                "  ".to_string()
+            }
+        })
+        .collect();
+    println!("     {}", hours.dimmed());
+
+    let max = heatmap.max_average();
+    for (day, row) in DAYS.iter().zip(&heatmap.buckets) {
+        let cells: String = row
+            .iter()
+            .map(|bucket| match bucket.average() {
+                None => SHADES[0].dimmed().to_string(),
+                Some(avg) => {
+                    let level = if max > 0.0 {
+                        1 + ((avg.max(0.0) / max) * 3.0).round() as usize
+                    } else {
+                        1
+                    };
+                    let shade = SHADES[level.min(4)];
+                    // Thin buckets are shown but never suggested.
+                    if bucket.posts < MIN_BUCKET_POSTS {
+                        shade.dimmed().to_string()
+                    } else {
+                        shade.bright_green().to_string()
+                    }
+                }
+            })
+            .collect();
+        println!("  {} {}", day.bold(), cells);
+    }
+    println!(
+        "     {}",
+        "darker = more engagement per post; dim = fewer than 3 posts".dimmed()
+    );
+
+    println!();
+    if windows.is_empty() {
+        println!(
+            "{}",
+            "Not enough posts per hour yet to suggest a window; run again later to sample more."
+                .yellow()
+        );
+        return;
+    }
+    println!("{}", "Suggested windows".bold());
+    for (i, window) in windows.iter().enumerate() {
+        println!(
+            "  {}. {} {:02}:00–{:02}:00  {} {}",
+            i + 1,
+            DAYS[window.weekday].cyan(),
+            window.hour,
+            (window.hour + 1) % 24,
+            format!("avg {:.1}", window.average).green(),
+            format!("({} posts)", window.posts).dimmed()
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use crate::api::error::format_wait;
+use crate::budget::Allowance;
+use colored::*;
+
+/// Shows today's usage of each kind of action against its daily budget.
+pub fn display_budget(allowances: &[Allowance], resets_in: u64) {
+    println!("\n{}", "Daily Budget".bright_green().bold());
+    println!("{}", "=".repeat(60));
+
+    for allowance in allowances {
+        let label = format!("{}s", allowance.kind.noun());
+        match (allowance.limit, allowance.remaining()) {
+            (Some(limit), Some(0)) => println!(
+                "  {:<10} {:>4} / {:<5} {}",
+                label.cyan(),
+                allowance.used,
+                limit,
+                "used up".red().bold()
+            ),
+            (Some(limit), Some(left)) => println!(
+                "  {:<10} {:>4} / {:<5} {}",
+                label.cyan(),
+                allowance.used,
+                limit,
+                format!("{} left", left).green()
+            ),
+            _ => println!(
+                "  {:<10} {:>4}         {}",
+                label.cyan(),
+                allowance.used,
+                "unlimited".dimmed()
+            ),
+        }
+    }
+    println!(
+        "\n{}",
+        format!("Resets in {} (local midnight).", format_wait(resets_in)).dimmed()
+    );
+}
@@ -0,0 +1,21 @@
+use crate::media::CacheStats;
+use colored::*;
+use indicatif::HumanBytes;
+use std::path::Path;
+
+pub fn display_cache_stats(stats: &CacheStats, root: &Path) {
+    println!("\n{}", "Media Cache".bright_green().bold());
+    println!("{}", "=".repeat(60));
+    println!(
+        "  {:<10} {}",
+        "Location:",
+        root.display().to_string().dimmed()
+    );
+    println!("  {:<10} {}", "URLs:", stats.urls.to_string().cyan());
+    println!("  {:<10} {}", "Files:", stats.files.to_string().cyan());
+    println!(
+        "  {:<10} {}",
+        "Size:",
+        HumanBytes(stats.bytes).to_string().yellow()
+    );
+}
@@ -0,0 +1,142 @@
+//! Reply trees for comment listings.
+//!
+//! The API may nest replies under `replies` or return them flat, each naming
+//! its `parent_id`. [`build_thread`] turns either shape, or a mix, into one
+//! tree so replies are indented under the comment they answer.
+
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Reply levels shown below a top-level comment before the rest are collapsed.
+pub const DEFAULT_REPLY_DEPTH: usize = 2;
+
+/// Nests every comment under its parent, keeping the order they came in.
+///
+/// Comments whose parent is not among `comments` (e.g. on another page) stay
+/// at the top level.
+pub fn build_thread(comments: Vec<Value>) -> Vec<Value> {
+    let mut nodes = Vec::new();
+    flatten(comments, None, &mut nodes);
+
+    let index: HashMap<String, usize> = nodes
+        .iter()
+        .enumerate()
+        .filter_map(|(i, (comment, _))| Some((comment["id"].as_str()?.to_string(), i)))
+        .collect();
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+    let mut roots = Vec::new();
+    for (i, (_, parent)) in nodes.iter().enumerate() {
+        match parent.as_ref().and_then(|p| index.get(p)) {
+            Some(&p) if p != i => children[p].push(i),
+            _ => roots.push(i),
+        }
+    }
+
+    let mut placed = vec![false; nodes.len()];
+    let mut thread: Vec<Value> = roots
+        .into_iter()
+        .map(|i| assemble(i, &nodes, &children, &mut placed))
+        .collect();
+    // Comments in a parent cycle are reachable from no root; list them as such.
+    for i in 0..nodes.len() {
+        if !placed[i] {
+            thread.push(assemble(i, &nodes, &children, &mut placed));
+        }
+    }
+    thread
+}
+
+/// Replies below `comment`, at any depth.
+pub fn count_replies(comment: &Value) -> usize {
+    comment["replies"].as_array().map_or(0, |replies| {
+        replies.iter().map(|r| 1 + count_replies(r)).sum()
+    })
+}
+
+/// Appends each comment without its replies, followed by its replies, along
+/// with the ID of its parent.
+fn flatten(comments: Vec<Value>, container: Option<&str>, out: &mut Vec<(Value, Option<String>)>) {
+    for mut comment in comments {
+        let replies = comment
+            .as_object_mut()
+            .and_then(|object| object.remove("replies"))
+            .and_then(|replies| match replies {
+                Value::Array(replies) => Some(replies),
+                _ => None,
+            })
+            .unwrap_or_default();
+        let parent = comment["parent_id"]
+            .as_str()
+            .or(container)
+            .map(String::from);
+        let id = comment["id"].as_str().map(String::from);
+        out.push((comment, parent));
+        flatten(replies, id.as_deref(), out);
+    }
+}
+
+fn assemble(
+    i: usize,
+    nodes: &[(Value, Option<String>)],
+    children: &[Vec<usize>],
+    placed: &mut [bool],
+) -> Value {
+    placed[i] = true;
+    let mut comment = nodes[i].0.clone();
+    let mut replies = Vec::new();
+    for &child in &children[i] {
+        if !placed[child] {
+            replies.push(assemble(child, nodes, children, placed));
+        }
+    }
+    if !replies.is_empty()
+        && let Some(object) = comment.as_object_mut()
+    {
+        object.insert("replies".to_string(), Value::Array(replies));
+    }
+    comment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn ids(comments: &[Value]) -> Vec<&str> {
+        comments.iter().map(|c| c["id"].as_str().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_build_thread() {
+        let flat = vec![
+            json!({"id": "a"}),
+            json!({"id": "b", "parent_id": "a"}),
+            json!({"id": "c"}),
+            json!({"id": "d", "parent_id": "b"}),
+            json!({"id": "e", "parent_id": "elsewhere"}),
+        ];
+        let thread = build_thread(flat);
+        assert_eq!(ids(&thread), ["a", "c", "e"]);
+        let b = &thread[0]["replies"][0];
+        assert_eq!(b["id"], "b");
+        assert_eq!(b["replies"][0]["id"], "d");
+        assert_eq!(count_replies(&thread[0]), 2);
+
+        // Already nested replies keep their place; flat ones join them.
+        let mixed = vec![
+            json!({"id": "a", "replies": [{"id": "b"}]}),
+            json!({"id": "c", "parent_id": "b"}),
+        ];
+        let thread = build_thread(mixed);
+        assert_eq!(ids(&thread), ["a"]);
+        assert_eq!(thread[0]["replies"][0]["replies"][0]["id"], "c");
+
+        // A parent cycle does not lose comments.
+        let cycle = vec![
+            json!({"id": "x", "parent_id": "y"}),
+            json!({"id": "y", "parent_id": "x"}),
+        ];
+        let thread = build_thread(cycle);
+        assert_eq!(count_replies(&thread[0]) + thread.len(), 2);
+    }
+}
@@ -0,0 +1,80 @@
+use crate::api::types::Post;
+use crate::digest::DigestSubscription;
+use crate::display::utils::{get_term_width, info, relative_time};
+use chrono::{DateTime, Utc};
+use colored::*;
+
+pub fn display_digest_subscriptions(subscriptions: &[DigestSubscription]) {
+    let width = get_term_width();
+    println!("\n{}", "Digest Subscriptions".bright_green().bold());
+    println!("{}", "=".repeat(60));
+
+    if subscriptions.is_empty() {
+        info("No digest subscriptions yet.");
+        return;
+    }
+
+    for sub in subscriptions {
+        let last = sub
+            .last_run
+            .as_deref()
+            .map(relative_time)
+            .unwrap_or_else(|| "never".to_string());
+        println!(
+            "  m/{:<20} {:<8} last run: {}",
+            sub.submolt.green(),
+            sub.frequency.as_str().cyan(),
+            last.dimmed()
+        );
+    }
+    println!("{}", "─".repeat(width.min(60)).dimmed());
+}
+
+/// Renders one submolt section of a digest report.
+pub fn display_digest(submolt: &str, frequency: &str, since: &DateTime<Utc>, posts: &[&Post]) {
+    let width = get_term_width();
+
+    println!(
+        "\n{} {} {}",
+        "📰".cyan(),
+        format!("m/{} {} digest", submolt, frequency)
+            .bright_green()
+            .bold(),
+        format!("(since {})", since.format("%Y-%m-%d")).dimmed()
+    );
+    println!("{}", "━".repeat(width).dimmed());
+
+    if posts.is_empty() {
+        println!("  {}", "No new posts this period.".dimmed());
+        println!();
+        return;
+    }
+
+    for (i, post) in posts.iter().enumerate() {
+        let max_title = width.saturating_sub(16);
+        let title = if post.title.chars().count() > max_title {
+            format!(
+                "{}…",
+                post.title
+                    .chars()
+                    .take(max_title.saturating_sub(1))
+                    .collect::<String>()
+            )
+        } else {
+            post.title.clone()
+        };
+        println!(
+            "  {} {} {}",
+            format!("#{:<2}", i + 1).dimmed(),
+            format!("▲{:<4}", post.upvotes).yellow(),
+            title.bright_cyan()
+        );
+        println!(
+            "       by {} • {} comments • {}",
+            post.author.name.yellow(),
+            post.comment_count.unwrap_or(0),
+            format!("moltbook view-post {}", post.id).dimmed()
+        );
+    }
+    println!();
+}
@@ -1,5 +1,7 @@
 use crate::api::types::{Conversation, DmCheckResponse, DmRequest, Message};
 use crate::display::utils::{get_term_width, relative_time};
+use crate::dm_watch::DmActivity;
+use crate::llm;
 use colored::*;
 
 /// Displays a DM request with action guidance.
@@ -112,7 +114,31 @@ pub fn display_dm_check(response: &DmCheckResponse) {
     println!();
 }
 
+/// Shows what one `dm-watch` poll found, if anything.
+pub fn display_dm_activity(activity: &DmActivity) {
+    if activity.is_empty() {
+        return;
+    }
+    let time = chrono::Local::now().format("%H:%M:%S").to_string();
+    for request in &activity.requests {
+        println!("{} {}", time.dimmed(), "New DM request".yellow().bold());
+        display_dm_request(request);
+    }
+    if let Some((total, new)) = activity.unread {
+        println!(
+            "{} ✉️  {} new message(s), {} unread",
+            time.dimmed(),
+            new.to_string().yellow().bold(),
+            total
+        );
+    }
+}
+
 pub fn display_conversation(conv: &Conversation) {
+    if llm::is_enabled() {
+        print!("{}", llm::conversation(conv));
+        return;
+    }
     let width = get_term_width();
     let unread_msg = if conv.unread_count > 0 {
         format!(" ({} unread)", conv.unread_count)
@@ -128,6 +154,19 @@ pub fn display_conversation(conv: &Conversation) {
         conv.with_agent.name.bright_cyan().bold(),
         unread_msg
     );
+    if let Some(last) = &conv.last_message {
+        let from = last.sender.as_ref().map_or("", |s| s.name.as_str());
+        let from = if from.is_empty() {
+            String::new()
+        } else {
+            format!("{}: ", from)
+        };
+        let space = width.saturating_sub(from.chars().count() + 4);
+        println!("   {}{}", from.dimmed(), llm::compact(&last.content, space));
+    }
+    if let Some(at) = conv.last_activity() {
+        println!("   Last active: {}", relative_time(at).dimmed());
+    }
     println!("   Conversation ID: {}", conv.conversation_id.dimmed());
     println!(
         "   Read: {}",
@@ -136,7 +175,54 @@ pub fn display_conversation(conv: &Conversation) {
     println!("{}", "─".repeat(width).dimmed());
 }
 
+/// Points at the command that shows the messages before `before`.
+pub fn display_older_messages_hint(conversation_id: &str, before: &str) {
+    if llm::is_enabled() {
+        println!("older_messages_before: {}", before);
+        return;
+    }
+    println!(
+        "\n{} {}",
+        "── older messages:".dimmed(),
+        format!("moltbook dm-read {} --before {}", conversation_id, before).bright_cyan()
+    );
+}
+
+/// Prints the totals line closing `dm-list`.
+pub fn display_dm_list_summary(conversations: &[Conversation]) {
+    let unread: u64 = conversations.iter().map(|c| c.unread_count).sum();
+    let waiting = conversations.iter().filter(|c| c.unread_count > 0).count();
+    let pending = conversations
+        .iter()
+        .filter(|c| c.status == "pending")
+        .count();
+    if llm::is_enabled() {
+        println!(
+            "summary conversations={} unread={} unread_conversations={} pending={}",
+            conversations.len(),
+            unread,
+            waiting,
+            pending
+        );
+        return;
+    }
+    let mut summary = format!(
+        "{} conversations, {} unread in {}",
+        conversations.len(),
+        unread,
+        waiting
+    );
+    if pending > 0 {
+        summary.push_str(&format!(", {} pending", pending));
+    }
+    println!("{}", summary.bold());
+}
+
 pub fn display_message(msg: &Message, my_name: &str) {
+    if llm::is_enabled() {
+        println!("{}", llm::message(msg, my_name));
+        return;
+    }
     let width = get_term_width();
     let from_you = msg.sender.name == my_name;
     let prefix = if from_you {
@@ -153,13 +239,34 @@ pub fn display_message(msg: &Message, my_name: &str) {
 
     let time = relative_time(&msg.created_at);
 
-    println!("\n{} {} ({})", icon, color.bold(), time.dimmed());
+    if msg.id.is_empty() {
+        println!("\n{} {} ({})", icon, color.bold(), time.dimmed());
+    } else {
+        println!(
+            "\n{} {} ({}) {}",
+            icon,
+            color.bold(),
+            time.dimmed(),
+            msg.id.dimmed()
+        );
+    }
 
     let wrapped = textwrap::fill(&msg.content, width.saturating_sub(4));
     for line in wrapped.lines() {
         println!("  {}", line);
     }
 
+    let reactions = msg.reaction_counts();
+    if !reactions.is_empty() {
+        let reactions: Vec<String> = reactions
+            .iter()
+            .map(|(emoji, count)| match count {
+                1 => emoji.to_string(),
+                _ => format!("{} {}", emoji, count),
+            })
+            .collect();
+        println!("  {}", reactions.join("  "));
+    }
     if msg.needs_human_input {
         println!("  {}", "⚠ Needs human input".red());
     }
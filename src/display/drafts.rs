@@ -0,0 +1,37 @@
+use crate::display::utils::{get_term_width, info, relative_time};
+use crate::draft::PostDraft;
+use crate::llm;
+use colored::*;
+
+pub fn display_drafts(drafts: &[PostDraft]) {
+    let width = get_term_width();
+    println!("\n{}", "Drafts".bright_green().bold());
+    println!("{}", "=".repeat(60));
+
+    if drafts.is_empty() {
+        info("No saved drafts. Save one with `moltbook draft save`.");
+        return;
+    }
+
+    for draft in drafts {
+        println!(
+            "  {} {} {}",
+            draft.short_id().yellow(),
+            format!("m/{}", draft.submolt).cyan(),
+            draft.title.bright_white().bold()
+        );
+        let body = match (&draft.content, &draft.url) {
+            (Some(content), _) => llm::compact(content, width.saturating_sub(15).max(20)),
+            (None, Some(url)) => url.clone(),
+            (None, None) => String::new(),
+        };
+        if !body.is_empty() {
+            println!("           {}", body);
+        }
+        println!(
+            "           {}",
+            format!("edited {}", relative_time(&draft.updated_at)).dimmed()
+        );
+    }
+    println!("{}", "─".repeat(width.min(60)).dimmed());
+}
@@ -0,0 +1,108 @@
+use crate::display::utils::relative_time;
+use crate::engagement::EngagementReport;
+use colored::*;
+
+/// Number of posts listed under the totals.
+const TOP_POSTS: usize = 5;
+
+fn date(timestamp: &str) -> &str {
+    timestamp.get(..10).unwrap_or(timestamp)
+}
+
+/// `part` as a percentage of `whole`, when there is a whole to compare with.
+fn rate(part: u64, whole: Option<u64>) -> String {
+    match whole {
+        Some(whole) if whole > 0 => {
+            format!(
+                "({:.1}% of impressions)",
+                part as f64 * 100.0 / whole as f64
+            )
+        }
+        _ => String::new(),
+    }
+}
+
+pub fn display_engagement_report(report: &EngagementReport) {
+    println!(
+        "\n{} {}",
+        "Engagement Report".bright_green().bold(),
+        format!(
+            "({} → {})",
+            date(&report.period_start),
+            date(&report.period_end)
+        )
+        .dimmed()
+    );
+    println!("{}", "=".repeat(60));
+
+    println!("  {:<13} {}", "Posts:", report.posts.to_string().cyan());
+    match report.impressions {
+        Some(impressions) => {
+            println!(
+                "  {:<13} {}",
+                "Impressions:",
+                impressions.to_string().cyan()
+            )
+        }
+        None => println!(
+            "  {:<13} {}",
+            "Impressions:",
+            "not reported by the API".dimmed()
+        ),
+    }
+    let votes = (report.upvotes + report.downvotes).max(0) as u64;
+    println!(
+        "  {:<13} {} {} {}",
+        "Votes:",
+        format!("+{}", report.upvotes).green(),
+        format!("-{}", report.downvotes).red(),
+        rate(votes, report.impressions).dimmed()
+    );
+    println!(
+        "  {:<13} {} {}",
+        "Comments:",
+        report.comments.to_string().cyan(),
+        rate(report.comments, report.impressions).dimmed()
+    );
+    match (report.followers, &report.follower_change) {
+        (Some(followers), Some(change)) => {
+            let delta = format!("{:+}", change.change);
+            let delta = if change.change >= 0 {
+                delta.green()
+            } else {
+                delta.red()
+            };
+            println!(
+                "  {:<13} {} {} {}",
+                "Followers:",
+                followers.to_string().cyan(),
+                delta,
+                format!("since {}", relative_time(&change.since)).dimmed()
+            );
+        }
+        (Some(followers), None) => println!(
+            "  {:<13} {} {}",
+            "Followers:",
+            followers.to_string().cyan(),
+            "(baseline recorded; changes show from the next report)".dimmed()
+        ),
+        (None, _) => println!("  {:<13} {}", "Followers:", "unknown".dimmed()),
+    }
+
+    if report.top_posts.is_empty() {
+        println!("\n{}", "No posts in this period.".dimmed());
+        return;
+    }
+    println!("\n{}", "Top posts".bold());
+    for (i, post) in report.top_posts.iter().take(TOP_POSTS).enumerate() {
+        let mut stats = vec![
+            format!("▲{}", post.upvotes),
+            format!("▼{}", post.downvotes),
+            format!("💬{}", post.comments),
+        ];
+        if let Some(impressions) = post.impressions {
+            stats.push(format!("👁 {}", impressions));
+        }
+        println!("  {}. {} {}", i + 1, post.title, stats.join("  ").dimmed());
+    }
+}
@@ -0,0 +1,48 @@
+use crate::display::utils::relative_time;
+use crate::follows::Audit;
+use crate::hydrate::AgentSummary;
+use colored::*;
+use std::collections::HashMap;
+
+fn display_names(heading: &str, names: &[String], profiles: &HashMap<String, AgentSummary>) {
+    println!("\n{} ({})", heading.bold(), names.len());
+    if names.is_empty() {
+        println!("  {}", "none".dimmed());
+    }
+    for name in names {
+        println!("  • {}{}", name.cyan(), profile_suffix(profiles.get(name)));
+    }
+}
+
+/// ` (karma 120 · owner @alice)` for hydrated agents, otherwise nothing.
+pub fn profile_suffix(summary: Option<&AgentSummary>) -> String {
+    match summary.map(|s| s.label()).filter(|l| !l.is_empty()) {
+        Some(label) => format!(" ({})", label).dimmed().to_string(),
+        None => String::new(),
+    }
+}
+
+/// Shows the audit; `profiles` holds details for agents hydrated with `--hydrate`.
+pub fn display_follow_audit(
+    audit: &Audit,
+    fetched_at: &str,
+    profiles: &HashMap<String, AgentSummary>,
+) {
+    println!(
+        "\n{} {}",
+        "Follow Audit".bright_green().bold(),
+        format!("(lists from {})", relative_time(fetched_at)).dimmed()
+    );
+    println!("{}", "=".repeat(60));
+    println!("  {:<8} {}", "Mutual:", audit.mutual.to_string().cyan());
+    display_names(
+        "You follow, they don't follow back",
+        &audit.not_following_back,
+        profiles,
+    );
+    display_names(
+        "They follow you, you don't follow back",
+        &audit.not_followed_back,
+        profiles,
+    );
+}
@@ -180,10 +180,10 @@ fn display_briefings(home: &HomeResponse, thin: &str, width: usize) {
             println!("    {}", msg.dimmed());
         }
 
-        if let Some(cadence) = briefing.cadence_minutes {
-            if cadence > 0 {
-                println!("    Cadence: every {}m", cadence.to_string().dimmed());
-            }
+        if let Some(cadence) = briefing.cadence_minutes
+            && cadence > 0
+        {
+            println!("    Cadence: every {}m", cadence.to_string().dimmed());
         }
         println!();
     }
@@ -0,0 +1,29 @@
+//! JSON Lines output for listings that downstream programs consume.
+//!
+//! Each item is written as one compact JSON object on its own line and
+//! stdout is flushed after every line, so readers like `jq` or a Python loop
+//! see items as they are fetched instead of waiting for the whole listing.
+//! Anything meant for humans (headers, cursors, warnings) goes to stderr.
+
+use crate::api::error::ApiError;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+/// How a listing command writes its items.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// Rendered for the terminal.
+    #[default]
+    Text,
+    /// One JSON object per line.
+    Jsonl,
+}
+
+/// Writes `item` as one line of JSON and flushes stdout.
+pub fn write_jsonl<T: Serialize>(item: &T) -> Result<(), ApiError> {
+    let mut stdout = std::io::stdout().lock();
+    writeln!(stdout, "{}", serde_json::to_string(item)?)?;
+    stdout.flush()?;
+    Ok(())
+}
@@ -31,10 +31,9 @@ pub fn display_labels(response: &LabelsResponse, submolt_name: &str) {
     let width = get_term_width();
 
     println!(
-        "\n{} {} {}",
+        "\n{} {} ",
         "🏷️ ".bright_cyan(),
-        format!("Labels — m/{}", submolt_name).bright_cyan().bold(),
-        ""
+        format!("Labels — m/{}", submolt_name).bright_cyan().bold()
     );
     println!("{}", "━".repeat(width).dimmed());
 
@@ -88,10 +87,10 @@ fn display_label_row(label: &LabelDefinition, width: usize) {
                 println!("    prompt: {}", line.italic().dimmed());
             }
         }
-        if let Some(cadence) = label.cadence_minutes {
-            if cadence > 0 {
-                println!("    cadence: every {}m", cadence.to_string().dimmed());
-            }
+        if let Some(cadence) = label.cadence_minutes
+            && cadence > 0
+        {
+            println!("    cadence: every {}m", cadence.to_string().dimmed());
         }
     }
     println!();
@@ -133,10 +132,10 @@ pub fn display_roles(response: &RolesResponse, submolt_name: &str) {
             }
         }
 
-        if let Some(cadence) = role.cadence_minutes {
-            if cadence > 0 {
-                println!("    cadence: every {}m", cadence.to_string().dimmed());
-            }
+        if let Some(cadence) = role.cadence_minutes
+            && cadence > 0
+        {
+            println!("    cadence: every {}m", cadence.to_string().dimmed());
         }
 
         let holders = role.holders.as_deref().unwrap_or(&[]);
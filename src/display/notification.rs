@@ -1,6 +1,6 @@
 // author: kelexine <https://github.com/kelexine>
 
-use crate::api::types::{Notification, NotificationsResponse};
+use crate::api::types::{Notification, NotificationSettings, NotificationsResponse};
 use crate::display::utils::{get_term_width, info, relative_time};
 use colored::*;
 
@@ -36,14 +36,14 @@ pub fn display_notifications(response: &NotificationsResponse) {
         display_notification(notif, width);
     }
 
-    if response.has_more.unwrap_or(false) {
-        if let Some(cursor) = &response.next_cursor {
-            println!(
-                "  {} More notifications available — {}",
-                "→".dimmed(),
-                format!("moltbook notifications --cursor {}", cursor).cyan()
-            );
-        }
+    if response.has_more.unwrap_or(false)
+        && let Some(cursor) = &response.next_cursor
+    {
+        println!(
+            "  {} More notifications available — {}",
+            "→".dimmed(),
+            format!("moltbook notifications --cursor {}", cursor).cyan()
+        );
     }
     println!();
 }
@@ -102,3 +102,30 @@ fn display_notification(notif: &Notification, width: usize) {
     }
     println!();
 }
+
+pub fn display_notification_settings(settings: &NotificationSettings) {
+    let width = get_term_width();
+
+    println!(
+        "\n{} {}",
+        "🔔".bright_yellow(),
+        "Notification Settings".bright_yellow().bold()
+    );
+    println!("{}", "━".repeat(width).dimmed());
+
+    let rows = [
+        ("Replies:", settings.reply_notifications),
+        ("Mentions:", settings.mention_notifications),
+        ("DM emails:", settings.dm_emails),
+        ("Weekly digest:", settings.weekly_digest),
+    ];
+    for (label, value) in rows {
+        let state = match value {
+            Some(true) => "on".green(),
+            Some(false) => "off".red(),
+            None => "unknown".dimmed(),
+        };
+        println!("  {:<15} {}", label, state);
+    }
+    println!();
+}
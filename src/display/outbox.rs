@@ -0,0 +1,26 @@
+use crate::display::utils::{get_term_width, info, relative_time};
+use crate::outbox::OutboxEntry;
+use colored::*;
+
+pub fn display_outbox(entries: &[OutboxEntry]) {
+    let width = get_term_width();
+    println!("\n{}", "Outbox".bright_green().bold());
+    println!("{}", "=".repeat(60));
+
+    if entries.is_empty() {
+        info("Outbox is empty.");
+        return;
+    }
+
+    for entry in entries {
+        println!(
+            "  {} {} {:<40} {}",
+            entry.short_id().yellow(),
+            "POST".cyan(),
+            entry.endpoint,
+            relative_time(&entry.queued_at).dimmed()
+        );
+        println!("           {}", entry.reason.dimmed());
+    }
+    println!("{}", "─".repeat(width.min(60)).dimmed());
+}
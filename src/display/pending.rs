@@ -0,0 +1,25 @@
+use crate::display::utils::{get_term_width, info, relative_time};
+use crate::idempotency::PendingAction;
+use colored::*;
+
+pub fn display_pending_actions(actions: &[PendingAction]) {
+    let width = get_term_width();
+    println!("\n{}", "Pending Actions".bright_green().bold());
+    println!("{}", "=".repeat(60));
+
+    if actions.is_empty() {
+        info("No pending actions.");
+        return;
+    }
+
+    for action in actions {
+        println!(
+            "  {} {:<40} {}",
+            "POST".cyan(),
+            action.endpoint,
+            relative_time(&action.started_at).dimmed()
+        );
+        println!("       key: {}", action.key.dimmed());
+    }
+    println!("{}", "─".repeat(width.min(60)).dimmed());
+}
@@ -1,6 +1,12 @@
-use crate::api::types::Post;
-use crate::display::utils::{get_term_width, relative_time};
+use crate::api::types::{Post, Tombstone};
+use crate::config::DEFAULT_PREVIEW_LINES;
+use crate::dedupe::PostGroup;
+use crate::display::comments::{DEFAULT_REPLY_DEPTH, count_replies};
+use crate::display::utils::{get_term_width, print_item, relative_time};
+use crate::llm;
 use colored::*;
+use std::borrow::Cow;
+use std::fmt::{self, Write};
 
 /// Renders a Moltbook post in a premium box-styled layout.
 ///
@@ -8,31 +14,89 @@ use colored::*;
 ///
 /// * `post` - The post object to display.
 /// * `index` - Optional positional index for use in lists.
+///
+/// Numbered list entries show a short preview of the content; a post shown
+/// on its own is printed in full.
 pub fn display_post(post: &Post, index: Option<usize>) {
+    print_item(|out| write_post(out, post, index));
+}
+
+/// Writes the layout of [`display_post`] to `out` instead of stdout.
+pub fn write_post(out: &mut impl Write, post: &Post, index: Option<usize>) -> fmt::Result {
+    render_post(
+        out,
+        post,
+        index,
+        1,
+        false,
+        index.map(|_| DEFAULT_PREVIEW_LINES),
+    )
+}
+
+/// Renders a collapsed group of near-duplicate posts as a single list entry.
+///
+/// The first post of the group is shown, tagged with a "×N similar" marker
+/// when it stands in for more than one post. `subscribed` adds a badge to the
+/// submolt name. Content is cut off after `preview_lines` wrapped lines, or
+/// shown in full when it is `None`.
+pub fn display_post_group(
+    group: &PostGroup,
+    index: usize,
+    subscribed: bool,
+    preview_lines: Option<usize>,
+) {
+    print_item(|out| {
+        render_post(
+            out,
+            group.post,
+            Some(index),
+            group.similar_count(),
+            subscribed,
+            preview_lines,
+        )
+    });
+}
+
+fn render_post(
+    out: &mut impl Write,
+    post: &Post,
+    index: Option<usize>,
+    similar: usize,
+    subscribed: bool,
+    preview_lines: Option<usize>,
+) -> fmt::Result {
+    if llm::is_enabled() {
+        return writeln!(out, "{}", llm::post(post, index, similar));
+    }
     let width = get_term_width();
 
-    let mut prefix_str = String::new();
+    let mut prefix = String::new();
     if let Some(i) = index {
-        prefix_str.push_str(&format!("#{:<2} ", i));
+        write!(prefix, "#{:<2} ", i)?;
     }
     if post.is_pinned.unwrap_or(false) {
-        prefix_str.push_str("📌 ");
+        prefix.push_str("📌 ");
     }
     if post.is_locked.unwrap_or(false) {
-        prefix_str.push_str("🔒 ");
+        prefix.push_str("🔒 ");
+    }
+    if !prefix.is_empty() {
+        write!(out, "{}", prefix.bright_white().bold())?;
     }
 
-    let prefix = if !prefix_str.is_empty() {
-        prefix_str.bright_white().bold()
-    } else {
-        "".normal()
-    };
-
-    println!("{}Title: {}", prefix, post.title.bright_cyan().bold());
+    let tombstone = post.tombstone();
+    match &tombstone {
+        Some(t) => write!(out, "Title: {} {}", tombstone_tag(t), post.title.dimmed())?,
+        None => write!(out, "Title: {}", post.title.bright_cyan().bold())?,
+    }
+    if similar > 1 {
+        write!(out, "{}", format!(" ×{} similar", similar).yellow())?;
+    }
+    writeln!(out)?;
 
-    let mut author_display = post.author.name.yellow().to_string();
+    write!(out, "👤 {}", post.author.name.yellow())?;
     if post.you_follow_author.unwrap_or(false) {
-        author_display.push_str(&" [Following]".bright_blue().to_string());
+        write!(out, "{}", " [Following]".bright_blue())?;
     }
 
     // Handle submolt name fallback
@@ -43,104 +107,322 @@ pub fn display_post(post: &Post, index: Option<usize>) {
     } else {
         "unknown"
     };
+    write!(out, " in m/{}", sub_name.green())?;
+    if subscribed {
+        write!(out, "{}", " [Subscribed]".bright_magenta())?;
+    }
 
-    let sub = sub_name.green();
-
-    let score_str = if let Some(score) = post.score {
-        format!(" | score ({})", score)
-    } else {
-        String::new()
-    };
-
-    let stats = format!(
-        "upvotes ({}) | downvotes ({}) | comments ({}){}",
+    let mut stats = format!(
+        "upvotes ({}) | downvotes ({}) | comments ({})",
         post.upvotes,
         post.downvotes,
         post.comment_count.unwrap_or(0),
-        score_str
     );
+    if let Some(score) = post.score {
+        write!(stats, " | score ({})", score)?;
+    }
+    writeln!(out, " {}", stats.dimmed())?;
 
-    println!("👤 {} in m/{} {}", author_display, sub, stats.dimmed());
-
-    if let Some(content) = &post.content {
-        let is_listing = index.is_some();
-        let max_lines = if is_listing { 3 } else { 1000 };
-
-        let wrapped_width = width.saturating_sub(4);
-        let wrapped = textwrap::fill(content, wrapped_width);
-
-        for (i, line) in wrapped.lines().enumerate() {
-            if i >= max_lines {
-                println!("│  {}", "...".dimmed());
+    if let Some(t) = &tombstone {
+        writeln!(out, "│  {}", tombstone_notice(t, "post").dimmed().italic())?;
+    } else if let Some(content) = &post.content {
+        for (i, line) in wrap_lines(content, width.saturating_sub(4)).enumerate() {
+            if preview_lines.is_some_and(|max| i >= max) {
+                writeln!(out, "│  {}", "...".dimmed())?;
                 break;
             }
-            println!("│  {}", line);
+            writeln!(out, "│  {}", line)?;
         }
     }
 
-    if let Some(url) = &post.url {
-        println!("│  🔗 {}", url.blue().underline());
+    if let Some(url) = post.url.as_ref().filter(|_| tombstone.is_none()) {
+        writeln!(out, "│  🔗 {}", url.blue().underline())?;
     }
 
-    println!(
+    writeln!(
+        out,
         "└─ Post ID: {} • {}",
         post.id.dimmed(),
         relative_time(&post.created_at).dimmed()
+    )?;
+    writeln!(out)
+}
+
+/// The lines of `text` wrapped to `width`, like `textwrap::fill(..).lines()`
+/// but without joining them into a new string first.
+fn wrap_lines(text: &str, width: usize) -> impl Iterator<Item = Cow<'_, str>> {
+    let mut lines = textwrap::wrap(text, width);
+    if lines.last().is_some_and(|l| l.is_empty()) {
+        lines.pop();
+    }
+    lines.into_iter()
+}
+
+/// A short marker for titles and author lines, e.g. `[deleted]`.
+fn tombstone_tag(tombstone: &Tombstone) -> ColoredString {
+    match tombstone {
+        Tombstone::Deleted => "[deleted]".red(),
+        Tombstone::Removed(_) => "[removed]".red().bold(),
+    }
+}
+
+/// The placeholder shown instead of the content of a deleted or removed item.
+fn tombstone_notice(tombstone: &Tombstone, kind: &str) -> String {
+    match tombstone {
+        Tombstone::Deleted => format!("This {} was deleted by its author.", kind),
+        Tombstone::Removed(Some(reason)) => {
+            format!("This {} was removed by the moderators: {}", kind, reason)
+        }
+        Tombstone::Removed(None) => format!("This {} was removed by the moderators.", kind),
+    }
+}
+
+/// Whether a moderator has stickied this comment to the top of its thread.
+pub fn is_stickied(comment: &serde_json::Value) -> bool {
+    comment["is_stickied"]
+        .as_bool()
+        .or_else(|| comment["is_sticky"].as_bool())
+        .unwrap_or(false)
+}
+
+/// Whether the comment was posted as an official moderator reply.
+///
+/// The API reports this either as `"distinguished": "moderator"` or as a boolean.
+fn is_distinguished(comment: &serde_json::Value) -> bool {
+    match &comment["distinguished"] {
+        serde_json::Value::String(s) => s == "moderator" || s == "admin",
+        serde_json::Value::Bool(b) => *b,
+        _ => false,
+    }
+}
+
+/// Marks where the previous read of a thread ended, before the new comments.
+pub fn display_read_marker(last_visit: &str, earlier: usize, new: usize) {
+    let marker = format!(
+        "── last read {} · {} earlier comment{} hidden ──",
+        relative_time(last_visit),
+        earlier,
+        if earlier == 1 { "" } else { "s" }
     );
-    println!();
+    println!("{}\n", marker.dimmed());
+    if new == 0 {
+        println!("{}\n", "No new comments since then.".dimmed());
+    }
 }
 
 pub fn display_comment(comment: &serde_json::Value, index: usize, depth: usize) {
+    display_comment_tree(comment, index, depth, DEFAULT_REPLY_DEPTH);
+}
+
+/// Shows a comment with its replies down to `max_depth` levels below the
+/// top level; deeper replies are collapsed into a count.
+pub fn display_comment_tree(
+    comment: &serde_json::Value,
+    index: usize,
+    depth: usize,
+    max_depth: usize,
+) {
+    print_item(|out| write_comment(out, comment, index, depth, max_depth));
+}
+
+/// Writes the layout of [`display_comment_tree`] to `out`.
+pub fn write_comment(
+    out: &mut impl Write,
+    comment: &serde_json::Value,
+    index: usize,
+    depth: usize,
+    max_depth: usize,
+) -> fmt::Result {
+    if llm::is_enabled() {
+        writeln!(out, "{}", llm::comment(comment, depth))?;
+        let replies = comment["replies"].as_array().filter(|r| !r.is_empty());
+        match replies {
+            Some(replies) if depth < max_depth => {
+                for (i, reply) in replies.iter().enumerate() {
+                    write_comment(out, reply, i + 1, depth + 1, max_depth)?;
+                }
+            }
+            Some(_) => writeln!(
+                out,
+                "collapsed parent={} replies={}\n",
+                comment["id"].as_str().unwrap_or("unknown"),
+                count_replies(comment)
+            )?,
+            None => {}
+        }
+        return Ok(());
+    }
     let indent = "  ".repeat(depth);
-    let author = comment["author"]["name"].as_str().unwrap_or("unknown");
+    let tombstone = Tombstone::of_comment(comment);
     let content = comment["content"].as_str().unwrap_or("");
     let upvotes = comment["upvotes"].as_i64().unwrap_or(0);
     let id = comment["id"].as_str().unwrap_or("unknown");
     let width = get_term_width();
+    let sticky = if is_stickied(comment) { "📌 " } else { "" };
 
     if depth == 0 {
-        println!(
-            "{} {} (⬆ {})",
-            format!("#{:<2}", index).dimmed(),
-            author.yellow().bold(),
-            upvotes
-        );
+        write!(out, "{} {}", format!("#{:<2}", index).dimmed(), sticky)?;
     } else {
-        println!("{}↳ {} (⬆ {})", indent, author.yellow().bold(), upvotes);
+        write!(out, "{}↳ {}", indent, sticky)?;
     }
+    match &tombstone {
+        Some(t) => write!(out, "{}", tombstone_tag(t))?,
+        None => write!(
+            out,
+            "{}",
+            comment["author"]["name"]
+                .as_str()
+                .unwrap_or("unknown")
+                .yellow()
+                .bold()
+        )?,
+    }
+    if is_distinguished(comment) {
+        write!(out, " {}", "[MOD]".green().bold())?;
+    }
+    writeln!(out, " (⬆ {})", upvotes)?;
 
     let text_width = width.saturating_sub(indent.len() + 2);
-    let wrapped = textwrap::fill(content, text_width);
-    for line in wrapped.lines() {
-        println!("{}│ {}", indent, line);
+    if let Some(t) = &tombstone {
+        // Replies stay visible below, so the thread keeps its shape.
+        writeln!(
+            out,
+            "{}│ {}",
+            indent,
+            tombstone_notice(t, "comment").dimmed().italic()
+        )?;
+    } else {
+        for line in wrap_lines(content, text_width) {
+            writeln!(out, "{}│ {}", indent, line)?;
+        }
     }
-    println!("{}└─ {}", indent, id.dimmed());
-    println!();
+    writeln!(out, "{}└─ {}", indent, id.dimmed())?;
+    writeln!(out)?;
 
-    let empty = vec![];
-    let replies = comment["replies"].as_array().unwrap_or(&empty);
-
-    if replies.is_empty() {
-        return;
-    }
+    let Some(replies) = comment["replies"].as_array().filter(|r| !r.is_empty()) else {
+        return Ok(());
+    };
 
-    if depth < 2 {
+    if depth < max_depth {
         for (i, reply) in replies.iter().enumerate() {
-            display_comment(reply, i + 1, depth + 1);
+            write_comment(out, reply, i + 1, depth + 1, max_depth)?;
         }
     } else {
-        // Beyond 2 levels — show a count hint rather than rendering further
+        // Beyond the requested depth — show a count hint rather than rendering further
+        let hidden = count_replies(comment);
         let hint_indent = "  ".repeat(depth + 1);
-        println!(
+        writeln!(
+            out,
             "{}{}",
             hint_indent,
             format!(
                 "·· {} more repl{} in this thread ··",
-                replies.len(),
-                if replies.len() == 1 { "y" } else { "ies" }
+                hidden,
+                if hidden == 1 { "y" } else { "ies" }
             )
             .dimmed()
-        );
+        )?;
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+/// Shows a single comment without its replies, e.g. before replying to it.
+pub fn display_comment_preview(comment: &serde_json::Value) {
+    if llm::is_enabled() {
+        println!("{}", llm::comment(comment, 0));
+        return;
+    }
+    let author = comment["author"]["name"].as_str().unwrap_or("unknown");
+    let content = comment["content"].as_str().unwrap_or("");
+    let upvotes = comment["upvotes"].as_i64().unwrap_or(0);
+    let width = get_term_width();
+
+    if let Some(t) = Tombstone::of_comment(comment) {
+        println!("💬 {} (⬆ {})", tombstone_tag(&t), upvotes);
+        println!("│ {}", tombstone_notice(&t, "comment").dimmed().italic());
         println!();
+        return;
+    }
+    println!("💬 {} (⬆ {})", author.yellow().bold(), upvotes);
+    let wrapped = textwrap::fill(content, width.saturating_sub(2));
+    for line in wrapped.lines() {
+        println!("│ {}", line);
+    }
+    println!();
+}
+
+/// Shows the final text of a comment before it is sent, with its length
+/// against `limit` and, if it was cut, the length it had before.
+pub fn display_draft(text: &str, limit: usize, original: usize) {
+    println!("\n{}", "Comment preview".bright_green().bold());
+    println!("{}", "=".repeat(60));
+    for line in text.lines() {
+        println!("│ {}", line);
+    }
+    let length = crate::draft::char_count(text);
+    let mut footer = format!("└─ {}/{} characters", length, limit);
+    if original > length {
+        footer.push_str(&format!(" (truncated from {})", original));
+    }
+    println!("{}", footer.dimmed());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_write_post_preview() {
+        colored::control::set_override(false);
+        let post: Post = serde_json::from_value(json!({
+            "id": "p1",
+            "title": "Hello",
+            "content": "one\ntwo\nthree\nfour\nfive\n",
+            "author": {"name": "alice"},
+            "submolt": {"name": "general"},
+        }))
+        .unwrap();
+
+        let mut full = String::new();
+        write_post(&mut full, &post, None).unwrap();
+        assert!(full.starts_with("Title: Hello\n👤 alice in m/general "));
+        assert!(full.contains("│  five\n└─ Post ID: p1"));
+        assert!(!full.contains("│  \n"));
+
+        let mut listed = String::new();
+        write_post(&mut listed, &post, Some(3)).unwrap();
+        assert!(listed.starts_with("#3  Title: Hello\n"));
+        assert_eq!(
+            listed.matches("│  ").count(),
+            DEFAULT_PREVIEW_LINES + 1,
+            "preview lines plus the ellipsis"
+        );
+    }
+
+    #[test]
+    fn test_write_comment_depth() {
+        colored::control::set_override(false);
+        let comment = json!({
+            "id": "c1",
+            "author": {"name": "a"},
+            "replies": [{
+                "id": "c2",
+                "author": {"name": "b"},
+                "replies": [{"id": "c3", "author": {"name": "c"}, "replies": [{"id": "c4"}]}]
+            }]
+        });
+
+        let mut out = String::new();
+        write_comment(&mut out, &comment, 1, 0, 1).unwrap();
+        assert!(out.contains("  ↳ b"));
+        assert!(!out.contains("↳ c"));
+        assert!(out.contains("·· 2 more replies in this thread ··"));
+
+        let mut out = String::new();
+        write_comment(&mut out, &comment, 1, 0, 5).unwrap();
+        assert!(out.contains("      ↳ unknown"));
+        assert!(!out.contains("more repl"));
     }
 }
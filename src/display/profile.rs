@@ -1,12 +1,19 @@
-use crate::api::types::Agent;
+use crate::api::error::format_wait;
+use crate::api::types::{Agent, OwnerEmailStatus};
+use crate::capabilities::{AccountFacts, Availability, Capability};
+use crate::completeness::CheckItem;
 use crate::display::utils::{get_term_width, relative_time};
+use crate::registration::Registration;
 use colored::*;
+use std::path::Path;
 
 /// Renders a comprehensive profile view for an agent.
 ///
 /// Displays agent stats, karma, following/follower counts, and owner information
 /// in a structured, multi-section layout.
-pub fn display_profile(agent: &Agent, title: Option<&str>) {
+///
+/// `avatar` is the locally cached copy of the avatar, when one was fetched.
+pub fn display_profile(agent: &Agent, title: Option<&str>, avatar: Option<&Path>) {
     let width = get_term_width();
 
     let title_str = title.unwrap_or("Profile");
@@ -15,8 +22,11 @@ pub fn display_profile(agent: &Agent, title: Option<&str>) {
 
     println!("  {:<15} {}", "Name:", agent.name.bright_white().bold());
     println!("  {:<15} {}", "Agent ID:", agent.id.dimmed());
-    if let Some(avatar) = &agent.avatar_url {
-        println!("  {:<15} {}", "Avatar:", avatar.blue().underline());
+    if let Some(url) = &agent.avatar_url {
+        println!("  {:<15} {}", "Avatar:", url.blue().underline());
+    }
+    if let Some(path) = avatar {
+        println!("  {:<15} {}", "", path.display().to_string().dimmed());
     }
 
     if let Some(desc) = &agent.description {
@@ -175,3 +185,214 @@ pub fn display_status(status: &crate::api::types::StatusResponse) {
     }
     println!();
 }
+
+/// Read-only commands suggested while a new agent's cooldown runs.
+const WHILE_RESTRICTED: &[(&str, &str)] = &[
+    ("moltbook feed", "catch up on your feed"),
+    ("moltbook search \"<topic>\"", "find threads to join later"),
+    ("moltbook submolts", "browse communities"),
+    ("moltbook status", "see when restrictions lift"),
+];
+
+/// Explains a new-agent cooldown: when it ends and what still works meanwhile.
+pub fn display_new_agent_restriction(wait: Option<u64>, action: &str) {
+    println!(
+        "\n{} {}",
+        "⏳".yellow(),
+        format!("New agents can't {} yet.", action)
+            .bright_yellow()
+            .bold()
+    );
+    match wait {
+        Some(seconds) => {
+            let at = chrono::Local::now() + chrono::Duration::seconds(seconds as i64);
+            println!(
+                "   Try again in {} (around {}).",
+                format_wait(seconds).bright_white().bold(),
+                at.format("%H:%M")
+            );
+        }
+        None => println!("   Restrictions lift 24 hours after registration."),
+    }
+    println!("   Meanwhile, reading still works:");
+    for (command, purpose) in WHILE_RESTRICTED {
+        println!("     {:<28} {}", command.cyan(), purpose.dimmed());
+    }
+    println!();
+}
+
+/// Renders `capabilities`: one line per group of commands, with the reason
+/// when it is limited or locked.
+pub fn display_capabilities(name: &str, facts: &AccountFacts, capabilities: &[Capability]) {
+    println!(
+        "\n{} {}",
+        "Capabilities".bright_green().bold(),
+        format!("for {}", name).dimmed()
+    );
+    println!("{}", "=".repeat(60));
+    let claimed = match facts.claimed {
+        Some(true) => "claimed".green(),
+        Some(false) => "not claimed".yellow(),
+        None => "unknown".dimmed(),
+    };
+    println!("  {:<8} {}", "Claim:", claimed);
+    if let Some(karma) = facts.karma {
+        println!("  {:<8} {}", "Karma:", karma.to_string().cyan());
+    }
+    for (role, submolt) in &facts.roles {
+        println!(
+            "  {:<8} {} in {}",
+            "Role:",
+            role.bright_magenta(),
+            format!("m/{}", submolt).green()
+        );
+    }
+    println!();
+
+    for capability in capabilities {
+        let (mark, reason) = match &capability.availability {
+            Availability::Available => ("✓".green(), None),
+            Availability::Limited(reason) => ("~".yellow(), Some(reason)),
+            Availability::Locked(reason) => ("✗".red(), Some(reason)),
+        };
+        println!(
+            "  {} {:<16} {}",
+            mark,
+            capability.area,
+            capability.commands.dimmed()
+        );
+        if let Some(reason) = reason {
+            println!("    {:<16} {}", "", reason.yellow());
+        }
+    }
+    println!();
+}
+
+/// Reminds that the agent still awaits its human's claim.
+///
+/// Printed to stderr so it never mixes into a command's output.
+pub fn display_claim_reminder(registration: &Registration) {
+    eprintln!(
+        "\n{} {}",
+        "⚠️".yellow(),
+        format!("{} is not claimed yet.", registration.agent_name)
+            .bright_yellow()
+            .bold()
+    );
+    eprintln!(
+        "   Give this URL to your human: {}",
+        registration.claim_url.bright_white().bold()
+    );
+    eprintln!(
+        "   Verification code: {}",
+        registration.verification_code.yellow()
+    );
+    eprintln!(
+        "   {}\n",
+        "Set `moltbook config set require_claimed true` to block writes until then.".dimmed()
+    );
+}
+
+/// Renders the owner dashboard link and what the owner still has to do
+/// before it works.
+pub fn display_dashboard_link(agent: &Agent, url: &str) {
+    println!(
+        "\n{} {}",
+        "🔑".cyan(),
+        "Owner Dashboard".bright_green().bold()
+    );
+    println!("{}", "━".repeat(get_term_width()).dimmed());
+    println!("  {:<15} {}", "Agent:", agent.name.bright_white().bold());
+    println!("  {:<15} {}", "Link:", url.cyan().underline());
+
+    match (&agent.owner_email, agent.owner_email_verified) {
+        (None, _) => {
+            println!("  {:<15} {}", "Owner Email:", "not set".yellow());
+            println!(
+                "\n  {} Your human logs in with an email. Set one with:",
+                "→".yellow()
+            );
+            println!("    {}", "moltbook setup-owner-email EMAIL".cyan());
+        }
+        (Some(email), Some(false)) => {
+            println!(
+                "  {:<15} {} {}",
+                "Owner Email:",
+                email,
+                "(unverified)".yellow()
+            );
+            println!(
+                "\n  {} The dashboard opens once the email is verified. Resend the link with:",
+                "→".yellow()
+            );
+            println!("    {}", "moltbook setup-owner-email --resend".cyan());
+        }
+        (Some(email), _) => {
+            println!("  {:<15} {} {}", "Owner Email:", email, "✓".green());
+        }
+    }
+    println!();
+}
+
+/// Renders the owner email state and the command that moves it forward.
+pub fn display_owner_status(status: &OwnerEmailStatus) {
+    println!("\n{} {}", "📧".cyan(), "Owner Email".bright_green().bold());
+    println!("{}", "━".repeat(get_term_width()).dimmed());
+
+    let (next_step, command) = match (&status.email, status.verified) {
+        (None, _) => {
+            println!("  {:<15} {}", "Email:", "not set".yellow());
+            (
+                "Set an email so your human can log in to the dashboard:",
+                "moltbook setup-owner-email EMAIL",
+            )
+        }
+        (Some(email), Some(true)) => {
+            println!("  {:<15} {} {}", "Email:", email, "✓ verified".green());
+            (
+                "Your human can log in to the dashboard:",
+                "moltbook dashboard",
+            )
+        }
+        (Some(email), _) => {
+            println!("  {:<15} {} {}", "Email:", email, "(unverified)".yellow());
+            if let Some(sent_at) = &status.verification_sent_at {
+                println!("  {:<15} {}", "Link Sent:", relative_time(sent_at).dimmed());
+            }
+            (
+                "Ask your human to click the link in the email, or send a new one:",
+                "moltbook setup-owner-email --resend",
+            )
+        }
+    };
+    println!("\n  {} {}", "→".yellow(), next_step);
+    println!("    {}\n", command.cyan());
+}
+
+/// Renders the profile completeness score and the steps still missing.
+pub fn display_profile_check(agent_name: &str, score: u8, items: &[CheckItem]) {
+    println!(
+        "\n{} {}",
+        "📋".cyan(),
+        format!("Profile Check: {}", agent_name)
+            .bright_green()
+            .bold()
+    );
+    println!("{}", "━".repeat(get_term_width()).dimmed());
+    let score_display = match score {
+        100 => format!("{}%", score).green().bold(),
+        60..=99 => format!("{}%", score).yellow().bold(),
+        _ => format!("{}%", score).red().bold(),
+    };
+    println!("  {:<15} {}\n", "Completeness:", score_display);
+
+    for item in items {
+        if item.done {
+            println!("  {} {}", "✓".green(), item.item);
+        } else {
+            println!("  {} {}", "✗".red(), item.item);
+            println!("      {} {}", "→".dimmed(), item.action.cyan());
+        }
+    }
+    println!();
+}
@@ -0,0 +1,52 @@
+//! Progress indicators for uploads, multi-page fetches and batches.
+//!
+//! Every constructor takes an `enabled` flag (see
+//! [`MoltbookClient::progress_enabled`](crate::api::client::MoltbookClient::progress_enabled))
+//! and returns a hidden bar when it is false, so callers never branch on it.
+//! Output printed while a bar is visible should go through
+//! [`ProgressBar::suspend`] to avoid tearing.
+
+use indicatif::{ProgressBar, ProgressStyle};
+use std::time::Duration;
+
+const TICK: Duration = Duration::from_millis(100);
+
+/// A bar counting `len` items, e.g. the votes of a batch.
+pub fn items(len: u64, enabled: bool) -> ProgressBar {
+    if !enabled {
+        return ProgressBar::hidden();
+    }
+    let bar = ProgressBar::new(len);
+    bar.set_style(
+        ProgressStyle::with_template("{bar:30.green/dim} {pos}/{len} {msg}")
+            .expect("valid progress template")
+            .progress_chars("━╸─"),
+    );
+    bar
+}
+
+/// A bar counting uploaded bytes.
+pub fn bytes(len: u64, enabled: bool) -> ProgressBar {
+    if !enabled {
+        return ProgressBar::hidden();
+    }
+    let bar = ProgressBar::new(len);
+    bar.set_style(
+        ProgressStyle::with_template(
+            "{msg} {bar:30.cyan/dim} {bytes}/{total_bytes} ({bytes_per_sec})",
+        )
+        .expect("valid progress template")
+        .progress_chars("━╸─"),
+    );
+    bar
+}
+
+/// A spinner for a step of unknown length, e.g. fetching the next page.
+pub fn spinner(message: String, enabled: bool) -> ProgressBar {
+    if !enabled {
+        return ProgressBar::hidden();
+    }
+    let bar = ProgressBar::new_spinner().with_message(message);
+    bar.enable_steady_tick(TICK);
+    bar
+}
@@ -0,0 +1,35 @@
+use crate::api::types::Report;
+use crate::display::utils::relative_time;
+use colored::*;
+
+pub fn display_report(report: &Report) {
+    let status = report.status.as_deref().unwrap_or("open");
+    let status_display = match status {
+        "actioned" | "resolved" => status.green(),
+        "dismissed" | "rejected" => status.red(),
+        _ => status.yellow(),
+    };
+    let target_type = report.target_type.as_deref().unwrap_or("post");
+    let target_id = report.target_id.as_deref().unwrap_or("?");
+
+    println!(
+        "  [{}] {} {} — {}",
+        status_display,
+        target_type,
+        target_id.dimmed(),
+        report.reason.as_deref().unwrap_or("unspecified").cyan()
+    );
+    if let Some(note) = &report.note {
+        println!("       {}", note.italic());
+    }
+
+    let mut meta = vec![format!("id {}", report.id)];
+    if let Some(t) = &report.created_at {
+        meta.push(format!("filed {}", relative_time(t)));
+    }
+    if let Some(t) = &report.resolved_at {
+        meta.push(format!("resolved {}", relative_time(t)));
+    }
+    println!("       {}", meta.join("  •  ").dimmed());
+    println!();
+}
@@ -1,8 +1,36 @@
 use crate::api::types::SearchResult;
 use crate::display::utils::get_term_width;
+use crate::hydrate::AgentSummary;
+use crate::llm;
+use crate::search::{Explanation, find_terms};
 use colored::*;
 
-pub fn display_search_result(result: &SearchResult, index: usize) {
+/// Wraps each occurrence of `terms` in `text` with `style`.
+fn highlight(text: &str, terms: &[String], style: impl Fn(&str) -> ColoredString) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+    for range in find_terms(text, terms) {
+        out.push_str(&text[last..range.start]);
+        out.push_str(&style(&text[range.clone()]).to_string());
+        last = range.end;
+    }
+    out.push_str(&text[last..]);
+    out
+}
+
+/// Renders one search result box; occurrences of `terms` in the content are underlined.
+///
+/// `author` adds karma and owner details hydrated with `--hydrate`.
+pub fn display_search_result(
+    result: &SearchResult,
+    index: usize,
+    terms: &[String],
+    author: Option<&AgentSummary>,
+) {
+    if llm::is_enabled() {
+        println!("{}", llm::search_result(result, index));
+        return;
+    }
     let width = get_term_width();
     let inner_width = width.saturating_sub(4);
 
@@ -42,15 +70,24 @@ pub fn display_search_result(result: &SearchResult, index: usize) {
         format!("├{}┤", "─".repeat(width.saturating_sub(2))).dimmed()
     );
 
+    let details = author
+        .map(|a| a.label())
+        .filter(|l| !l.is_empty())
+        .map(|l| format!(" ({})", l))
+        .unwrap_or_default();
     let author = result.author.name.yellow();
     let type_label = result.result_type.blue();
 
-    let left_len = result.author.name.chars().count() + result.result_type.chars().count() + 8;
+    let left_len = result.author.name.chars().count()
+        + details.chars().count()
+        + result.result_type.chars().count()
+        + 8;
     let meta_padding = inner_width.saturating_sub(left_len);
 
     println!(
-        "│ 👤 {}  •  {}{:>p$} │",
+        "│ 👤 {}{}  •  {}{:>p$} │",
         author,
+        details.dimmed(),
         type_label,
         "",
         p = meta_padding
@@ -65,7 +102,14 @@ pub fn display_search_result(result: &SearchResult, index: usize) {
                 println!("│  {: <w$} │", "...".dimmed(), w = wrapped_width);
                 break;
             }
-            println!("│  {:<w$}│", line, w = wrapped_width);
+            // Pad by the plain width; the underline escape codes take no columns.
+            let padding = wrapped_width.saturating_sub(line.chars().count());
+            println!(
+                "│  {}{:p$}│",
+                highlight(line, terms, |t| t.underline()),
+                "",
+                p = padding
+            );
         }
     }
 
@@ -78,3 +122,50 @@ pub fn display_search_result(result: &SearchResult, index: usize) {
     }
     println!();
 }
+
+/// Prints why a result matched, below the result box (`search --explain`).
+pub fn display_search_explanation(explanation: &Explanation, terms: &[String]) {
+    let mut score = match explanation.similarity {
+        Some(s) if s > 1.0 => format!("similarity {:.2}", s),
+        Some(s) => format!("similarity {:.2} ({:.0}%)", s, s * 100.0),
+        None => "no similarity score".to_string(),
+    };
+    if let Some(relative) = explanation.relative {
+        score.push_str(&format!(" • {:.0}% of the best match", relative * 100.0));
+    }
+    println!("   {} {}", "Why:".bright_white().bold(), score);
+
+    if explanation.is_semantic_only() {
+        println!(
+            "        {}",
+            "semantic match only — no query terms appear in the text".dimmed()
+        );
+    } else {
+        let mut parts = Vec::new();
+        if !explanation.title_terms.is_empty() {
+            parts.push(format!(
+                "title: {}",
+                explanation.title_terms.join(", ").green()
+            ));
+        }
+        if !explanation.content_terms.is_empty() {
+            parts.push(format!(
+                "content: {}",
+                explanation.content_terms.join(", ").green()
+            ));
+        }
+        if !explanation.missing_terms.is_empty() {
+            parts.push(format!(
+                "missing: {}",
+                explanation.missing_terms.join(", ").dimmed()
+            ));
+        }
+        println!("        {}", parts.join(" • "));
+    }
+
+    if let Some(snippet) = &explanation.snippet {
+        let highlighted = highlight(snippet, terms, |t| t.bright_yellow().bold());
+        println!("        \"{}\"", highlighted);
+    }
+    println!();
+}
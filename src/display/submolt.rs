@@ -1,6 +1,12 @@
-use crate::api::types::Submolt;
-use crate::display::utils::get_term_width;
+use crate::api::types::{Moderator, Submolt};
+use crate::color::HexColor;
+use crate::compare::SubmoltStats;
+use crate::diff::{DiffLine, FieldChange, line_diff};
+use crate::discover::Suggestion;
+use crate::display::utils::{get_term_width, relative_time};
+use crate::hydrate::AgentSummary;
 use colored::*;
+use std::collections::{BTreeMap, HashMap};
 
 pub fn display_submolt(submolt: &Submolt) {
     let width = get_term_width();
@@ -49,3 +55,260 @@ pub fn display_submolt(submolt: &Submolt) {
     println!("{}", "─".repeat(width.min(60)).dimmed());
     println!();
 }
+
+/// Renders a submolt's moderators as a table.
+///
+/// Karma and owner columns, and each moderator's avatar URL, are added for
+/// moderators in `profiles` (hydrated with `--hydrate`).
+pub fn display_moderators(
+    name: &str,
+    moderators: &[Moderator],
+    profiles: &HashMap<String, AgentSummary>,
+) {
+    println!(
+        "\n{} {}",
+        "Moderators for".bright_green().bold(),
+        format!("m/{}", name).cyan()
+    );
+    if moderators.is_empty() {
+        println!("  {}", "none".dimmed());
+        return;
+    }
+    let hydrated = !profiles.is_empty();
+    let agent_width = moderators
+        .iter()
+        .map(|m| m.agent.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(5)
+        + 2;
+    let or_dash = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+
+    let mut header = format!(
+        "  {:<agent_width$}{:<11}{:<16}{:<16}",
+        "Agent", "Role", "Added", "Added by"
+    );
+    if hydrated {
+        header.push_str(&format!("{:<8}{}", "Karma", "Owner"));
+    }
+    println!("{}", header.bold());
+    for m in moderators {
+        let role = format!("{:<11}", m.role);
+        let role = if m.role.eq_ignore_ascii_case("owner") {
+            role.bright_magenta()
+        } else {
+            role.normal()
+        };
+        print!(
+            "  {}{}{:<16}{:<16}",
+            format!("{:<agent_width$}", m.agent).yellow(),
+            role,
+            or_dash(m.added_at.as_deref().map(relative_time)),
+            or_dash(m.added_by.clone()),
+        );
+        let profile = profiles.get(&m.agent);
+        if hydrated {
+            print!(
+                "{:<8}{}",
+                or_dash(profile.and_then(|p| p.karma).map(|k| k.to_string())),
+                or_dash(
+                    profile
+                        .and_then(|p| p.owner.as_ref())
+                        .map(|o| format!("@{}", o))
+                ),
+            );
+        }
+        println!();
+        if let Some(url) = profile.and_then(|p| p.avatar_url.as_deref()) {
+            println!("  {:<agent_width$}{}", "", url.dimmed());
+        }
+    }
+    println!();
+}
+
+/// Renders a submolt suggested by `discover`, with the reasons it matched.
+pub fn display_suggestion(suggestion: &Suggestion, index: usize) {
+    println!(
+        "{} {} (m/{})",
+        format!("#{}", index).dimmed(),
+        suggestion.display_name.bright_cyan().bold(),
+        suggestion.name.green()
+    );
+
+    let details = suggestion.details.as_ref();
+    if let Some(desc) = details.and_then(|d| d.description.as_deref()) {
+        println!("   {}", desc.dimmed());
+    }
+
+    let mut stats = Vec::new();
+    if let Some(subscribers) = details.and_then(|d| d.subscriber_count) {
+        stats.push(format!("{} subscribers", subscribers));
+    }
+    if let Some(posts) = details.and_then(|d| d.post_count) {
+        stats.push(format!("{} posts", posts));
+    }
+    if let Some(active) = details.and_then(|d| d.last_activity_at.as_deref()) {
+        stats.push(format!("active {}", relative_time(active)));
+    }
+    if !stats.is_empty() {
+        println!("   {}", stats.join(" • "));
+    }
+
+    let mut reasons = Vec::new();
+    if !suggestion.matched_terms.is_empty() {
+        reasons.push(format!("mentions {}", suggestion.matched_terms.join(", ")));
+    }
+    if suggestion.related_posts > 0 {
+        reasons.push(format!(
+            "{} related post{}",
+            suggestion.related_posts,
+            if suggestion.related_posts == 1 {
+                ""
+            } else {
+                "s"
+            }
+        ));
+    }
+    println!(
+        "   {} {}",
+        "Why:".bright_white(),
+        reasons.join("; ").yellow()
+    );
+    println!();
+}
+
+/// Renders `compare-submolts` as a table with one column per submolt.
+///
+/// Contributors who rank in more than one of the compared submolts are
+/// highlighted and listed again below the table.
+pub fn display_submolt_comparison(stats: &[SubmoltStats], shared: &BTreeMap<String, Vec<String>>) {
+    const LABEL: usize = 18;
+    let column = stats
+        .iter()
+        .map(|s| s.name.chars().count() + 2)
+        .max()
+        .unwrap_or(0)
+        .clamp(14, 24);
+    let cell = |text: String| {
+        let text: String = text.chars().take(column - 1).collect();
+        format!("{:<column$}", text)
+    };
+    let row = |label: &str, values: Vec<String>| {
+        let cells: String = values.into_iter().map(cell).collect();
+        println!("  {:<LABEL$}{}", label, cells);
+    };
+    let or_dash = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+
+    println!("\n{}", "Submolt Comparison".bright_green().bold());
+    println!("{}", "=".repeat(LABEL + 2 + column * stats.len()));
+    let header: String = stats
+        .iter()
+        .map(|s| cell(format!("m/{}", s.name)))
+        .collect();
+    println!("  {:<LABEL$}{}", "", header.bright_cyan().bold());
+    row(
+        "Subscribers",
+        stats
+            .iter()
+            .map(|s| or_dash(s.subscribers.map(|n| n.to_string())))
+            .collect(),
+    );
+    row(
+        "Posts (total)",
+        stats
+            .iter()
+            .map(|s| or_dash(s.total_posts.map(|n| n.to_string())))
+            .collect(),
+    );
+    row(
+        "Posts/day",
+        stats
+            .iter()
+            .map(|s| or_dash(s.posts_per_day.map(|n| format!("{:.1}", n))))
+            .collect(),
+    );
+    row(
+        "Avg score",
+        stats
+            .iter()
+            .map(|s| or_dash(s.average_score.map(|n| format!("{:.1}", n))))
+            .collect(),
+    );
+    row(
+        "Sampled posts",
+        stats.iter().map(|s| s.sampled.to_string()).collect(),
+    );
+
+    let ranks = stats
+        .iter()
+        .map(|s| s.top_contributors.len())
+        .max()
+        .unwrap_or(0);
+    for rank in 0..ranks {
+        let label = if rank == 0 { "Top contributors" } else { "" };
+        let cells: String = stats
+            .iter()
+            .map(|s| {
+                let name = s.top_contributors.get(rank).cloned().unwrap_or_default();
+                let padded = cell(name.clone());
+                if shared.contains_key(&name) {
+                    padded.yellow().to_string()
+                } else {
+                    padded
+                }
+            })
+            .collect();
+        println!("  {:<LABEL$}{}", label, cells);
+    }
+
+    if shared.is_empty() {
+        println!("\n{}", "No top contributors in common.".dimmed());
+        return;
+    }
+    println!("\n{}", "Shared top contributors".bold());
+    for (author, submolts) in shared {
+        let submolts: Vec<String> = submolts.iter().map(|s| format!("m/{}", s)).collect();
+        println!("  {} {}", author.yellow(), submolts.join(", ").dimmed());
+    }
+}
+
+/// Renders the settings an update would change, old lines in red and new
+/// lines in green.
+pub fn display_settings_diff(name: &str, changes: &[FieldChange]) {
+    println!(
+        "\n{} {}",
+        "Pending changes to".bright_green().bold(),
+        format!("m/{}", name).bright_cyan().bold()
+    );
+    println!("{}", "=".repeat(60));
+    for change in changes {
+        println!("{}", change.field.bold());
+        if change.old.is_empty() {
+            println!("  {}", "(unset)".dimmed());
+        }
+        for line in line_diff(&change.old, &change.new) {
+            match line {
+                DiffLine::Same(text) => println!("  {}", text.dimmed()),
+                DiffLine::Removed(text) => println!("{}", format!("- {}", text).red()),
+                DiffLine::Added(text) => println!("{}", format!("+ {}", text).green()),
+            }
+        }
+    }
+    println!("{}", "=".repeat(60));
+}
+
+/// Draws a block of each color in truecolor, with its hex value.
+///
+/// Terminals without truecolor support approximate the nearest color.
+pub fn display_color_swatches(colors: &[(&str, HexColor)]) {
+    if colors.is_empty() {
+        return;
+    }
+    println!("\n{}", "Color preview".bold());
+    for (field, color) in colors {
+        let block = "        ".on_truecolor(color.r, color.g, color.b);
+        println!("  {:<14} {} {}", field, block, color.to_string().dimmed());
+        println!("  {:<14} {}", "", block);
+    }
+    println!();
+}
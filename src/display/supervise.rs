@@ -0,0 +1,125 @@
+use crate::display::utils::get_term_width;
+use crate::supervise::Snapshot;
+use colored::*;
+use std::io::IsTerminal;
+
+/// Redraws the `status --watch` dashboard, replacing the previous screen
+/// when stdout is a terminal.
+pub fn display_supervision(snapshot: &Snapshot) {
+    if std::io::stdout().is_terminal() {
+        // Clear the screen and move the cursor home.
+        print!("\x1b[2J\x1b[H");
+    }
+    let width = get_term_width();
+    let agent = snapshot
+        .status
+        .as_ref()
+        .ok()
+        .and_then(|s| s.agent.as_ref())
+        .map(|a| a.name.as_str())
+        .unwrap_or("unknown agent");
+    println!(
+        "{} {}  {}",
+        "🦞 Supervising".bright_green().bold(),
+        agent.bright_white().bold(),
+        format!(
+            "refreshed {} · every {}s · Ctrl-C to stop",
+            snapshot
+                .refreshed_at
+                .with_timezone(&chrono::Local)
+                .format("%H:%M:%S"),
+            snapshot.interval.as_secs()
+        )
+        .dimmed()
+    );
+    println!("{}", "━".repeat(width).dimmed());
+
+    match &snapshot.status {
+        Ok(status) => {
+            let state = match status.status.as_deref() {
+                Some("claimed") => "✓ Claimed".green(),
+                Some("pending_claim") => "⏳ Pending Claim".yellow(),
+                Some(other) => other.normal(),
+                None => "unknown".dimmed(),
+            };
+            println!("  {:<16} {}", "Status:", state);
+            if let Some(step) = &status.next_step {
+                println!("  {:<16} {}", "Next step:", step.yellow());
+            }
+        }
+        Err(e) => println!("  {:<16} {}", "Status:", e.red()),
+    }
+
+    match (snapshot.unread_dms(), &snapshot.dms) {
+        (Some((unread, requests)), _) => {
+            let count = |n: u64| {
+                if n > 0 {
+                    n.to_string().yellow().bold()
+                } else {
+                    n.to_string().green()
+                }
+            };
+            println!(
+                "  {:<16} {} unread · {} pending request(s)",
+                "DMs:",
+                count(unread),
+                count(requests)
+            );
+        }
+        (None, Err(e)) => println!("  {:<16} {}", "DMs:", e.red()),
+        (None, Ok(_)) => {}
+    }
+
+    let budget = match &snapshot.rate_limit {
+        Some(limit) => {
+            let mut parts = Vec::new();
+            match (limit.remaining, limit.limit) {
+                (Some(remaining), Some(total)) => {
+                    parts.push(format!("{}/{} remaining", remaining, total))
+                }
+                (Some(remaining), None) => parts.push(format!("{} remaining", remaining)),
+                _ => {}
+            }
+            let now = snapshot.refreshed_at.timestamp().max(0) as u64;
+            if let Some(wait) = limit.resets_in(now) {
+                parts.push(format!("resets in {}s", wait.as_secs()));
+            }
+            parts.join(" · ")
+        }
+        None => "not reported".dimmed().to_string(),
+    };
+    println!("  {:<16} {}", "Rate limit:", budget);
+    if snapshot.rate_limited > 0 {
+        println!(
+            "  {:<16} {}",
+            "",
+            format!(
+                "{} request(s) rate limited this session",
+                snapshot.rate_limited
+            )
+            .yellow()
+        );
+    }
+
+    println!("{}", "─".repeat(width).dimmed());
+    let depth = |n: usize, hint: &str| {
+        if n > 0 {
+            format!("{} {}", n.to_string().yellow().bold(), hint.dimmed())
+        } else {
+            "0".green().to_string()
+        }
+    };
+    println!(
+        "  {:<16} {}",
+        "Outbox:",
+        depth(snapshot.gauges.outbox_depth, "(moltbook outbox flush)")
+    );
+    println!(
+        "  {:<16} {}",
+        "Pending actions:",
+        depth(
+            snapshot.gauges.pending_actions,
+            "(moltbook pending --replay)"
+        )
+    );
+}
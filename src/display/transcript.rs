@@ -0,0 +1,114 @@
+use crate::display::utils::{error, get_term_width, relative_time};
+use crate::transcript::{Exchange, Transcript};
+use colored::*;
+
+/// Shows a recorded run: the command, its output as it was printed and any
+/// error, followed by the API exchanges when `exchanges` is set.
+pub fn display_transcript(transcript: &Transcript, exchanges: bool) {
+    let width = get_term_width().min(80);
+    let command = transcript
+        .command
+        .iter()
+        .skip(1)
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(" ");
+    println!(
+        "{} {}",
+        "▶ moltbook".bright_green().bold(),
+        command.bright_white().bold()
+    );
+    println!(
+        "{}",
+        format!(
+            "Recorded {} with v{}, {} API request{}",
+            relative_time(&transcript.recorded_at),
+            transcript.version,
+            transcript.exchanges.len(),
+            if transcript.exchanges.len() == 1 {
+                ""
+            } else {
+                "s"
+            }
+        )
+        .dimmed()
+    );
+    println!("{}", "─".repeat(width).dimmed());
+
+    // The colors recorded are only replayed where colors would be shown now.
+    if colored::control::SHOULD_COLORIZE.should_colorize() {
+        print!("{}", transcript.output);
+    } else {
+        print!("{}", strip_ansi(&transcript.output));
+    }
+    if let Some(message) = &transcript.error {
+        error(message);
+    }
+
+    if exchanges {
+        println!("{}", "─".repeat(width).dimmed());
+        println!("{}", "API Exchanges".bright_green().bold());
+        for (i, exchange) in transcript.exchanges.iter().enumerate() {
+            display_exchange(i + 1, exchange);
+        }
+    }
+}
+
+fn display_exchange(number: usize, exchange: &Exchange) {
+    let status = match exchange.status {
+        Some(status) if (200..300).contains(&status) => status.to_string().green(),
+        Some(status) => status.to_string().red(),
+        None => "no response".red(),
+    };
+    println!(
+        "\n{} {} {} → {}",
+        format!("#{}", number).dimmed(),
+        exchange.method.cyan(),
+        exchange.url,
+        status
+    );
+    for (label, body) in [
+        ("Request:", &exchange.request),
+        ("Response:", &exchange.response),
+    ] {
+        if let Some(body) = body {
+            println!("  {}", label.dimmed());
+            let body = serde_json::to_string_pretty(body).unwrap_or_default();
+            for line in body.lines() {
+                println!("    {}", line);
+            }
+        }
+    }
+}
+
+/// Removes ANSI escape sequences such as color codes.
+fn strip_ansi(text: &str) -> String {
+    let mut plain = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            // Skip `ESC [`, the parameters and the final letter.
+            if chars.next() == Some('[') {
+                for c in chars.by_ref() {
+                    if c.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+            }
+        } else {
+            plain.push(c);
+        }
+    }
+    plain
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_ansi() {
+        assert_eq!(strip_ansi("\u{1b}[1;32mFeed\u{1b}[0m ok"), "Feed ok");
+        assert_eq!(strip_ansi("plain"), "plain");
+    }
+}
@@ -1,5 +1,9 @@
+use crate::api::types::SkippedItem;
+use crate::llm;
 use chrono::{DateTime, Utc};
 use colored::*;
+use std::cell::RefCell;
+use std::fmt;
 use terminal_size::{Width, terminal_size};
 
 /// Detects the available terminal width for responsive layout.
@@ -23,6 +27,25 @@ pub fn get_term_width() -> usize {
     }
 }
 
+thread_local! {
+    /// The buffer items are rendered into, reused so its capacity is kept.
+    static ITEM: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+/// Renders one list item with `render` and writes it to stdout in a single call.
+///
+/// Items are built in a shared buffer first, so a long listing costs one
+/// write per item rather than one per line, and the same allocation serves
+/// every item.
+pub fn print_item(render: impl FnOnce(&mut String) -> fmt::Result) {
+    ITEM.with_borrow_mut(|item| {
+        item.clear();
+        // Writing to a String cannot fail.
+        let _ = render(item);
+        print!("{}", item);
+    });
+}
+
 /// Formats a UTC timestamp into a human-readable relative string (e.g., "2h ago").
 ///
 /// Supports: "just now", minutes, hours, days, or YYYY-MM-DD for older items.
@@ -49,25 +72,81 @@ pub fn relative_time(timestamp: &str) -> String {
 
 /// Prints a success message with a green checkmark.
 pub fn success(msg: &str) {
+    if llm::is_enabled() {
+        println!("ok: {}", msg);
+        return;
+    }
     println!("{} {}", "✅".green(), msg.bright_green());
 }
 
 /// Prints an error message with a red cross.
 pub fn error(msg: &str) {
+    if llm::is_enabled() {
+        eprintln!("error: {}", msg);
+        return;
+    }
     eprintln!("{} {}", "❌".red().bold(), msg.bright_red());
 }
 
 /// Prints an informational message with a cyan icon.
 pub fn info(msg: &str) {
+    if llm::is_enabled() {
+        println!("info: {}", msg);
+        return;
+    }
     println!("{} {}", "ℹ️ ".cyan(), msg.bright_cyan());
 }
 
 /// Prints the cursor for the next page when more results are available.
 pub fn print_next_cursor(cursor: &str) {
+    if llm::is_enabled() {
+        println!("next_cursor: {}", cursor);
+        return;
+    }
     println!("\n{} {}", "── next page cursor:".dimmed(), cursor.bright_cyan());
 }
 
+/// Prints which numbered page was shown and the `--page` that continues it.
+pub fn print_page_hint(page: u64, pages: Option<u64>) {
+    let position = match pages {
+        Some(pages) => format!("Page {} of {}", page, pages),
+        None => format!("Page {}", page),
+    };
+    let next = format!("--page {}", page + 1);
+    if llm::is_enabled() {
+        println!("{}, run `{}` for more", position, next);
+        return;
+    }
+    println!(
+        "{} {}{}",
+        format!("── {}, run", position).dimmed(),
+        next.bright_cyan(),
+        " for more".dimmed()
+    );
+}
+
 /// Prints a warning message with a yellow triangle.
 pub fn warn(msg: &str) {
+    if llm::is_enabled() {
+        println!("warning: {}", msg);
+        return;
+    }
     println!("{} {}", "⚠️ ".yellow(), msg.bright_yellow());
 }
+
+/// Warns about list entries that were dropped because they failed to parse.
+pub fn warn_skipped(kind: &str, skipped: &[SkippedItem]) {
+    if skipped.is_empty() {
+        return;
+    }
+    warn(&format!(
+        "Skipped {} malformed {}{} from the response:",
+        skipped.len(),
+        kind,
+        if skipped.len() == 1 { "" } else { "s" }
+    ));
+    for item in skipped {
+        let id = item.id.as_deref().unwrap_or("no id");
+        println!("   #{} ({}): {}", item.index + 1, id, item.error.dimmed());
+    }
+}
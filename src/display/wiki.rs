@@ -0,0 +1,77 @@
+use crate::api::types::SubmoltWiki;
+use crate::display::utils::{get_term_width, info, relative_time};
+use colored::*;
+
+/// Renders a submolt wiki page with its edit metadata.
+pub fn display_wiki(submolt: &str, wiki: &SubmoltWiki) {
+    let width = get_term_width();
+    println!(
+        "\n{} {}",
+        "📖".cyan(),
+        format!("m/{} — About", submolt).bright_green().bold()
+    );
+    println!("{}", "━".repeat(width).dimmed());
+
+    match wiki.content.as_deref() {
+        Some(content) if !content.trim().is_empty() => display_markdown(content),
+        _ => info("This submolt has no wiki page yet."),
+    }
+
+    let mut meta = Vec::new();
+    if let Some(by) = &wiki.updated_by {
+        meta.push(format!("edited by {}", by));
+    }
+    if let Some(at) = &wiki.updated_at {
+        meta.push(relative_time(at));
+    }
+    if !meta.is_empty() {
+        println!("{}", "─".repeat(width).dimmed());
+        println!("  {}", meta.join(" • ").dimmed());
+    }
+    println!();
+}
+
+/// Renders markdown as styled terminal text.
+///
+/// Handles headings, bullet lists, block quotes and fenced code; everything
+/// else is wrapped to the terminal width as plain paragraphs.
+pub fn display_markdown(content: &str) {
+    let width = get_term_width().saturating_sub(4);
+    let mut in_code = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+
+        if trimmed.starts_with("```") {
+            in_code = !in_code;
+            println!("  {}", "┄".repeat(width.min(40)).dimmed());
+            continue;
+        }
+        if in_code {
+            println!("  {}", line.bright_black());
+            continue;
+        }
+
+        if let Some(heading) = trimmed.strip_prefix('#') {
+            let text = heading.trim_start_matches('#').trim();
+            println!("\n  {}", text.bright_cyan().bold());
+        } else if let Some(item) = trimmed
+            .strip_prefix("- ")
+            .or_else(|| trimmed.strip_prefix("* "))
+        {
+            let wrapped = textwrap::fill(item, width.saturating_sub(4));
+            for (i, l) in wrapped.lines().enumerate() {
+                let bullet = if i == 0 { "•" } else { " " };
+                println!("  {} {}", bullet.yellow(), l);
+            }
+        } else if let Some(quote) = trimmed.strip_prefix('>') {
+            println!("  {} {}", "│".dimmed(), quote.trim().italic());
+        } else if trimmed.is_empty() {
+            println!();
+        } else {
+            for l in textwrap::fill(trimmed, width).lines() {
+                println!("  {}", l);
+            }
+        }
+    }
+}
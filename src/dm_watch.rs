@@ -0,0 +1,122 @@
+//! What `dm-watch` has already reported.
+//!
+//! Each poll of the DM check is compared with `dm_watch.json` in the config
+//! directory: requests whose conversation was reported before are skipped,
+//! and unread messages are reported only when their count goes up. The record
+//! outlives the process, so a supervisor that restarts the watcher does not
+//! see the same activity twice.
+
+use crate::api::error::ApiError;
+use crate::api::types::{DmCheckResponse, DmRequest};
+use crate::state;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// The filename for the watch record.
+const WATCH_FILE: &str = "dm_watch.json";
+/// Maximum number of reported requests remembered; the oldest go first.
+const MAX_REQUESTS: usize = 500;
+
+/// Activity reported so far.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct DmWatchState {
+    /// Conversation IDs of the requests already reported, oldest first.
+    pub requests: Vec<String>,
+    /// The unread message count at the last poll.
+    pub unread: u64,
+}
+
+/// New activity found by one poll.
+#[derive(Debug, Default)]
+pub struct DmActivity {
+    /// Requests not reported before.
+    pub requests: Vec<DmRequest>,
+    /// The unread count and how much it grew, if it did.
+    pub unread: Option<(u64, u64)>,
+}
+
+impl DmActivity {
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty() && self.unread.is_none()
+    }
+}
+
+impl DmWatchState {
+    /// Loads the record, returning an empty one if none exists yet.
+    pub fn load() -> Result<Self, ApiError> {
+        state::load(&Self::path()?, "DM watch state")
+    }
+
+    /// Applies `f` to the record on disk, locked against concurrent
+    /// invocations, keeping only the `MAX_REQUESTS` most recent requests.
+    pub fn update<R>(f: impl FnOnce(&mut Self) -> R) -> Result<R, ApiError> {
+        state::update(&Self::path()?, "DM watch state", |state: &mut Self| {
+            let result = f(state);
+            let excess = state.requests.len().saturating_sub(MAX_REQUESTS);
+            state.requests.drain(..excess);
+            result
+        })
+    }
+
+    /// Returns what in `response` was not reported before, and records it.
+    pub fn observe(&mut self, response: &DmCheckResponse) -> DmActivity {
+        let mut activity = DmActivity::default();
+        let items = response.requests.iter().flat_map(|data| &data.items);
+        for request in items {
+            if !self.requests.contains(&request.conversation_id) {
+                self.requests.push(request.conversation_id.clone());
+                activity.requests.push(request.clone());
+            }
+        }
+
+        // Reading messages lowers the count, so a later rise is new again.
+        let unread = response.messages.as_ref().map_or(0, |m| m.total_unread);
+        if unread > self.unread {
+            activity.unread = Some((unread, unread - self.unread));
+        }
+        self.unread = unread;
+        activity
+    }
+
+    fn path() -> Result<PathBuf, ApiError> {
+        state::path(WATCH_FILE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn check(requests: &[&str], unread: u64) -> DmCheckResponse {
+        let items: Vec<_> = requests
+            .iter()
+            .map(|id| json!({"from": {"name": "bot"}, "conversation_id": id}))
+            .collect();
+        serde_json::from_value(json!({
+            "has_activity": true,
+            "requests": {"items": items},
+            "messages": {"total_unread": unread}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_observe() {
+        let mut state = DmWatchState::default();
+        let first = state.observe(&check(&["c1"], 2));
+        assert_eq!(first.requests.len(), 1);
+        assert_eq!(first.unread, Some((2, 2)));
+
+        // The same activity is not reported twice.
+        assert!(state.observe(&check(&["c1"], 2)).is_empty());
+
+        let next = state.observe(&check(&["c1", "c2"], 3));
+        assert_eq!(next.requests[0].conversation_id, "c2");
+        assert_eq!(next.unread, Some((3, 1)));
+
+        // After the messages are read, new ones count from zero.
+        assert!(state.observe(&check(&[], 0)).is_empty());
+        assert_eq!(state.observe(&check(&[], 1)).unread, Some((1, 1)));
+    }
+}
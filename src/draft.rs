@@ -0,0 +1,214 @@
+//! Text that is not on Moltbook yet.
+//!
+//! Replies written by a model easily run past what the API accepts, and the
+//! request is then rejected after the work of writing it. `comment` checks
+//! the length locally first and, with `--truncate`, cuts the text at the end
+//! of the last sentence that fits.
+//!
+//! Posts can also be staged in `drafts.json` in the config directory, e.g.
+//! while the post rate limit is running, and published later with
+//! `moltbook draft publish`.
+
+use crate::api::error::ApiError;
+use crate::state;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// The filename for saved post drafts.
+const DRAFTS_FILE: &str = "drafts.json";
+
+/// The longest comment the API accepts, in characters.
+pub const COMMENT_CHAR_LIMIT: usize = 10_000;
+
+/// The length of `text` in characters, as the API counts it.
+pub fn char_count(text: &str) -> usize {
+    text.chars().count()
+}
+
+/// Cuts `text` to at most `max` characters, ending after the last complete
+/// sentence that fits.
+///
+/// A sentence ends at `.`, `!` or `?` followed by whitespace, or at a blank
+/// line. When not even the first sentence fits, the text is cut at a word
+/// boundary and ends in `…`.
+pub fn truncate_at_sentence(text: &str, max: usize) -> String {
+    let text = text.trim_end();
+    if char_count(text) <= max {
+        return text.to_string();
+    }
+    let end = text.char_indices().nth(max).map_or(text.len(), |(i, _)| i);
+    let head = &text[..end];
+
+    let mut cut = None;
+    for (i, c) in head.char_indices() {
+        let after = i + c.len_utf8();
+        let ends_sentence = matches!(c, '.' | '!' | '?')
+            && text[after..].chars().next().is_none_or(char::is_whitespace);
+        let blank_line = c == '\n' && head[..i].ends_with('\n');
+        if ends_sentence || blank_line {
+            cut = Some(after);
+        }
+    }
+    if let Some(cut) = cut {
+        let cut = head[..cut].trim_end();
+        if !cut.is_empty() {
+            return cut.to_string();
+        }
+    }
+
+    let words: String = text.chars().take(max.saturating_sub(1)).collect();
+    let words = match words.rfind(char::is_whitespace) {
+        Some(i) if i > words.len() / 2 => &words[..i],
+        _ => &words,
+    };
+    format!("{}…", words.trim_end())
+}
+
+/// A post saved locally for later.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PostDraft {
+    /// Unique identifier of the draft.
+    pub id: String,
+    pub title: String,
+    pub submolt: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// When the draft was saved (RFC 3339).
+    pub created_at: String,
+    /// When the draft was last edited (RFC 3339).
+    pub updated_at: String,
+}
+
+impl PostDraft {
+    pub fn new(
+        title: String,
+        submolt: String,
+        content: Option<String>,
+        url: Option<String>,
+    ) -> Self {
+        let now = chrono::Utc::now().to_rfc3339();
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            title,
+            submolt,
+            content,
+            url,
+            created_at: now.clone(),
+            updated_at: now,
+        }
+    }
+
+    /// Short identifier shown to users: the first 8 characters of the id.
+    pub fn short_id(&self) -> &str {
+        self.id.get(..8).unwrap_or(&self.id)
+    }
+}
+
+/// Saved post drafts, oldest first.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct DraftStore {
+    pub drafts: Vec<PostDraft>,
+}
+
+impl DraftStore {
+    /// Loads the drafts, returning none if none were saved yet.
+    pub fn load() -> Result<Self, ApiError> {
+        state::load(&Self::path()?, "drafts")
+    }
+
+    /// Applies `f` to the drafts on disk, locked against concurrent invocations.
+    pub fn update<R>(f: impl FnOnce(&mut Self) -> R) -> Result<R, ApiError> {
+        state::update(&Self::path()?, "drafts", f)
+    }
+
+    /// The one draft whose id starts with `prefix`.
+    pub fn find(&self, prefix: &str) -> Result<&PostDraft, ApiError> {
+        let mut matches = self.drafts.iter().filter(|d| d.id.starts_with(prefix));
+        match (matches.next(), matches.next()) {
+            (Some(draft), None) if !prefix.is_empty() => Ok(draft),
+            (Some(_), _) => Err(ApiError::ConfigError(format!(
+                "'{}' matches several drafts; give more of the id",
+                prefix
+            ))),
+            (None, _) => Err(ApiError::ConfigError(format!(
+                "No draft '{}'. Run `moltbook draft list` to see your drafts",
+                prefix
+            ))),
+        }
+    }
+
+    /// Replaces the draft with the same id, returning whether it existed.
+    pub fn replace(&mut self, draft: PostDraft) -> bool {
+        match self.drafts.iter_mut().find(|d| d.id == draft.id) {
+            Some(saved) => {
+                *saved = draft;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes the draft with exactly this id, returning whether it existed.
+    pub fn remove(&mut self, id: &str) -> bool {
+        let before = self.drafts.len();
+        self.drafts.retain(|d| d.id != id);
+        self.drafts.len() != before
+    }
+
+    fn path() -> Result<PathBuf, ApiError> {
+        state::path(DRAFTS_FILE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_draft_store() {
+        let draft = |id: &str| PostDraft {
+            id: id.to_string(),
+            ..PostDraft::new("Title".to_string(), "general".to_string(), None, None)
+        };
+        let mut store = DraftStore {
+            drafts: vec![draft("abc12345-1"), draft("abc99999-2")],
+        };
+        assert_eq!(store.find("abc1").unwrap().id, "abc12345-1");
+        assert!(store.find("abc").is_err());
+        assert!(store.find("").is_err());
+
+        let mut edited = store.find("abc9").unwrap().clone();
+        edited.title = "Better title".to_string();
+        assert!(store.replace(edited));
+        assert_eq!(store.drafts[1].title, "Better title");
+        assert!(store.remove("abc12345-1"));
+        assert!(!store.remove("abc12345-1"));
+        assert_eq!(store.drafts.len(), 1);
+    }
+
+    #[test]
+    fn test_truncate_at_sentence() {
+        let text = "First point. Second point! A third one that runs long?";
+        assert_eq!(truncate_at_sentence(text, 100), text);
+        assert_eq!(truncate_at_sentence(text, 30), "First point. Second point!");
+        assert_eq!(truncate_at_sentence(text, 15), "First point.");
+        // Decimals and file names are not sentence ends.
+        assert_eq!(
+            truncate_at_sentence("Pi is 3.14 roughly. More text here.", 25),
+            "Pi is 3.14 roughly."
+        );
+
+        let cut = truncate_at_sentence("One very long sentence without an end", 20);
+        assert_eq!(cut, "One very long…");
+        assert!(char_count(&cut) <= 20);
+
+        let paragraphs = "A heading without a period\n\nBody text follows here";
+        assert_eq!(
+            truncate_at_sentence(paragraphs, 35),
+            "A heading without a period"
+        );
+        assert_eq!(char_count("héllo"), 5);
+    }
+}
@@ -0,0 +1,265 @@
+//! Engagement funnel reports for `moltbook engagement`.
+//!
+//! A report totals the impressions (when the API reports them), votes and
+//! comments on the agent's posts from a period, and the follower change over
+//! the same period. The API only returns the current follower count, so each
+//! report records a snapshot in `engagement_history.json` in the config
+//! directory; changes are measured against the snapshot closest to the start
+//! of the period.
+
+use crate::api::error::ApiError;
+use crate::api::types::Post;
+use crate::state;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// The filename for follower snapshots.
+const HISTORY_FILE: &str = "engagement_history.json";
+/// Maximum number of snapshots kept.
+const MAX_SNAPSHOTS: usize = 1000;
+/// Post fields that hold a view count, in the order they are tried.
+const IMPRESSION_FIELDS: [&str; 3] = ["impressions", "view_count", "views"];
+
+/// Parses a period such as `30d`, `2w` or `12h`.
+pub fn parse_period(period: &str) -> Result<TimeDelta, String> {
+    let period = period.trim();
+    let invalid = || format!("expected a number followed by h, d or w, got '{}'", period);
+    let (split, _) = period.char_indices().last().ok_or_else(invalid)?;
+    let (amount, unit) = period.split_at(split);
+    let amount: i64 = amount.parse().ok().filter(|n| *n > 0).ok_or_else(invalid)?;
+    match unit {
+        "h" => Ok(TimeDelta::hours(amount)),
+        "d" => Ok(TimeDelta::days(amount)),
+        "w" => Ok(TimeDelta::weeks(amount)),
+        _ => Err(format!("unknown unit '{}'; use h, d or w", unit)),
+    }
+}
+
+/// The view count of a post, if the API reports one.
+pub fn impressions(post: &Post) -> Option<u64> {
+    IMPRESSION_FIELDS
+        .iter()
+        .find_map(|field| post.extra.get(*field).and_then(|v| v.as_u64()))
+}
+
+/// One post's line in the report.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct PostEngagement {
+    pub id: String,
+    pub title: String,
+    pub created_at: String,
+    pub impressions: Option<u64>,
+    pub upvotes: i64,
+    pub downvotes: i64,
+    pub comments: u64,
+}
+
+/// How the follower count moved over the period.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct FollowerChange {
+    pub change: i64,
+    /// When the baseline snapshot was taken; later than the period start if
+    /// tracking began during the period.
+    pub since: String,
+}
+
+/// The engagement funnel for a period.
+#[derive(Serialize, Debug, Clone)]
+pub struct EngagementReport {
+    pub period_start: String,
+    pub period_end: String,
+    pub posts: usize,
+    /// Total impressions, if the API reported them for every post.
+    pub impressions: Option<u64>,
+    pub upvotes: i64,
+    pub downvotes: i64,
+    pub comments: u64,
+    pub followers: Option<u64>,
+    pub follower_change: Option<FollowerChange>,
+    /// Posts from the period, most engaging first.
+    pub top_posts: Vec<PostEngagement>,
+}
+
+impl EngagementReport {
+    /// Totals the posts created at or after `start`; older posts are ignored.
+    pub fn new(
+        posts: &[Post],
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        followers: Option<u64>,
+        follower_change: Option<FollowerChange>,
+    ) -> Self {
+        let mut top_posts: Vec<PostEngagement> = posts
+            .iter()
+            .filter(|p| p.tombstone().is_none() && created_at(p).is_some_and(|t| t >= start))
+            .map(|p| PostEngagement {
+                id: p.id.clone(),
+                title: p.title.clone(),
+                created_at: p.created_at.clone(),
+                impressions: impressions(p),
+                upvotes: p.upvotes,
+                downvotes: p.downvotes,
+                comments: p.comment_count.unwrap_or(0),
+            })
+            .collect();
+        top_posts.sort_by_key(|p| std::cmp::Reverse(p.upvotes - p.downvotes + p.comments as i64));
+
+        Self {
+            period_start: start.to_rfc3339(),
+            period_end: end.to_rfc3339(),
+            posts: top_posts.len(),
+            impressions: top_posts.iter().map(|p| p.impressions).sum(),
+            upvotes: top_posts.iter().map(|p| p.upvotes).sum(),
+            downvotes: top_posts.iter().map(|p| p.downvotes).sum(),
+            comments: top_posts.iter().map(|p| p.comments).sum(),
+            followers,
+            follower_change,
+            top_posts,
+        }
+    }
+}
+
+/// When a post was created, if its timestamp parses.
+pub fn created_at(post: &Post) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(&post.created_at)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+/// The follower count at one point in time.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    /// When it was taken (RFC 3339).
+    pub at: String,
+    pub followers: u64,
+}
+
+/// Follower snapshots in the order they were taken.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct EngagementHistory {
+    pub snapshots: Vec<Snapshot>,
+}
+
+impl EngagementHistory {
+    /// Loads the history, returning an empty one if none exists yet.
+    pub fn load() -> Result<Self, ApiError> {
+        state::load(&Self::path()?, "engagement history")
+    }
+
+    /// Applies `f` to the history on disk, locked against concurrent
+    /// invocations, keeping only the most recent `MAX_SNAPSHOTS`.
+    pub fn update<R>(f: impl FnOnce(&mut Self) -> R) -> Result<R, ApiError> {
+        state::update(
+            &Self::path()?,
+            "engagement history",
+            |history: &mut Self| {
+                let result = f(history);
+                let excess = history.snapshots.len().saturating_sub(MAX_SNAPSHOTS);
+                history.snapshots.drain(..excess);
+                result
+            },
+        )
+    }
+
+    pub fn record(&mut self, at: DateTime<Utc>, followers: u64) {
+        self.snapshots.push(Snapshot {
+            at: at.to_rfc3339(),
+            followers,
+        });
+    }
+
+    /// The change from the baseline for a period starting at `start` to `now`.
+    ///
+    /// The baseline is the last snapshot taken at or before `start`, or the
+    /// first one after it when tracking began during the period.
+    pub fn change_since(&self, start: DateTime<Utc>, now: u64) -> Option<FollowerChange> {
+        let dated = self.snapshots.iter().filter_map(|s| {
+            let at = DateTime::parse_from_rfc3339(&s.at)
+                .ok()?
+                .with_timezone(&Utc);
+            Some((at, s))
+        });
+        let (before, after): (Vec<_>, Vec<_>) = dated.partition(|(at, _)| *at <= start);
+        let (_, baseline) = before.last().or(after.first())?;
+        Some(FollowerChange {
+            change: now as i64 - baseline.followers as i64,
+            since: baseline.at.clone(),
+        })
+    }
+
+    fn path() -> Result<PathBuf, ApiError> {
+        state::path(HISTORY_FILE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn time(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_parse_period() {
+        assert_eq!(parse_period("30d"), Ok(TimeDelta::days(30)));
+        assert_eq!(parse_period("2w"), Ok(TimeDelta::weeks(2)));
+        assert_eq!(parse_period("12h"), Ok(TimeDelta::hours(12)));
+        assert!(parse_period("0d").is_err());
+        assert!(parse_period("30").is_err());
+        assert!(parse_period("d").is_err());
+        assert!(parse_period("3m").is_err());
+    }
+
+    #[test]
+    fn test_report_totals_posts_in_period() {
+        let posts: Vec<Post> = serde_json::from_value(json!([
+            { "id": "a", "title": "New", "author": { "name": "me" }, "upvotes": 10,
+              "downvotes": 1, "comment_count": 4, "created_at": "2025-06-20T10:00:00Z",
+              "impressions": 200 },
+            { "id": "b", "title": "Newer", "author": { "name": "me" }, "upvotes": 2,
+              "comment_count": 9, "created_at": "2025-06-25T10:00:00Z", "views": 50 },
+            { "id": "c", "title": "Old", "author": { "name": "me" }, "upvotes": 99,
+              "created_at": "2025-05-01T10:00:00Z" }
+        ]))
+        .unwrap();
+        let start = time("2025-06-01T00:00:00Z");
+        let report = EngagementReport::new(&posts, start, time("2025-07-01T00:00:00Z"), None, None);
+        assert_eq!(report.posts, 2);
+        assert_eq!(report.impressions, Some(250));
+        assert_eq!(
+            (report.upvotes, report.downvotes, report.comments),
+            (12, 1, 13)
+        );
+        assert_eq!(report.top_posts[0].id, "a");
+
+        let mut posts = posts;
+        posts[1].extra.remove("views");
+        let report = EngagementReport::new(&posts, start, time("2025-07-01T00:00:00Z"), None, None);
+        assert_eq!(report.impressions, None, "a partial total would mislead");
+    }
+
+    #[test]
+    fn test_follower_change_baseline() {
+        let mut history = EngagementHistory::default();
+        assert_eq!(history.change_since(time("2025-06-01T00:00:00Z"), 10), None);
+
+        history.record(time("2025-05-20T00:00:00Z"), 40);
+        history.record(time("2025-05-30T00:00:00Z"), 45);
+        history.record(time("2025-06-10T00:00:00Z"), 50);
+        let change = history
+            .change_since(time("2025-06-01T00:00:00Z"), 60)
+            .unwrap();
+        assert_eq!(change.change, 15);
+        assert!(change.since.starts_with("2025-05-30"));
+
+        // Tracking began after the period start: measure from the first snapshot.
+        let change = history
+            .change_since(time("2025-01-01T00:00:00Z"), 38)
+            .unwrap();
+        assert_eq!(change.change, -2);
+        assert!(change.since.starts_with("2025-05-20"));
+    }
+}
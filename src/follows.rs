@@ -0,0 +1,150 @@
+//! Follow relationship cache and audit.
+//!
+//! `moltbook follows audit` compares who the agent follows with who follows
+//! it back. Reading both lists costs a request per page, so they are cached
+//! in `relationships.json` in the config directory for [`CACHE_TTL_MINUTES`];
+//! `follow`, `unfollow` and the audit's own cleanup keep the cached lists
+//! current so they stay usable until the cache expires.
+
+use crate::api::error::ApiError;
+use crate::state;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+/// The filename for cached relationships.
+const RELATIONSHIPS_FILE: &str = "relationships.json";
+/// How long fetched lists are trusted before the audit reads them again.
+pub const CACHE_TTL_MINUTES: i64 = 60;
+
+/// The agent's followers and followed agents as last fetched.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Relationships {
+    /// The agent the lists belong to, so a changed login is not mixed up.
+    pub agent: String,
+    /// When the lists were fetched (RFC 3339).
+    pub fetched_at: String,
+    pub followers: BTreeSet<String>,
+    pub following: BTreeSet<String>,
+    /// Whether either list was cut off at the fetch limit, so agents that
+    /// follow back may be missing.
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+/// Relationships that only go one way.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Audit {
+    /// Agents followed that do not follow back.
+    pub not_following_back: Vec<String>,
+    /// Followers that are not followed back.
+    pub not_followed_back: Vec<String>,
+    pub mutual: usize,
+}
+
+impl Relationships {
+    /// Loads the cached lists, if any.
+    pub fn load() -> Result<Option<Self>, ApiError> {
+        state::read(&Self::path()?, "relationships")
+    }
+
+    /// Persists the lists.
+    pub fn save(&self) -> Result<(), ApiError> {
+        state::write(&Self::path()?, &serde_json::to_string_pretty(self)?)
+    }
+
+    /// Applies `f` to the cached lists on disk, locked against concurrent
+    /// invocations.
+    pub fn update<R>(f: impl FnOnce(&mut Self) -> R) -> Result<R, ApiError> {
+        state::update(&Self::path()?, "relationships", f)
+    }
+
+    /// Whether the lists belong to `agent` and were fetched recently enough.
+    pub fn is_fresh(&self, agent: &str, now: DateTime<Utc>) -> bool {
+        self.agent == agent
+            && DateTime::parse_from_rfc3339(&self.fetched_at).is_ok_and(|at| {
+                now.signed_duration_since(at) < Duration::minutes(CACHE_TTL_MINUTES)
+            })
+    }
+
+    pub fn audit(&self) -> Audit {
+        Audit {
+            not_following_back: self
+                .following
+                .difference(&self.followers)
+                .cloned()
+                .collect(),
+            not_followed_back: self
+                .followers
+                .difference(&self.following)
+                .cloned()
+                .collect(),
+            mutual: self.following.intersection(&self.followers).count(),
+        }
+    }
+
+    /// Records a follow or unfollow in the cached lists, if there are any.
+    ///
+    /// A cache that cannot be read or written is left for the next audit to
+    /// replace; the follow itself already succeeded.
+    pub fn note_follow(name: &str, following: bool) {
+        if !matches!(Self::load(), Ok(Some(_))) {
+            return;
+        }
+        let _ = Self::update(|cached| {
+            if following {
+                cached.following.insert(name.to_string());
+            } else {
+                cached.following.remove(name);
+            }
+        });
+    }
+
+    fn path() -> Result<PathBuf, ApiError> {
+        state::path(RELATIONSHIPS_FILE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(list: &[&str]) -> BTreeSet<String> {
+        list.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn test_audit() {
+        let relationships = Relationships {
+            agent: "me".to_string(),
+            fetched_at: String::new(),
+            followers: names(&["ann", "bo", "cy"]),
+            following: names(&["bo", "dee", "eve"]),
+            truncated: false,
+        };
+        assert_eq!(
+            relationships.audit(),
+            Audit {
+                not_following_back: vec!["dee".to_string(), "eve".to_string()],
+                not_followed_back: vec!["ann".to_string(), "cy".to_string()],
+                mutual: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_freshness() {
+        let now = DateTime::parse_from_rfc3339("2025-06-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let cached = Relationships {
+            agent: "me".to_string(),
+            fetched_at: "2025-06-10T11:30:00Z".to_string(),
+            ..Default::default()
+        };
+        assert!(cached.is_fresh("me", now));
+        assert!(!cached.is_fresh("someone_else", now));
+        assert!(!cached.is_fresh("me", now + Duration::minutes(31)));
+    }
+}
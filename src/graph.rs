@@ -0,0 +1,189 @@
+//! Follower graph export for `moltbook graph export`.
+//!
+//! The walk starts at the agent itself and follows follower and following
+//! lists breadth-first up to a fixed depth. The result is a directed graph
+//! with an edge from each follower to the agent it follows, written as
+//! Graphviz DOT or GraphML (which Gephi opens directly).
+
+use crate::api::types::Author;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A file format the graph can be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum GraphFormat {
+    Dot,
+    Graphml,
+}
+
+/// What is known about an agent in the graph.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Node {
+    /// Hops from the agent the walk started at.
+    pub depth: usize,
+    pub karma: Option<i64>,
+    pub followers: Option<u64>,
+}
+
+/// A directed follower graph.
+#[derive(Debug, Default)]
+pub struct Graph {
+    pub nodes: BTreeMap<String, Node>,
+    /// `(follower, followed)` pairs.
+    pub edges: BTreeSet<(String, String)>,
+}
+
+impl Graph {
+    /// Starts a graph at the root agent.
+    pub fn new(root: &str) -> Self {
+        let mut graph = Self::default();
+        graph.nodes.insert(root.to_string(), Node::default());
+        graph
+    }
+
+    /// Adds an agent found `depth` hops out; returns whether it is new.
+    ///
+    /// Known agents keep their depth but pick up stats they were missing.
+    pub fn add_agent(&mut self, agent: &Author, depth: usize) -> bool {
+        match self.nodes.get_mut(&agent.name) {
+            Some(node) => {
+                node.karma = node.karma.or(agent.karma);
+                node.followers = node.followers.or(agent.follower_count);
+                false
+            }
+            None => {
+                self.nodes.insert(
+                    agent.name.clone(),
+                    Node {
+                        depth,
+                        karma: agent.karma,
+                        followers: agent.follower_count,
+                    },
+                );
+                true
+            }
+        }
+    }
+
+    pub fn add_follow(&mut self, follower: &str, followed: &str) {
+        self.edges
+            .insert((follower.to_string(), followed.to_string()));
+    }
+
+    pub fn render(&self, format: GraphFormat) -> String {
+        match format {
+            GraphFormat::Dot => self.to_dot(),
+            GraphFormat::Graphml => self.to_graphml(),
+        }
+    }
+
+    /// Graphviz DOT.
+    pub fn to_dot(&self) -> String {
+        let quote = |s: &str| format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""));
+        let mut out = String::from("digraph moltbook {\n");
+        for (name, node) in &self.nodes {
+            let mut attrs = vec![format!("depth={}", node.depth)];
+            if let Some(karma) = node.karma {
+                attrs.push(format!("karma={}", karma));
+            }
+            if let Some(followers) = node.followers {
+                attrs.push(format!("followers={}", followers));
+            }
+            out.push_str(&format!("  {} [{}];\n", quote(name), attrs.join(", ")));
+        }
+        for (from, to) in &self.edges {
+            out.push_str(&format!("  {} -> {};\n", quote(from), quote(to)));
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// GraphML with depth, karma and follower counts as node attributes.
+    pub fn to_graphml(&self) -> String {
+        let mut out = String::from(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n",
+            "  <key id=\"depth\" for=\"node\" attr.name=\"depth\" attr.type=\"int\"/>\n",
+            "  <key id=\"karma\" for=\"node\" attr.name=\"karma\" attr.type=\"long\"/>\n",
+            "  <key id=\"followers\" for=\"node\" attr.name=\"followers\" attr.type=\"long\"/>\n",
+            "  <graph id=\"moltbook\" edgedefault=\"directed\">\n",
+        ));
+        for (name, node) in &self.nodes {
+            out.push_str(&format!("    <node id=\"{}\">\n", escape_xml(name)));
+            out.push_str(&format!(
+                "      <data key=\"depth\">{}</data>\n",
+                node.depth
+            ));
+            if let Some(karma) = node.karma {
+                out.push_str(&format!("      <data key=\"karma\">{}</data>\n", karma));
+            }
+            if let Some(followers) = node.followers {
+                out.push_str(&format!(
+                    "      <data key=\"followers\">{}</data>\n",
+                    followers
+                ));
+            }
+            out.push_str("    </node>\n");
+        }
+        for (from, to) in &self.edges {
+            out.push_str(&format!(
+                "    <edge source=\"{}\" target=\"{}\"/>\n",
+                escape_xml(from),
+                escape_xml(to)
+            ));
+        }
+        out.push_str("  </graph>\n</graphml>\n");
+        out
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn author(name: &str, followers: Option<u64>) -> Author {
+        serde_json::from_value(json!({ "name": name, "follower_count": followers })).unwrap()
+    }
+
+    fn sample() -> Graph {
+        let mut graph = Graph::new("me");
+        assert!(graph.add_agent(&author("crab \"bot\"", Some(3)), 1));
+        assert!(!graph.add_agent(&author("me", Some(9)), 1));
+        graph.add_follow("crab \"bot\"", "me");
+        graph.add_follow("me", "crab \"bot\"");
+        graph.add_follow("me", "crab \"bot\"");
+        graph
+    }
+
+    #[test]
+    fn test_known_agents_keep_their_depth() {
+        let graph = sample();
+        assert_eq!(graph.nodes["me"].depth, 0);
+        assert_eq!(graph.nodes["me"].followers, Some(9));
+        assert_eq!(graph.edges.len(), 2);
+    }
+
+    #[test]
+    fn test_dot() {
+        let dot = sample().to_dot();
+        assert!(dot.starts_with("digraph moltbook {\n"));
+        assert!(dot.contains("  \"crab \\\"bot\\\"\" [depth=1, followers=3];\n"));
+        assert!(dot.contains("  \"me\" -> \"crab \\\"bot\\\"\";\n"));
+    }
+
+    #[test]
+    fn test_graphml() {
+        let xml = sample().to_graphml();
+        assert!(xml.contains("<node id=\"crab &quot;bot&quot;\">"));
+        assert!(xml.contains("<edge source=\"me\" target=\"crab &quot;bot&quot;\"/>"));
+        assert_eq!(xml.matches("<edge ").count(), 2);
+    }
+}
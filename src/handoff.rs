@@ -0,0 +1,279 @@
+//! Handoff packets for the human owner.
+//!
+//! When a DM is flagged `needs_human_input`, the owner usually has none of
+//! the context the agents built up. A handoff packet collects the flagged
+//! messages, the other agent's profile, the posts linked from the
+//! conversation and the full transcript into one markdown document the owner
+//! can read without the CLI.
+
+use crate::api::types::{Agent, Message, Post};
+use crate::card::WEB_BASE;
+use crate::llm;
+
+/// Linked posts fetched for context; later links are left out.
+pub const MAX_POSTS: usize = 5;
+
+/// Characters of a linked post's content quoted in the packet.
+const POST_EXCERPT: usize = 400;
+
+/// What precedes a post ID in a link, with or without `www.`.
+const POST_LINK: &str = "moltbook.com/post/";
+
+/// Everything that goes into a handoff packet.
+#[derive(Debug, Clone)]
+pub struct Handoff<'a> {
+    pub conversation_id: &'a str,
+    /// The agent preparing the packet, whose owner it is for.
+    pub me: &'a str,
+    /// The agent on the other side of the conversation.
+    pub other: &'a str,
+    /// The other agent's profile, if it could be fetched.
+    pub profile: Option<&'a Agent>,
+    /// The conversation, oldest first.
+    pub messages: &'a [Message],
+    /// Posts linked from the conversation.
+    pub posts: &'a [Post],
+}
+
+/// The first sender in `messages` who is not `me`.
+pub fn other_agent<'a>(messages: &'a [Message], me: &str) -> Option<&'a str> {
+    messages
+        .iter()
+        .map(|m| m.sender.name.as_str())
+        .find(|name| !name.eq_ignore_ascii_case(me))
+}
+
+/// IDs of Moltbook posts linked from `messages`, in the order first seen.
+pub fn linked_posts(messages: &[Message]) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::new();
+    for message in messages {
+        let mut text = message.content.as_str();
+        while let Some(at) = text.find(POST_LINK) {
+            text = &text[at + POST_LINK.len()..];
+            let id: String = text
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+                .collect();
+            if !id.is_empty() && !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+    }
+    ids
+}
+
+impl Handoff<'_> {
+    /// Messages flagged as needing the owner's input.
+    pub fn flagged(&self) -> Vec<&Message> {
+        self.messages
+            .iter()
+            .filter(|m| m.needs_human_input)
+            .collect()
+    }
+
+    /// Renders the packet as a markdown document.
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("# Handoff: conversation with {}\n\n", self.other);
+        out.push_str(&format!(
+            "Prepared by {} for its human owner · conversation `{}` · {} messages\n",
+            self.me,
+            self.conversation_id,
+            self.messages.len()
+        ));
+
+        out.push_str("\n## Needs your input\n\n");
+        let flagged = self.flagged();
+        if flagged.is_empty() {
+            out.push_str("No message is flagged as needing human input; the whole conversation is below for review.\n");
+        }
+        for message in flagged {
+            out.push_str(&format!(
+                "**{}** · {}\n\n{}\n\n",
+                self.speaker(message),
+                message.created_at,
+                quote(&message.content)
+            ));
+        }
+
+        out.push_str(&format!("\n## About {}\n\n", self.other));
+        if let Some(desc) = self
+            .profile
+            .and_then(|p| p.description.as_deref())
+            .filter(|d| !d.trim().is_empty())
+        {
+            out.push_str(&format!("{}\n\n", quote(desc)));
+        }
+        out.push_str(&format!("- **Profile:** {}/u/{}\n", WEB_BASE, self.other));
+        match self.profile {
+            Some(agent) => {
+                let mut stats = Vec::new();
+                if let Some(karma) = agent.karma {
+                    stats.push(format!("{} karma", karma));
+                }
+                if let Some(followers) = agent.follower_count {
+                    stats.push(format!("{} followers", followers));
+                }
+                if !stats.is_empty() {
+                    out.push_str(&format!("- **Stats:** {}\n", stats.join(" · ")));
+                }
+                out.push_str(&format!(
+                    "- **Claimed by a human:** {}\n",
+                    if agent.is_claimed.unwrap_or(false) {
+                        "yes"
+                    } else {
+                        "no"
+                    }
+                ));
+                if let Some(handle) = agent.owner.as_ref().and_then(|o| o.x_handle.as_ref()) {
+                    out.push_str(&format!(
+                        "- **Human owner:** @{}\n",
+                        handle.trim_start_matches('@')
+                    ));
+                }
+            }
+            None => out.push_str("- The profile could not be loaded.\n"),
+        }
+
+        if !self.posts.is_empty() {
+            out.push_str("\n## Linked posts\n\n");
+            for post in self.posts {
+                out.push_str(&format!(
+                    "### [{}]({}/post/{})\n\n",
+                    post.title, WEB_BASE, post.id
+                ));
+                let submolt = post
+                    .submolt
+                    .as_ref()
+                    .map(|s| s.name.clone())
+                    .or_else(|| post.submolt_name.clone())
+                    .map(|s| format!("m/{} · ", s))
+                    .unwrap_or_default();
+                out.push_str(&format!(
+                    "{}by {} · {} points · {} comments\n\n",
+                    submolt,
+                    post.author.name,
+                    post.upvotes - post.downvotes,
+                    post.comment_count.unwrap_or(0)
+                ));
+                if let Some(content) = post.content.as_deref().filter(|c| !c.trim().is_empty()) {
+                    out.push_str(&format!(
+                        "{}\n\n",
+                        quote(&llm::compact(content, POST_EXCERPT))
+                    ));
+                } else if let Some(url) = &post.url {
+                    out.push_str(&format!("Link: {}\n\n", url));
+                }
+            }
+        }
+
+        out.push_str("\n## Transcript\n\n");
+        if self.messages.is_empty() {
+            out.push_str("No messages yet.\n\n");
+        }
+        for message in self.messages {
+            let flag = if message.needs_human_input {
+                " · ⚠️ needs human input"
+            } else {
+                ""
+            };
+            out.push_str(&format!(
+                "**{}** · {}{}\n\n{}\n\n",
+                self.speaker(message),
+                message.created_at,
+                flag,
+                quote(&message.content)
+            ));
+        }
+
+        out.push_str("## Replying\n\n");
+        out.push_str(&format!(
+            "- Reply as {}: `moltbook dm-send {} --message \"...\"`\n",
+            self.me, self.conversation_id
+        ));
+        out.push_str(&format!(
+            "- Read newer messages: `moltbook dm-read {}`\n",
+            self.conversation_id
+        ));
+        out
+    }
+
+    fn speaker(&self, message: &Message) -> String {
+        if message.sender.name.eq_ignore_ascii_case(self.me) {
+            format!("{} (your agent)", message.sender.name)
+        } else {
+            message.sender.name.clone()
+        }
+    }
+}
+
+/// Formats `text` as a markdown blockquote.
+fn quote(text: &str) -> String {
+    format!("> {}", text.trim().replace('\n', "\n> "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn message(sender: &str, content: &str, needs_human: bool) -> Message {
+        serde_json::from_value(json!({
+            "id": format!("m-{}", content.len()),
+            "sender": { "name": sender },
+            "content": content,
+            "needs_human_input": needs_human,
+            "created_at": "2025-06-10T12:00:00Z"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_handoff_packet() {
+        let messages = vec![
+            message(
+                "CrabBot",
+                "Saw your post https://www.moltbook.com/post/p-1 and https://moltbook.com/post/p-2.",
+                false,
+            ),
+            message(
+                "MyAgent",
+                "Thanks! See https://www.moltbook.com/post/p-1",
+                false,
+            ),
+            message(
+                "CrabBot",
+                "Can your owner approve a co-authored paper?",
+                true,
+            ),
+        ];
+        assert_eq!(other_agent(&messages, "myagent"), Some("CrabBot"));
+        assert_eq!(linked_posts(&messages), vec!["p-1", "p-2"]);
+
+        let agent: Agent = serde_json::from_value(json!({
+            "id": "a1",
+            "name": "CrabBot",
+            "description": "Summarizes research papers.",
+            "karma": 42,
+            "is_claimed": true,
+            "owner": { "x_handle": "crabowner" }
+        }))
+        .unwrap();
+        let handoff = Handoff {
+            conversation_id: "conv-1",
+            me: "MyAgent",
+            other: "CrabBot",
+            profile: Some(&agent),
+            messages: &messages,
+            posts: &[],
+        };
+        let markdown = handoff.to_markdown();
+        assert!(markdown.starts_with("# Handoff: conversation with CrabBot\n"));
+        let needs = markdown.split("## About").next().unwrap();
+        assert!(needs.contains("> Can your owner approve a co-authored paper?"));
+        assert!(!needs.contains("Saw your post"));
+        assert!(markdown.contains("- **Human owner:** @crabowner\n"));
+        assert!(markdown.contains("**MyAgent (your agent)** · 2025-06-10T12:00:00Z\n"));
+        assert!(markdown.contains("· ⚠️ needs human input\n"));
+        assert!(markdown.contains("`moltbook dm-send conv-1 --message \"...\"`"));
+    }
+}
@@ -0,0 +1,147 @@
+//! A minimal HTTP/1.1 server side for the long-running modes.
+//!
+//! `moltbook listen` and the metrics endpoint only need to read one small
+//! request per connection and answer it, so this reads the head with
+//! `httparse`, takes the body by `Content-Length` and always closes the
+//! connection after responding.
+
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// Largest request body accepted.
+const MAX_BODY: usize = 64 * 1024;
+/// Largest header block accepted.
+const MAX_HEADERS: usize = 8 * 1024;
+/// Time a caller gets to send its whole request.
+pub const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// An inbound request, reduced to what the handlers need.
+#[derive(Debug)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    /// Header names and values; look them up with [`Request::header`].
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Request {
+    /// The value of the first header named `name`, ignoring case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Reads one request, or returns the status to reject it with.
+pub async fn read_request<R: AsyncRead + Unpin>(stream: &mut R) -> Result<Request, u16> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    loop {
+        let n = stream.read(&mut chunk).await.map_err(|_| 400u16)?;
+        if n == 0 {
+            return Err(400);
+        }
+        buf.extend_from_slice(&chunk[..n]);
+
+        let mut headers = [httparse::EMPTY_HEADER; 32];
+        let mut parsed = httparse::Request::new(&mut headers);
+        let header_len = match parsed.parse(&buf) {
+            Ok(httparse::Status::Complete(len)) => len,
+            Ok(httparse::Status::Partial) if buf.len() <= MAX_HEADERS => continue,
+            Ok(httparse::Status::Partial) => return Err(431),
+            Err(_) => return Err(400),
+        };
+
+        let mut request = Request {
+            method: parsed.method.unwrap_or_default().to_string(),
+            path: parsed.path.unwrap_or_default().to_string(),
+            headers: parsed
+                .headers
+                .iter()
+                .filter_map(|h| {
+                    let value = std::str::from_utf8(h.value).ok()?;
+                    Some((h.name.to_string(), value.trim().to_string()))
+                })
+                .collect(),
+            body: buf[header_len..].to_vec(),
+        };
+        let length = match request.header("content-length") {
+            Some(value) => value.parse::<usize>().map_err(|_| 400u16)?,
+            None => 0,
+        };
+        if length > MAX_BODY {
+            return Err(413);
+        }
+
+        while request.body.len() < length {
+            let n = stream.read(&mut chunk).await.map_err(|_| 400u16)?;
+            if n == 0 {
+                return Err(400);
+            }
+            request.body.extend_from_slice(&chunk[..n]);
+        }
+        request.body.truncate(length);
+        return Ok(request);
+    }
+}
+
+/// Writes a complete response and closes the connection.
+pub async fn write_response<W: AsyncWrite + Unpin>(
+    stream: &mut W,
+    status: u16,
+    content_type: &str,
+    body: &str,
+) -> std::io::Result<()> {
+    let response = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\n\
+         Connection: close\r\n\r\n{}",
+        status,
+        reason(status),
+        content_type,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+/// The reason phrase for the statuses these servers send.
+pub fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        413 => "Payload Too Large",
+        422 => "Unprocessable Entity",
+        429 => "Too Many Requests",
+        431 => "Request Header Fields Too Large",
+        _ => "Bad Gateway",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_read_request() {
+        let raw = b"POST /hooks/deploy HTTP/1.1\r\nHost: x\r\nContent-Length: 2\r\n\
+                    X-Moltbook-Signature: sha256=ab\r\n\r\n{}trailing";
+        let request = read_request(&mut &raw[..]).await.unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/hooks/deploy");
+        assert_eq!(request.header("x-moltbook-signature"), Some("sha256=ab"));
+        assert_eq!(request.body, b"{}");
+
+        let truncated = b"POST / HTTP/1.1\r\nContent-Length: 10\r\n\r\n{}";
+        assert_eq!(read_request(&mut &truncated[..]).await.unwrap_err(), 400);
+        let huge = b"POST / HTTP/1.1\r\nContent-Length: 999999\r\n\r\n";
+        assert_eq!(read_request(&mut &huge[..]).await.unwrap_err(), 413);
+    }
+}
@@ -0,0 +1,145 @@
+//! Cached profile details for agent lists rendered with `--hydrate`.
+//!
+//! Follower audits, moderator lists and search results only name agents.
+//! With `--hydrate` their profiles are fetched to show karma and the owner
+//! (and, for moderators, the avatar) next to each name. Summaries are kept in
+//! `profile_cache.json` in the config directory for [`PROFILE_TTL_MINUTES`]
+//! so repeated listings stay cheap.
+
+use crate::api::error::ApiError;
+use crate::api::types::Agent;
+use crate::state;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// The filename for cached profile summaries.
+const PROFILE_CACHE_FILE: &str = "profile_cache.json";
+/// How long a fetched profile is shown without fetching it again.
+pub const PROFILE_TTL_MINUTES: i64 = 15;
+
+/// The parts of a profile shown next to an agent's name.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AgentSummary {
+    pub karma: Option<i64>,
+    /// The owner's X handle, without the `@`.
+    pub owner: Option<String>,
+    pub is_claimed: Option<bool>,
+    #[serde(default)]
+    pub avatar_url: Option<String>,
+    /// When the profile was fetched (RFC 3339).
+    pub fetched_at: String,
+}
+
+impl AgentSummary {
+    pub fn new(agent: &Agent, now: DateTime<Utc>) -> Self {
+        Self {
+            karma: agent.karma,
+            owner: agent.owner.as_ref().and_then(|o| o.x_handle.clone()),
+            is_claimed: agent.is_claimed,
+            avatar_url: agent.avatar_url.clone(),
+            fetched_at: now.to_rfc3339(),
+        }
+    }
+
+    fn is_fresh(&self, now: DateTime<Utc>) -> bool {
+        DateTime::parse_from_rfc3339(&self.fetched_at)
+            .is_ok_and(|at| now.signed_duration_since(at) < Duration::minutes(PROFILE_TTL_MINUTES))
+    }
+
+    /// A short label such as `karma 120 · owner @alice`.
+    pub fn label(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(karma) = self.karma {
+            parts.push(format!("karma {}", karma));
+        }
+        match (&self.owner, self.is_claimed) {
+            (Some(owner), _) => parts.push(format!("owner @{}", owner)),
+            (None, Some(false)) => parts.push("unclaimed".to_string()),
+            _ => {}
+        }
+        parts.join(" · ")
+    }
+}
+
+/// Profile summaries by agent name.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct ProfileCache {
+    pub agents: BTreeMap<String, AgentSummary>,
+}
+
+impl ProfileCache {
+    /// Loads the cache, returning an empty one if none exists yet.
+    pub fn load() -> Result<Self, ApiError> {
+        state::load(&Self::path()?, "profile cache")
+    }
+
+    /// Applies `f` to the cache on disk, locked against concurrent
+    /// invocations, dropping entries expired by `now`.
+    pub fn update<R>(now: DateTime<Utc>, f: impl FnOnce(&mut Self) -> R) -> Result<R, ApiError> {
+        state::update(&Self::path()?, "profile cache", |cache: &mut Self| {
+            let result = f(cache);
+            cache.agents.retain(|_, summary| summary.is_fresh(now));
+            result
+        })
+    }
+
+    /// The cached summary for `name`, if it has not expired.
+    pub fn fresh(&self, name: &str, now: DateTime<Utc>) -> Option<&AgentSummary> {
+        self.agents
+            .get(name)
+            .filter(|summary| summary.is_fresh(now))
+    }
+
+    pub fn insert(&mut self, name: &str, summary: AgentSummary) {
+        self.agents.insert(name.to_string(), summary);
+    }
+
+    fn path() -> Result<PathBuf, ApiError> {
+        state::path(PROFILE_CACHE_FILE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-06-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_summary_label() {
+        let agent: Agent = serde_json::from_value(json!({
+            "id": "1",
+            "name": "bot",
+            "karma": 120,
+            "is_claimed": true,
+            "owner": { "x_handle": "alice" }
+        }))
+        .unwrap();
+        assert_eq!(
+            AgentSummary::new(&agent, now()).label(),
+            "karma 120 · owner @alice"
+        );
+
+        let unclaimed: Agent =
+            serde_json::from_value(json!({ "id": "2", "name": "new", "is_claimed": false }))
+                .unwrap();
+        assert_eq!(AgentSummary::new(&unclaimed, now()).label(), "unclaimed");
+    }
+
+    #[test]
+    fn test_entries_expire() {
+        let agent: Agent = serde_json::from_value(json!({ "id": "1", "name": "bot" })).unwrap();
+        let mut cache = ProfileCache::default();
+        cache.insert("bot", AgentSummary::new(&agent, now()));
+        assert!(cache.fresh("bot", now() + Duration::minutes(5)).is_some());
+        let later = now() + Duration::minutes(PROFILE_TTL_MINUTES);
+        assert!(cache.fresh("bot", later).is_none());
+    }
+}
@@ -0,0 +1,184 @@
+//! Idempotency keys and the in-flight action journal.
+//!
+//! Mutating POSTs (posts, comments, DMs) carry an `Idempotency-Key` header so
+//! that a retry after a timeout cannot apply the action twice. Before each
+//! request the key, endpoint and body are written to `pending_actions.json` in
+//! the config directory and removed once the server answers. An entry that
+//! survives means the process died or lost the connection before learning the
+//! outcome; `moltbook pending --replay` re-sends it with the same key so the
+//! server can report whether it already landed.
+
+use crate::api::client::MoltbookClient;
+use crate::api::error::ApiError;
+use crate::outbox::{OutboxEntry, OutboxStore, is_retryable};
+use crate::state;
+use chrono::{DateTime, Duration, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::PathBuf;
+
+/// The filename for the in-flight action journal.
+const PENDING_FILE: &str = "pending_actions.json";
+/// How long an unresolved action is kept before it is pruned.
+const PENDING_TTL_HOURS: i64 = 24;
+
+/// Generates a fresh idempotency key.
+pub fn new_key() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+/// A mutation whose outcome has not been confirmed by the server.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PendingAction {
+    /// The idempotency key sent with the request.
+    pub key: String,
+    /// The API endpoint the request was sent to.
+    pub endpoint: String,
+    /// The JSON body of the request.
+    pub body: Value,
+    /// When the request was first attempted (RFC 3339).
+    pub started_at: String,
+}
+
+/// All unresolved actions, persisted between invocations.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct PendingStore {
+    pub actions: Vec<PendingAction>,
+}
+
+impl PendingStore {
+    /// Loads the journal, returning an empty one if none exists yet.
+    pub fn load() -> Result<Self, ApiError> {
+        state::load(&Self::path()?, "pending actions")
+    }
+
+    /// Persists the journal.
+    pub fn save(&self) -> Result<(), ApiError> {
+        state::write(&Self::path()?, &serde_json::to_string_pretty(self)?)
+    }
+
+    /// Applies `f` to the journal on disk, locked against concurrent invocations.
+    pub fn update<R>(f: impl FnOnce(&mut Self) -> R) -> Result<R, ApiError> {
+        state::update(&Self::path()?, "pending actions", f)
+    }
+
+    /// Removes the action with the given key, returning whether it existed.
+    pub fn finish(&mut self, key: &str) -> bool {
+        let before = self.actions.len();
+        self.actions.retain(|a| a.key != key);
+        self.actions.len() != before
+    }
+
+    /// Drops actions older than the retention window.
+    pub fn prune(&mut self, now: DateTime<Utc>) {
+        let cutoff = now - Duration::hours(PENDING_TTL_HOURS);
+        self.actions.retain(|a| {
+            DateTime::parse_from_rfc3339(&a.started_at)
+                .map(|t| t.with_timezone(&Utc) >= cutoff)
+                .unwrap_or(false)
+        });
+    }
+
+    fn path() -> Result<PathBuf, ApiError> {
+        state::path(PENDING_FILE)
+    }
+}
+
+/// Whether an error leaves the outcome of a request unknown.
+///
+/// Only transport failures qualify; any HTTP response means the server decided.
+pub fn is_outcome_unknown(error: &ApiError) -> bool {
+    matches!(error, ApiError::RequestFailed(_) | ApiError::Timeout(_))
+}
+
+/// Sends a mutating POST with a fresh idempotency key, journaling it while in flight.
+///
+/// The journal is best-effort: failing to read or write it never blocks the
+/// request itself. When the client has the outbox enabled, an undeliverable
+/// request is queued instead and [`ApiError::Queued`] is returned.
+pub async fn post_tracked<T: DeserializeOwned>(
+    client: &MoltbookClient,
+    endpoint: &str,
+    body: &Value,
+) -> Result<T, ApiError> {
+    let key = new_key();
+
+    let _ = PendingStore::update(|store| {
+        store.prune(Utc::now());
+        store.actions.push(PendingAction {
+            key: key.clone(),
+            endpoint: endpoint.to_string(),
+            body: body.clone(),
+            started_at: Utc::now().to_rfc3339(),
+        });
+    });
+
+    let result = client.post_idempotent(endpoint, body, &key).await;
+
+    if let Err(e) = &result
+        && client.outbox_enabled()
+        && is_retryable(e)
+    {
+        let entry = OutboxEntry {
+            key: key.clone(),
+            endpoint: endpoint.to_string(),
+            body: body.clone(),
+            queued_at: Utc::now().to_rfc3339(),
+            reason: e.to_string(),
+        };
+        let id = entry.short_id().to_string();
+        OutboxStore::update(|outbox| outbox.entries.push(entry))?;
+        forget(&key);
+        return Err(ApiError::Queued(id));
+    }
+
+    let settled = match &result {
+        Ok(_) => true,
+        Err(e) => !is_outcome_unknown(e),
+    };
+    if settled {
+        forget(&key);
+    }
+    result
+}
+
+/// Removes an action from the journal, best-effort.
+fn forget(key: &str) {
+    let _ = PendingStore::update(|store| store.finish(key));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn action(key: &str, started_at: DateTime<Utc>) -> PendingAction {
+        PendingAction {
+            key: key.to_string(),
+            endpoint: "/posts".to_string(),
+            body: serde_json::json!({}),
+            started_at: started_at.to_rfc3339(),
+        }
+    }
+
+    #[test]
+    fn test_prune_and_finish() {
+        let now = Utc::now();
+        let mut store = PendingStore {
+            actions: vec![
+                action("old", now - Duration::hours(30)),
+                action("recent", now - Duration::minutes(5)),
+            ],
+        };
+        store.prune(now);
+        assert_eq!(store.actions.len(), 1);
+        assert!(store.finish("recent"));
+        assert!(!store.finish("recent"));
+        assert!(store.actions.is_empty());
+    }
+
+    #[test]
+    fn test_new_keys_are_unique() {
+        assert_ne!(new_key(), new_key());
+    }
+}
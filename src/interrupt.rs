@@ -0,0 +1,78 @@
+//! Graceful Ctrl-C handling.
+//!
+//! Once [`install`] has run, the first Ctrl-C does not kill the process.
+//! It sets a flag and wakes everything waiting in [`cancelled`], so long
+//! operations (`--all` pagination, batch votes, `outbox flush`, `watch`)
+//! can drop their in-flight request, save their state and show what they
+//! got before returning [`ApiError::Interrupted`]. Commands that never look
+//! at the flag are given [`GRACE`] to finish; after that, or on a second
+//! Ctrl-C, the process exits immediately. Either way the exit code is
+//! [`EXIT_CODE`].
+//!
+//! State files are replaced atomically (see [`crate::state`]), so even an
+//! immediate exit never leaves one half-written.
+
+use crate::api::error::ApiError;
+use colored::Colorize;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::sync::Notify;
+
+/// The exit code after an interruption, as a shell reports death by SIGINT.
+pub const EXIT_CODE: i32 = 130;
+/// How long a command has to wind down after the first Ctrl-C.
+pub const GRACE: Duration = Duration::from_secs(5);
+
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+static NOTIFY: Notify = Notify::const_new();
+
+/// Starts listening for Ctrl-C. Call once, from within the runtime.
+pub fn install() {
+    tokio::spawn(async {
+        if tokio::signal::ctrl_c().await.is_err() {
+            return;
+        }
+        INTERRUPTED.store(true, Ordering::SeqCst);
+        NOTIFY.notify_waiters();
+        eprintln!(
+            "\n{}",
+            "⏹  Interrupted; finishing up (Ctrl-C again to quit now)".yellow()
+        );
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => {}
+            _ = tokio::time::sleep(GRACE) => {}
+        }
+        std::process::exit(EXIT_CODE);
+    });
+}
+
+/// Whether Ctrl-C has been pressed.
+pub fn requested() -> bool {
+    INTERRUPTED.load(Ordering::SeqCst)
+}
+
+/// Completes once Ctrl-C is pressed; never, if [`install`] was not called.
+pub async fn cancelled() {
+    // Registered before the flag is checked, so a press in between still wakes us.
+    let notified = NOTIFY.notified();
+    if requested() {
+        return;
+    }
+    notified.await;
+}
+
+/// Runs `future` unless Ctrl-C is pressed first, in which case it is dropped
+/// (aborting any request it has in flight) and [`ApiError::Interrupted`] is
+/// returned.
+pub async fn or_cancel<T>(future: impl Future<Output = T>) -> Result<T, ApiError> {
+    tokio::select! {
+        output = future => Ok(output),
+        _ = cancelled() => Err(ApiError::Interrupted),
+    }
+}
+
+/// Sleeps for `duration`, waking early if Ctrl-C is pressed.
+pub async fn sleep(duration: Duration) {
+    let _ = or_cancel(tokio::time::sleep(duration)).await;
+}
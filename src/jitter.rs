@@ -0,0 +1,122 @@
+//! Randomized pauses between batch actions.
+//!
+//! Commands that act on many items in a row (`upvote --batch`, `import-rss`,
+//! `outbox flush`, `pending --replay`, `follows audit` cleanup) otherwise
+//! fire requests at a steady cadence, a pattern anti-bot heuristics flag
+//! even when it stays under the rate limit. With jitter enabled each
+//! action waits a uniformly random delay within a configured range first.
+
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// The range used by a bare `--jitter`.
+pub const DEFAULT_RANGE: &str = "1s-5s";
+/// Longest delay accepted as the upper bound.
+pub const MAX_DELAY: Duration = Duration::from_secs(300);
+
+/// A range of delays to pick from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Jitter {
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Jitter {
+    /// A random delay between `min` and `max`, inclusive.
+    pub fn sample(&self) -> Duration {
+        let min = self.min.as_millis() as u64;
+        let max = self.max.as_millis() as u64;
+        Duration::from_millis(fastrand::u64(min..=max))
+    }
+}
+
+impl FromStr for Jitter {
+    type Err = String;
+
+    /// Parses `MIN-MAX` (e.g. `500ms-3s`, `2-8s`) or a single upper bound
+    /// (`5s`, meaning `0s-5s`).
+    ///
+    /// A bare number takes the unit of the upper bound, or seconds.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (min, max) = s.split_once('-').unwrap_or(("0", s));
+        let (max, unit) = parse_delay(max, "s")?;
+        let (min, _) = parse_delay(min, unit)?;
+        if min > max {
+            return Err(format!(
+                "jitter minimum {:?} is above the maximum {:?}",
+                min, max
+            ));
+        }
+        if max > MAX_DELAY {
+            return Err(format!(
+                "jitter maximum {:?} is above the {}s limit",
+                max,
+                MAX_DELAY.as_secs()
+            ));
+        }
+        Ok(Self { min, max })
+    }
+}
+
+impl fmt::Display for Jitter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}ms-{}ms", self.min.as_millis(), self.max.as_millis())
+    }
+}
+
+/// Parses `500ms`, `3s` or a bare number in `default_unit`; returns the
+/// delay and the unit it was given in.
+fn parse_delay<'a>(s: &'a str, default_unit: &'a str) -> Result<(Duration, &'a str), String> {
+    let s = s.trim();
+    let (number, unit) = if let Some(n) = s.strip_suffix("ms") {
+        (n, "ms")
+    } else if let Some(n) = s.strip_suffix('s') {
+        (n, "s")
+    } else {
+        (s, default_unit)
+    };
+    let value: u64 = number
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid jitter delay '{}' (expected e.g. 500ms or 3s)", s))?;
+    let delay = if unit == "ms" {
+        Duration::from_millis(value)
+    } else {
+        Duration::from_secs(value)
+    };
+    Ok((delay, unit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_parse() {
+        let parse = |s: &str| s.parse::<Jitter>().map(|j| (j.min, j.max));
+        assert_eq!(parse("500ms-3s"), Ok((ms(500), ms(3000))));
+        assert_eq!(parse("2-8s"), Ok((ms(2000), ms(8000))));
+        assert_eq!(parse("200-900ms"), Ok((ms(200), ms(900))));
+        assert_eq!(parse("5s"), Ok((ms(0), ms(5000))));
+        assert_eq!(parse(DEFAULT_RANGE), Ok((ms(1000), ms(5000))));
+        assert!(parse("8s-2s").is_err());
+        assert!(parse("1s-10m").is_err());
+        assert!(parse("1s-301s").is_err());
+    }
+
+    #[test]
+    fn test_sample_stays_in_range() {
+        let jitter: Jitter = "100ms-200ms".parse().unwrap();
+        for _ in 0..100 {
+            let delay = jitter.sample();
+            assert!(delay >= ms(100) && delay <= ms(200));
+        }
+        assert_eq!(jitter.to_string().parse(), Ok(jitter));
+    }
+}
@@ -3,7 +3,64 @@
 //! This crate provides the core logic and components for interacting with the Moltbook API,
 //! including account management, post creation, direct messaging, and community engagement.
 
+pub mod activity;
+pub mod alias;
 pub mod api;
+pub mod approval;
+pub mod avatar;
+pub mod batch;
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod best_time;
+pub mod bridge;
+pub mod budget;
+pub mod capabilities;
+#[cfg(unix)]
+pub mod capture;
+pub mod card;
 pub mod cli;
+pub mod color;
+pub mod compare;
+pub mod completeness;
 pub mod config;
+pub mod content_hash;
+pub mod dedupe;
+pub mod diff;
+pub mod digest;
+pub mod discover;
 pub mod display;
+pub mod dm_watch;
+pub mod draft;
+pub mod engagement;
+pub mod follows;
+pub mod graph;
+pub mod handoff;
+pub mod http;
+pub mod hydrate;
+pub mod idempotency;
+pub mod interrupt;
+pub mod jitter;
+pub mod llm;
+pub mod media;
+pub mod metrics;
+pub mod ordering;
+pub mod outbox;
+pub mod policy;
+pub mod registration;
+pub mod rss;
+pub mod search;
+pub mod state;
+pub mod submolt_name;
+pub mod submolt_watch;
+pub mod subscriptions;
+pub mod supervise;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod transcript;
+#[cfg(feature = "tui")]
+pub mod tui;
+pub mod uploads;
+pub mod verification;
+pub mod visits;
+pub mod webhook;
+pub mod workspace;
@@ -0,0 +1,273 @@
+//! Compact plain-text output for `--llm`.
+//!
+//! Agents often paste CLI output straight into a model's context window,
+//! where box drawing, colors and terminal-width wrapping only cost tokens.
+//! In this mode each item becomes a `kind key=value ...` line followed by
+//! labelled `title:` / `content:` lines, with whitespace collapsed and text
+//! capped at a fixed length so one long post cannot crowd out the rest.
+
+use crate::api::types::{Conversation, Message, Post, SearchResult, Tombstone};
+use crate::content_hash;
+use serde_json::Value;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Longest title kept, in characters.
+pub const MAX_TITLE_CHARS: usize = 200;
+/// Longest post, comment or message body kept, in characters.
+pub const MAX_CONTENT_CHARS: usize = 500;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Switches the display functions to the compact format.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Collapses whitespace to single spaces and cuts `text` to `max` characters,
+/// noting how many were dropped.
+pub fn compact(text: &str, max: usize) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let total = text.chars().count();
+    if total <= max {
+        return text;
+    }
+    let kept: String = text.chars().take(max).collect();
+    format!("{}… [+{} chars]", kept.trim_end(), total - max)
+}
+
+/// A post: header line, title, content (or its tombstone) and link.
+///
+/// `index` numbers list entries; `similar` counts collapsed near-duplicates.
+pub fn post(post: &Post, index: Option<usize>, similar: usize) -> String {
+    let mut header = match index {
+        Some(i) => format!("post #{} id={}", i, post.id),
+        None => format!("post id={}", post.id),
+    };
+    let submolt = post
+        .submolt
+        .as_ref()
+        .map(|s| s.name.as_str())
+        .or(post.submolt_name.as_deref())
+        .unwrap_or("unknown");
+    header.push_str(&format!(
+        " submolt={} author={} up={} down={} comments={}",
+        submolt,
+        post.author.name,
+        post.upvotes,
+        post.downvotes,
+        post.comment_count.unwrap_or(0)
+    ));
+    if !post.created_at.is_empty() {
+        header.push_str(&format!(" created={}", post.created_at));
+    }
+    if post.is_pinned.unwrap_or(false) {
+        header.push_str(" pinned=true");
+    }
+    if post.is_locked.unwrap_or(false) {
+        header.push_str(" locked=true");
+    }
+    if similar > 1 {
+        header.push_str(&format!(" similar={}", similar));
+    }
+    header.push_str(&format!(" hash={}", content_hash::post_hash(post)));
+
+    let mut out = format!(
+        "{}\ntitle: {}\n",
+        header,
+        compact(&post.title, MAX_TITLE_CHARS)
+    );
+    match post.tombstone() {
+        Some(tombstone) => out.push_str(&format!("content: {}\n", tombstone_text(&tombstone))),
+        None => {
+            if let Some(content) = post.content.as_deref().filter(|c| !c.trim().is_empty()) {
+                out.push_str(&format!(
+                    "content: {}\n",
+                    compact(content, MAX_CONTENT_CHARS)
+                ));
+            }
+            if let Some(url) = &post.url {
+                out.push_str(&format!("url: {}\n", url));
+            }
+        }
+    }
+    out
+}
+
+/// A comment without its replies; `depth` is 0 for top-level comments.
+pub fn comment(comment: &Value, depth: usize) -> String {
+    let mut header = format!(
+        "comment id={} author={} up={} depth={}",
+        comment["id"].as_str().unwrap_or("unknown"),
+        comment["author"]["name"].as_str().unwrap_or("unknown"),
+        comment["upvotes"].as_i64().unwrap_or(0),
+        depth
+    );
+    if let Some(parent) = comment["parent_id"].as_str() {
+        header.push_str(&format!(" parent={}", parent));
+    }
+    if let Some(created) = comment["created_at"].as_str() {
+        header.push_str(&format!(" created={}", created));
+    }
+    header.push_str(&format!(" hash={}", content_hash::comment_hash(comment)));
+    let content = match Tombstone::of_comment(comment) {
+        Some(tombstone) => tombstone_text(&tombstone),
+        None => compact(comment["content"].as_str().unwrap_or(""), MAX_CONTENT_CHARS),
+    };
+    format!("{}\ncontent: {}\n", header, content)
+}
+
+/// A search hit.
+pub fn search_result(result: &SearchResult, index: usize) -> String {
+    let mut header = format!(
+        "result #{} type={} id={} author={}",
+        index, result.result_type, result.id, result.author.name
+    );
+    if let Some(post_id) = &result.post_id {
+        header.push_str(&format!(" post={}", post_id));
+    }
+    if let Some(similarity) = result.similarity {
+        header.push_str(&format!(" similarity={:.2}", similarity));
+    }
+    header.push_str(&format!(
+        " hash={}",
+        content_hash::search_result_hash(result)
+    ));
+    let mut out = format!("{}\n", header);
+    if let Some(title) = &result.title {
+        out.push_str(&format!("title: {}\n", compact(title, MAX_TITLE_CHARS)));
+    }
+    if let Some(content) = &result.content {
+        out.push_str(&format!(
+            "content: {}\n",
+            compact(content, MAX_CONTENT_CHARS)
+        ));
+    }
+    out
+}
+
+/// A conversation in the DM list.
+pub fn conversation(conv: &Conversation) -> String {
+    let mut out = format!(
+        "conversation id={} with={} unread={}",
+        conv.conversation_id, conv.with_agent.name, conv.unread_count
+    );
+    if let Some(at) = conv.last_activity() {
+        out.push_str(&format!(" last={}", at));
+    }
+    out.push('\n');
+    if let Some(last) = &conv.last_message {
+        out.push_str(&format!(
+            "last: {}\n",
+            compact(&last.content, MAX_TITLE_CHARS)
+        ));
+    }
+    out
+}
+
+/// A direct message; messages sent by `my_name` are attributed to `you`.
+pub fn message(msg: &Message, my_name: &str) -> String {
+    let from = if msg.sender.name == my_name {
+        "you"
+    } else {
+        msg.sender.name.as_str()
+    };
+    let mut header = format!("message from={}", from);
+    if !msg.id.is_empty() {
+        header.push_str(&format!(" id={}", msg.id));
+    }
+    if !msg.created_at.is_empty() {
+        header.push_str(&format!(" created={}", msg.created_at));
+    }
+    let reactions: Vec<String> = msg
+        .reaction_counts()
+        .iter()
+        .map(|(emoji, count)| format!("{}x{}", emoji, count))
+        .collect();
+    if !reactions.is_empty() {
+        header.push_str(&format!(" reactions={}", reactions.join(",")));
+    }
+    if msg.needs_human_input {
+        header.push_str(" needs_human=true");
+    }
+    format!(
+        "{}\ncontent: {}\n",
+        header,
+        compact(&msg.content, MAX_CONTENT_CHARS)
+    )
+}
+
+fn tombstone_text(tombstone: &Tombstone) -> String {
+    match tombstone {
+        Tombstone::Deleted => "[deleted by author]".to_string(),
+        Tombstone::Removed(Some(reason)) => {
+            format!(
+                "[removed by moderators: {}]",
+                compact(reason, MAX_TITLE_CHARS)
+            )
+        }
+        Tombstone::Removed(None) => "[removed by moderators]".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_compact() {
+        assert_eq!(compact("  two\n\nlines\t here ", 100), "two lines here");
+        assert_eq!(compact("abcdef ghij", 7), "abcdef… [+4 chars]");
+        assert_eq!(compact("ééééé", 5), "ééééé");
+    }
+
+    #[test]
+    fn test_post() {
+        let post: Post = serde_json::from_value(json!({
+            "id": "p1",
+            "title": "Hello\nworld",
+            "content": "x".repeat(MAX_CONTENT_CHARS + 10),
+            "upvotes": 3,
+            "downvotes": 1,
+            "comment_count": 2,
+            "created_at": "2025-06-01T10:00:00Z",
+            "author": { "name": "CrabBot" },
+            "submolt": { "name": "rust" }
+        }))
+        .unwrap();
+        let text = super::post(&post, Some(1), 1);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(
+            lines[0],
+            format!(
+                "post #1 id=p1 submolt=rust author=CrabBot up=3 down=1 comments=2 \
+                 created=2025-06-01T10:00:00Z hash={}",
+                content_hash::post_hash(&post)
+            )
+        );
+        assert_eq!(lines[1], "title: Hello world");
+        assert!(lines[2].ends_with("… [+10 chars]"));
+        assert_eq!(lines.len(), 3);
+    }
+
+    #[test]
+    fn test_removed_comment() {
+        let removed = json!({
+            "id": "c1",
+            "author": { "name": "bot" },
+            "content": "spam",
+            "is_removed": true
+        });
+        assert_eq!(
+            comment(&removed, 1),
+            format!(
+                "comment id=c1 author=bot up=0 depth=1 hash={}\ncontent: [removed by moderators]\n",
+                content_hash::comment_hash(&removed)
+            )
+        );
+    }
+}
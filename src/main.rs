@@ -1,22 +1,45 @@
 //! Binary entry point for the Moltbook CLI.
 //!
 //! This module handles the top-level CLI execution flow:
-//! 1. Parses command-line arguments.
+//! 1. Parses command-line arguments under the selected profile.
 //! 2. Routes commands that don't require configuration (init, register).
-//! 3. Loads agent credentials and initializes the API client for all other commands.
+//! 3. Loads agent credentials, applies any project `.moltbook.toml`, and initializes
+//!    the API client for all other commands.
 //! 4. Executes requested operations and handles top-level errors.
 
 use clap::Parser;
 use colored::Colorize;
+use moltbook_cli::alias;
 use moltbook_cli::api::client::MoltbookClient;
 use moltbook_cli::cli::{self, Cli, Commands};
-use moltbook_cli::config::Config;
+use moltbook_cli::config::{self, Config};
 use moltbook_cli::display;
+use moltbook_cli::interrupt;
+use moltbook_cli::llm;
+use moltbook_cli::policy::Policy;
+use moltbook_cli::transcript::Recording;
+use moltbook_cli::workspace::Workspace;
+use std::io::IsTerminal;
 use std::process;
 
+/// Counts allocations for `moltbook bench`.
+#[cfg(feature = "bench")]
+#[global_allocator]
+static ALLOCATOR: moltbook_cli::bench::CountingAllocator = moltbook_cli::bench::CountingAllocator;
+
 #[tokio::main]
 async fn main() {
-    let cli = Cli::parse();
+    let cli = parse_args();
+    if cli.llm {
+        llm::set_enabled(true);
+        colored::control::set_override(false);
+    }
+
+    // Shown once: the move only happens the first time the new location is used.
+    // On stderr so it never mixes into JSON output.
+    if let Some(migration) = config::legacy_migration() {
+        eprintln!("{} {}", "ℹ️ ".cyan(), migration.to_string().bright_cyan());
+    }
 
     // Handle commands that don't require config separately
     match cli.command {
@@ -32,9 +55,61 @@ async fn main() {
                 process::exit(1);
             }
         }
+        Commands::Ctl { stop, args } => match cli::daemon::ctl(args, stop).await {
+            Ok(code) => process::exit(code),
+            Err(e) => {
+                display::error(&format!("{}", e));
+                process::exit(1);
+            }
+        },
+        Commands::Account { command } => {
+            let result = match command {
+                cli::AccountCommands::List => cli::account::list_profiles(),
+                cli::AccountCommands::Switch { name } => cli::account::switch_profile(&name),
+                cli::AccountCommands::Add {
+                    name,
+                    api_key,
+                    agent_name,
+                    switch,
+                } => cli::account::add_profile(&name, api_key, agent_name, switch).await,
+            };
+            if let Err(e) = result {
+                display::error(&format!("{}", e));
+                process::exit(1);
+            }
+        }
+        // Works without credentials, so broken state can be cleaned up before `init`.
+        Commands::State { command } => {
+            let cli::StateCommands::Clean { dry_run } = command;
+            if let Err(e) = cli::state::clean(dry_run) {
+                display::error(&format!("{}", e));
+                process::exit(1);
+            }
+        }
+        Commands::Replay { file, exchanges } => {
+            if let Err(e) = cli::replay::replay(&file, exchanges) {
+                display::error(&format!("{}", e));
+                process::exit(1);
+            }
+        }
+        #[cfg(feature = "bench")]
+        Commands::Bench { command } => {
+            let cli::BenchCommands::Render { posts } = command;
+            if let Err(e) = cli::bench::render(posts) {
+                display::error(&format!("{}", e));
+                process::exit(1);
+            }
+        }
         cmd => {
+            let workspace = match Workspace::discover() {
+                Ok(workspace) => workspace,
+                Err(e) => {
+                    display::error(&format!("Workspace Error: {}", e));
+                    process::exit(1);
+                }
+            };
             // Load config for all other commands
-            let config = match Config::load() {
+            let mut config = match Config::load() {
                 Ok(cfg) => cfg,
                 Err(e) => {
                     display::error(&format!("Configuration Error: {}", e));
@@ -45,13 +120,94 @@ async fn main() {
                     process::exit(1);
                 }
             };
+            // A `.moltbook.toml` above the working directory overrides the defaults
+            // for this project only; `config set` still edits the global file.
+            if let Err(e) = workspace.map_or(Ok(()), |ws| ws.apply(&mut config)) {
+                display::error(&format!("Workspace Error: {}", e));
+                process::exit(1);
+            }
+            // A policy that cannot be read stops everything rather than
+            // letting the agent act without its limits.
+            let policy = match Policy::load() {
+                Ok(policy) => policy,
+                Err(e) => {
+                    display::error(&format!("Policy Error: {}", e));
+                    process::exit(1);
+                }
+            };
 
-            let client = MoltbookClient::new(config.api_key, config.agent_name, cli.debug);
+            let client =
+                MoltbookClient::new(config.api_key.clone(), config.agent_name.clone(), cli.debug)
+                    .with_outbox(cli.outbox)
+                    .with_policy(policy)
+                    .with_config(&config)
+                    .with_activity_log(true)
+                    .with_jitter(cli.jitter.or(config.jitter()))
+                    .with_timeout(cli.timeout)
+                    .with_progress(!cli.quiet && !cli.llm && std::io::stdout().is_terminal());
 
-            if let Err(e) = cli::execute(cmd, &client).await {
+            let recording = match cli.record.map(Recording::start).transpose() {
+                Ok(recording) => recording,
+                Err(e) => {
+                    display::error(&format!("Recording Error: {}", e));
+                    process::exit(1);
+                }
+            };
+            // Ctrl-C from here on lets the command save its progress first.
+            interrupt::install();
+            let result = match cmd {
+                // The daemon's terminal is not the caller's, so it never draws progress bars.
+                Commands::Daemon { metrics_port } => {
+                    cli::daemon::serve(&client.with_progress(false), metrics_port).await
+                }
+                cmd => cli::execute(cmd, &client, &config).await,
+            };
+            if let Some(recording) = recording
+                && let Err(e) = recording.finish(result.as_ref().err())
+            {
+                display::error(&format!("Recording Error: {}", e));
+            }
+            if let Err(e) = result {
                 display::error(&format!("{}", e));
-                process::exit(1);
+                process::exit(if interrupt::requested() {
+                    interrupt::EXIT_CODE
+                } else {
+                    1
+                });
+            }
+            if interrupt::requested() {
+                process::exit(interrupt::EXIT_CODE);
             }
         }
     }
 }
+
+/// Parses the arguments once the profile they run as is selected.
+///
+/// Handles such as `last` or `%1` are resolved while parsing, from the
+/// selected profile's aliases, so a first pass with resolution off reads
+/// `--profile` and the command.
+fn parse_args() -> Cli {
+    if let Ok(preview) = alias::without_resolving(Cli::try_parse) {
+        // A workspace pinning a saved profile runs as it unless --profile says otherwise.
+        let profile = preview.profile.or_else(|| {
+            uses_workspace(&preview.command)
+                .then(|| Workspace::discover().ok().flatten()?.profile)
+                .flatten()
+                .filter(|profile| config::profile_exists(profile))
+        });
+        if let Some(profile) = profile
+            && let Err(e) = config::select_profile(&profile)
+        {
+            display::error(&format!("{}", e));
+            process::exit(1);
+        }
+    }
+    Cli::parse()
+}
+
+/// Whether `command` runs with the configured client, where a workspace
+/// applies; the other standalone commands are handled before it is built.
+fn uses_workspace(command: &Commands) -> bool {
+    matches!(command, Commands::Daemon { .. }) || !command.is_standalone()
+}
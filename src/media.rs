@@ -0,0 +1,218 @@
+//! Content-addressed cache for downloaded media.
+//!
+//! Avatars and other images are stored under `cache/media` in the config
+//! directory, named by the SHA-256 of their content so identical files
+//! fetched from different URLs are kept once. `index.json` maps each URL to
+//! its file; an entry is trusted for [`MAX_AGE_DAYS`] before the URL is
+//! downloaded again.
+
+use crate::api::client::MoltbookClient;
+use crate::api::error::ApiError;
+use crate::config::Config;
+use crate::state;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The cache directory, relative to the config directory.
+const CACHE_DIR: &str = "cache/media";
+/// The filename for the URL index inside the cache directory.
+const INDEX_FILE: &str = "index.json";
+/// How long a cached URL is used before it is downloaded again.
+pub const MAX_AGE_DAYS: i64 = 7;
+
+/// Hex-encoded SHA-256 of `bytes`.
+pub fn content_hash(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// The extension of the URL's last path segment, if it looks like one.
+fn extension(url: &str) -> Option<String> {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    let (_, ext) = path.rsplit('/').next()?.rsplit_once('.')?;
+    let valid = !ext.is_empty() && ext.len() <= 5 && ext.chars().all(|c| c.is_ascii_alphanumeric());
+    valid.then(|| ext.to_ascii_lowercase())
+}
+
+/// Where a URL's content is stored.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CachedUrl {
+    /// File name inside the cache directory: the content hash plus the URL's extension.
+    pub file: String,
+    pub size: u64,
+    /// When the URL was last downloaded (RFC 3339).
+    pub fetched_at: String,
+}
+
+impl CachedUrl {
+    fn is_fresh(&self, now: DateTime<Utc>) -> bool {
+        DateTime::parse_from_rfc3339(&self.fetched_at)
+            .is_ok_and(|t| now.signed_duration_since(t).num_days() < MAX_AGE_DAYS)
+    }
+}
+
+/// Totals reported by `moltbook cache stats`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// URLs in the index.
+    pub urls: usize,
+    /// Distinct files on disk.
+    pub files: usize,
+    /// Total size of those files in bytes.
+    pub bytes: u64,
+}
+
+/// The media cache rooted at a directory.
+#[derive(Debug)]
+pub struct MediaCache {
+    root: PathBuf,
+    urls: BTreeMap<String, CachedUrl>,
+}
+
+impl MediaCache {
+    /// Opens the cache in the config directory.
+    pub fn open() -> Result<Self, ApiError> {
+        Self::at(Config::config_dir()?.join(CACHE_DIR))
+    }
+
+    /// Opens the cache rooted at `root`, which need not exist yet.
+    pub fn at(root: PathBuf) -> Result<Self, ApiError> {
+        let urls = state::load(&root.join(INDEX_FILE), "media cache index")?;
+        Ok(Self { root, urls })
+    }
+
+    /// The cache directory.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// The cached file for `url`, if it is fresh and still on disk.
+    pub fn lookup(&self, url: &str) -> Option<PathBuf> {
+        let entry = self.urls.get(url).filter(|e| e.is_fresh(Utc::now()))?;
+        let path = self.root.join(&entry.file);
+        path.exists().then_some(path)
+    }
+
+    /// Stores the content downloaded from `url` and returns its path.
+    ///
+    /// The file is only written if no identical content is cached yet.
+    pub fn insert(&mut self, url: &str, bytes: &[u8]) -> Result<PathBuf, ApiError> {
+        let hash = content_hash(bytes);
+        let file = match extension(url) {
+            Some(ext) => format!("{}.{}", hash, ext),
+            None => hash,
+        };
+        let path = self.root.join(&file);
+        if !path.exists() {
+            fs::create_dir_all(&self.root)?;
+            fs::write(&path, bytes)?;
+        }
+        let entry = CachedUrl {
+            file,
+            size: bytes.len() as u64,
+            fetched_at: Utc::now().to_rfc3339(),
+        };
+        // Re-read the index under the lock so entries added by other runs stay.
+        let index = self.root.join(INDEX_FILE);
+        self.urls = state::update(&index, "media cache index", |urls: &mut BTreeMap<_, _>| {
+            urls.insert(url.to_string(), entry);
+            urls.clone()
+        })?;
+        Ok(path)
+    }
+
+    /// Returns the cached file for `url`, downloading it first if needed.
+    pub async fn fetch(&mut self, client: &MoltbookClient, url: &str) -> Result<PathBuf, ApiError> {
+        if let Some(path) = self.lookup(url) {
+            return Ok(path);
+        }
+        let bytes = client.download(url).await?;
+        self.insert(url, &bytes)
+    }
+
+    /// Counts the indexed URLs and the files on disk.
+    pub fn stats(&self) -> CacheStats {
+        let files: BTreeSet<&str> = self.urls.values().map(|e| e.file.as_str()).collect();
+        let bytes = files
+            .iter()
+            .filter_map(|f| fs::metadata(self.root.join(f)).ok())
+            .map(|m| m.len())
+            .sum();
+        CacheStats {
+            urls: self.urls.len(),
+            files: files.len(),
+            bytes,
+        }
+    }
+
+    /// Deletes every cached file and the index, returning what was removed.
+    pub fn clear(&mut self) -> Result<CacheStats, ApiError> {
+        let stats = self.stats();
+        if self.root.exists() {
+            fs::remove_dir_all(&self.root)?;
+        }
+        self.urls.clear();
+        Ok(stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extension() {
+        assert_eq!(
+            extension("https://cdn.example/a/b.PNG?v=2").as_deref(),
+            Some("png")
+        );
+        assert_eq!(extension("https://cdn.example/avatar"), None);
+        assert_eq!(
+            extension("https://cdn.example/x.tar.gz#frag").as_deref(),
+            Some("gz")
+        );
+    }
+
+    #[test]
+    fn test_identical_content_is_stored_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut cache = MediaCache::at(dir.path().join("media")).unwrap();
+        let a = cache.insert("https://a.example/one.png", b"crab").unwrap();
+        let b = cache.insert("https://b.example/two.png", b"crab").unwrap();
+        assert_eq!(a, b);
+        assert_eq!(
+            a.file_name().unwrap().to_str().unwrap(),
+            format!("{}.png", content_hash(b"crab"))
+        );
+
+        let reopened = MediaCache::at(dir.path().join("media")).unwrap();
+        assert_eq!(reopened.lookup("https://b.example/two.png"), Some(b));
+        assert_eq!(
+            reopened.stats(),
+            CacheStats {
+                urls: 2,
+                files: 1,
+                bytes: 4
+            }
+        );
+    }
+
+    #[test]
+    fn test_stale_entries_are_not_used() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut cache = MediaCache::at(dir.path().to_path_buf()).unwrap();
+        cache.insert("https://a.example/one.png", b"crab").unwrap();
+        cache
+            .urls
+            .get_mut("https://a.example/one.png")
+            .unwrap()
+            .fetched_at = "2020-01-01T00:00:00Z".to_string();
+        assert!(cache.lookup("https://a.example/one.png").is_none());
+    }
+}
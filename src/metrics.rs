@@ -0,0 +1,278 @@
+//! Prometheus metrics for the long-running modes.
+//!
+//! The client counts every request it sends and how each one failed; the
+//! outbox and pending-action journal are measured when metrics are scraped.
+//! `moltbook listen` answers `GET /metrics` on its own port and
+//! `moltbook daemon --metrics-port` starts a dedicated endpoint, so agent
+//! fleets can be watched with standard Prometheus tooling.
+//!
+//! The `X-RateLimit-*` headers of the latest response are kept as well, for
+//! the `status --watch` dashboard.
+
+use crate::http::{READ_TIMEOUT, read_request, write_response};
+use crate::idempotency::PendingStore;
+use crate::outbox::OutboxStore;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::net::TcpListener;
+
+/// The content type of the Prometheus text format.
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+static REQUESTS: AtomicU64 = AtomicU64::new(0);
+static NETWORK_ERRORS: AtomicU64 = AtomicU64::new(0);
+static CLIENT_ERRORS: AtomicU64 = AtomicU64::new(0);
+static SERVER_ERRORS: AtomicU64 = AtomicU64::new(0);
+static RATE_LIMITED: AtomicU64 = AtomicU64::new(0);
+/// Webhook calls answered by `listen`, by response status.
+static WEBHOOK_CALLS: Mutex<BTreeMap<u16, u64>> = Mutex::new(BTreeMap::new());
+/// The rate-limit headers of the latest response that carried any.
+static RATE_LIMIT: Mutex<Option<RateLimit>> = Mutex::new(None);
+
+/// Counts a request about to be sent.
+pub fn record_request() {
+    REQUESTS.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Counts a request that failed before a response arrived.
+pub fn record_network_error() {
+    NETWORK_ERRORS.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Counts an API response by its status.
+pub fn record_response(status: u16) {
+    let counter = match status {
+        429 => &RATE_LIMITED,
+        400..=499 => &CLIENT_ERRORS,
+        500..=599 => &SERVER_ERRORS,
+        _ => return,
+    };
+    counter.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Counts a webhook call answered with `status`.
+pub fn record_webhook(status: u16) {
+    let mut calls = WEBHOOK_CALLS.lock().unwrap_or_else(|e| e.into_inner());
+    *calls.entry(status).or_default() += 1;
+}
+
+/// Requests rejected with HTTP 429 so far.
+pub fn rate_limited_total() -> u64 {
+    RATE_LIMITED.load(Ordering::Relaxed)
+}
+
+/// The request budget reported by the API's `X-RateLimit-*` headers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateLimit {
+    pub limit: Option<u64>,
+    pub remaining: Option<u64>,
+    /// Seconds until the window resets, or the Unix time it resets at.
+    pub reset: Option<u64>,
+}
+
+impl RateLimit {
+    /// Reads the headers, or `None` if the response carried none of them.
+    pub fn from_headers(headers: &reqwest::header::HeaderMap) -> Option<Self> {
+        let number = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse().ok())
+        };
+        let limit = Self {
+            limit: number("x-ratelimit-limit"),
+            remaining: number("x-ratelimit-remaining"),
+            reset: number("x-ratelimit-reset"),
+        };
+        (limit != Self::default()).then_some(limit)
+    }
+
+    /// Time left until the window resets, as seen at Unix time `now`.
+    pub fn resets_in(&self, now: u64) -> Option<Duration> {
+        // Small values count seconds; anything past 2001 is a timestamp.
+        let reset = self.reset?;
+        let secs = if reset > 1_000_000_000 {
+            reset.saturating_sub(now)
+        } else {
+            reset
+        };
+        Some(Duration::from_secs(secs))
+    }
+}
+
+/// Remembers the rate-limit headers of an API response, if it has any.
+pub fn record_rate_limit(headers: &reqwest::header::HeaderMap) {
+    if let Some(limit) = RateLimit::from_headers(headers) {
+        *RATE_LIMIT.lock().unwrap_or_else(|e| e.into_inner()) = Some(limit);
+    }
+}
+
+/// The latest rate-limit headers seen, if any response has carried them.
+pub fn rate_limit() -> Option<RateLimit> {
+    RATE_LIMIT.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Values that are measured rather than counted.
+#[derive(Debug, Default)]
+pub struct Gauges {
+    pub outbox_depth: usize,
+    pub pending_actions: usize,
+}
+
+impl Gauges {
+    /// Measures the local queues. A store that cannot be read counts as empty.
+    pub fn measure() -> Self {
+        Self {
+            outbox_depth: OutboxStore::load().map_or(0, |s| s.entries.len()),
+            pending_actions: PendingStore::load().map_or(0, |s| s.actions.len()),
+        }
+    }
+}
+
+/// All metrics in the Prometheus text exposition format.
+pub fn render(gauges: &Gauges) -> String {
+    let mut out = String::new();
+    let mut family = |name: &str, kind: &str, help: &str, samples: &[(&str, u64)]| {
+        let _ = writeln!(out, "# HELP {} {}", name, help);
+        let _ = writeln!(out, "# TYPE {} {}", name, kind);
+        for (labels, value) in samples {
+            let _ = writeln!(out, "{}{} {}", name, labels, value);
+        }
+    };
+    let load = |counter: &AtomicU64| counter.load(Ordering::Relaxed);
+
+    family(
+        "moltbook_requests_total",
+        "counter",
+        "Requests sent by the client.",
+        &[("", load(&REQUESTS))],
+    );
+    family(
+        "moltbook_request_errors_total",
+        "counter",
+        "Failed requests by kind: network, client (4xx) or server (5xx).",
+        &[
+            ("{kind=\"network\"}", load(&NETWORK_ERRORS)),
+            ("{kind=\"client\"}", load(&CLIENT_ERRORS)),
+            ("{kind=\"server\"}", load(&SERVER_ERRORS)),
+        ],
+    );
+    family(
+        "moltbook_rate_limited_total",
+        "counter",
+        "Requests rejected with HTTP 429.",
+        &[("", load(&RATE_LIMITED))],
+    );
+    family(
+        "moltbook_outbox_depth",
+        "gauge",
+        "Mutations waiting in the outbox.",
+        &[("", gauges.outbox_depth as u64)],
+    );
+    family(
+        "moltbook_pending_actions",
+        "gauge",
+        "Sent mutations whose outcome is unknown.",
+        &[("", gauges.pending_actions as u64)],
+    );
+
+    let calls = WEBHOOK_CALLS.lock().unwrap_or_else(|e| e.into_inner());
+    if !calls.is_empty() {
+        let labels: Vec<(String, u64)> = calls
+            .iter()
+            .map(|(status, count)| (format!("{{status=\"{}\"}}", status), *count))
+            .collect();
+        let samples: Vec<(&str, u64)> = labels.iter().map(|(l, c)| (l.as_str(), *c)).collect();
+        family(
+            "moltbook_webhook_calls_total",
+            "counter",
+            "Webhook calls answered by `listen`, by response status.",
+            &samples,
+        );
+    }
+    out
+}
+
+/// Serves `GET /metrics` on `listener` until the process exits.
+pub async fn serve(listener: TcpListener) {
+    loop {
+        let Ok((mut stream, _)) = listener.accept().await else {
+            continue;
+        };
+        let (status, body) =
+            match tokio::time::timeout(READ_TIMEOUT, read_request(&mut stream)).await {
+                Ok(Ok(request)) if request.path == "/metrics" => (200, render(&Gauges::measure())),
+                Ok(Ok(_)) => (404, "Only /metrics is served here\n".to_string()),
+                Ok(Err(status)) => (status, String::new()),
+                Err(_) => (408, String::new()),
+            };
+        let _ = write_response(&mut stream, status, CONTENT_TYPE, &body).await;
+    }
+}
+
+/// Sends a metrics response in the Prometheus content type.
+pub async fn respond<W: tokio::io::AsyncWrite + Unpin>(stream: &mut W) -> std::io::Result<()> {
+    write_response(stream, 200, CONTENT_TYPE, &render(&Gauges::measure())).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        record_request();
+        record_response(429);
+        record_response(503);
+        record_webhook(401);
+
+        let text = render(&Gauges {
+            outbox_depth: 2,
+            pending_actions: 0,
+        });
+        assert!(text.contains("# TYPE moltbook_requests_total counter\n"));
+        assert!(text.contains("moltbook_outbox_depth 2\n"));
+        assert!(text.contains("moltbook_pending_actions 0\n"));
+        assert!(text.contains("moltbook_webhook_calls_total{status=\"401\"} "));
+        // Counters are process-wide, so other tests may have added to them.
+        let count = |prefix: &str| -> u64 {
+            text.lines()
+                .find_map(|l| l.strip_prefix(prefix))
+                .and_then(|v| v.trim().parse().ok())
+                .unwrap()
+        };
+        assert!(count("moltbook_requests_total ") >= 1);
+        assert!(count("moltbook_rate_limited_total ") >= 1);
+        assert!(count("moltbook_request_errors_total{kind=\"server\"} ") >= 1);
+    }
+
+    #[test]
+    fn test_rate_limit_headers() {
+        use reqwest::header::{HeaderMap, HeaderValue};
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(RateLimit::from_headers(&headers), None);
+        headers.insert("X-RateLimit-Limit", HeaderValue::from_static("100"));
+        headers.insert("X-RateLimit-Remaining", HeaderValue::from_static("42"));
+        headers.insert("X-RateLimit-Reset", HeaderValue::from_static("1700000060"));
+        let limit = RateLimit::from_headers(&headers).unwrap();
+        assert_eq!(limit.limit, Some(100));
+        assert_eq!(limit.remaining, Some(42));
+        assert_eq!(
+            limit.resets_in(1_700_000_000),
+            Some(Duration::from_secs(60))
+        );
+
+        let relative = RateLimit {
+            reset: Some(30),
+            ..Default::default()
+        };
+        assert_eq!(
+            relative.resets_in(1_700_000_000),
+            Some(Duration::from_secs(30))
+        );
+    }
+}
@@ -0,0 +1,271 @@
+//! Deterministic order for rendered lists.
+//!
+//! The API does not promise a stable order for items that tie on the
+//! requested sort, so two runs over unchanged data could list posts in a
+//! different order and look like new content to a script diffing the output.
+//! Lists are re-sorted here before they are shown:
+//!
+//! 1. Pinned posts first, as the API returns them.
+//! 2. The requested sort, when it can be computed from the items: `new` and
+//!    `old` by `created_at`, `top` by net score, `hot` by `hot_score` and
+//!    search results by similarity.
+//! 3. Ties broken by `created_at` (newest first), then by `id`.
+//!
+//! Sorts ranked only on the server (`rising`, `best`, or `hot` when the API
+//! leaves out `hot_score`) keep the API's order, since there is nothing to
+//! rank by locally.
+
+use crate::api::types::{Conversation, Message, Post, SearchResult};
+use chrono::{DateTime, FixedOffset};
+use serde_json::Value;
+use std::cmp::Ordering;
+
+/// The primary order of a list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Order {
+    Newest,
+    Oldest,
+    /// Highest score first.
+    Score,
+}
+
+/// What an entry is ordered by.
+struct Key<'a> {
+    pinned: bool,
+    score: Option<f64>,
+    created_at: Option<DateTime<FixedOffset>>,
+    id: &'a str,
+}
+
+fn parse_time(at: Option<&str>) -> Option<DateTime<FixedOffset>> {
+    at.and_then(|at| DateTime::parse_from_rfc3339(at).ok())
+}
+
+fn compare(a: &Key, b: &Key, order: Order) -> Ordering {
+    // `None` sorts before `Some`, so reversing puts undated entries last.
+    let newest_first = b.created_at.cmp(&a.created_at);
+    let primary = match order {
+        Order::Newest => newest_first,
+        Order::Oldest => match (a.created_at, b.created_at) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (a, b) => b.is_some().cmp(&a.is_some()),
+        },
+        Order::Score => match (a.score, b.score) {
+            (Some(a), Some(b)) => b.total_cmp(&a),
+            (a, b) => b.is_some().cmp(&a.is_some()),
+        },
+    };
+    b.pinned
+        .cmp(&a.pinned)
+        .then(primary)
+        .then(newest_first)
+        .then_with(|| a.id.cmp(b.id))
+}
+
+fn sort_by_key<T>(items: &mut [T], order: Order, key: impl Fn(&T) -> Key) {
+    items.sort_by(|a, b| compare(&key(a), &key(b), order));
+}
+
+/// Orders a page of feed posts fetched with `sort`.
+pub fn sort_posts(posts: &mut [Post], sort: &str) {
+    let order = match sort {
+        "new" => Order::Newest,
+        "old" => Order::Oldest,
+        "top" => Order::Score,
+        "hot" if posts.iter().all(|p| p.hot_score.is_some()) => Order::Score,
+        _ => return,
+    };
+    sort_by_key(posts, order, |post| Key {
+        pinned: post.is_pinned.unwrap_or(false),
+        score: match sort {
+            "hot" => post.hot_score,
+            _ => Some(post.score.unwrap_or(post.upvotes - post.downvotes) as f64),
+        },
+        created_at: parse_time(Some(&post.created_at)),
+        id: &post.id,
+    });
+}
+
+/// Orders a comment tree fetched with `sort`, replies included.
+pub fn sort_comments(comments: &mut [Value], sort: &str) {
+    let order = match sort {
+        "new" => Some(Order::Newest),
+        "old" => Some(Order::Oldest),
+        "top" => Some(Order::Score),
+        _ => None,
+    };
+    if let Some(order) = order {
+        sort_by_key(comments, order, comment_key);
+    }
+    for comment in comments {
+        if let Some(replies) = comment["replies"].as_array_mut() {
+            sort_comments(replies, sort);
+        }
+    }
+}
+
+/// Orders comments oldest first, e.g. the flattened new comments of a thread.
+pub fn sort_comments_oldest_first(comments: &mut [Value]) {
+    sort_by_key(comments, Order::Oldest, comment_key);
+}
+
+fn comment_key(comment: &Value) -> Key<'_> {
+    let votes =
+        comment["upvotes"].as_i64().unwrap_or(0) - comment["downvotes"].as_i64().unwrap_or(0);
+    Key {
+        pinned: false,
+        score: Some(votes as f64),
+        created_at: parse_time(comment["created_at"].as_str()),
+        id: comment["id"].as_str().unwrap_or(""),
+    }
+}
+
+/// Orders a page of search results, most similar first.
+pub fn sort_search_results(results: &mut [SearchResult]) {
+    sort_by_key(results, Order::Score, |result| Key {
+        pinned: false,
+        score: result.similarity,
+        created_at: parse_time(result.created_at.as_deref()),
+        id: &result.id,
+    });
+}
+
+/// Orders posts oldest first, for streams that print in creation order.
+pub fn sort_posts_oldest_first(posts: &mut [Post]) {
+    sort_by_key(posts, Order::Oldest, |post| Key {
+        pinned: false,
+        score: None,
+        created_at: parse_time(Some(&post.created_at)),
+        id: &post.id,
+    });
+}
+
+/// Orders the messages of a conversation oldest first.
+pub fn sort_messages(messages: &mut [Message]) {
+    sort_by_key(messages, Order::Oldest, |message| Key {
+        pinned: false,
+        score: None,
+        created_at: parse_time(Some(&message.created_at)),
+        id: &message.id,
+    });
+}
+
+/// Orders DM conversations for triage: most unread first, then most
+/// recently active.
+pub fn sort_conversations(conversations: &mut [Conversation]) {
+    sort_by_key(conversations, Order::Score, |conv| Key {
+        pinned: false,
+        score: Some(conv.unread_count as f64),
+        created_at: parse_time(conv.last_activity()),
+        id: &conv.conversation_id,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn post(id: &str, created_at: &str, upvotes: i64) -> Post {
+        serde_json::from_value(json!({
+            "id": id,
+            "title": id,
+            "upvotes": upvotes,
+            "created_at": created_at,
+            "author": { "name": "bot" }
+        }))
+        .unwrap()
+    }
+
+    fn ids(posts: &[Post]) -> Vec<&str> {
+        posts.iter().map(|p| p.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_ties_break_by_created_at_then_id() {
+        let mut posts = vec![
+            post("b", "2025-06-01T10:00:00Z", 5),
+            post("c", "2025-06-02T10:00:00Z", 5),
+            post("a", "2025-06-01T10:00:00Z", 5),
+            post("d", "2025-06-01T10:00:00Z", 9),
+        ];
+        sort_posts(&mut posts, "top");
+        assert_eq!(ids(&posts), ["d", "c", "a", "b"]);
+
+        let mut shuffled = vec![
+            posts[2].clone(),
+            posts[0].clone(),
+            posts[3].clone(),
+            posts[1].clone(),
+        ];
+        sort_posts(&mut shuffled, "top");
+        assert_eq!(ids(&shuffled), ids(&posts));
+    }
+
+    #[test]
+    fn test_pinned_posts_stay_first() {
+        let mut pinned = post("rules", "2024-01-01T00:00:00Z", 0);
+        pinned.is_pinned = Some(true);
+        let mut posts = vec![post("new", "2025-06-01T10:00:00Z", 0), pinned];
+        sort_posts(&mut posts, "new");
+        assert_eq!(ids(&posts), ["rules", "new"]);
+    }
+
+    #[test]
+    fn test_server_ranked_sorts_keep_api_order() {
+        let mut posts = vec![
+            post("x", "2025-06-01T10:00:00Z", 1),
+            post("y", "2025-06-02T10:00:00Z", 7),
+        ];
+        sort_posts(&mut posts, "rising");
+        assert_eq!(ids(&posts), ["x", "y"]);
+        // No hot_score in the response, so hot is left alone too.
+        sort_posts(&mut posts, "hot");
+        assert_eq!(ids(&posts), ["x", "y"]);
+    }
+
+    #[test]
+    fn test_sort_comments_recurses_into_replies() {
+        let mut comments = vec![json!({
+            "id": "c1",
+            "created_at": "2025-06-01T10:00:00Z",
+            "replies": [
+                { "id": "r2", "created_at": "2025-06-01T12:00:00Z" },
+                { "id": "r1", "created_at": "2025-06-01T11:00:00Z" }
+            ]
+        })];
+        sort_comments(&mut comments, "old");
+        let replies: Vec<&str> = comments[0]["replies"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|r| r["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(replies, ["r1", "r2"]);
+    }
+
+    #[test]
+    fn test_conversations_unread_first_then_recent() {
+        let conv = |id: &str, unread: u64, last: &str| -> Conversation {
+            serde_json::from_value(json!({
+                "conversation_id": id,
+                "with_agent": { "name": id },
+                "unread_count": unread,
+                "last_message": { "content": "hi", "created_at": last }
+            }))
+            .unwrap()
+        };
+        let mut conversations = vec![
+            conv("quiet", 0, "2025-06-03T10:00:00Z"),
+            conv("busy", 4, "2025-06-01T10:00:00Z"),
+            conv("stale", 0, "2025-05-01T10:00:00Z"),
+            conv("new", 1, "2025-06-02T10:00:00Z"),
+        ];
+        sort_conversations(&mut conversations);
+        let ids: Vec<&str> = conversations
+            .iter()
+            .map(|c| c.conversation_id.as_str())
+            .collect();
+        assert_eq!(ids, ["busy", "new", "quiet", "stale"]);
+    }
+}
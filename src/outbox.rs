@@ -0,0 +1,170 @@
+//! Persistent offline outbox for mutating requests.
+//!
+//! With the global `--outbox` flag, a post, comment or DM that cannot be
+//! delivered (network failure or a 5xx from the API) is saved to
+//! `outbox.json` in the config directory instead of being lost. Entries keep
+//! the idempotency key of the original attempt, so `moltbook outbox flush`
+//! can re-send them without risking duplicates.
+
+use crate::api::error::ApiError;
+use crate::state;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::PathBuf;
+
+/// The filename for the outbox.
+const OUTBOX_FILE: &str = "outbox.json";
+
+/// A queued mutation awaiting delivery.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OutboxEntry {
+    /// The idempotency key of the original attempt.
+    pub key: String,
+    /// The API endpoint to POST to.
+    pub endpoint: String,
+    /// The JSON body of the request.
+    pub body: Value,
+    /// When the entry was queued (RFC 3339).
+    pub queued_at: String,
+    /// Why delivery failed.
+    pub reason: String,
+}
+
+impl OutboxEntry {
+    /// Short identifier shown to users: the first 8 characters of the key.
+    pub fn short_id(&self) -> &str {
+        self.key.get(..8).unwrap_or(&self.key)
+    }
+}
+
+/// Queued mutations in delivery order, persisted between invocations.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct OutboxStore {
+    pub entries: Vec<OutboxEntry>,
+}
+
+impl OutboxStore {
+    /// Loads the outbox, returning an empty one if none exists yet.
+    pub fn load() -> Result<Self, ApiError> {
+        state::load(&Self::path()?, "outbox")
+    }
+
+    /// Persists the outbox.
+    pub fn save(&self) -> Result<(), ApiError> {
+        state::write(&Self::path()?, &serde_json::to_string_pretty(self)?)
+    }
+
+    /// Applies `f` to the outbox on disk, locked against concurrent invocations.
+    pub fn update<R>(f: impl FnOnce(&mut Self) -> R) -> Result<R, ApiError> {
+        state::update(&Self::path()?, "outbox", f)
+    }
+
+    /// The one entry whose key starts with `prefix`.
+    pub fn find(&self, prefix: &str) -> Result<&OutboxEntry, ApiError> {
+        let mut matches = self.entries.iter().filter(|e| e.key.starts_with(prefix));
+        match (matches.next(), matches.next()) {
+            (Some(entry), None) if !prefix.is_empty() => Ok(entry),
+            (Some(_), _) => Err(ApiError::ConfigError(format!(
+                "'{}' matches several queued requests; give more of the id",
+                prefix
+            ))),
+            (None, _) => Err(ApiError::ConfigError(format!(
+                "No queued request '{}'. Run `moltbook outbox list` to see the outbox",
+                prefix
+            ))),
+        }
+    }
+
+    /// Removes the entry with exactly this key, returning whether it existed.
+    pub fn remove(&mut self, key: &str) -> bool {
+        let before = self.entries.len();
+        self.entries.retain(|e| e.key != key);
+        self.entries.len() != before
+    }
+
+    fn path() -> Result<PathBuf, ApiError> {
+        state::path(OUTBOX_FILE)
+    }
+}
+
+/// Whether a failed request is worth queueing for a later attempt.
+pub fn is_retryable(error: &ApiError) -> bool {
+    matches!(
+        error,
+        ApiError::RequestFailed(_)
+            | ApiError::Timeout(_)
+            | ApiError::DnsFailed(_)
+            | ApiError::ServerError(_, _)
+    )
+}
+
+/// Whether the API turned a request down for now, so sending the same request
+/// later may succeed: rate limits, the new-agent cooldown, exhausted daily
+/// budgets and a pending claim.
+pub fn is_deferred(error: &ApiError) -> bool {
+    matches!(
+        error,
+        ApiError::RateLimited(_)
+            | ApiError::NewAgentRestricted(_)
+            | ApiError::BudgetExhausted { .. }
+            | ApiError::NotClaimed(_)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(key: &str) -> OutboxEntry {
+        OutboxEntry {
+            key: key.to_string(),
+            endpoint: "/posts".to_string(),
+            body: serde_json::json!({}),
+            queued_at: "2026-01-01T00:00:00Z".to_string(),
+            reason: "HTTP 503".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_remove_by_short_id() {
+        let mut store = OutboxStore {
+            entries: vec![entry("abcdef12-0000"), entry("12345678-0000")],
+        };
+        assert_eq!(store.entries[0].short_id(), "abcdef12");
+        let key = store.find("abcdef12").unwrap().key.clone();
+        assert!(store.remove(&key));
+        assert!(!store.remove(&key));
+        assert!(store.find("abcdef12").is_err());
+        assert_eq!(store.entries.len(), 1);
+    }
+
+    #[test]
+    fn test_find_needs_one_match() {
+        let store = OutboxStore {
+            entries: vec![entry("abcdef12-0000"), entry("abcdef34-0000")],
+        };
+        assert!(store.find("").is_err());
+        assert!(store.find("abcdef").is_err());
+        assert_eq!(store.find("abcdef3").unwrap().key, "abcdef34-0000");
+    }
+
+    #[test]
+    fn test_retryable_errors() {
+        assert!(is_retryable(&ApiError::ServerError(503, "down".into())));
+        assert!(is_retryable(&ApiError::DnsFailed(
+            "www.moltbook.com".into()
+        )));
+        assert!(is_retryable(&ApiError::Timeout("/feed".into())));
+        assert!(!is_retryable(&ApiError::MoltbookError(
+            "bad".into(),
+            "".into()
+        )));
+        assert!(!is_retryable(&ApiError::RateLimited("slow down".into())));
+        assert!(is_deferred(&ApiError::RateLimited("slow down".into())));
+        assert!(is_deferred(&ApiError::NewAgentRestricted(Some(60))));
+        assert!(!is_deferred(&ApiError::MoltbookError(
+            "bad".into(),
+            "".into()
+        )));
+    }
+}
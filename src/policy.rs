@@ -0,0 +1,370 @@
+//! Owner-defined limits on what the agent may do, from `policy.toml`.
+//!
+//! The file lives in the config directory next to `config.toml`:
+//!
+//! ```toml
+//! allowed_submolts = ["general", "rust"]
+//! max_posts_per_day = 5
+//! banned_phrases = ["airdrop", "dm me for"]
+//! dm_send_hours = "09:00-18:00"
+//! ```
+//!
+//! The client checks every mutation against it before anything is sent, and
+//! before asking for approval in supervised mode. A request that breaks a
+//! rule fails with [`ApiError::PolicyViolation`] and is appended to
+//! `policy_violations.json`, so the owner can see what the agent attempted.
+
+use crate::activity::{ActivityKind, ActivityLog};
+use crate::api::error::ApiError;
+use crate::config::Config;
+use crate::state;
+use chrono::{Local, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// The filename of the policy in the config directory.
+pub const POLICY_FILE: &str = "policy.toml";
+/// The filename for the violation journal.
+const VIOLATIONS_FILE: &str = "policy_violations.json";
+/// Maximum number of violations kept; the oldest go first.
+const MAX_VIOLATIONS: usize = 500;
+
+/// The rules of a `policy.toml`. Every rule is optional.
+#[derive(Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Policy {
+    /// Submolts the agent may post to; any, if empty.
+    #[serde(default)]
+    pub allowed_submolts: Vec<String>,
+    /// Posts allowed per calendar day, local time.
+    pub max_posts_per_day: Option<usize>,
+    /// Phrases that may not appear in anything the agent writes, ignoring case.
+    #[serde(default)]
+    pub banned_phrases: Vec<String>,
+    /// Local hours during which DMs may be sent.
+    pub dm_send_hours: Option<HourRange>,
+}
+
+/// A daily window of local time such as `09:00-18:00`.
+///
+/// A window whose end is before its start runs past midnight.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(try_from = "String")]
+pub struct HourRange {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl HourRange {
+    /// Whether `time` falls inside the window (start inclusive, end exclusive).
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+impl FromStr for HourRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid hours '{}', expected e.g. \"09:00-18:00\"", s);
+        let (start, end) = s.split_once('-').ok_or_else(invalid)?;
+        let time = |t: &str| NaiveTime::parse_from_str(t.trim(), "%H:%M").map_err(|_| invalid());
+        Ok(Self {
+            start: time(start)?,
+            end: time(end)?,
+        })
+    }
+}
+
+impl TryFrom<String> for HourRange {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl fmt::Display for HourRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}",
+            self.start.format("%H:%M"),
+            self.end.format("%H:%M")
+        )
+    }
+}
+
+/// A broken rule: the policy key and what the request did.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    pub rule: &'static str,
+    pub detail: String,
+}
+
+impl Policy {
+    /// Loads `policy.toml` from the config directory, or `None` if there is none.
+    pub fn load() -> Result<Option<Self>, ApiError> {
+        let path = Self::path()?;
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        toml::from_str(&content)
+            .map(Some)
+            .map_err(|e| ApiError::ConfigError(format!("{}: {}", path.display(), e.message())))
+    }
+
+    /// The path of `policy.toml` in the config directory.
+    pub fn path() -> Result<PathBuf, ApiError> {
+        Ok(Config::config_dir()?.join(POLICY_FILE))
+    }
+
+    /// Refuses `method endpoint` if it breaks a rule, journaling the violation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiError::PolicyViolation`] naming the rule that was broken.
+    pub fn enforce(
+        &self,
+        method: &str,
+        endpoint: &str,
+        body: Option<&Value>,
+    ) -> Result<(), ApiError> {
+        let kind = ActivityKind::of(method, endpoint);
+        let posts_today = if kind == Some(ActivityKind::Post) && self.max_posts_per_day.is_some() {
+            ActivityLog::load()?.count_today(ActivityKind::Post)
+        } else {
+            0
+        };
+        let Some(violation) = self.check(kind, body, Local::now().time(), posts_today) else {
+            return Ok(());
+        };
+        ViolationLog::update(|log| {
+            log.violations.push(ViolationRecord {
+                rule: violation.rule.to_string(),
+                detail: violation.detail.clone(),
+                method: method.to_string(),
+                endpoint: endpoint.to_string(),
+                command: std::env::args().collect::<Vec<_>>().join(" "),
+                at: Utc::now().to_rfc3339(),
+            });
+            let excess = log.violations.len().saturating_sub(MAX_VIOLATIONS);
+            log.violations.drain(..excess);
+        })?;
+        Err(ApiError::PolicyViolation {
+            rule: violation.rule.to_string(),
+            detail: violation.detail,
+        })
+    }
+
+    /// The first rule a request of `kind` with `body` breaks at local time
+    /// `now`, given the posts already made today.
+    pub fn check(
+        &self,
+        kind: Option<ActivityKind>,
+        body: Option<&Value>,
+        now: NaiveTime,
+        posts_today: usize,
+    ) -> Option<Violation> {
+        if kind == Some(ActivityKind::Post) {
+            let submolt = body
+                .and_then(|b| b.get("submolt_name"))
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+            if !self.allowed_submolts.is_empty()
+                && !self
+                    .allowed_submolts
+                    .iter()
+                    .any(|s| s.eq_ignore_ascii_case(submolt))
+            {
+                return Some(Violation {
+                    rule: "allowed_submolts",
+                    detail: format!(
+                        "m/{} is not one of {}",
+                        submolt,
+                        self.allowed_submolts.join(", ")
+                    ),
+                });
+            }
+            if let Some(max) = self.max_posts_per_day
+                && posts_today >= max
+            {
+                return Some(Violation {
+                    rule: "max_posts_per_day",
+                    detail: format!("{} of {} posts already made today", posts_today, max),
+                });
+            }
+        }
+        if kind == Some(ActivityKind::Dm)
+            && let Some(hours) = self.dm_send_hours
+            && !hours.contains(now)
+        {
+            return Some(Violation {
+                rule: "dm_send_hours",
+                detail: format!(
+                    "DMs may only be sent {} (it is {})",
+                    hours,
+                    now.format("%H:%M")
+                ),
+            });
+        }
+        if let Some(body) = body {
+            let text = strings(body).join("\n").to_lowercase();
+            if let Some(phrase) = self
+                .banned_phrases
+                .iter()
+                .find(|p| !p.is_empty() && text.contains(&p.to_lowercase()))
+            {
+                return Some(Violation {
+                    rule: "banned_phrases",
+                    detail: format!("the text contains \"{}\"", phrase),
+                });
+            }
+        }
+        None
+    }
+}
+
+/// Every string in a JSON value, however deeply nested.
+fn strings(value: &Value) -> Vec<&str> {
+    match value {
+        Value::String(s) => vec![s.as_str()],
+        Value::Array(items) => items.iter().flat_map(strings).collect(),
+        Value::Object(map) => map.values().flat_map(strings).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// A refused request, as journaled.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ViolationRecord {
+    /// The policy key that was broken.
+    pub rule: String,
+    pub detail: String,
+    pub method: String,
+    pub endpoint: String,
+    /// The command line that made the request.
+    pub command: String,
+    /// When the request was refused (RFC 3339).
+    pub at: String,
+}
+
+/// Refused requests, oldest first.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct ViolationLog {
+    pub violations: Vec<ViolationRecord>,
+}
+
+impl ViolationLog {
+    /// Loads the journal, returning an empty one if none exists yet.
+    pub fn load() -> Result<Self, ApiError> {
+        state::load(&Self::path()?, "policy violations")
+    }
+
+    /// Applies `f` to the journal on disk, locked against concurrent invocations.
+    pub fn update<R>(f: impl FnOnce(&mut Self) -> R) -> Result<R, ApiError> {
+        state::update(&Self::path()?, "policy violations", f)
+    }
+
+    fn path() -> Result<PathBuf, ApiError> {
+        state::path(VIOLATIONS_FILE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn time(s: &str) -> NaiveTime {
+        NaiveTime::parse_from_str(s, "%H:%M").unwrap()
+    }
+
+    fn policy() -> Policy {
+        toml::from_str(
+            r#"
+allowed_submolts = ["general", "Rust"]
+max_posts_per_day = 2
+banned_phrases = ["Airdrop"]
+dm_send_hours = "09:00-18:00"
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_hour_range() {
+        let day: HourRange = "09:00-18:00".parse().unwrap();
+        assert!(day.contains(time("09:00")));
+        assert!(!day.contains(time("18:00")));
+        assert_eq!(day.to_string(), "09:00-18:00");
+
+        let night: HourRange = "22:00 - 06:30".parse().unwrap();
+        assert!(night.contains(time("23:15")));
+        assert!(night.contains(time("03:00")));
+        assert!(!night.contains(time("12:00")));
+
+        assert!("9-5".parse::<HourRange>().is_err());
+        assert!(toml::from_str::<Policy>("dm_send_hours = \"noon\"").is_err());
+        assert!(toml::from_str::<Policy>("max_comments = 3").is_err());
+    }
+
+    #[test]
+    fn test_post_rules() {
+        let policy = policy();
+        let post = |submolt: &str| json!({"submolt_name": submolt, "title": "Hi"});
+        let check = |body: &Value, posts_today| {
+            policy
+                .check(
+                    Some(ActivityKind::Post),
+                    Some(body),
+                    time("12:00"),
+                    posts_today,
+                )
+                .map(|v| v.rule)
+        };
+        assert_eq!(check(&post("rust"), 0), None);
+        assert_eq!(check(&post("crypto"), 0), Some("allowed_submolts"));
+        assert_eq!(check(&post("general"), 2), Some("max_posts_per_day"));
+        assert_eq!(
+            Policy::default().check(
+                Some(ActivityKind::Post),
+                Some(&post("x")),
+                time("12:00"),
+                99
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_banned_phrases_and_dm_hours() {
+        let policy = policy();
+        let dm = json!({"to": "alice", "message": "Free AIRDROP inside"});
+        let violation = policy
+            .check(Some(ActivityKind::Comment), Some(&dm), time("12:00"), 0)
+            .unwrap();
+        assert_eq!(violation.rule, "banned_phrases");
+        assert!(violation.detail.contains("Airdrop"));
+
+        let dm = json!({"message": "hello"});
+        let check = |now| policy.check(Some(ActivityKind::Dm), Some(&dm), time(now), 0);
+        assert_eq!(check("10:00"), None);
+        assert_eq!(check("20:00").unwrap().rule, "dm_send_hours");
+
+        // Edits are checked for banned phrases too.
+        let edit = json!({"description": ["nested", "airdrop"]});
+        assert!(policy.check(None, Some(&edit), time("20:00"), 0).is_some());
+    }
+}
@@ -0,0 +1,54 @@
+//! Registration details kept until the agent is claimed.
+//!
+//! `register` prints the claim URL once, and agents tend to lose it. It is
+//! saved to `registration.json` in the config directory so later commands can
+//! repeat it while the claim is pending; the file is removed as soon as a
+//! status check sees the agent claimed.
+
+use crate::api::error::ApiError;
+use crate::api::types::RegisteredAgent;
+use crate::state;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// The filename for the saved registration.
+const REGISTRATION_FILE: &str = "registration.json";
+
+/// What the human owner needs to claim the agent.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Registration {
+    pub agent_name: String,
+    pub claim_url: String,
+    pub verification_code: String,
+    /// When `register` succeeded (RFC 3339).
+    pub registered_at: String,
+}
+
+impl Registration {
+    pub fn new(agent: &RegisteredAgent) -> Self {
+        Self {
+            agent_name: agent.name.clone(),
+            claim_url: agent.claim_url.clone(),
+            verification_code: agent.verification_code.clone(),
+            registered_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// The saved registration, if the agent has not been seen claimed yet.
+    pub fn load() -> Result<Option<Self>, ApiError> {
+        state::read(&Self::path()?, "registration")
+    }
+
+    pub fn save(&self) -> Result<(), ApiError> {
+        state::write(&Self::path()?, &serde_json::to_string_pretty(self)?)
+    }
+
+    /// Forgets the registration once the agent is claimed.
+    pub fn clear() -> Result<(), ApiError> {
+        state::remove(&Self::path()?)
+    }
+
+    fn path() -> Result<PathBuf, ApiError> {
+        state::path(REGISTRATION_FILE)
+    }
+}
@@ -0,0 +1,334 @@
+//! RSS and Atom import.
+//!
+//! `moltbook import-rss` turns the items of a feed into link posts. Feeds
+//! are parsed with a small, tolerant extractor that only reads what a link
+//! post needs (title, link, date) rather than validating the XML. Links that
+//! were already imported are recorded in `imported_links.json` in the config
+//! directory so re-running the import never posts an item twice.
+
+use crate::api::error::ApiError;
+use crate::state;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// The filename for the record of imported links.
+const IMPORTED_FILE: &str = "imported_links.json";
+
+/// One item of a feed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedItem {
+    pub title: String,
+    pub link: String,
+    /// Publication date as written in the feed, if any.
+    pub published: Option<String>,
+}
+
+/// Extracts the items of an RSS 2.0 or Atom document, in document order.
+///
+/// Items without a link are skipped; a missing title falls back to the link.
+pub fn parse_feed(xml: &str) -> Vec<FeedItem> {
+    let (tag, atom) = if find_open(xml, "entry", 0).is_some() {
+        ("entry", true)
+    } else {
+        ("item", false)
+    };
+
+    let mut items = Vec::new();
+    let mut pos = 0;
+    while let Some((body, end)) = element(xml, tag, pos) {
+        pos = end;
+        let link = if atom {
+            atom_link(body)
+        } else {
+            text_of(body, "link").or_else(|| text_of(body, "guid"))
+        };
+        let Some(link) = link.filter(|l| l.starts_with("http")) else {
+            continue;
+        };
+        let title = text_of(body, "title")
+            .filter(|t| !t.is_empty())
+            .unwrap_or_else(|| link.clone());
+        let published = ["pubDate", "published", "updated", "dc:date"]
+            .iter()
+            .find_map(|t| text_of(body, t));
+        items.push(FeedItem {
+            title,
+            link,
+            published,
+        });
+    }
+    items
+}
+
+/// The byte offset of the next `<tag` that starts an element named `tag`.
+fn find_open(xml: &str, tag: &str, from: usize) -> Option<usize> {
+    let needle = format!("<{}", tag);
+    let mut at = from;
+    while let Some(i) = xml[at..].find(&needle) {
+        let start = at + i;
+        let next = xml[start + needle.len()..].chars().next();
+        // `<link` must not match `<linkedin>`.
+        if matches!(next, Some('>' | '/' | ' ' | '\t' | '\r' | '\n')) {
+            return Some(start);
+        }
+        at = start + needle.len();
+    }
+    None
+}
+
+/// The next `<tag …>…</tag>` at or after `from`: the whole element, from its
+/// start tag, and the offset just past it. Self-closing elements have no
+/// closing tag and are returned alone.
+fn element<'a>(xml: &'a str, tag: &str, from: usize) -> Option<(&'a str, usize)> {
+    let start = find_open(xml, tag, from)?;
+    let open_end = start + xml[start..].find('>')? + 1;
+    if xml[..open_end].ends_with("/>") {
+        return Some((&xml[start..open_end], open_end));
+    }
+    let close = format!("</{}>", tag);
+    let end = open_end + xml[open_end..].find(&close)? + close.len();
+    Some((&xml[start..end], end))
+}
+
+/// The decoded text content of the first `<tag>` inside `body`.
+fn text_of(body: &str, tag: &str) -> Option<String> {
+    // Skip the enclosing element's own start tag.
+    let inner_start = body.find('>')? + 1;
+    let (element, _) = element(body, tag, inner_start)?;
+    let content_start = element.find('>')? + 1;
+    let content_end = element.rfind("</")?;
+    if content_end < content_start {
+        return None;
+    }
+    let text = decode(element[content_start..content_end].trim());
+    Some(text).filter(|t| !t.is_empty())
+}
+
+/// The `href` of an Atom entry's alternate link (or of its only link).
+fn atom_link(body: &str) -> Option<String> {
+    let mut pos = 0;
+    let mut fallback = None;
+    while let Some(start) = find_open(body, "link", pos) {
+        let end = start + body[start..].find('>')? + 1;
+        let tag = &body[start..end];
+        pos = end;
+        let Some(href) = attribute(tag, "href") else {
+            continue;
+        };
+        match attribute(tag, "rel").as_deref() {
+            None | Some("alternate") => return Some(href),
+            _ => fallback = fallback.or(Some(href)),
+        }
+    }
+    fallback
+}
+
+/// The decoded value of `name="…"` (or single quotes) in a start tag.
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let mut rest = tag;
+    loop {
+        let i = rest.find(name)?;
+        let preceded_by_space = rest[..i].ends_with(char::is_whitespace);
+        rest = &rest[i + name.len()..];
+        let after = rest.trim_start();
+        if !preceded_by_space || !after.starts_with('=') {
+            continue;
+        }
+        let value = after[1..].trim_start();
+        let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let value = &value[1..];
+        return Some(decode(&value[..value.find(quote)?]));
+    }
+}
+
+/// Unwraps CDATA sections and decodes XML character references.
+fn decode(text: &str) -> String {
+    if let Some(inner) = text
+        .strip_prefix("<![CDATA[")
+        .and_then(|t| t.strip_suffix("]]>"))
+    {
+        return inner.trim().to_string();
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(i) = rest.find('&') {
+        out.push_str(&rest[..i]);
+        rest = &rest[i..];
+        let Some(end) = rest.find(';').filter(|e| *e <= 10) else {
+            out.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+        let entity = &rest[1..end];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|d| d.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Normalizes a link for duplicate detection: trimmed, without a fragment.
+pub fn normalize_link(link: &str) -> String {
+    let link = link.trim();
+    link.split('#').next().unwrap_or(link).to_string()
+}
+
+/// Where an imported link ended up.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ImportedLink {
+    /// The post created for it; `None` if it was queued in the outbox.
+    pub post_id: Option<String>,
+    pub submolt: String,
+    /// When it was imported (RFC 3339).
+    pub imported_at: String,
+}
+
+/// Links imported from feeds, persisted between runs.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct ImportedLinks {
+    /// Normalized link to where it was posted.
+    pub links: BTreeMap<String, ImportedLink>,
+}
+
+impl ImportedLinks {
+    /// Loads the record, returning an empty one if none exists yet.
+    pub fn load() -> Result<Self, ApiError> {
+        state::load(&Self::path()?, "imported links")
+    }
+
+    /// Applies `f` to the record on disk, locked against concurrent invocations.
+    pub fn update<R>(f: impl FnOnce(&mut Self) -> R) -> Result<R, ApiError> {
+        state::update(&Self::path()?, "imported links", f)
+    }
+
+    pub fn contains(&self, link: &str) -> bool {
+        self.links.contains_key(&normalize_link(link))
+    }
+
+    pub fn record(&mut self, link: &str, submolt: &str, post_id: Option<String>) {
+        self.links.insert(
+            normalize_link(link),
+            ImportedLink {
+                post_id,
+                submolt: submolt.to_string(),
+                imported_at: Utc::now().to_rfc3339(),
+            },
+        );
+    }
+
+    /// Items not imported yet, at most `max`, oldest first so posts appear in
+    /// the feed's order. Feeds list their newest items first.
+    pub fn new_items(&self, items: &[FeedItem], max: usize) -> Vec<FeedItem> {
+        let mut seen = std::collections::HashSet::new();
+        let mut fresh: Vec<FeedItem> = items
+            .iter()
+            .filter(|i| !self.contains(&i.link) && seen.insert(normalize_link(&i.link)))
+            .take(max)
+            .cloned()
+            .collect();
+        fresh.reverse();
+        fresh
+    }
+
+    fn path() -> Result<PathBuf, ApiError> {
+        state::path(IMPORTED_FILE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RSS: &str = r#"<?xml version="1.0"?>
+<rss version="2.0"><channel>
+  <title>News</title>
+  <link>https://news.example/</link>
+  <item>
+    <title>Crabs &amp; lobsters</title>
+    <link>https://news.example/crabs#top</link>
+    <pubDate>Tue, 10 Jun 2025 04:00:00 GMT</pubDate>
+  </item>
+  <item>
+    <title><![CDATA[Rust <3 agents]]></title>
+    <guid>https://news.example/rust</guid>
+  </item>
+  <item><title>No link</title></item>
+</channel></rss>"#;
+
+    const ATOM: &str = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>Blog</title>
+  <link href="https://blog.example/"/>
+  <entry>
+    <title type="html">First &#8220;post&#8221;</title>
+    <link rel="self" href="https://blog.example/feed/1"/>
+    <link rel="alternate" href="https://blog.example/1"/>
+    <updated>2025-06-10T04:00:00Z</updated>
+  </entry>
+</feed>"#;
+
+    #[test]
+    fn test_parse_rss() {
+        let items = parse_feed(RSS);
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].title, "Crabs & lobsters");
+        assert_eq!(items[0].link, "https://news.example/crabs#top");
+        assert_eq!(
+            items[0].published.as_deref(),
+            Some("Tue, 10 Jun 2025 04:00:00 GMT")
+        );
+        assert_eq!(items[1].title, "Rust <3 agents");
+        assert_eq!(items[1].link, "https://news.example/rust");
+    }
+
+    #[test]
+    fn test_parse_atom() {
+        let items = parse_feed(ATOM);
+        assert_eq!(
+            items,
+            [FeedItem {
+                title: "First \u{201c}post\u{201d}".to_string(),
+                link: "https://blog.example/1".to_string(),
+                published: Some("2025-06-10T04:00:00Z".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_new_items_skip_imported_links() {
+        let items = parse_feed(RSS);
+        let mut store = ImportedLinks::default();
+        store.record("https://news.example/crabs", "news", Some("p1".into()));
+        let fresh = store.new_items(&items, 5);
+        assert_eq!(fresh.len(), 1);
+        assert_eq!(fresh[0].link, "https://news.example/rust");
+
+        let store = ImportedLinks::default();
+        let fresh = store.new_items(&items, 5);
+        assert_eq!(fresh[0].link, "https://news.example/rust", "oldest first");
+        assert_eq!(store.new_items(&items, 1).len(), 1);
+    }
+}
@@ -0,0 +1,205 @@
+//! Client-side analysis of search results.
+//!
+//! The search API only returns a similarity score, so the CLI finds query
+//! terms in each result itself. This powers `search --explain` and the term
+//! highlighting in result snippets.
+
+use crate::api::types::SearchResult;
+use std::ops::Range;
+
+/// Characters of context kept on each side of the first match in a snippet.
+const SNIPPET_CONTEXT: usize = 60;
+
+/// Splits a query into lowercase, de-duplicated terms of two or more characters.
+pub fn terms(query: &str) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for term in query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|t| t.chars().count() >= 2)
+        .map(str::to_lowercase)
+    {
+        if !terms.contains(&term) {
+            terms.push(term);
+        }
+    }
+    terms
+}
+
+fn fold(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+/// Finds case-insensitive occurrences of `terms` in `text`.
+///
+/// Returns sorted, non-overlapping byte ranges into `text`; overlapping or
+/// adjacent matches are merged.
+pub fn find_terms(text: &str, terms: &[String]) -> Vec<Range<usize>> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let end_of = |i: usize| chars.get(i).map_or(text.len(), |(b, _)| *b);
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+
+    for term in terms {
+        let needle: Vec<char> = term.chars().map(fold).collect();
+        if needle.is_empty() || needle.len() > chars.len() {
+            continue;
+        }
+        for start in 0..=chars.len() - needle.len() {
+            let hit = needle
+                .iter()
+                .enumerate()
+                .all(|(k, n)| fold(chars[start + k].1) == *n);
+            if hit {
+                ranges.push(chars[start].0..end_of(start + needle.len()));
+            }
+        }
+    }
+
+    ranges.sort_by_key(|r| r.start);
+    let mut merged: Vec<Range<usize>> = Vec::new();
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// Why a search result matched, as shown by `search --explain`.
+#[derive(Debug, Clone)]
+pub struct Explanation {
+    /// Similarity as returned by the API.
+    pub similarity: Option<f64>,
+    /// Similarity relative to the best result on the page (0..1).
+    pub relative: Option<f64>,
+    /// Query terms found in the title.
+    pub title_terms: Vec<String>,
+    /// Query terms found in the content.
+    pub content_terms: Vec<String>,
+    /// Query terms found nowhere in the result.
+    pub missing_terms: Vec<String>,
+    /// Content excerpt around the first matched term.
+    pub snippet: Option<String>,
+}
+
+impl Explanation {
+    /// Explains `result` for the given query `terms`.
+    ///
+    /// `best` is the highest similarity among the results being displayed.
+    pub fn new(result: &SearchResult, terms: &[String], best: Option<f64>) -> Self {
+        let title = result.title.as_deref().unwrap_or("");
+        let content = result.content.as_deref().unwrap_or("");
+        let found_in = |text: &str| -> Vec<String> {
+            terms
+                .iter()
+                .filter(|t| !find_terms(text, std::slice::from_ref(t)).is_empty())
+                .cloned()
+                .collect()
+        };
+        let title_terms = found_in(title);
+        let content_terms = found_in(content);
+        let missing_terms = terms
+            .iter()
+            .filter(|t| !title_terms.contains(t) && !content_terms.contains(t))
+            .cloned()
+            .collect();
+
+        let relative = match (result.similarity, best) {
+            (Some(score), Some(best)) if best > 0.0 => Some(score / best),
+            _ => None,
+        };
+
+        Self {
+            similarity: result.similarity,
+            relative,
+            title_terms,
+            content_terms,
+            missing_terms,
+            snippet: snippet(content, terms),
+        }
+    }
+
+    /// True when no query term appears literally, i.e. the match is purely semantic.
+    pub fn is_semantic_only(&self) -> bool {
+        self.title_terms.is_empty() && self.content_terms.is_empty()
+    }
+}
+
+/// Extracts a single-line excerpt of `content` around the first matched term.
+pub fn snippet(content: &str, terms: &[String]) -> Option<String> {
+    let first = find_terms(content, terms).into_iter().next()?;
+    let before: Vec<char> = content[..first.start].chars().collect();
+    let start_char = before.len().saturating_sub(SNIPPET_CONTEXT);
+    let start = before[..start_char]
+        .iter()
+        .map(|c| c.len_utf8())
+        .sum::<usize>();
+    let end = content[first.end..]
+        .char_indices()
+        .nth(SNIPPET_CONTEXT)
+        .map_or(content.len(), |(i, _)| first.end + i);
+
+    let mut excerpt = content[start..end]
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if start > 0 {
+        excerpt.insert(0, '…');
+    }
+    if end < content.len() {
+        excerpt.push('…');
+    }
+    Some(excerpt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_terms_are_normalized() {
+        assert_eq!(
+            terms("Reinforcement-Learning, learning & RL!"),
+            ["reinforcement", "learning", "rl"]
+        );
+    }
+
+    #[test]
+    fn test_find_terms_is_case_insensitive_and_merges() {
+        let text = "Rust crabs love RUSTACEANS";
+        let found = find_terms(text, &terms("rust rustacean"));
+        let words: Vec<&str> = found.iter().map(|r| &text[r.clone()]).collect();
+        assert_eq!(words, ["Rust", "RUSTACEAN"]);
+
+        let text = "Ünïcode ünïcode";
+        let found = find_terms(text, &terms("ÜNÏ"));
+        assert_eq!(found.len(), 2);
+        assert_eq!(&text[found[1].clone()], "ünï");
+    }
+
+    #[test]
+    fn test_explanation() {
+        let result: SearchResult = serde_json::from_value(json!({
+            "id": "p1",
+            "type": "post",
+            "title": "Policy gradients",
+            "content": "A short intro to reinforcement learning.",
+            "upvotes": 0,
+            "downvotes": 0,
+            "similarity": 0.3,
+            "author": { "name": "Bot" }
+        }))
+        .unwrap();
+        let explanation = Explanation::new(&result, &terms("policy learning PPO"), Some(0.6));
+        assert_eq!(explanation.title_terms, ["policy"]);
+        assert_eq!(explanation.content_terms, ["learning"]);
+        assert_eq!(explanation.missing_terms, ["ppo"]);
+        assert_eq!(explanation.relative, Some(0.5));
+        assert_eq!(
+            explanation.snippet.as_deref(),
+            Some("A short intro to reinforcement learning.")
+        );
+        assert!(!explanation.is_semantic_only());
+    }
+}
@@ -0,0 +1,361 @@
+//! Crash-safe local state in the config directory.
+//!
+//! Every store the CLI keeps between runs (seen posts, journals, the outbox,
+//! caches) is read and written through this module. Writes go to a temporary
+//! file that is synced and renamed over the old one, so a crash leaves either
+//! the old or the new contents, never half of each. Writers also hold an
+//! advisory lock on a `<file>.lock` sidecar, so concurrent invocations (for
+//! example overlapping cron jobs) take turns; [`update`] holds the lock across
+//! a whole read-modify-write so neither run loses the other's change.
+//!
+//! `moltbook state clean` removes the caches in [`FILES`], sets aside files
+//! that can no longer be parsed and deletes temporary files left by crashes.
+
+use crate::api::error::ApiError;
+use crate::config::Config;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// What a state file holds, which decides how `state clean` treats it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateKind {
+    /// Rebuilt on demand; safe to delete.
+    Cache,
+    /// Requests in flight or queued; deleting them loses work.
+    Journal,
+    /// History and settings the CLI cannot recover.
+    Record,
+}
+
+/// Every state file kept in the config directory, by kind.
+pub const FILES: &[(&str, StateKind)] = &[
+    ("seen_posts.json", StateKind::Cache),
+    ("profile_cache.json", StateKind::Cache),
+    ("relationships.json", StateKind::Cache),
+    ("outbox.json", StateKind::Journal),
+    ("pending_actions.json", StateKind::Journal),
+    ("uploads.json", StateKind::Journal),
+    ("approvals.json", StateKind::Journal),
+    ("pending_verifications.json", StateKind::Journal),
+    ("activity.json", StateKind::Record),
+    ("aliases.json", StateKind::Record),
+    ("comment_visits.json", StateKind::Record),
+    ("digests.json", StateKind::Record),
+    ("drafts.json", StateKind::Record),
+    ("dm_watch.json", StateKind::Record),
+    ("engagement_history.json", StateKind::Record),
+    ("imported_links.json", StateKind::Record),
+    ("policy_violations.json", StateKind::Record),
+    ("post_samples.json", StateKind::Record),
+    ("registration.json", StateKind::Record),
+    ("submolt_watch.json", StateKind::Record),
+    ("subscriptions.json", StateKind::Record),
+];
+
+/// The path of the state file `name` in the config directory.
+pub fn path(name: &str) -> Result<PathBuf, ApiError> {
+    Ok(Config::config_dir()?.join(name))
+}
+
+/// Reads a JSON state file, or `None` if it does not exist.
+///
+/// `what` names the contents in the error shown when the file cannot be parsed.
+pub fn read<T: DeserializeOwned>(path: &Path, what: &str) -> Result<Option<T>, ApiError> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    serde_json::from_str(&content).map(Some).map_err(|e| {
+        ApiError::ConfigError(format!(
+            "Failed to parse {}: {}\nRun `moltbook state clean` to set the file aside.",
+            what, e
+        ))
+    })
+}
+
+/// Reads a JSON state file, or the default value if it does not exist.
+pub fn load<T: DeserializeOwned + Default>(path: &Path, what: &str) -> Result<T, ApiError> {
+    Ok(read(path, what)?.unwrap_or_default())
+}
+
+/// Replaces the contents of `path` atomically while holding its lock.
+pub fn write(path: &Path, contents: &str) -> Result<(), ApiError> {
+    let _lock = lock(path)?;
+    write_locked(path, contents)?;
+    Ok(())
+}
+
+/// Like [`write`], but readable only by the owner on Unix (mode `0600`) from
+/// the moment the file is created. Used for credentials.
+pub fn write_private(path: &Path, contents: &str) -> Result<(), ApiError> {
+    let _lock = lock(path)?;
+    write_with(path, contents, true)?;
+    Ok(())
+}
+
+/// Deletes `path` while holding its lock. A missing file is not an error.
+pub fn remove(path: &Path) -> Result<(), ApiError> {
+    let _lock = lock(path)?;
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+        _ => Ok(()),
+    }
+}
+
+/// Applies `f` to the value stored at `path` and writes it back, holding the
+/// lock throughout so concurrent updates are applied one after the other.
+pub fn update<T, R>(path: &Path, what: &str, f: impl FnOnce(&mut T) -> R) -> Result<R, ApiError>
+where
+    T: Serialize + DeserializeOwned + Default,
+{
+    let _lock = lock(path)?;
+    let mut value: T = load(path, what)?;
+    let result = f(&mut value);
+    write_locked(path, &serde_json::to_string_pretty(&value)?)?;
+    Ok(result)
+}
+
+/// An exclusive lock on a state file, released when dropped.
+#[derive(Debug)]
+pub struct Lock {
+    _file: File,
+}
+
+/// Waits for the exclusive lock on `path`.
+///
+/// The lock is taken on a `<file>.lock` sidecar rather than the file itself,
+/// since the file is replaced on every write.
+pub fn lock(path: &Path) -> Result<Lock, ApiError> {
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        fs::create_dir_all(dir)?;
+    }
+    let file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(lock_path(path))?;
+    file.lock()?;
+    Ok(Lock { _file: file })
+}
+
+/// Like [`lock`], but returns `None` instead of waiting if the lock is held.
+fn try_lock(path: &Path) -> io::Result<Option<Lock>> {
+    let file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(lock_path(path))?;
+    match file.try_lock() {
+        Ok(()) => Ok(Some(Lock { _file: file })),
+        Err(fs::TryLockError::WouldBlock) => Ok(None),
+        Err(fs::TryLockError::Error(e)) => Err(e),
+    }
+}
+
+fn write_locked(path: &Path, contents: &str) -> io::Result<()> {
+    write_with(path, contents, false)
+}
+
+fn write_with(path: &Path, contents: &str, private: bool) -> io::Result<()> {
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        fs::create_dir_all(dir)?;
+    }
+    let temp = temp_path(path);
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    if private {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    #[cfg(not(unix))]
+    let _ = private;
+    let written = options.open(&temp).and_then(|mut file| {
+        file.write_all(contents.as_bytes())?;
+        file.sync_all()
+    });
+    match written.and_then(|()| fs::rename(&temp, path)) {
+        Ok(()) => Ok(()),
+        Err(e) => {
+            let _ = fs::remove_file(&temp);
+            Err(e)
+        }
+    }
+}
+
+fn lock_path(path: &Path) -> PathBuf {
+    sibling(path, |name| format!("{}.lock", name))
+}
+
+/// `.<file>.<pid>.tmp` next to `path`.
+fn temp_path(path: &Path) -> PathBuf {
+    sibling(path, |name| format!(".{}.{}.tmp", name, std::process::id()))
+}
+
+fn sibling(path: &Path, name: impl FnOnce(&str) -> String) -> PathBuf {
+    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("state");
+    path.with_file_name(name(file_name))
+}
+
+/// The state file a leftover temporary file was written for.
+fn temp_target(dir: &Path, file_name: &str) -> Option<PathBuf> {
+    let stem = file_name.strip_prefix('.')?.strip_suffix(".tmp")?;
+    let (target, pid) = stem.rsplit_once('.')?;
+    pid.parse::<u32>().ok()?;
+    Some(dir.join(target))
+}
+
+/// What `state clean` did, or would do with `dry_run`.
+#[derive(Debug, Default)]
+pub struct CleanReport {
+    /// Caches and leftover temporary files deleted.
+    pub removed: Vec<PathBuf>,
+    /// Unparseable files renamed to `<file>.corrupt`, from and to.
+    pub set_aside: Vec<(PathBuf, PathBuf)>,
+    /// Files skipped because another invocation was writing them.
+    pub busy: Vec<PathBuf>,
+    /// Bytes freed by the removals.
+    pub bytes: u64,
+}
+
+/// Cleans the state files in `dir`.
+///
+/// Caches are deleted. Other files are kept unless they no longer parse as
+/// JSON, in which case they are renamed to `<file>.corrupt` for inspection.
+/// Temporary files left by interrupted writes are deleted. Files another
+/// invocation holds the lock on are left alone.
+pub fn clean(dir: &Path, dry_run: bool) -> Result<CleanReport, ApiError> {
+    let mut report = CleanReport::default();
+    if !dir.exists() {
+        return Ok(report);
+    }
+
+    for &(name, kind) in FILES {
+        let path = dir.join(name);
+        let Ok(metadata) = fs::metadata(&path) else {
+            continue;
+        };
+        let Some(_lock) = try_lock(&path)? else {
+            report.busy.push(path);
+            continue;
+        };
+        if kind == StateKind::Cache {
+            if !dry_run {
+                fs::remove_file(&path)?;
+            }
+            report.bytes += metadata.len();
+            report.removed.push(path);
+        } else if read::<serde_json::Value>(&path, name).is_err() {
+            let aside = sibling(&path, |name| format!("{}.corrupt", name));
+            if !dry_run {
+                fs::rename(&path, &aside)?;
+            }
+            report.set_aside.push((path, aside));
+        }
+    }
+
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let Some(target) = name.to_str().and_then(|n| temp_target(dir, n)) else {
+            continue;
+        };
+        // Temporary files only exist while their target's lock is held.
+        let Some(_lock) = try_lock(&target)? else {
+            continue;
+        };
+        if !dry_run {
+            fs::remove_file(entry.path())?;
+        }
+        report.bytes += entry.metadata().map_or(0, |m| m.len());
+        report.removed.push(entry.path());
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_write_and_update() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("counts.json");
+        assert_eq!(
+            read::<BTreeMap<String, u32>>(&path, "counts").unwrap(),
+            None
+        );
+
+        write(&path, r#"{"a": 1}"#).unwrap();
+        let bumped = update(&path, "counts", |counts: &mut BTreeMap<String, u32>| {
+            *counts.entry("a".to_string()).or_default() += 1;
+            counts["a"]
+        })
+        .unwrap();
+        assert_eq!(bumped, 2);
+        let counts: BTreeMap<String, u32> = load(&path, "counts").unwrap();
+        assert_eq!(counts["a"], 2);
+        assert!(!temp_path(&path).exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_private_mode() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("credentials.json");
+        write_private(&path, "{}").unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn test_parse_error_suggests_clean() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("outbox.json");
+        fs::write(&path, r#"{"entries": ["#).unwrap();
+        let err = read::<serde_json::Value>(&path, "outbox").unwrap_err();
+        assert!(err.to_string().contains("moltbook state clean"));
+    }
+
+    #[test]
+    fn test_clean() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::write(root.join("seen_posts.json"), r#"{"posts": []}"#).unwrap();
+        fs::write(root.join("aliases.json"), "{}").unwrap();
+        fs::write(root.join("outbox.json"), r#"{"entries": ["#).unwrap();
+        fs::write(root.join(".digests.json.4242.tmp"), "{").unwrap();
+        fs::write(root.join("notes.txt"), "keep").unwrap();
+
+        let preview = clean(root, true).unwrap();
+        assert_eq!(preview.removed.len(), 2);
+        assert!(root.join("seen_posts.json").exists());
+
+        let report = clean(root, false).unwrap();
+        assert_eq!(report.removed.len(), 2);
+        assert_eq!(report.set_aside.len(), 1);
+        assert!(!root.join("seen_posts.json").exists());
+        assert!(!root.join(".digests.json.4242.tmp").exists());
+        assert!(root.join("outbox.json.corrupt").exists());
+        assert!(root.join("aliases.json").exists());
+        assert!(root.join("notes.txt").exists());
+    }
+
+    #[test]
+    fn test_clean_skips_locked_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("seen_posts.json");
+        fs::write(&path, "{}").unwrap();
+        let _held = lock(&path).unwrap();
+        let report = clean(dir.path(), false).unwrap();
+        assert_eq!(report.busy, vec![path.clone()]);
+        assert!(path.exists());
+    }
+}
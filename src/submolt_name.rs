@@ -0,0 +1,104 @@
+//! Submolt names, checked before `create-submolt` spends its request.
+//!
+//! A name is the community's URL slug: lowercase letters, digits and single
+//! hyphens between them. [`validate`] enforces that locally; a name that
+//! breaks the rules is turned into one that keeps them by [`normalize`], and
+//! [`alternatives`] proposes variations to try when a name is taken.
+
+/// Shortest name accepted.
+pub const MIN_LEN: usize = 3;
+/// Longest name accepted.
+pub const MAX_LEN: usize = 30;
+
+/// Why `name` cannot be used as a submolt name, if it cannot.
+pub fn validate(name: &str) -> Result<(), String> {
+    let length = name.chars().count();
+    if !(MIN_LEN..=MAX_LEN).contains(&length) {
+        return Err(format!(
+            "names are {} to {} characters long",
+            MIN_LEN, MAX_LEN
+        ));
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| !c.is_ascii_lowercase() && !c.is_ascii_digit() && *c != '-')
+    {
+        return Err(format!(
+            "'{}' is not allowed; use lowercase letters, digits and hyphens",
+            c
+        ));
+    }
+    if name.starts_with('-') || name.ends_with('-') || name.contains("--") {
+        return Err("hyphens go between letters or digits, one at a time".to_string());
+    }
+    Ok(())
+}
+
+/// `name` made valid: lowercased, with spaces and underscores turned into
+/// hyphens and other characters dropped. `None` if too little is left.
+pub fn normalize(name: &str) -> Option<String> {
+    let mut slug = String::new();
+    for c in name.trim().chars() {
+        match c {
+            'a'..='z' | '0'..='9' => slug.push(c),
+            'A'..='Z' => slug.push(c.to_ascii_lowercase()),
+            ' ' | '_' | '-' | '.' if !slug.is_empty() && !slug.ends_with('-') => slug.push('-'),
+            _ => {}
+        }
+    }
+    let slug: String = slug.chars().take(MAX_LEN).collect();
+    let slug = slug.trim_end_matches('-').to_string();
+    validate(&slug).is_ok().then_some(slug)
+}
+
+/// Valid variations of `name` to offer when it is taken, best first.
+pub fn alternatives(name: &str) -> Vec<String> {
+    let base = normalize(name).unwrap_or_else(|| name.to_string());
+    let mut candidates = vec![
+        format!("{}-hub", base),
+        format!("{}-community", base),
+        format!("the-{}", base),
+        format!("{}-talk", base),
+        format!("{}-2", base),
+    ];
+    if !base.ends_with('s') {
+        candidates.insert(0, format!("{}s", base));
+    }
+    candidates.retain(|c| validate(c).is_ok() && *c != base);
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_and_normalize() {
+        assert!(validate("rust-lang").is_ok());
+        assert!(validate("ai2").is_ok());
+        assert!(validate("ab").is_err());
+        assert!(validate("Rust").unwrap_err().contains("'R'"));
+        assert!(validate("rust_lang").is_err());
+        assert!(validate("-rust").is_err());
+        assert!(validate("rust--lang").is_err());
+        assert!(validate(&"a".repeat(MAX_LEN + 1)).is_err());
+
+        assert_eq!(
+            normalize("My Cool_Community!").unwrap(),
+            "my-cool-community"
+        );
+        assert_eq!(normalize("  Rust  "), Some("rust".to_string()));
+        assert_eq!(normalize("!!"), None);
+
+        let options = alternatives("rust");
+        assert_eq!(options[0], "rusts");
+        assert!(options.contains(&"rust-hub".to_string()));
+        assert!(options.iter().all(|o| validate(o).is_ok()));
+        // Variations that would be too long are not offered.
+        assert!(
+            alternatives(&"a".repeat(MAX_LEN))
+                .iter()
+                .all(|o| o.len() <= MAX_LEN)
+        );
+    }
+}
@@ -0,0 +1,102 @@
+//! What `watch-submolt` has already streamed.
+//!
+//! Each watched submolt keeps its own list of emitted post IDs in
+//! `submolt_watch.json` in the config directory. It is separate from the
+//! feed's seen-post store (see [`crate::dedupe`]), so listing a feed does not
+//! hide posts from a watch, watching does not collapse them in the feed, and
+//! two watches do not hide each other's posts.
+
+use crate::api::error::ApiError;
+use crate::api::types::Post;
+use crate::state;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// The filename for the watch record.
+const WATCH_FILE: &str = "submolt_watch.json";
+/// Maximum number of post IDs remembered per submolt; the oldest go first.
+const MAX_POSTS: usize = 500;
+
+/// Posts streamed so far, by submolt name.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct SubmoltWatchState {
+    /// IDs of the posts already streamed from each submolt, oldest first.
+    pub submolts: BTreeMap<String, Vec<String>>,
+}
+
+impl SubmoltWatchState {
+    /// Loads the record, returning an empty one if none exists yet.
+    pub fn load() -> Result<Self, ApiError> {
+        state::load(&Self::path()?, "submolt watch state")
+    }
+
+    /// Applies `f` to the record on disk, locked against concurrent invocations.
+    pub fn update<R>(f: impl FnOnce(&mut Self) -> R) -> Result<R, ApiError> {
+        state::update(&Self::path()?, "submolt watch state", f)
+    }
+
+    /// The posts in `posts` not streamed from m/`submolt` before, oldest first.
+    pub fn unseen(&self, submolt: &str, posts: &[Post]) -> Vec<Post> {
+        let seen = self.submolts.get(submolt);
+        let mut fresh: Vec<Post> = posts
+            .iter()
+            .filter(|p| !seen.is_some_and(|ids| ids.contains(&p.id)))
+            .cloned()
+            .collect();
+        crate::ordering::sort_posts_oldest_first(&mut fresh);
+        fresh
+    }
+
+    /// Records `posts` as streamed from m/`submolt`.
+    pub fn record(&mut self, submolt: &str, posts: &[Post]) {
+        let ids = self.submolts.entry(submolt.to_string()).or_default();
+        for post in posts {
+            if !ids.contains(&post.id) {
+                ids.push(post.id.clone());
+            }
+        }
+        let excess = ids.len().saturating_sub(MAX_POSTS);
+        ids.drain(..excess);
+    }
+
+    fn path() -> Result<PathBuf, ApiError> {
+        state::path(WATCH_FILE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn post(id: &str, created_at: &str) -> Post {
+        serde_json::from_value(json!({
+            "id": id,
+            "title": "Hello",
+            "upvotes": 0,
+            "downvotes": 0,
+            "created_at": created_at,
+            "author": { "name": "bot" }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_each_submolt_has_its_own_record() {
+        let posts = [
+            post("p2", "2025-06-10T12:00:00Z"),
+            post("p1", "2025-06-10T11:00:00Z"),
+        ];
+        let mut state = SubmoltWatchState::default();
+        let fresh = state.unseen("rust", &posts);
+        assert_eq!(
+            fresh.iter().map(|p| p.id.as_str()).collect::<Vec<_>>(),
+            ["p1", "p2"]
+        );
+
+        state.record("rust", &fresh);
+        assert!(state.unseen("rust", &posts).is_empty());
+        assert_eq!(state.unseen("news", &posts).len(), 2);
+    }
+}
@@ -0,0 +1,71 @@
+//! Locally known submolt subscriptions.
+//!
+//! The API has no endpoint listing an agent's subscriptions, so the CLI keeps
+//! its own record in `subscriptions.json` in the config directory, updated by
+//! `moltbook subscribe` and `moltbook unsubscribe`. Feed listings use it to
+//! badge and filter posts from subscribed submolts.
+
+use crate::api::error::ApiError;
+use crate::state;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// The filename for the subscription record.
+const SUBSCRIPTIONS_FILE: &str = "subscriptions.json";
+
+/// Submolt slugs the agent subscribed to through the CLI.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct SubscriptionStore {
+    pub submolts: Vec<String>,
+}
+
+impl SubscriptionStore {
+    /// Loads the record, returning an empty one if none exists yet.
+    pub fn load() -> Result<Self, ApiError> {
+        state::load(&Self::path()?, "subscriptions")
+    }
+
+    /// Applies `f` to the record on disk, locked against concurrent invocations.
+    pub fn update<R>(f: impl FnOnce(&mut Self) -> R) -> Result<R, ApiError> {
+        state::update(&Self::path()?, "subscriptions", f)
+    }
+
+    /// Whether `submolt` is a known subscription (case-insensitive).
+    pub fn contains(&self, submolt: &str) -> bool {
+        self.submolts
+            .iter()
+            .any(|s| s.eq_ignore_ascii_case(submolt))
+    }
+
+    pub fn insert(&mut self, submolt: &str) {
+        if !self.contains(submolt) {
+            self.submolts.push(submolt.to_string());
+            self.submolts.sort();
+        }
+    }
+
+    pub fn remove(&mut self, submolt: &str) {
+        self.submolts.retain(|s| !s.eq_ignore_ascii_case(submolt));
+    }
+
+    fn path() -> Result<PathBuf, ApiError> {
+        state::path(SUBSCRIPTIONS_FILE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_remove_ignore_case() {
+        let mut store = SubscriptionStore::default();
+        store.insert("rust");
+        store.insert("Rust");
+        store.insert("ai");
+        assert_eq!(store.submolts, ["ai", "rust"]);
+        assert!(store.contains("RUST"));
+        store.remove("RuSt");
+        assert!(!store.contains("rust"));
+    }
+}
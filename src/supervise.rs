@@ -0,0 +1,90 @@
+//! The `status --watch` supervision dashboard.
+//!
+//! Each refresh gathers the account status, DM activity, the latest
+//! rate-limit headers and the local queue depths into a [`Snapshot`], which
+//! [`crate::display::display_supervision`] draws over the previous one. A
+//! panel whose request failed shows the error instead, so one bad poll does
+//! not blank the screen.
+
+use crate::api::types::{DmCheckResponse, StatusResponse};
+use crate::batch::retry_after;
+use crate::metrics::{Gauges, RateLimit};
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+/// Everything shown by one refresh of the dashboard.
+#[derive(Debug)]
+pub struct Snapshot {
+    /// The account status, or why it could not be loaded.
+    pub status: Result<StatusResponse, String>,
+    /// DM activity, or why it could not be loaded.
+    pub dms: Result<DmCheckResponse, String>,
+    /// The latest `X-RateLimit-*` headers, if the API sends them.
+    pub rate_limit: Option<RateLimit>,
+    /// Requests rejected with HTTP 429 since the dashboard started.
+    pub rate_limited: u64,
+    pub gauges: Gauges,
+    pub refreshed_at: DateTime<Utc>,
+    pub interval: Duration,
+}
+
+impl Snapshot {
+    /// Unread messages and pending requests, when DM activity loaded.
+    pub fn unread_dms(&self) -> Option<(u64, u64)> {
+        let dms = self.dms.as_ref().ok()?;
+        let unread = dms.messages.as_ref().map_or(0, |m| m.total_unread);
+        let requests = dms
+            .requests
+            .as_ref()
+            .map_or(0, |r| r.count.unwrap_or(r.items.len() as u64));
+        Some((unread, requests))
+    }
+}
+
+/// How long to wait before the next refresh.
+///
+/// A rate limit (its retry hint in `rate_limited`) stretches the wait to the
+/// hinted time when that is longer than `interval`.
+pub fn next_refresh(interval: Duration, rate_limited: Option<&str>) -> Duration {
+    rate_limited
+        .and_then(retry_after)
+        .map_or(interval, |wait| wait.max(interval))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_refresh() {
+        let interval = Duration::from_secs(300);
+        assert_eq!(next_refresh(interval, None), interval);
+        assert_eq!(next_refresh(interval, Some("30 seconds")), interval);
+        assert_eq!(
+            next_refresh(interval, Some("10 minutes")),
+            Duration::from_secs(600)
+        );
+        assert_eq!(
+            next_refresh(interval, Some("Wait before retrying")),
+            interval
+        );
+    }
+
+    #[test]
+    fn test_unread_dms() {
+        let dms: DmCheckResponse = serde_json::from_str(
+            r#"{"has_activity": true, "requests": {"items": []}, "messages": {"total_unread": "3"}}"#,
+        )
+        .unwrap();
+        let snapshot = Snapshot {
+            status: Err("offline".to_string()),
+            dms: Ok(dms),
+            rate_limit: None,
+            rate_limited: 0,
+            gauges: Gauges::default(),
+            refreshed_at: Utc::now(),
+            interval: Duration::from_secs(300),
+        };
+        assert_eq!(snapshot.unread_dms(), Some((3, 0)));
+    }
+}
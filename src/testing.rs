@@ -0,0 +1,262 @@
+//! A fake Moltbook API for integration tests (requires the `testing` feature).
+//!
+//! Downstream bots can spin up [`MockMoltbook`], mount the canned scenarios
+//! they need, and point a [`MoltbookClient`] at it:
+//!
+//! ```no_run
+//! # async fn demo() {
+//! use moltbook_cli::testing::MockMoltbook;
+//!
+//! let mock = MockMoltbook::start().await;
+//! mock.mount_feed().await;
+//! mock.mount_verification().await;
+//!
+//! let client = mock.client();
+//! let feed: serde_json::Value = client.get("/feed").await.unwrap();
+//! assert_eq!(feed["posts"][0]["id"], "post_1");
+//! # }
+//! ```
+//!
+//! The underlying [`MockServer`] is exposed for mounting custom mocks on top
+//! of the fixtures.
+
+use crate::api::client::MoltbookClient;
+use serde_json::{Value, json};
+use wiremock::matchers::{body_partial_json, header, method, path, path_regex};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// API key accepted by the fake server.
+pub const TEST_API_KEY: &str = "moltbook_test_key";
+/// Agent name the fake server treats as the authenticated agent.
+pub const TEST_AGENT_NAME: &str = "TestAgent";
+/// Verification code issued by the canned challenge.
+pub const TEST_VERIFICATION_CODE: &str = "moltbook_verify_test";
+/// The correct answer to the canned challenge.
+pub const TEST_VERIFICATION_ANSWER: &str = "42.00";
+
+/// Canned JSON payloads matching the live API's shapes.
+pub mod fixtures {
+    use serde_json::{Value, json};
+
+    pub fn author(name: &str) -> Value {
+        json!({ "id": format!("agent_{}", name.to_lowercase()), "name": name, "karma": 10 })
+    }
+
+    pub fn post(id: &str, title: &str) -> Value {
+        json!({
+            "id": id,
+            "title": title,
+            "content": format!("Content of {}", title),
+            "upvotes": 5,
+            "downvotes": 0,
+            "comment_count": 2,
+            "created_at": "2026-01-01T00:00:00Z",
+            "author": author("LobsterBot"),
+            "submolt": { "name": "general", "display_name": "General" }
+        })
+    }
+
+    pub fn feed() -> Value {
+        json!({
+            "success": true,
+            "posts": [post("post_1", "Hello Moltbook"), post("post_2", "Second post")],
+            "has_more": false,
+            "next_cursor": null
+        })
+    }
+
+    pub fn conversation(id: &str, with: &str) -> Value {
+        json!({
+            "conversation_id": id,
+            "with_agent": author(with),
+            "you_initiated": false,
+            "status": "approved",
+            "unread_count": 1
+        })
+    }
+
+    pub fn message(id: &str, sender: &str, content: &str) -> Value {
+        json!({
+            "id": id,
+            "sender": author(sender),
+            "content": content,
+            "needs_human_input": false,
+            "created_at": "2026-01-01T00:00:00Z"
+        })
+    }
+
+    pub fn verification_challenge() -> Value {
+        json!({
+            "verification_code": super::TEST_VERIFICATION_CODE,
+            "challenge_text": "A lobster has twenty claws and gains twenty-two more. How many?",
+            "instructions": "Answer with two decimal places.",
+            "expires_at": "2099-01-01T00:00:00Z"
+        })
+    }
+}
+
+/// A running fake Moltbook API.
+pub struct MockMoltbook {
+    server: MockServer,
+}
+
+impl MockMoltbook {
+    /// Starts a fake server on a random local port with no routes mounted.
+    pub async fn start() -> Self {
+        Self {
+            server: MockServer::start().await,
+        }
+    }
+
+    /// The base URL to pass to [`MoltbookClient::with_base_url`].
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// The underlying wiremock server, for mounting custom mocks.
+    pub fn server(&self) -> &MockServer {
+        &self.server
+    }
+
+    /// A client authenticated as [`TEST_AGENT_NAME`] and pointed at this server.
+    pub fn client(&self) -> MoltbookClient {
+        MoltbookClient::new(TEST_API_KEY.to_string(), TEST_AGENT_NAME.to_string(), false)
+            .with_base_url(self.uri())
+    }
+
+    async fn mount_get(&self, route: &str, body: Value) {
+        Mock::given(method("GET"))
+            .and(path(route))
+            .and(header("Authorization", format!("Bearer {}", TEST_API_KEY)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(&self.server)
+            .await;
+    }
+
+    /// Mounts the personalized feed, global feed, and single-post routes.
+    pub async fn mount_feed(&self) {
+        self.mount_get("/feed", fixtures::feed()).await;
+        self.mount_get("/posts", fixtures::feed()).await;
+
+        Mock::given(method("GET"))
+            .and(path_regex(r"^/posts/[^/]+$"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "success": true,
+                "post": fixtures::post("post_1", "Hello Moltbook")
+            })))
+            .mount(&self.server)
+            .await;
+    }
+
+    /// Mounts DM check, request list, conversation list/read, and send routes.
+    pub async fn mount_dms(&self) {
+        self.mount_get(
+            "/agents/dm/check",
+            json!({
+                "success": true,
+                "has_activity": true,
+                "summary": "1 pending request, 1 unread message",
+                "requests": {
+                    "count": 1,
+                    "items": [{
+                        "from": fixtures::author("CrabBot"),
+                        "message_preview": "Hi! Can we chat?",
+                        "conversation_id": "conv_pending"
+                    }]
+                },
+                "messages": { "total_unread": 1 }
+            }),
+        )
+        .await;
+        self.mount_get(
+            "/agents/dm/requests",
+            json!({
+                "success": true,
+                "requests": [{
+                    "from": fixtures::author("CrabBot"),
+                    "message": "Hi! Can we chat?",
+                    "conversation_id": "conv_pending"
+                }]
+            }),
+        )
+        .await;
+        self.mount_get(
+            "/agents/dm/conversations",
+            json!({
+                "success": true,
+                "conversations": { "items": [fixtures::conversation("conv_1", "ShrimpBot")] },
+                "total_unread": 1
+            }),
+        )
+        .await;
+        self.mount_get(
+            "/agents/dm/conversations/conv_1",
+            json!({
+                "success": true,
+                "messages": [
+                    fixtures::message("msg_1", "ShrimpBot", "Hey there!"),
+                    fixtures::message("msg_2", TEST_AGENT_NAME, "Hello!")
+                ]
+            }),
+        )
+        .await;
+
+        Mock::given(method("POST"))
+            .and(path_regex(r"^/agents/dm/conversations/[^/]+/send$"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": true })))
+            .mount(&self.server)
+            .await;
+    }
+
+    /// Mounts a post-creation route that demands verification, plus `/verify`.
+    ///
+    /// `/verify` succeeds only for [`TEST_VERIFICATION_CODE`] with
+    /// [`TEST_VERIFICATION_ANSWER`]; any other answer gets a 400 error.
+    pub async fn mount_verification(&self) {
+        Mock::given(method("POST"))
+            .and(path("/posts"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "success": true,
+                "message": "Post pending verification",
+                "post": {
+                    "id": "post_pending",
+                    "verification": fixtures::verification_challenge()
+                },
+                "verification_required": true
+            })))
+            .mount(&self.server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/verify"))
+            .and(body_partial_json(json!({
+                "verification_code": TEST_VERIFICATION_CODE,
+                "answer": TEST_VERIFICATION_ANSWER
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "success": true,
+                "message": "Verification successful",
+                "post": fixtures::post("post_pending", "Verified post")
+            })))
+            .with_priority(1)
+            .mount(&self.server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/verify"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+                "success": false,
+                "error": "Incorrect answer",
+                "hint": "Double-check the arithmetic"
+            })))
+            .mount(&self.server)
+            .await;
+    }
+
+    /// Mounts every canned scenario.
+    pub async fn mount_all(&self) {
+        self.mount_feed().await;
+        self.mount_dms().await;
+        self.mount_verification().await;
+    }
+}
@@ -0,0 +1,186 @@
+//! Session transcripts for `--record` and `moltbook replay`.
+//!
+//! While a recording is active the client notes every Moltbook API request
+//! it sends and the response it gets back. At the end of the command the
+//! exchanges are saved together with the rendered output and any error, so
+//! the run can be re-rendered later with `moltbook replay` or attached to
+//! an issue. Request headers are not recorded, so the API key never ends up
+//! in a transcript; bodies are kept as sent.
+
+use crate::api::error::ApiError;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Exchanges of the recording in progress, if any.
+static EXCHANGES: Mutex<Option<Vec<Exchange>>> = Mutex::new(None);
+
+/// One API request and the response to it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Exchange {
+    pub method: String,
+    pub url: String,
+    /// The JSON body sent, or a description of a binary one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request: Option<Value>,
+    /// The HTTP status; missing if no response arrived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    /// The response body, as JSON when it parses and as a string otherwise.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response: Option<Value>,
+    /// When the request was sent (RFC 3339).
+    pub sent_at: String,
+}
+
+/// A recorded command run, as saved by `--record`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Transcript {
+    /// The command line, starting with the program name.
+    pub command: Vec<String>,
+    /// The CLI version that made the recording.
+    pub version: String,
+    /// When the command finished (RFC 3339).
+    pub recorded_at: String,
+    pub exchanges: Vec<Exchange>,
+    /// Everything the command printed to stdout, including color codes.
+    pub output: String,
+    /// The error the command failed with, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl Transcript {
+    /// Reads a transcript saved by `--record`.
+    pub fn load(path: &Path) -> Result<Self, ApiError> {
+        let content = fs::read_to_string(path).map_err(|e| {
+            ApiError::ConfigError(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        serde_json::from_str(&content).map_err(|e| {
+            ApiError::ConfigError(format!(
+                "{} is not a moltbook transcript: {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+
+    /// Writes the transcript as pretty-printed JSON.
+    pub fn save(&self, path: &Path) -> Result<(), ApiError> {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+/// Notes a request about to be sent, returning its slot for [`response`].
+///
+/// Returns `None`, without calling `body`, when nothing is being recorded.
+pub fn request(method: &str, url: &str, body: impl FnOnce() -> Option<Value>) -> Option<usize> {
+    let mut exchanges = EXCHANGES.lock().unwrap_or_else(|e| e.into_inner());
+    let exchanges = exchanges.as_mut()?;
+    exchanges.push(Exchange {
+        method: method.to_string(),
+        url: url.to_string(),
+        request: body(),
+        status: None,
+        response: None,
+        sent_at: chrono::Utc::now().to_rfc3339(),
+    });
+    Some(exchanges.len() - 1)
+}
+
+/// Fills in the response to the request in `slot`.
+pub fn response(slot: Option<usize>, status: u16, body: &str) {
+    let Some(slot) = slot else {
+        return;
+    };
+    let mut exchanges = EXCHANGES.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(exchange) = exchanges.as_mut().and_then(|e| e.get_mut(slot)) {
+        exchange.status = Some(status);
+        exchange.response =
+            Some(serde_json::from_str(body).unwrap_or_else(|_| Value::String(body.to_string())));
+    }
+}
+
+/// A recording in progress, started by `--record <path>`.
+pub struct Recording {
+    path: PathBuf,
+    #[cfg(unix)]
+    capture: crate::capture::Capture,
+}
+
+impl Recording {
+    /// Starts recording API exchanges and stdout.
+    ///
+    /// Colors are decided now, while stdout is still the terminal, so the
+    /// transcript holds the output exactly as it would have been shown.
+    pub fn start(path: PathBuf) -> Result<Self, ApiError> {
+        colored::control::set_override(colored::control::SHOULD_COLORIZE.should_colorize());
+        *EXCHANGES.lock().unwrap_or_else(|e| e.into_inner()) = Some(Vec::new());
+        Ok(Self {
+            path,
+            #[cfg(unix)]
+            capture: crate::capture::Capture::stdout()?,
+        })
+    }
+
+    /// Stops recording, shows the captured output and saves the transcript.
+    pub fn finish(self, error: Option<&ApiError>) -> Result<(), ApiError> {
+        #[cfg(unix)]
+        let output = {
+            let (output, _) = self.capture.finish()?;
+            print!("{}", output);
+            output
+        };
+        #[cfg(not(unix))]
+        let output = String::new();
+
+        let exchanges = EXCHANGES
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take()
+            .unwrap_or_default();
+        let transcript = Transcript {
+            command: std::env::args().collect(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            recorded_at: chrono::Utc::now().to_rfc3339(),
+            exchanges,
+            output,
+            error: error.map(ToString::to_string),
+        };
+        transcript.save(&self.path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_transcript_round_trip() {
+        let transcript = Transcript {
+            command: vec!["moltbook".into(), "feed".into()],
+            version: "1.0.0".into(),
+            recorded_at: "2026-01-01T00:00:00Z".into(),
+            exchanges: vec![Exchange {
+                method: "GET".into(),
+                url: "https://www.moltbook.com/api/v1/feed".into(),
+                request: None,
+                status: Some(200),
+                response: Some(json!({"posts": []})),
+                sent_at: "2026-01-01T00:00:00Z".into(),
+            }],
+            output: "\u{1b}[1mFeed\u{1b}[0m\n".into(),
+            error: None,
+        };
+        let file = tempfile::NamedTempFile::new().unwrap();
+        transcript.save(file.path()).unwrap();
+        assert_eq!(Transcript::load(file.path()).unwrap(), transcript);
+
+        fs::write(file.path(), "{}").unwrap();
+        assert!(Transcript::load(file.path()).is_err());
+    }
+}
@@ -0,0 +1,494 @@
+//! State and rendering for `moltbook tui` (requires the `tui` feature).
+//!
+//! [`App`] holds what is on screen and turns key presses into [`Action`]s.
+//! The network side lives in `cli::tui`, which carries the actions out and
+//! hands the results back, so key handling can be tested without a terminal
+//! or an API.
+
+use crate::api::types::Post;
+use crate::display::relative_time;
+use ratatui::Frame;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph, Wrap};
+use serde_json::Value;
+
+/// Which screen is showing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum View {
+    Feed,
+    Post,
+    Comments,
+}
+
+/// Something the key handler needs done outside the app.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    None,
+    Quit,
+    /// Fetch the next page of the feed.
+    LoadMore,
+    /// Fetch the comments of the post.
+    LoadComments(String),
+    UpvotePost(String),
+    UpvoteComment(String),
+    /// Post `content` as a comment, replying to `parent` if set.
+    SendReply {
+        post_id: String,
+        parent: Option<String>,
+        content: String,
+    },
+}
+
+/// A reply being typed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Draft {
+    pub post_id: String,
+    /// The comment replied to and its author; `None` comments on the post.
+    pub parent: Option<(String, String)>,
+    pub text: String,
+}
+
+/// Everything on screen.
+#[derive(Debug)]
+pub struct App {
+    pub view: View,
+    pub posts: Vec<Post>,
+    /// Cursor for the next page, if there is one.
+    pub cursor: Option<String>,
+    pub feed: ListState,
+    /// Lines scrolled down in the post view.
+    pub scroll: u16,
+    /// The post the comments belong to.
+    pub thread_post: Option<String>,
+    /// Comments in display order, with their nesting depth.
+    pub comments: Vec<(usize, Value)>,
+    pub thread: ListState,
+    pub draft: Option<Draft>,
+    /// The last result or error, shown in the status bar.
+    pub status: String,
+}
+
+impl App {
+    pub fn new(posts: Vec<Post>, cursor: Option<String>) -> Self {
+        let mut feed = ListState::default();
+        if !posts.is_empty() {
+            feed.select(Some(0));
+        }
+        Self {
+            view: View::Feed,
+            posts,
+            cursor,
+            feed,
+            scroll: 0,
+            thread_post: None,
+            comments: Vec::new(),
+            thread: ListState::default(),
+            draft: None,
+            status: String::new(),
+        }
+    }
+
+    /// The highlighted post.
+    pub fn selected_post(&self) -> Option<&Post> {
+        self.posts.get(self.feed.selected()?)
+    }
+
+    /// The highlighted comment.
+    pub fn selected_comment(&self) -> Option<&Value> {
+        self.comments.get(self.thread.selected()?).map(|(_, c)| c)
+    }
+
+    /// Adds the next page of the feed.
+    pub fn append_posts(&mut self, posts: Vec<Post>, cursor: Option<String>) {
+        if self.feed.selected().is_none() && !posts.is_empty() {
+            self.feed.select(Some(0));
+        }
+        self.posts.extend(posts);
+        self.cursor = cursor;
+    }
+
+    /// Shows the comments of `post_id`, replies nested under their parents.
+    pub fn show_comments(&mut self, post_id: &str, comments: &[Value]) {
+        self.comments.clear();
+        flatten(comments, 0, &mut self.comments);
+        self.thread = ListState::default();
+        if !self.comments.is_empty() {
+            self.thread.select(Some(0));
+        }
+        self.thread_post = Some(post_id.to_string());
+        self.view = View::Comments;
+    }
+
+    /// Handles a key press, returning what has to be done in response.
+    pub fn handle_key(&mut self, key: KeyEvent) -> Action {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return Action::Quit;
+        }
+        if self.draft.is_some() {
+            return self.edit_draft(key);
+        }
+        match (self.view, key.code) {
+            (View::Feed, KeyCode::Char('q') | KeyCode::Esc) => return Action::Quit,
+            (_, KeyCode::Char('q') | KeyCode::Esc) => {
+                self.view = View::Feed;
+                return Action::None;
+            }
+            (View::Feed, KeyCode::Char('j') | KeyCode::Down) => return self.next_post(),
+            (View::Feed, KeyCode::Char('k') | KeyCode::Up) => {
+                self.feed.select_previous();
+                return Action::None;
+            }
+            (View::Feed, KeyCode::Enter | KeyCode::Char('o')) => {
+                if self.selected_post().is_some() {
+                    self.scroll = 0;
+                    self.view = View::Post;
+                }
+                return Action::None;
+            }
+            (View::Feed, KeyCode::Char('n')) if self.cursor.is_some() => {
+                return Action::LoadMore;
+            }
+            (View::Post, KeyCode::Char('j') | KeyCode::Down) => {
+                self.scroll = self.scroll.saturating_add(1);
+                return Action::None;
+            }
+            (View::Post, KeyCode::Char('k') | KeyCode::Up) => {
+                self.scroll = self.scroll.saturating_sub(1);
+                return Action::None;
+            }
+            (View::Comments, KeyCode::Char('j') | KeyCode::Down) => {
+                self.thread.select_next();
+                return Action::None;
+            }
+            (View::Comments, KeyCode::Char('k') | KeyCode::Up) => {
+                self.thread.select_previous();
+                return Action::None;
+            }
+            (View::Comments, KeyCode::Char('u')) => {
+                return match self.selected_comment().and_then(|c| c["id"].as_str()) {
+                    Some(id) => Action::UpvoteComment(id.to_string()),
+                    None => Action::None,
+                };
+            }
+            (View::Comments, KeyCode::Char('r')) => {
+                if let (Some(post_id), Some(comment)) =
+                    (self.thread_post.clone(), self.selected_comment())
+                {
+                    let id = comment["id"].as_str().unwrap_or_default().to_string();
+                    let author = comment["author"]["name"].as_str().unwrap_or("unknown");
+                    self.draft = Some(Draft {
+                        post_id,
+                        parent: Some((id, author.to_string())),
+                        text: String::new(),
+                    });
+                    return Action::None;
+                }
+            }
+            _ => {}
+        }
+
+        // The rest act on the highlighted post, or the one whose comments are shown.
+        let post_id = match self.view {
+            View::Comments => self.thread_post.clone(),
+            _ => self.selected_post().map(|p| p.id.clone()),
+        };
+        let Some(post_id) = post_id else {
+            return Action::None;
+        };
+        match key.code {
+            KeyCode::Char('c') => Action::LoadComments(post_id),
+            KeyCode::Char('u') => Action::UpvotePost(post_id),
+            KeyCode::Char('r') => {
+                self.draft = Some(Draft {
+                    post_id,
+                    parent: None,
+                    text: String::new(),
+                });
+                Action::None
+            }
+            _ => Action::None,
+        }
+    }
+
+    /// Moves down the feed, asking for the next page at the end of it.
+    fn next_post(&mut self) -> Action {
+        let last = self.posts.len().saturating_sub(1);
+        match self.feed.selected() {
+            Some(i) if i >= last && self.cursor.is_some() => Action::LoadMore,
+            Some(i) if i < last => {
+                self.feed.select(Some(i + 1));
+                Action::None
+            }
+            _ => Action::None,
+        }
+    }
+
+    fn edit_draft(&mut self, key: KeyEvent) -> Action {
+        let Some(draft) = self.draft.as_mut() else {
+            return Action::None;
+        };
+        match key.code {
+            KeyCode::Esc => self.draft = None,
+            KeyCode::Backspace => {
+                draft.text.pop();
+            }
+            KeyCode::Enter if !draft.text.trim().is_empty() => {
+                let action = Action::SendReply {
+                    post_id: std::mem::take(&mut draft.post_id),
+                    parent: draft.parent.take().map(|(id, _)| id),
+                    content: draft.text.trim().to_string(),
+                };
+                self.draft = None;
+                return action;
+            }
+            KeyCode::Char(c) => draft.text.push(c),
+            _ => {}
+        }
+        Action::None
+    }
+}
+
+/// Appends `comments` and, after each, its replies one level deeper.
+fn flatten(comments: &[Value], depth: usize, out: &mut Vec<(usize, Value)>) {
+    for comment in comments {
+        out.push((depth, comment.clone()));
+        if let Some(replies) = comment["replies"].as_array() {
+            flatten(replies, depth + 1, out);
+        }
+    }
+}
+
+/// Key hints for the status bar.
+fn help(app: &App) -> &'static str {
+    if app.draft.is_some() {
+        return "Enter send · Esc cancel";
+    }
+    match app.view {
+        View::Feed => "↑↓ move · Enter open · c comments · u upvote · r reply · n more · q quit",
+        View::Post => "↑↓ scroll · c comments · u upvote · r reply · q back",
+        View::Comments => "↑↓ move · u upvote · r reply · q back",
+    }
+}
+
+/// Draws the current view, the reply box when typing, and the status bar.
+pub fn draw(frame: &mut Frame, app: &mut App) {
+    let input_height = if app.draft.is_some() { 3 } else { 0 };
+    let [main, input, status] = Layout::vertical([
+        Constraint::Min(1),
+        Constraint::Length(input_height),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    match app.view {
+        View::Feed => draw_feed(frame, app, main),
+        View::Post => draw_post(frame, app, main),
+        View::Comments => draw_comments(frame, app, main),
+    }
+
+    if let Some(draft) = &app.draft {
+        let title = match &draft.parent {
+            Some((_, author)) => format!(" Reply to {} ", author),
+            None => " Comment ".to_string(),
+        };
+        let text = format!("{}▏", draft.text);
+        frame.render_widget(
+            Paragraph::new(text).block(Block::bordered().title(title)),
+            input,
+        );
+    }
+
+    let line = if app.status.is_empty() {
+        Line::from(help(app).dark_gray())
+    } else {
+        Line::from(vec![
+            Span::raw(app.status.as_str()).yellow(),
+            Span::raw("  ·  "),
+            Span::raw(help(app)).dark_gray(),
+        ])
+    };
+    frame.render_widget(Paragraph::new(line), status);
+}
+
+fn draw_feed(frame: &mut Frame, app: &mut App, area: Rect) {
+    let items: Vec<ListItem> = app
+        .posts
+        .iter()
+        .map(|post| {
+            let submolt = post
+                .submolt
+                .as_ref()
+                .map(|s| s.name.as_str())
+                .or(post.submolt_name.as_deref())
+                .unwrap_or("general");
+            let meta = format!(
+                "       m/{} · {} · 💬 {} · {}",
+                submolt,
+                post.author.name,
+                post.comment_count.unwrap_or(0),
+                relative_time(&post.created_at)
+            );
+            ListItem::new(vec![
+                Line::from(vec![
+                    Span::raw(format!("⬆ {:>4} ", post.upvotes)).green(),
+                    Span::raw(post.title.as_str()).bold(),
+                ]),
+                Line::from(meta.dark_gray()),
+            ])
+        })
+        .collect();
+    let more = if app.cursor.is_some() {
+        " (n for more)"
+    } else {
+        ""
+    };
+    let title = format!(" Your Feed · {} posts{} ", app.posts.len(), more);
+    let list = List::new(items)
+        .block(Block::bordered().title(title))
+        .highlight_style(Style::new().bg(Color::DarkGray))
+        .highlight_symbol("▶ ");
+    frame.render_stateful_widget(list, area, &mut app.feed);
+}
+
+fn draw_post(frame: &mut Frame, app: &App, area: Rect) {
+    let Some(post) = app.selected_post() else {
+        return;
+    };
+    let mut text = Text::from(Line::from(post.title.as_str().bold()));
+    text.push_line(Line::from(
+        format!(
+            "{} · ⬆ {} ⬇ {} · 💬 {} · {}",
+            post.author.name,
+            post.upvotes,
+            post.downvotes,
+            post.comment_count.unwrap_or(0),
+            relative_time(&post.created_at)
+        )
+        .dark_gray(),
+    ));
+    if let Some(url) = &post.url {
+        text.push_line(Line::from(url.as_str().cyan()));
+    }
+    text.push_line(Line::default());
+    for line in post.content.as_deref().unwrap_or("").lines() {
+        text.push_line(Line::from(line));
+    }
+    let paragraph = Paragraph::new(text)
+        .block(Block::bordered().title(format!(" {} ", post.id)))
+        .wrap(Wrap { trim: false })
+        .scroll((app.scroll, 0));
+    frame.render_widget(paragraph, area);
+}
+
+fn draw_comments(frame: &mut Frame, app: &mut App, area: Rect) {
+    // Borders, the highlight symbol and a little slack.
+    let width = usize::from(area.width).saturating_sub(6);
+    let items: Vec<ListItem> = app
+        .comments
+        .iter()
+        .map(|(depth, comment)| {
+            let indent = "  ".repeat(*depth);
+            let author = comment["author"]["name"].as_str().unwrap_or("unknown");
+            let upvotes = comment["upvotes"].as_i64().unwrap_or(0);
+            let mut lines = vec![Line::from(vec![
+                Span::raw(indent.clone()),
+                Span::raw(author.to_string()).yellow().bold(),
+                Span::raw(format!(" ⬆ {}", upvotes)).dark_gray(),
+            ])];
+            let content = comment["content"].as_str().unwrap_or("");
+            for line in textwrap::wrap(content, width.saturating_sub(indent.len()).max(20)) {
+                lines.push(Line::from(format!("{}{}", indent, line)));
+            }
+            ListItem::new(lines)
+        })
+        .collect();
+    let title = format!(" Comments · {} ", app.comments.len());
+    let list = List::new(items)
+        .block(Block::bordered().title(title))
+        .highlight_style(Style::new().bg(Color::DarkGray))
+        .highlight_symbol("▶ ");
+    frame.render_stateful_widget(list, area, &mut app.thread);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn press(app: &mut App, code: KeyCode) -> Action {
+        app.handle_key(KeyEvent::from(code))
+    }
+
+    #[test]
+    fn test_handle_key() {
+        let posts: Vec<Post> = serde_json::from_value(json!([
+            {"id": "p1", "title": "One", "author": {"name": "a"}},
+            {"id": "p2", "title": "Two", "author": {"name": "b"}}
+        ]))
+        .unwrap();
+        let mut app = App::new(posts, Some("c1".to_string()));
+
+        assert_eq!(press(&mut app, KeyCode::Down), Action::None);
+        assert_eq!(app.selected_post().unwrap().id, "p2");
+        // At the end of the loaded posts, moving down asks for the next page.
+        assert_eq!(press(&mut app, KeyCode::Down), Action::LoadMore);
+        assert_eq!(
+            press(&mut app, KeyCode::Char('u')),
+            Action::UpvotePost("p2".to_string())
+        );
+        assert_eq!(
+            press(&mut app, KeyCode::Char('c')),
+            Action::LoadComments("p2".to_string())
+        );
+
+        app.show_comments(
+            "p2",
+            &[json!({"id": "c1", "author": {"name": "x"}, "replies": [
+                {"id": "c2", "author": {"name": "y"}}
+            ]})],
+        );
+        assert_eq!(app.comments.len(), 2);
+        assert_eq!(app.comments[1].0, 1);
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Char('r'));
+        // Keys go to the reply box while it is open.
+        for c in "hi q".chars() {
+            assert_eq!(press(&mut app, KeyCode::Char(c)), Action::None);
+        }
+        assert_eq!(
+            press(&mut app, KeyCode::Enter),
+            Action::SendReply {
+                post_id: "p2".to_string(),
+                parent: Some("c2".to_string()),
+                content: "hi q".to_string(),
+            }
+        );
+        assert!(app.draft.is_none());
+
+        assert_eq!(press(&mut app, KeyCode::Char('q')), Action::None);
+        assert_eq!(app.view, View::Feed);
+        assert_eq!(press(&mut app, KeyCode::Char('q')), Action::Quit);
+    }
+
+    #[test]
+    fn test_draw() {
+        let posts: Vec<Post> = serde_json::from_value(json!([
+            {"id": "p1", "title": "Hello molts", "content": "Body", "author": {"name": "a"}}
+        ]))
+        .unwrap();
+        let mut app = App::new(posts, None);
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 12)).unwrap();
+        for key in [KeyCode::Enter, KeyCode::Char('r')] {
+            terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+            press(&mut app, key);
+        }
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+        let screen = format!("{:?}", terminal.backend().buffer());
+        assert!(screen.contains("Hello molts"));
+        assert!(screen.contains("Comment"));
+    }
+}
@@ -0,0 +1,151 @@
+//! Local state for resumable uploads.
+//!
+//! Large files are sent in chunks through an upload session (see
+//! [`MoltbookClient::upload_resumable`](crate::api::client::MoltbookClient::upload_resumable)).
+//! After every chunk the session is recorded in `uploads.json` in the config
+//! directory, so re-running the same command after a dropped connection
+//! continues where the last attempt stopped instead of starting over.
+
+use crate::api::error::ApiError;
+use crate::state;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+/// The filename for partial upload state.
+const UPLOADS_FILE: &str = "uploads.json";
+
+/// Identifies one version of a local file.
+///
+/// A file that changed since its upload started does not match and is sent
+/// from scratch.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FileStamp {
+    /// Canonical path of the file.
+    pub path: String,
+    pub size: u64,
+    /// Modification time in seconds since the Unix epoch.
+    pub modified: u64,
+}
+
+impl FileStamp {
+    /// Reads the stamp of the file at `path`.
+    pub fn of(path: &Path) -> Result<Self, ApiError> {
+        let metadata = fs::metadata(path)?;
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |d| d.as_secs());
+        Ok(Self {
+            path: fs::canonicalize(path)?.to_string_lossy().to_string(),
+            size: metadata.len(),
+            modified,
+        })
+    }
+}
+
+/// An upload session that has not been completed yet.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PartialUpload {
+    /// The endpoint the finished file is attached to.
+    pub target: String,
+    pub file: FileStamp,
+    pub upload_id: String,
+    pub chunk_size: u64,
+    /// Bytes the server has acknowledged.
+    pub received: u64,
+    /// When the session was opened (RFC 3339).
+    pub started_at: String,
+}
+
+/// Partial uploads, persisted between invocations.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct UploadStore {
+    pub uploads: Vec<PartialUpload>,
+}
+
+impl UploadStore {
+    /// Loads the partial uploads, returning an empty store if none exist yet.
+    pub fn load() -> Result<Self, ApiError> {
+        state::load(&Self::path()?, "uploads")
+    }
+
+    /// Applies `f` to the partial uploads on disk, locked against concurrent
+    /// invocations.
+    pub fn update<R>(f: impl FnOnce(&mut Self) -> R) -> Result<R, ApiError> {
+        state::update(&Self::path()?, "uploads", f)
+    }
+
+    /// The unfinished upload of `file` to `target`, if any.
+    pub fn find(&self, target: &str, file: &FileStamp) -> Option<&PartialUpload> {
+        self.uploads
+            .iter()
+            .find(|u| u.target == target && u.file == *file)
+    }
+
+    /// Records `upload`, replacing any earlier upload of the same file to the same target.
+    pub fn upsert(&mut self, upload: PartialUpload) {
+        self.uploads
+            .retain(|u| !(u.target == upload.target && u.file.path == upload.file.path));
+        self.uploads.push(upload);
+    }
+
+    /// Forgets the session `upload_id`.
+    pub fn remove(&mut self, upload_id: &str) {
+        self.uploads.retain(|u| u.upload_id != upload_id);
+    }
+
+    fn path() -> Result<PathBuf, ApiError> {
+        state::path(UPLOADS_FILE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn upload(id: &str, modified: u64) -> PartialUpload {
+        PartialUpload {
+            target: "/agents/me/avatar".to_string(),
+            file: FileStamp {
+                path: "/tmp/video.mp4".to_string(),
+                size: 50_000_000,
+                modified,
+            },
+            upload_id: id.to_string(),
+            chunk_size: 5_000_000,
+            received: 10_000_000,
+            started_at: "2026-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_find_requires_unchanged_file() {
+        let mut store = UploadStore::default();
+        store.upsert(upload("u1", 100));
+        let stamp = upload("", 100).file;
+        assert_eq!(
+            store.find("/agents/me/avatar", &stamp).unwrap().upload_id,
+            "u1"
+        );
+        assert!(store.find("/submolts/rust/banner", &stamp).is_none());
+        assert!(
+            store
+                .find("/agents/me/avatar", &upload("", 200).file)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_upsert_replaces_stale_session() {
+        let mut store = UploadStore::default();
+        store.upsert(upload("u1", 100));
+        store.upsert(upload("u2", 200));
+        assert_eq!(store.uploads.len(), 1);
+        assert_eq!(store.uploads[0].upload_id, "u2");
+        store.remove("u2");
+        assert!(store.uploads.is_empty());
+    }
+}
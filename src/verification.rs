@@ -0,0 +1,185 @@
+//! Requests waiting on a verification challenge.
+//!
+//! When the API answers a mutation with a challenge, the client saves the
+//! request (method, endpoint and body) in `pending_verifications.json` under
+//! the challenge's code, together with whatever the response said was
+//! created. Once `moltbook verify` solves the challenge it looks the request
+//! up again, so it can show the post or comment that went live or send the
+//! request again if the API held it back. Entries older than [`KEEP_HOURS`]
+//! are dropped whenever a new one is saved.
+
+use crate::alias::{AliasTarget, ItemKind};
+use crate::api::error::ApiError;
+use crate::state;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::PathBuf;
+
+/// The filename for the pending challenges.
+const CHALLENGES_FILE: &str = "pending_verifications.json";
+/// How long an unsolved challenge is kept.
+pub const KEEP_HOURS: i64 = 24;
+
+/// The challenge object in a response, top-level or inside the created
+/// `post` or `comment`.
+pub fn find_challenge(response: &Value) -> Option<&Value> {
+    if response["verification"].is_object() {
+        return Some(&response["verification"]);
+    }
+    let inner = response.get("comment").or_else(|| response.get("post"))?;
+    inner["verification"]
+        .is_object()
+        .then(|| &inner["verification"])
+}
+
+/// A request whose response asked for verification.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PendingChallenge {
+    /// The challenge's verification code.
+    pub code: String,
+    pub method: String,
+    pub endpoint: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+    /// The `Idempotency-Key` the request was sent with, so sending it again
+    /// cannot apply it twice.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+    /// The post or comment the response said it created, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<AliasTarget>,
+    /// Whether the response reported failure, so nothing was done and the
+    /// request has to be sent again once the challenge is solved.
+    #[serde(default)]
+    pub held: bool,
+    /// When the challenge was received (RFC 3339).
+    pub at: String,
+}
+
+impl PendingChallenge {
+    /// The pending request for `response`, or `None` if it carries no challenge.
+    pub fn from_response(method: &str, endpoint: &str, response: &Value) -> Option<Self> {
+        let challenge = find_challenge(response)?;
+        let code = challenge["verification_code"]
+            .as_str()
+            .or_else(|| challenge["code"].as_str())
+            .filter(|code| !code.is_empty())?;
+        let created = [("post", ItemKind::Post), ("comment", ItemKind::Comment)]
+            .into_iter()
+            .find_map(|(key, kind)| {
+                let id = response[key]["id"].as_str()?;
+                Some(AliasTarget::new(kind, id))
+            });
+        Some(Self {
+            code: code.to_string(),
+            method: method.to_string(),
+            endpoint: endpoint.to_string(),
+            body: None,
+            idempotency_key: None,
+            created,
+            held: !response["success"].as_bool().unwrap_or(false),
+            at: Utc::now().to_rfc3339(),
+        })
+    }
+
+    /// Keeps the JSON body the request was sent with.
+    pub fn with_body(mut self, body: Option<Value>) -> Self {
+        self.body = body;
+        self
+    }
+
+    pub fn with_idempotency_key(mut self, key: Option<&str>) -> Self {
+        self.idempotency_key = key.map(String::from);
+        self
+    }
+
+    fn time(&self) -> Option<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(&self.at)
+            .ok()
+            .map(|at| at.with_timezone(&Utc))
+    }
+}
+
+/// Unsolved challenges, oldest first.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct ChallengeStore {
+    pub challenges: Vec<PendingChallenge>,
+}
+
+impl ChallengeStore {
+    /// Loads the store, returning an empty one if none exists yet.
+    pub fn load() -> Result<Self, ApiError> {
+        state::load(&Self::path()?, "pending verifications")
+    }
+
+    /// Saves `challenge`, replacing any earlier one with the same code.
+    pub fn record(challenge: PendingChallenge) -> Result<(), ApiError> {
+        let now = Utc::now();
+        state::update(
+            &Self::path()?,
+            "pending verifications",
+            |store: &mut Self| {
+                store.challenges.retain(|c| {
+                    c.code != challenge.code
+                        && c.time()
+                            .is_some_and(|at| now - at < Duration::hours(KEEP_HOURS))
+                });
+                store.challenges.push(challenge);
+            },
+        )
+    }
+
+    /// Removes and returns the challenge with `code`, if one was saved.
+    pub fn take(code: &str) -> Result<Option<PendingChallenge>, ApiError> {
+        state::update(
+            &Self::path()?,
+            "pending verifications",
+            |store: &mut Self| {
+                let index = store.challenges.iter().position(|c| c.code == code)?;
+                Some(store.challenges.remove(index))
+            },
+        )
+    }
+
+    fn path() -> Result<PathBuf, ApiError> {
+        state::path(CHALLENGES_FILE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_from_response() {
+        let response = json!({
+            "success": true,
+            "comment": {
+                "id": "c1",
+                "verification": {"verification_code": "v-123", "challenge_text": "2+2"}
+            }
+        });
+        let body = json!({"content": "Hi"});
+        let pending = PendingChallenge::from_response("POST", "/posts/p1/comments", &response)
+            .unwrap()
+            .with_body(Some(body.clone()));
+        assert_eq!(pending.code, "v-123");
+        assert_eq!(pending.body, Some(body));
+        assert!(!pending.held);
+        assert_eq!(
+            pending.created,
+            Some(AliasTarget::new(ItemKind::Comment, "c1"))
+        );
+
+        let held = json!({"verification": {"code": "v-9"}, "verification_required": true});
+        let pending = PendingChallenge::from_response("POST", "/posts/p1/upvote", &held).unwrap();
+        assert_eq!(pending.created, None);
+        assert!(pending.held);
+
+        assert!(
+            PendingChallenge::from_response("POST", "/posts", &json!({"success": true})).is_none()
+        );
+    }
+}
@@ -0,0 +1,139 @@
+//! When each post's comments were last viewed, for `comments --new-only`.
+//!
+//! Every `comments` run records the time in `comment_visits.json` in the
+//! config directory. On the next visit, comments created after that time
+//! (including replies deep in a thread) can be shown on their own.
+
+use crate::api::error::ApiError;
+use crate::state;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// The filename for the visit record.
+const VISITS_FILE: &str = "comment_visits.json";
+/// Maximum number of posts remembered; the least recently visited go first.
+const MAX_VISITS: usize = 500;
+
+/// Last comment visit per post ID, as RFC 3339 timestamps.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct VisitStore {
+    pub posts: BTreeMap<String, String>,
+}
+
+impl VisitStore {
+    /// Loads the visit record, returning an empty one if none exists yet.
+    pub fn load() -> Result<Self, ApiError> {
+        state::load(&Self::path()?, "comment visits")
+    }
+
+    /// Applies `f` to the record on disk, locked against concurrent invocations,
+    /// keeping only the `MAX_VISITS` most recent posts.
+    pub fn update<R>(f: impl FnOnce(&mut Self) -> R) -> Result<R, ApiError> {
+        state::update(&Self::path()?, "comment visits", |visits: &mut Self| {
+            let result = f(visits);
+            visits.trim();
+            result
+        })
+    }
+
+    /// When the comments of `post_id` were last viewed.
+    pub fn last(&self, post_id: &str) -> Option<DateTime<Utc>> {
+        self.posts
+            .get(post_id)
+            .and_then(|at| DateTime::parse_from_rfc3339(at).ok())
+            .map(|at| at.with_timezone(&Utc))
+    }
+
+    pub fn record(&mut self, post_id: &str, at: DateTime<Utc>) {
+        let at = at.to_rfc3339_opts(SecondsFormat::Secs, true);
+        self.posts.insert(post_id.to_string(), at);
+    }
+
+    fn trim(&mut self) {
+        if self.posts.len() > MAX_VISITS {
+            let mut by_age: Vec<(String, String)> =
+                std::mem::take(&mut self.posts).into_iter().collect();
+            // Timestamps share one UTC format, so they sort chronologically as strings.
+            by_age.sort_by(|a, b| b.1.cmp(&a.1));
+            by_age.truncate(MAX_VISITS);
+            self.posts = by_age.into_iter().collect();
+        }
+    }
+
+    fn path() -> Result<PathBuf, ApiError> {
+        state::path(VISITS_FILE)
+    }
+}
+
+/// Comments created after `since`, oldest first, and the number of earlier
+/// ones.
+///
+/// Replies are pulled out of their threads so a new reply under an old
+/// comment is not missed; the returned comments carry no `replies` of their
+/// own. Comments without a readable `created_at` count as earlier.
+pub fn new_since(comments: &[Value], since: DateTime<Utc>) -> (Vec<Value>, usize) {
+    let mut fresh = Vec::new();
+    let mut earlier = 0;
+    let mut stack: Vec<&Value> = comments.iter().rev().collect();
+    while let Some(comment) = stack.pop() {
+        if let Some(replies) = comment["replies"].as_array() {
+            stack.extend(replies.iter().rev());
+        }
+        let created = comment["created_at"]
+            .as_str()
+            .and_then(|at| DateTime::parse_from_rfc3339(at).ok());
+        match created {
+            Some(at) if at > since => {
+                let mut comment = comment.clone();
+                if let Some(object) = comment.as_object_mut() {
+                    object.remove("replies");
+                }
+                fresh.push(comment);
+            }
+            _ => earlier += 1,
+        }
+    }
+    crate::ordering::sort_comments_oldest_first(&mut fresh);
+    (fresh, earlier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_new_since_finds_nested_replies() {
+        let comments = vec![
+            json!({
+                "id": "c1",
+                "created_at": "2025-06-01T10:00:00Z",
+                "replies": [
+                    { "id": "r2", "created_at": "2025-06-02T09:00:00Z" },
+                    { "id": "r1", "created_at": "2025-06-01T11:00:00Z" }
+                ]
+            }),
+            json!({ "id": "c2", "created_at": "2025-06-02T08:00:00Z" }),
+            json!({ "id": "c3" }),
+        ];
+        let (fresh, earlier) = new_since(&comments, at("2025-06-01T12:00:00Z"));
+        let ids: Vec<&str> = fresh.iter().map(|c| c["id"].as_str().unwrap()).collect();
+        assert_eq!(ids, ["c2", "r2"]);
+        assert_eq!(earlier, 3);
+    }
+
+    #[test]
+    fn test_record_and_last() {
+        let mut store = VisitStore::default();
+        assert_eq!(store.last("p1"), None);
+        store.record("p1", at("2025-06-01T12:00:00Z"));
+        assert_eq!(store.last("p1"), Some(at("2025-06-01T12:00:00Z")));
+    }
+}
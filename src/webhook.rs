@@ -0,0 +1,238 @@
+//! Inbound webhooks for `moltbook listen`.
+//!
+//! The `webhooks` config section names hooks, each mapping a signed
+//! `POST /hooks/<name>` call to one Moltbook action: a post or a DM built
+//! from templates. `{{field}}` placeholders are filled from the JSON body of
+//! the call (`{{repo.name}}` reaches into nested objects).
+//!
+//! Callers sign the raw request body with HMAC-SHA256 using the shared
+//! secret and send it as `X-Moltbook-Signature: sha256=<hex>`, the scheme
+//! GitHub and most CI services use.
+
+use crate::api::endpoint::Endpoint;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+
+/// The header carrying the body signature.
+pub const SIGNATURE_HEADER: &str = "X-Moltbook-Signature";
+
+/// The `webhooks` section of the configuration.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Webhooks {
+    /// Shared secret that callers sign request bodies with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    /// Hooks by name, called at `POST /hooks/<name>`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub hooks: BTreeMap<String, HookAction>,
+}
+
+impl Webhooks {
+    pub fn is_empty(&self) -> bool {
+        self.secret.is_none() && self.hooks.is_empty()
+    }
+}
+
+/// What a hook does when called. Every string field is a template.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum HookAction {
+    /// Creates a post.
+    Post {
+        submolt: String,
+        title: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        content: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        url: Option<String>,
+    },
+    /// Sends a message into an existing DM conversation.
+    Dm {
+        conversation: String,
+        message: String,
+    },
+}
+
+impl HookAction {
+    /// Short description for listings, e.g. `post to m/ci`.
+    pub fn describe(&self) -> String {
+        match self {
+            HookAction::Post { submolt, .. } => format!("post to m/{}", submolt),
+            HookAction::Dm { conversation, .. } => format!("DM in {}", conversation),
+        }
+    }
+
+    /// The API endpoint and request body for a call with `payload`.
+    ///
+    /// Fails with the name of the first placeholder the payload lacks.
+    pub fn render(&self, payload: &Value) -> Result<(String, Value), String> {
+        match self {
+            HookAction::Post {
+                submolt,
+                title,
+                content,
+                url,
+            } => {
+                let mut body = json!({
+                    "submolt_name": render(submolt, payload)?,
+                    "title": render(title, payload)?,
+                });
+                if let Some(content) = content {
+                    body["content"] = json!(render(content, payload)?);
+                }
+                if let Some(url) = url {
+                    body["url"] = json!(render(url, payload)?);
+                }
+                Ok((Endpoint::CreatePost.path(), body))
+            }
+            HookAction::Dm {
+                conversation,
+                message,
+            } => {
+                let conversation = render(conversation, payload)?;
+                let body = json!({ "message": render(message, payload)? });
+                Ok((Endpoint::DmSend(&conversation).path(), body))
+            }
+        }
+    }
+}
+
+/// Fills `{{path}}` placeholders from `payload`.
+///
+/// Strings are inserted as-is and other values as JSON. A missing or null
+/// value is an error naming the placeholder.
+pub fn render(template: &str, payload: &Value) -> Result<String, String> {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let Some(len) = rest[start..].find("}}") else {
+            break;
+        };
+        let path = rest[start + 2..start + len].trim();
+        let value = path
+            .split('.')
+            .try_fold(payload, |v, key| v.get(key))
+            .filter(|v| !v.is_null())
+            .ok_or_else(|| path.to_string())?;
+        match value {
+            Value::String(s) => out.push_str(s),
+            other => out.push_str(&other.to_string()),
+        }
+        rest = &rest[start + len + 2..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// HMAC-SHA256 of `message` under `key` (RFC 2104).
+pub fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    const BLOCK: usize = 64;
+    let mut block = [0u8; BLOCK];
+    if key.len() > BLOCK {
+        block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Sha256::new();
+    inner.update(block.map(|b| b ^ 0x36));
+    inner.update(message);
+    let mut outer = Sha256::new();
+    outer.update(block.map(|b| b ^ 0x5c));
+    outer.update(inner.finalize());
+    outer.finalize().into()
+}
+
+/// The signature header value for `body`: `sha256=<hex>`.
+pub fn sign(secret: &str, body: &[u8]) -> String {
+    let mac = hmac_sha256(secret.as_bytes(), body);
+    let hex: String = mac.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("sha256={}", hex)
+}
+
+/// Whether `signature` is the valid signature of `body`.
+///
+/// Compares in constant time so the expected value cannot be probed.
+pub fn verify(secret: &str, body: &[u8], signature: &str) -> bool {
+    let expected = sign(secret, body);
+    let given = signature.trim().to_ascii_lowercase();
+    expected.len() == given.len()
+        && expected
+            .bytes()
+            .zip(given.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hmac_rfc4231_vector() {
+        let mac = hmac_sha256(b"Jefe", b"what do ya want for nothing?");
+        let hex: String = mac.iter().map(|b| format!("{:02x}", b)).collect();
+        assert_eq!(
+            hex,
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_verify() {
+        let signature = sign("s3cret", b"{}");
+        assert!(verify("s3cret", b"{}", &signature));
+        assert!(verify(
+            "s3cret",
+            b"{}",
+            &signature.to_uppercase().replace("SHA", "sha")
+        ));
+        assert!(!verify("s3cret", b"{ }", &signature));
+        assert!(!verify("other", b"{}", &signature));
+        assert!(!verify("s3cret", b"{}", ""));
+    }
+
+    #[test]
+    fn test_render() {
+        let payload = json!({ "repo": { "name": "crate" }, "run": 42, "gone": null });
+        assert_eq!(
+            render("{{ repo.name }} build #{{run}} passed", &payload).unwrap(),
+            "crate build #42 passed"
+        );
+        assert_eq!(
+            render("{{repo.owner}}", &payload).unwrap_err(),
+            "repo.owner"
+        );
+        assert_eq!(render("{{gone}}", &payload).unwrap_err(), "gone");
+        assert_eq!(
+            render("no placeholders", &payload).unwrap(),
+            "no placeholders"
+        );
+    }
+
+    #[test]
+    fn test_actions_from_config() {
+        let hooks: Webhooks = serde_json::from_value(json!({
+            "secret": "s",
+            "hooks": {
+                "deploy": { "action": "post", "submolt": "ci", "title": "Shipped {{version}}" },
+                "alert": { "action": "dm", "conversation": "conv_1", "message": "{{text}}" }
+            }
+        }))
+        .unwrap();
+
+        let payload = json!({ "version": "1.2.0", "text": "CI is red" });
+        let (endpoint, body) = hooks.hooks["deploy"].render(&payload).unwrap();
+        assert_eq!(endpoint, "/posts");
+        assert_eq!(
+            body,
+            json!({ "submolt_name": "ci", "title": "Shipped 1.2.0" })
+        );
+        let (endpoint, body) = hooks.hooks["alert"].render(&payload).unwrap();
+        assert_eq!(endpoint, "/agents/dm/conversations/conv_1/send");
+        assert_eq!(body, json!({ "message": "CI is red" }));
+    }
+}
@@ -0,0 +1,161 @@
+//! Project-local settings from `.moltbook.toml`.
+//!
+//! Like `.git`, the file is found by walking up from the current directory,
+//! so every command run inside an agent's project picks it up. It overlays
+//! the `defaults` section of the configuration and can pin the profile the
+//! project expects to post as, which is selected if it is a saved profile
+//! (see `moltbook account add`) and otherwise must match the agent name:
+//!
+//! ```toml
+//! profile = "research-bot"
+//!
+//! [defaults]
+//! submolt = "ml-papers"
+//! format = "jsonl"
+//! ```
+
+use crate::api::error::ApiError;
+use crate::config::{self, Config, Defaults};
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The filename looked for in the current directory and its ancestors.
+pub const WORKSPACE_FILE: &str = ".moltbook.toml";
+
+/// The contents of a `.moltbook.toml` file.
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Workspace {
+    /// Profile or agent the project posts as; commands refuse to run under another.
+    pub profile: Option<String>,
+    /// Overrides for the configuration's `defaults` section.
+    #[serde(default)]
+    pub defaults: Defaults,
+    /// Where the file was found.
+    #[serde(skip)]
+    pub path: PathBuf,
+}
+
+impl Workspace {
+    /// Finds and parses the nearest `.moltbook.toml` above the current directory.
+    pub fn discover() -> Result<Option<Self>, ApiError> {
+        let Ok(cwd) = std::env::current_dir() else {
+            return Ok(None);
+        };
+        find(&cwd).map(|path| Self::load(&path)).transpose()
+    }
+
+    /// Parses the workspace file at `path`.
+    pub fn load(path: &Path) -> Result<Self, ApiError> {
+        let content = fs::read_to_string(path).map_err(|e| {
+            ApiError::ConfigError(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        let mut workspace = parse(&content)
+            .map_err(|e| ApiError::ConfigError(format!("{}: {}", path.display(), e)))?;
+        workspace.path = path.to_path_buf();
+        Ok(workspace)
+    }
+
+    /// Overlays the workspace defaults onto `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ApiError::ConfigError` when the workspace pins a profile
+    /// that is neither the active one nor the agent the credentials belong to.
+    pub fn apply(self, config: &mut Config) -> Result<(), ApiError> {
+        if let Some(profile) = &self.profile
+            && !profile.eq_ignore_ascii_case(&config.agent_name)
+            && config::active_profile().as_deref() != Some(profile.as_str())
+        {
+            return Err(ApiError::ConfigError(format!(
+                "{} expects profile '{}', but the configured agent is '{}'",
+                self.path.display(),
+                profile,
+                config.agent_name
+            )));
+        }
+        config.defaults.merge(self.defaults);
+        Ok(())
+    }
+}
+
+/// The nearest workspace file in `start` or one of its ancestors.
+pub fn find(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join(WORKSPACE_FILE))
+        .find(|path| path.is_file())
+}
+
+fn parse(content: &str) -> Result<Workspace, String> {
+    let workspace: Workspace = toml::from_str(content).map_err(|e| e.message().to_string())?;
+    // Route values through the same validation as `config set`.
+    let mut checked = Defaults::default();
+    for key in Defaults::KEYS {
+        if let Some(value) = workspace.defaults.get(key).map_err(|e| e.to_string())? {
+            checked.set(key, &value).map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(workspace)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::OutputFormat;
+
+    #[test]
+    fn test_find_walks_up() {
+        let dir = tempfile::tempdir().unwrap();
+        let nested = dir.path().join("agents/scout/src");
+        fs::create_dir_all(&nested).unwrap();
+        assert_eq!(find(&nested), None);
+
+        let file = dir.path().join("agents").join(WORKSPACE_FILE);
+        fs::write(&file, "").unwrap();
+        assert_eq!(find(&nested), Some(file.clone()));
+
+        // The nearest file wins.
+        let inner = dir.path().join("agents/scout").join(WORKSPACE_FILE);
+        fs::write(&inner, "").unwrap();
+        assert_eq!(find(&nested), Some(inner));
+    }
+
+    #[test]
+    fn test_parse_and_apply() {
+        let workspace =
+            parse("profile = \"Scout\"\n[defaults]\nsubmolt = \"ml-papers\"\nformat = \"jsonl\"\n")
+                .unwrap();
+        let mut config = Config {
+            api_key: "key".to_string(),
+            agent_name: "scout".to_string(),
+            defaults: Defaults {
+                submolt: Some("general".to_string()),
+                feed_sort: Some("new".to_string()),
+                ..Defaults::default()
+            },
+            max_concurrent_requests: None,
+            jitter: None,
+            require_claimed: None,
+            require_approval: None,
+            budgets: Default::default(),
+            bridges: Default::default(),
+            webhooks: Default::default(),
+        };
+        workspace.apply(&mut config).unwrap();
+        assert_eq!(config.defaults.submolt(), "ml-papers");
+        assert_eq!(config.defaults.feed_sort(), "new");
+        assert_eq!(config.defaults.format(), OutputFormat::Jsonl);
+
+        let other = parse("profile = \"herald\"\n").unwrap();
+        assert!(other.apply(&mut config).is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_values() {
+        assert!(parse("[defaults]\nfeed_sort = \"sideways\"\n").is_err());
+        assert!(parse("[defaults]\nformat = \"xml\"\n").is_err());
+        assert!(parse("submolt = \"rust\"\n").is_err());
+    }
+}
@@ -39,3 +39,382 @@ fn test_version_output() {
         .success()
         .stdout(predicate::str::starts_with("moltbook-cli"));
 }
+
+#[cfg(unix)]
+#[test]
+fn test_ctl_without_daemon() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut cmd = Command::new(assert_cmd::cargo_bin!("moltbook-cli"));
+    cmd.env("MOLTBOOK_CONFIG_DIR", dir.path())
+        .args(["ctl", "feed"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("moltbook daemon"));
+}
+
+#[test]
+fn test_daemon_refuses_commands_that_never_return() {
+    use clap::Parser;
+    use moltbook_cli::cli::Cli;
+
+    // Parsing the full command set needs more than a test thread's stack in
+    // debug builds with every feature on.
+    let runs_until_stopped = |args: &'static [&'static str]| {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(move || {
+                Cli::try_parse_from(args)
+                    .unwrap()
+                    .command
+                    .runs_until_stopped()
+            })
+            .unwrap()
+            .join()
+            .unwrap()
+    };
+    assert!(runs_until_stopped(&["moltbook", "dm-watch"]));
+    assert!(runs_until_stopped(&["moltbook", "watch-submolt", "rust"]));
+    assert!(runs_until_stopped(&["moltbook", "status", "--watch"]));
+    assert!(runs_until_stopped(&["moltbook", "heartbeat", "--watch"]));
+    #[cfg(feature = "tui")]
+    assert!(runs_until_stopped(&["moltbook", "tui"]));
+    assert!(!runs_until_stopped(&["moltbook", "status"]));
+    assert!(!runs_until_stopped(&["moltbook", "dm-check"]));
+}
+
+#[test]
+fn test_require_approval_queues_without_terminal() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("config.toml"),
+        "agent_name = \"bot\"\nrequire_approval = true\n",
+    )
+    .unwrap();
+    std::fs::write(dir.path().join("credentials.toml"), "api_key = \"key\"\n").unwrap();
+
+    let mut cmd = Command::new(assert_cmd::cargo_bin!("moltbook-cli"));
+    cmd.env("MOLTBOOK_CONFIG_DIR", dir.path())
+        .args(["upvote", "post-123"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Approval required"));
+
+    let queue = std::fs::read_to_string(dir.path().join("approvals.json")).unwrap();
+    assert!(queue.contains("\"method\": \"POST\""));
+    assert!(queue.contains("/posts/post-123/upvote"));
+    let queue: serde_json::Value = serde_json::from_str(&queue).unwrap();
+    let id = queue["requests"][0]["id"].as_str().unwrap()[..8].to_string();
+
+    let approvals = |args: &[&str]| {
+        let mut cmd = Command::new(assert_cmd::cargo_bin!("moltbook-cli"));
+        cmd.env("MOLTBOOK_CONFIG_DIR", dir.path())
+            .arg("approvals")
+            .args(args)
+            .assert()
+    };
+    approvals(&["list"])
+        .success()
+        .stdout(predicate::str::contains(&id));
+    // Scripts, and so agents, cannot approve their own requests.
+    approvals(&["approve", &id])
+        .failure()
+        .stderr(predicate::str::contains("interactive terminal"));
+    approvals(&["deny", &id])
+        .success()
+        .stdout(predicate::str::contains("nothing was sent"));
+    approvals(&["list"])
+        .success()
+        .stdout(predicate::str::contains("No requests awaiting approval"));
+}
+
+#[test]
+fn test_policy_refuses_and_journals_violations() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("config.toml"), "agent_name = \"bot\"\n").unwrap();
+    std::fs::write(dir.path().join("credentials.toml"), "api_key = \"key\"\n").unwrap();
+    std::fs::write(
+        dir.path().join("policy.toml"),
+        "allowed_submolts = [\"general\"]\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(assert_cmd::cargo_bin!("moltbook-cli"));
+    cmd.env("MOLTBOOK_CONFIG_DIR", dir.path())
+        .args([
+            "post",
+            "--title",
+            "Hi",
+            "--submolt",
+            "crypto",
+            "--content",
+            "x",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Policy violation (allowed_submolts)",
+        ));
+
+    let journal = std::fs::read_to_string(dir.path().join("policy_violations.json")).unwrap();
+    let journal: serde_json::Value = serde_json::from_str(&journal).unwrap();
+    assert_eq!(journal["violations"][0]["rule"], "allowed_submolts");
+    assert_eq!(journal["violations"][0]["endpoint"], "/posts");
+}
+
+#[test]
+fn test_comment_length_is_checked_before_sending() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("config.toml"), "agent_name = \"bot\"\n").unwrap();
+    std::fs::write(dir.path().join("credentials.toml"), "api_key = \"key\"\n").unwrap();
+    let reply = "Short first point. A second sentence that is far too long.\n";
+
+    let comment = |extra: &[&str]| {
+        let mut cmd = Command::new(assert_cmd::cargo_bin!("moltbook-cli"));
+        cmd.env("MOLTBOOK_CONFIG_DIR", dir.path())
+            .args(["comment", "p1", "--content-file", "-"])
+            .write_stdin(reply)
+            .args(["--max-chars", "30"])
+            .args(extra)
+            .assert()
+    };
+    comment(&[])
+        .failure()
+        .stderr(predicate::str::contains("over the limit of 30"));
+    comment(&["--truncate", "--preview"])
+        .success()
+        .stdout(predicate::str::contains("│ Short first point."))
+        .stdout(predicate::str::contains(
+            "18/30 characters (truncated from 58)",
+        ));
+}
+
+#[test]
+fn test_profiles_keep_agents_apart() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("config.toml"), "agent_name = \"bot\"\n").unwrap();
+    std::fs::write(dir.path().join("credentials.toml"), "api_key = \"key\"\n").unwrap();
+
+    let moltbook = |args: &[&str]| {
+        let mut cmd = Command::new(assert_cmd::cargo_bin!("moltbook-cli"));
+        cmd.env("MOLTBOOK_CONFIG_DIR", dir.path())
+            .args(args)
+            .assert()
+    };
+    moltbook(&[
+        "account",
+        "add",
+        "research",
+        "--api-key",
+        "key2",
+        "--agent-name",
+        "scout",
+    ])
+    .success();
+    assert!(
+        dir.path()
+            .join("profiles/research/credentials.toml")
+            .exists()
+    );
+
+    moltbook(&["config", "get", "agent_name"])
+        .success()
+        .stdout(predicate::str::contains("bot"));
+    moltbook(&["--profile", "research", "config", "get", "agent_name"])
+        .success()
+        .stdout(predicate::str::contains("scout"));
+
+    moltbook(&["account", "switch", "research"]).success();
+    moltbook(&["config", "get", "agent_name"])
+        .success()
+        .stdout(predicate::str::contains("scout"));
+    moltbook(&["account", "list"])
+        .success()
+        .stdout(predicate::str::is_match(r"\* +research +scout").unwrap());
+
+    moltbook(&["account", "switch", "missing"])
+        .failure()
+        .stderr(predicate::str::contains("does not exist"));
+    moltbook(&["--profile", "missing", "status"])
+        .failure()
+        .stderr(predicate::str::contains("account add missing"));
+    moltbook(&[
+        "account",
+        "add",
+        "research",
+        "--api-key",
+        "k",
+        "--agent-name",
+        "x",
+    ])
+    .failure()
+    .stderr(predicate::str::contains("already exists"));
+}
+
+#[test]
+fn test_handles_resolve_in_the_selected_profile() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("config.toml"), "agent_name = \"bot\"\n").unwrap();
+    std::fs::write(dir.path().join("credentials.toml"), "api_key = \"key\"\n").unwrap();
+
+    let moltbook = |args: &[&str]| {
+        let mut cmd = Command::new(assert_cmd::cargo_bin!("moltbook-cli"));
+        cmd.env("MOLTBOOK_CONFIG_DIR", dir.path())
+            .args(args)
+            .assert()
+    };
+    moltbook(&[
+        "account",
+        "add",
+        "research",
+        "--api-key",
+        "key2",
+        "--agent-name",
+        "scout",
+    ])
+    .success();
+    moltbook(&["alias", "set", "intro", "post-default"]).success();
+    moltbook(&[
+        "--profile",
+        "research",
+        "alias",
+        "set",
+        "intro",
+        "post-research",
+    ])
+    .success();
+    moltbook(&[
+        "--profile",
+        "research",
+        "config",
+        "set",
+        "require_approval",
+        "true",
+    ])
+    .success();
+
+    // Held for approval, so the resolved ID shows up in the profile's queue.
+    moltbook(&["--profile", "research", "upvote", "intro"])
+        .failure()
+        .stderr(predicate::str::contains("Approval required"));
+    let queue =
+        std::fs::read_to_string(dir.path().join("profiles/research/approvals.json")).unwrap();
+    assert!(queue.contains("/posts/post-research/upvote"));
+}
+
+#[test]
+fn test_record_and_replay_transcript() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("config.toml"), "agent_name = \"bot\"\n").unwrap();
+    std::fs::write(dir.path().join("credentials.toml"), "api_key = \"key\"\n").unwrap();
+    let transcript = dir.path().join("session.json");
+
+    let mut cmd = Command::new(assert_cmd::cargo_bin!("moltbook-cli"));
+    cmd.env("MOLTBOOK_CONFIG_DIR", dir.path())
+        .arg("budget")
+        .arg("--record")
+        .arg(&transcript)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Daily Budget"));
+
+    let saved: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&transcript).unwrap()).unwrap();
+    assert_eq!(saved["command"][1], "budget");
+    assert!(saved["output"].as_str().unwrap().contains("Daily Budget"));
+    assert!(!saved.to_string().contains("\"key\""));
+
+    let mut cmd = Command::new(assert_cmd::cargo_bin!("moltbook-cli"));
+    cmd.arg("replay")
+        .arg(&transcript)
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("moltbook budget")
+                .and(predicate::str::contains("Daily Budget")),
+        );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_ctrl_c_exits_with_interrupt_code() {
+    use std::process::Stdio;
+    use std::time::{Duration, Instant};
+
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("config.toml"),
+        "agent_name = \"bot\"\n\n[webhooks.hooks]\nping = { action = \"dm\", conversation = \"c1\", message = \"hi\" }\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("credentials.toml"),
+        "api_key = \"key\"\n\n[webhooks]\nsecret = \"s\"\n",
+    )
+    .unwrap();
+
+    let mut child = std::process::Command::new(assert_cmd::cargo_bin!("moltbook-cli"))
+        .env("MOLTBOOK_CONFIG_DIR", dir.path())
+        .args(["listen", "--port", "0"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    // Wait for the listener to come up, so the handler is installed.
+    let mut stdout = std::io::BufReader::new(child.stdout.take().unwrap());
+    let mut line = String::new();
+    std::io::BufRead::read_line(&mut stdout, &mut line).unwrap();
+    assert!(line.contains("Listening"), "{}", line);
+
+    // SAFETY: plain kill(2) on a child we own.
+    unsafe { libc::kill(child.id() as libc::pid_t, libc::SIGINT) };
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait().unwrap() {
+            break status;
+        }
+        assert!(started.elapsed() < Duration::from_secs(10), "no exit");
+        std::thread::sleep(Duration::from_millis(50));
+    };
+    assert_eq!(status.code(), Some(130));
+}
+
+#[test]
+fn test_drafts_are_kept_until_deleted() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("config.toml"), "agent_name = \"bot\"\n").unwrap();
+    std::fs::write(dir.path().join("credentials.toml"), "api_key = \"key\"\n").unwrap();
+
+    let moltbook = |args: &[&str]| {
+        let mut cmd = Command::new(assert_cmd::cargo_bin!("moltbook-cli"));
+        cmd.env("MOLTBOOK_CONFIG_DIR", dir.path())
+            .args(args)
+            .write_stdin("# Launch notes\n\nLong body from a pipe.\n")
+            .assert()
+    };
+    moltbook(&[
+        "draft",
+        "save",
+        "Launch",
+        "-s",
+        "rust",
+        "--content-file",
+        "-",
+    ])
+    .success()
+    .stdout(predicate::str::contains("Saved draft"));
+
+    let drafts = std::fs::read_to_string(dir.path().join("drafts.json")).unwrap();
+    let drafts: serde_json::Value = serde_json::from_str(&drafts).unwrap();
+    let id = drafts["drafts"][0]["id"].as_str().unwrap()[..8].to_string();
+    assert_eq!(drafts["drafts"][0]["submolt"], "rust");
+
+    moltbook(&["draft", "edit", &id, "--title", "Launch day"]).success();
+    moltbook(&["draft", "list"])
+        .success()
+        .stdout(predicate::str::contains("Launch day"))
+        .stdout(predicate::str::contains("Long body from a pipe."));
+    moltbook(&["draft", "delete", &id]).success();
+    moltbook(&["draft", "publish", &id])
+        .failure()
+        .stderr(predicate::str::contains("No draft"));
+}
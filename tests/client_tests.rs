@@ -1,7 +1,7 @@
 use moltbook_cli::api::client::MoltbookClient;
 use moltbook_cli::api::error::ApiError;
 use serde_json::json;
-use wiremock::matchers::{header, method, path};
+use wiremock::matchers::{header, header_regex, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[tokio::test]
@@ -76,6 +76,29 @@ async fn test_rate_limit_handling_seconds() {
     }
 }
 
+#[tokio::test]
+async fn test_new_agent_cooldown() {
+    let mock_server = MockServer::start().await;
+    let client = MoltbookClient::new("test-key".to_string(), "test-agent".to_string(), false)
+        .with_base_url(mock_server.uri());
+
+    Mock::given(method("POST"))
+        .and(path("/posts"))
+        .respond_with(ResponseTemplate::new(429).set_body_json(json!({
+            "error": "New agents can only post once every 2 hours",
+            "retry_after_minutes": 83
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let result: Result<serde_json::Value, ApiError> = client.post("/posts", &json!({})).await;
+
+    match result {
+        Err(ApiError::NewAgentRestricted(wait)) => assert_eq!(wait, Some(83 * 60)),
+        _ => panic!("Expected NewAgentRestricted error"),
+    }
+}
+
 #[tokio::test]
 async fn test_moltbook_error_with_hint() {
     let mock_server = MockServer::start().await;
@@ -127,6 +150,63 @@ async fn test_captcha_required_error() {
     }
 }
 
+#[tokio::test]
+async fn test_server_error() {
+    let mock_server = MockServer::start().await;
+    let client = MoltbookClient::new("test-key".to_string(), "test-agent".to_string(), false)
+        .with_base_url(mock_server.uri());
+
+    Mock::given(method("POST"))
+        .and(path("/posts"))
+        .respond_with(ResponseTemplate::new(503).set_body_json(json!({
+            "success": false,
+            "error": "Service unavailable"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let result: Result<serde_json::Value, ApiError> = client.post("/posts", &json!({})).await;
+
+    match result {
+        Err(ApiError::ServerError(status, msg)) => {
+            assert_eq!(status, 503);
+            assert_eq!(msg, "Service unavailable");
+        }
+        _ => panic!("Expected ServerError, got {:?}", result),
+    }
+}
+
+#[tokio::test]
+async fn test_gzip_responses_are_decoded() {
+    use flate2::{Compression, write::GzEncoder};
+    use std::io::Write;
+
+    let mock_server = MockServer::start().await;
+    let client = MoltbookClient::new("test-key".to_string(), "test-agent".to_string(), false)
+        .with_base_url(mock_server.uri());
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(br#"{"success": true, "posts": []}"#)
+        .unwrap();
+    let body = encoder.finish().unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(header_regex("Accept-Encoding", "gzip"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Encoding", "gzip")
+                .insert_header("Content-Type", "application/json")
+                .set_body_bytes(body),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let result: serde_json::Value = client.get("/feed").await.unwrap();
+    assert_eq!(result["success"], true);
+}
+
 #[test]
 fn test_handle_verification_detects_challenge() {
     use moltbook_cli::cli::verification::handle_verification;
@@ -141,3 +221,68 @@ fn test_handle_verification_detects_challenge() {
     });
     assert!(handle_verification(&response, "post"));
 }
+
+#[test]
+fn test_unwrap_envelope_shapes() {
+    use moltbook_cli::api::client::unwrap_envelope;
+
+    let wrapped: Vec<u32> =
+        unwrap_envelope(json!({ "success": true, "ids": [1, 2] }), "ids").unwrap();
+    assert_eq!(wrapped, vec![1, 2]);
+
+    let paged: Vec<u32> = unwrap_envelope(json!({ "ids": { "items": [3] } }), "ids").unwrap();
+    assert_eq!(paged, vec![3]);
+
+    let bare: Vec<u32> = unwrap_envelope(json!([4]), "ids").unwrap();
+    assert_eq!(bare, vec![4]);
+}
+
+#[tokio::test]
+async fn test_dns_failure_is_reported_as_such() {
+    // `.invalid` is reserved and never resolves.
+    let client = MoltbookClient::new("test-key".to_string(), "test-agent".to_string(), false)
+        .with_base_url("http://moltbook.invalid".to_string());
+
+    let result: Result<serde_json::Value, ApiError> = client.get("/agents/me").await;
+    match result {
+        Err(ApiError::DnsFailed(host)) => assert_eq!(host, "moltbook.invalid"),
+        other => panic!("Expected DnsFailed, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[tokio::test]
+async fn test_timeout_is_reported_as_such() {
+    let mock_server = MockServer::start().await;
+    let client = MoltbookClient::new("test-key".to_string(), "test-agent".to_string(), false)
+        .with_base_url(mock_server.uri())
+        .with_timeout(Some(std::time::Duration::from_millis(100)));
+
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "posts": [] }))
+                .set_delay(std::time::Duration::from_secs(2)),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let result: Result<serde_json::Value, ApiError> = client.get("/feed").await;
+    match result {
+        Err(ApiError::Timeout(url)) => assert!(url.ends_with("/feed"), "{}", url),
+        other => panic!("Expected Timeout, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_parse_timeout() {
+    use moltbook_cli::api::client::parse_timeout;
+    use std::time::Duration;
+
+    assert_eq!(parse_timeout("10s"), Ok(Duration::from_secs(10)));
+    assert_eq!(parse_timeout("500ms"), Ok(Duration::from_millis(500)));
+    assert_eq!(parse_timeout("2m"), Ok(Duration::from_secs(120)));
+    assert_eq!(parse_timeout("45"), Ok(Duration::from_secs(45)));
+    assert!(parse_timeout("0s").is_err());
+    assert!(parse_timeout("soon").is_err());
+}
@@ -0,0 +1,445 @@
+//! Commands that keep state in the config directory. Each test points the
+//! directory at its own temporary one with [`override_config_dir`], so they
+//! can share a binary and run in parallel.
+
+use moltbook_cli::activity::{ActivityKind, ActivityLog};
+use moltbook_cli::api::client::{MoltbookClient, RESUMABLE_THRESHOLD};
+use moltbook_cli::api::error::ApiError;
+use moltbook_cli::cli::account::verify;
+use moltbook_cli::cli::outbox;
+use moltbook_cli::cli::post::engage;
+use moltbook_cli::cli::rss::import_rss;
+use moltbook_cli::config::{Budgets, Config, ConfigDirOverride, override_config_dir};
+use moltbook_cli::outbox::{OutboxEntry, OutboxStore};
+use moltbook_cli::registration::Registration;
+use moltbook_cli::rss::ImportedLinks;
+use moltbook_cli::uploads::UploadStore;
+use moltbook_cli::verification::ChallengeStore;
+use serde_json::{Value, json};
+use tempfile::TempDir;
+use wiremock::matchers::{body_json, body_partial_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Keeps this test's state in a fresh directory until dropped.
+fn isolate_config_dir() -> (ConfigDirOverride, TempDir) {
+    let dir = tempfile::tempdir().unwrap();
+    (override_config_dir(dir.path()), dir)
+}
+
+fn client(server: &MockServer) -> MoltbookClient {
+    MoltbookClient::new("key".to_string(), "agent".to_string(), false).with_base_url(server.uri())
+}
+
+const CHUNK: u64 = 4 * 1024 * 1024;
+
+#[tokio::test]
+async fn test_upload_resumes_after_failed_chunk() {
+    let _config_dir = isolate_config_dir();
+
+    let size = RESUMABLE_THRESHOLD + CHUNK / 2;
+    let file = tempfile::Builder::new().suffix(".png").tempfile().unwrap();
+    std::fs::write(file.path(), vec![1u8; size as usize]).unwrap();
+
+    let server = MockServer::start().await;
+    let client = client(&server);
+    let range = |start: u64, end: u64| format!("bytes {}-{}/{}", start, end - 1, size);
+
+    Mock::given(method("POST"))
+        .and(path("/uploads"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "upload_id": "up_1", "chunk_size": CHUNK })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/uploads/up_1"))
+        .and(header("Content-Range", range(0, CHUNK).as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "received": CHUNK })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    // The connection "drops" on the second chunk the first time around.
+    Mock::given(method("PUT"))
+        .and(path("/uploads/up_1"))
+        .and(header("Content-Range", range(CHUNK, 2 * CHUNK).as_str()))
+        .respond_with(ResponseTemplate::new(503).set_body_json(json!({ "error": "down" })))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+
+    let first: Result<serde_json::Value, ApiError> = client
+        .upload_resumable("/agents/me/avatar", file.path().to_path_buf())
+        .await;
+    assert!(matches!(first, Err(ApiError::ServerError(503, _))));
+    let store = UploadStore::load().unwrap();
+    assert_eq!(store.uploads.len(), 1);
+    assert_eq!(store.uploads[0].received, CHUNK);
+
+    Mock::given(method("GET"))
+        .and(path("/uploads/up_1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "received": CHUNK })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    for (start, end) in [(CHUNK, 2 * CHUNK), (2 * CHUNK, size)] {
+        Mock::given(method("PUT"))
+            .and(path("/uploads/up_1"))
+            .and(header("Content-Range", range(start, end).as_str()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "received": end })))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+    Mock::given(method("POST"))
+        .and(path("/uploads/up_1/complete"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": true })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let second: serde_json::Value = client
+        .upload_resumable("/agents/me/avatar", file.path().to_path_buf())
+        .await
+        .unwrap();
+    assert_eq!(second["success"], true);
+    assert!(UploadStore::load().unwrap().uploads.is_empty());
+}
+
+const FEED: &str = r#"<rss version="2.0"><channel>
+  <item><title>Newer</title><link>https://news.example/2</link></item>
+  <item><title>Older</title><link>https://news.example/1</link></item>
+</channel></rss>"#;
+
+#[tokio::test]
+async fn test_import_rss_posts_each_link_once() {
+    let _config_dir = isolate_config_dir();
+
+    let server = MockServer::start().await;
+    let client = client(&server);
+    Mock::given(method("GET"))
+        .and(path("/feed.xml"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(FEED))
+        .mount(&server)
+        .await;
+    for n in ["1", "2"] {
+        Mock::given(method("POST"))
+            .and(path("/posts"))
+            .and(body_partial_json(json!({
+                "submolt_name": "news",
+                "url": format!("https://news.example/{}", n),
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "success": true,
+                "post": { "id": format!("p{}", n) }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let feed_url = format!("{}/feed.xml", server.uri());
+    import_rss(&client, &feed_url, "news", 5, false)
+        .await
+        .unwrap();
+    // A second run finds nothing new.
+    import_rss(&client, &feed_url, "news", 5, false)
+        .await
+        .unwrap();
+
+    let imported = ImportedLinks::load().unwrap();
+    assert_eq!(
+        imported.links["https://news.example/2"].post_id.as_deref(),
+        Some("p2")
+    );
+}
+
+#[tokio::test]
+async fn test_vote_budget_stops_after_limit() {
+    let _config_dir = isolate_config_dir();
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/posts/p1/upvote"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": true })))
+        .expect(2)
+        .mount(&server)
+        .await;
+    let client = client(&server)
+        .with_activity_log(true)
+        .with_budgets(Budgets {
+            votes: Some(2),
+            ..Budgets::default()
+        });
+
+    for _ in 0..2 {
+        let _: Value = client.post("/posts/p1/upvote", &json!({})).await.unwrap();
+    }
+    let third = client.post::<Value>("/posts/p1/upvote", &json!({})).await;
+    match third {
+        Err(ApiError::BudgetExhausted {
+            kind, used, limit, ..
+        }) => {
+            assert_eq!((kind.as_str(), used, limit), ("vote", 2, 2));
+        }
+        other => panic!("expected BudgetExhausted, got {:?}", other.map(|_| ())),
+    }
+
+    let log = ActivityLog::load().unwrap();
+    assert_eq!(log.count_today(ActivityKind::Vote), 2);
+    assert_eq!(log.count_today(ActivityKind::Post), 0);
+}
+
+#[tokio::test]
+async fn test_verify_sends_held_request_again() {
+    let _config_dir = isolate_config_dir();
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/submolts/rust/subscribe"))
+        .and(body_json(json!({ "notify": true })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "success": false,
+            "verification_required": true,
+            "verification": {
+                "verification_code": "v-1",
+                "challenge_text": "2+2",
+                "instructions": "Answer with a number"
+            }
+        })))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/submolts/rust/subscribe"))
+        .and(body_json(json!({ "notify": true })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": true })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/verify"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": true })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = client(&server).with_activity_log(true);
+
+    let _: Value = client
+        .post("/submolts/rust/subscribe", &json!({ "notify": true }))
+        .await
+        .unwrap();
+    let saved = ChallengeStore::load().unwrap();
+    assert_eq!(saved.challenges.len(), 1);
+    assert!(saved.challenges[0].held);
+
+    verify(&client, "v-1", "4").await.unwrap();
+    assert!(ChallengeStore::load().unwrap().challenges.is_empty());
+}
+
+#[tokio::test]
+async fn test_engage_runs_both_steps_and_reports_failure() {
+    let _config_dir = isolate_config_dir();
+
+    let server = MockServer::start().await;
+    // The upvote fails, but the comment is still sent.
+    Mock::given(method("POST"))
+        .and(path("/posts/p1/upvote"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "success": false,
+            "error": "Already upvoted"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/posts/p1/comments"))
+        .and(body_json(json!({ "content": "Great point" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "success": true,
+            "comment": { "id": "c1", "content": "Great point" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = client(&server);
+
+    let result = engage(&client, "p1", true, Some("  Great point\n".to_string())).await;
+    match result {
+        Err(ApiError::MoltbookError(message, _)) => assert_eq!(message, "1 of 2 steps failed"),
+        other => panic!("expected a failed step, got {:?}", other),
+    }
+
+    // Too long to post: nothing is sent at all.
+    let long = "x".repeat(moltbook_cli::draft::COMMENT_CHAR_LIMIT + 1);
+    assert!(matches!(
+        engage(&client, "p1", true, Some(long)).await,
+        Err(ApiError::TooLong { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_pending_claim_blocks_every_write() {
+    let _config_dir = isolate_config_dir();
+    Registration {
+        agent_name: "agent".to_string(),
+        claim_url: "https://www.moltbook.com/claim/c-1".to_string(),
+        verification_code: "reef-1".to_string(),
+        registered_at: "2025-06-10T12:00:00Z".to_string(),
+    }
+    .save()
+    .unwrap();
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/agents/status"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "status": "pending_claim" })),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": true })))
+        .expect(0)
+        .mount(&server)
+        .await;
+    let config: Config = serde_json::from_value(json!({
+        "api_key": "key",
+        "agent_name": "agent",
+        "require_claimed": true
+    }))
+    .unwrap();
+    let client = client(&server).with_config(&config);
+
+    // The check sits in the client, so any write is covered.
+    let result = client
+        .post::<Value>("/submolts/rust/wiki", &json!({ "content": "Hi" }))
+        .await;
+    match result {
+        Err(ApiError::NotClaimed(url)) => assert_eq!(url, "https://www.moltbook.com/claim/c-1"),
+        other => panic!("expected NotClaimed, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[tokio::test]
+async fn test_flush_keeps_entries_the_api_defers() {
+    let _config_dir = isolate_config_dir();
+    let entry = |key: &str, endpoint: &str| OutboxEntry {
+        key: key.to_string(),
+        endpoint: endpoint.to_string(),
+        body: json!({}),
+        queued_at: "2026-01-01T00:00:00Z".to_string(),
+        reason: "HTTP 503".to_string(),
+    };
+    OutboxStore {
+        entries: vec![
+            entry("k-rejected", "/posts/p1/upvote"),
+            entry("k-limited", "/posts/p2/upvote"),
+            entry("k-later", "/posts/p3/upvote"),
+        ],
+    }
+    .save()
+    .unwrap();
+
+    let server = MockServer::start().await;
+    // An unsuccessful response is a rejection, not a delivery.
+    Mock::given(method("POST"))
+        .and(path("/posts/p1/upvote"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "success": false,
+            "error": "Post was deleted"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/posts/p2/upvote"))
+        .respond_with(ResponseTemplate::new(429).set_body_json(json!({ "error": "Slow down" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/posts/p3/upvote"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": true })))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    outbox::flush(&client(&server)).await.unwrap();
+
+    let keys: Vec<String> = OutboxStore::load()
+        .unwrap()
+        .entries
+        .into_iter()
+        .map(|e| e.key)
+        .collect();
+    assert_eq!(keys, ["k-limited", "k-later"]);
+}
+
+#[tokio::test]
+async fn test_verify_resends_with_the_original_idempotency_key() {
+    let _config_dir = isolate_config_dir();
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/posts"))
+        .and(header("Idempotency-Key", "key-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "success": false,
+            "verification_required": true,
+            "verification": { "verification_code": "v-2", "challenge_text": "3+3" }
+        })))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/posts"))
+        .and(header("Idempotency-Key", "key-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "success": true,
+            "post": { "id": "p1" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/verify"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": true })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = client(&server).with_activity_log(true);
+
+    let _: Value = client
+        .post_idempotent("/posts", &json!({ "title": "Hi" }), "key-1")
+        .await
+        .unwrap();
+    let saved = ChallengeStore::load().unwrap();
+    assert_eq!(
+        saved.challenges[0].idempotency_key.as_deref(),
+        Some("key-1")
+    );
+
+    verify(&client, "v-2", "6").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_verify_is_sent_without_approval() {
+    let (_config_dir, dir) = isolate_config_dir();
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/verify"))
+        .and(body_partial_json(json!({ "verification_code": "v-1" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": true })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = client(&server).with_approval(true);
+
+    verify(&client, "v-1", "4").await.unwrap();
+    assert!(!dir.path().join("approvals.json").exists());
+}
@@ -0,0 +1,55 @@
+use moltbook_cli::api::client::MoltbookClient;
+use moltbook_cli::api::error::ApiError;
+use moltbook_cli::cli::submolt::create_submolt;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_create_submolt_checks_the_name_first() {
+    let server = MockServer::start().await;
+    for taken in ["rust", "rusts"] {
+        Mock::given(method("GET"))
+            .and(path(format!("/submolts/{}", taken)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "success": true,
+                "submolt": { "name": taken, "display_name": taken }
+            })))
+            .mount(&server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "success": false,
+            "error": "Submolt not found"
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": true })))
+        .expect(0)
+        .mount(&server)
+        .await;
+    let client = MoltbookClient::new("key".to_string(), "agent".to_string(), false)
+        .with_base_url(server.uri());
+
+    match create_submolt(&client, "rust", "Rust", None, false, false).await {
+        Err(ApiError::SubmoltNameUnavailable {
+            reason,
+            suggestions,
+            ..
+        }) => {
+            assert_eq!(reason, "the name is taken");
+            assert_eq!(suggestions, ["rust-hub", "rust-community", "the-rust"]);
+        }
+        other => panic!("expected the name to be taken, got {:?}", other),
+    }
+
+    // An invalid name is fixed up rather than sent.
+    match create_submolt(&client, "Rust Lang", "Rust", None, false, false).await {
+        Err(ApiError::SubmoltNameUnavailable { suggestions, .. }) => {
+            assert_eq!(suggestions, ["rust-lang"]);
+        }
+        other => panic!("expected the name to be rejected, got {:?}", other),
+    }
+}
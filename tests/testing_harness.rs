@@ -0,0 +1,570 @@
+use moltbook_cli::api::error::ApiError;
+use moltbook_cli::api::types::{FeedResponse, Post};
+use moltbook_cli::testing::{MockMoltbook, TEST_VERIFICATION_ANSWER, TEST_VERIFICATION_CODE};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_mock_feed_fixtures_deserialize() {
+    let mock = MockMoltbook::start().await;
+    mock.mount_feed().await;
+    let client = mock.client();
+
+    let feed: FeedResponse = client.get("/feed?sort=hot&limit=25").await.unwrap();
+    assert_eq!(feed.posts.len(), 2);
+
+    let response: serde_json::Value = client.get("/posts/post_1").await.unwrap();
+    let post: Post = serde_json::from_value(response["post"].clone()).unwrap();
+    assert_eq!(post.title, "Hello Moltbook");
+}
+
+#[tokio::test]
+async fn test_mock_dm_conversation() {
+    let mock = MockMoltbook::start().await;
+    mock.mount_dms().await;
+    let client = mock.client();
+
+    let response: serde_json::Value = client.get("/agents/dm/conversations/conv_1").await.unwrap();
+    assert_eq!(response["messages"].as_array().unwrap().len(), 2);
+
+    let sent: serde_json::Value = client
+        .post(
+            "/agents/dm/conversations/conv_1/send",
+            &json!({ "message": "hi" }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(sent["success"], true);
+}
+
+#[tokio::test]
+async fn test_mock_verification_flow() {
+    let mock = MockMoltbook::start().await;
+    mock.mount_verification().await;
+    let client = mock.client();
+
+    let created: serde_json::Value = client
+        .post("/posts", &json!({ "title": "x" }))
+        .await
+        .unwrap();
+    assert!(moltbook_cli::cli::verification::handle_verification(
+        &created, "post"
+    ));
+
+    let wrong: Result<serde_json::Value, ApiError> = client
+        .post(
+            "/verify",
+            &json!({ "verification_code": TEST_VERIFICATION_CODE, "answer": "1" }),
+        )
+        .await;
+    assert!(matches!(wrong, Err(ApiError::MoltbookError(_, _))));
+
+    let ok: serde_json::Value = client
+        .post(
+            "/verify",
+            &json!({ "verification_code": TEST_VERIFICATION_CODE, "answer": TEST_VERIFICATION_ANSWER }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(ok["success"], true);
+}
+
+#[tokio::test]
+async fn test_pages_follow_cursor() {
+    use moltbook_cli::api::pagination::Pages;
+    use moltbook_cli::testing::fixtures;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    let mock = MockMoltbook::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .and(query_param("cursor", "page2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixtures::feed()))
+        .with_priority(1)
+        .mount(mock.server())
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "success": true,
+            "posts": [fixtures::post("post_0", "First page")],
+            "has_more": true,
+            "next_cursor": "page2"
+        })))
+        .mount(mock.server())
+        .await;
+    let client = mock.client();
+
+    let mut pages = Pages::<FeedResponse>::new(&client, "/feed?limit=1".to_string(), None);
+    let first = pages.next().await.unwrap().unwrap();
+    assert_eq!(first.posts[0].id, "post_0");
+    assert_eq!(pages.cursor(), Some("page2"));
+
+    let second = pages.next().await.unwrap().unwrap();
+    assert_eq!(second.posts.len(), 2);
+    assert!(pages.cursor().is_none());
+    assert!(pages.next().await.is_none());
+}
+
+#[tokio::test]
+async fn test_post_idempotent_sends_key() {
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    let mock = MockMoltbook::start().await;
+    Mock::given(method("POST"))
+        .and(path("/posts"))
+        .and(header("Idempotency-Key", "key-123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": true })))
+        .expect(1)
+        .mount(mock.server())
+        .await;
+    let client = mock.client();
+
+    let result: serde_json::Value = client
+        .post_idempotent("/posts", &json!({ "title": "x" }), "key-123")
+        .await
+        .unwrap();
+    assert_eq!(result["success"], true);
+}
+
+#[tokio::test]
+async fn test_vote_batch_stops_at_long_rate_limit() {
+    use moltbook_cli::cli::post::vote_batch;
+    use std::time::Duration;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    let mock = MockMoltbook::start().await;
+    let vote = |id: &str, status: u16, body: serde_json::Value, calls: u64| {
+        Mock::given(method("POST"))
+            .and(path(format!("/posts/{}/upvote", id)))
+            .respond_with(ResponseTemplate::new(status).set_body_json(body))
+            .expect(calls)
+    };
+    vote("p1", 200, json!({ "success": true }), 1)
+        .mount(mock.server())
+        .await;
+    vote("p2", 404, json!({ "error": "Post not found" }), 1)
+        .mount(mock.server())
+        .await;
+    vote("p3", 429, json!({ "retry_after_minutes": 30 }), 1)
+        .mount(mock.server())
+        .await;
+    vote("p4", 200, json!({ "success": true }), 0)
+        .mount(mock.server())
+        .await;
+
+    let file = tempfile::NamedTempFile::new().unwrap();
+    std::fs::write(file.path(), "p1\np2 # gone?\np3\np4\n").unwrap();
+    vote_batch(&mock.client(), file.path(), true, Duration::ZERO)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_max_concurrency_serializes_requests() {
+    use std::time::{Duration, Instant};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    let mock = MockMoltbook::start().await;
+    Mock::given(method("GET"))
+        .and(path("/slow"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "success": true }))
+                .set_delay(Duration::from_millis(200)),
+        )
+        .mount(mock.server())
+        .await;
+    let client = mock.client().with_max_concurrency(1);
+
+    let started = Instant::now();
+    let (a, b) = tokio::join!(
+        client.get::<serde_json::Value>("/slow"),
+        client.get::<serde_json::Value>("/slow")
+    );
+    assert!(a.is_ok() && b.is_ok());
+    assert!(started.elapsed() >= Duration::from_millis(400));
+}
+
+#[tokio::test]
+async fn test_deleted_posts_are_hidden_unless_requested() {
+    use moltbook_cli::cli::post::{ListingOptions, display_post_list, global_feed};
+    use moltbook_cli::subscriptions::SubscriptionStore;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    let posts: Vec<Post> = serde_json::from_value(json!([
+        { "id": "p1", "title": "Kept", "author": { "name": "a" } },
+        { "id": "p2", "title": "Gone", "author": { "name": "b" }, "is_deleted": true },
+        { "id": "p3", "title": "Spam", "author": { "name": "c" }, "is_removed": true }
+    ]))
+    .unwrap();
+    let subscriptions = SubscriptionStore::default();
+    let hidden = display_post_list(&posts, &ListingOptions::default(), &subscriptions, 0);
+    assert_eq!(hidden, ["p1"]);
+    let options = ListingOptions {
+        include_deleted: true,
+        ..Default::default()
+    };
+    let shown = display_post_list(&posts, &options, &subscriptions, 0);
+    assert_eq!(shown, ["p1", "p2", "p3"]);
+
+    let mock = MockMoltbook::start().await;
+    Mock::given(method("GET"))
+        .and(path("/posts"))
+        .and(query_param("include_deleted", "true"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "success": true, "posts": [] })),
+        )
+        .expect(1)
+        .mount(mock.server())
+        .await;
+    global_feed(&mock.client(), "new", 5, None, options)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_bridge_publishes_to_mastodon() {
+    use moltbook_cli::bridge::Platform;
+    use moltbook_cli::cli::bridge::bridge;
+    use moltbook_cli::config::Config;
+    use wiremock::matchers::{body_string_contains, header, method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    let mock = MockMoltbook::start().await;
+    mock.mount_feed().await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/statuses"))
+        .and(header("Authorization", "Bearer masto-token"))
+        .and(body_string_contains("https://www.moltbook.com/post/post_1"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "url": "https://m.example/@bot/1" })),
+        )
+        .expect(1)
+        .mount(mock.server())
+        .await;
+
+    let mut config: Config =
+        serde_json::from_value(json!({ "api_key": "k", "agent_name": "bot" })).unwrap();
+    config.bridges.mastodon_instance = Some(mock.server().uri());
+    config.bridges.mastodon_token = Some("masto-token".to_string());
+    bridge(
+        &mock.client(),
+        &config,
+        Platform::Mastodon,
+        "post_1",
+        false,
+        true,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_graph_export_walks_two_hops() {
+    use moltbook_cli::cli::graph::export;
+    use moltbook_cli::graph::GraphFormat;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    let mock = MockMoltbook::start().await;
+    let list = |key: &str, names: &[&str]| {
+        let agents: Vec<_> = names.iter().map(|n| json!({ "name": n })).collect();
+        ResponseTemplate::new(200).set_body_json(json!({ key: agents, "has_more": false }))
+    };
+    let me = json!({ "agent": { "id": "1", "name": "bot" } });
+    let private = json!({ "error": "private" });
+    let routes = [
+        ("/agents/me", ResponseTemplate::new(200).set_body_json(me)),
+        ("/agents/bot/followers", list("followers", &["ann"])),
+        ("/agents/bot/following", list("following", &["ann", "cy"])),
+        ("/agents/ann/followers", list("followers", &["bot"])),
+        ("/agents/ann/following", list("following", &[])),
+        (
+            "/agents/cy/followers",
+            ResponseTemplate::new(404).set_body_json(private),
+        ),
+        ("/agents/cy/following", list("following", &["bot"])),
+    ];
+    for (route, response) in routes {
+        Mock::given(method("GET"))
+            .and(path(route))
+            .respond_with(response)
+            .expect(1)
+            .mount(mock.server())
+            .await;
+    }
+
+    let file = tempfile::NamedTempFile::new().unwrap();
+    export(&mock.client(), GraphFormat::Dot, 2, 100, Some(file.path()))
+        .await
+        .unwrap();
+    let dot = std::fs::read_to_string(file.path()).unwrap();
+    assert!(dot.contains("\"ann\" -> \"bot\";"));
+    assert!(dot.contains("\"bot\" -> \"ann\";"));
+    assert!(dot.contains("\"bot\" -> \"cy\";"));
+    assert!(dot.contains("\"cy\" -> \"bot\";"));
+    assert_eq!(dot.matches(" -> ").count(), 4);
+}
+
+#[tokio::test]
+async fn test_follow_list_reports_truncation() {
+    use moltbook_cli::cli::graph::follow_list;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    let mock = MockMoltbook::start().await;
+    Mock::given(method("GET"))
+        .and(path("/agents/bot/followers"))
+        .and(query_param("cursor", "c2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "followers": [{ "name": "cy" }],
+            "has_more": false
+        })))
+        .with_priority(1)
+        .mount(mock.server())
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/agents/bot/followers"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "followers": [{ "name": "ann" }, { "name": "bo" }],
+            "has_more": true,
+            "next_cursor": "c2"
+        })))
+        .mount(mock.server())
+        .await;
+
+    let client = mock.client();
+    let (agents, truncated) = follow_list(&client, "bot", true, 3).await.unwrap();
+    assert_eq!((agents.len(), truncated), (3, false));
+    // The limit falls on a page boundary with more pages to come.
+    let (agents, truncated) = follow_list(&client, "bot", true, 2).await.unwrap();
+    assert_eq!((agents.len(), truncated), (2, true));
+    let (agents, truncated) = follow_list(&client, "bot", true, 1).await.unwrap();
+    assert_eq!((agents.len(), truncated), (1, true));
+}
+
+#[tokio::test]
+async fn test_compare_submolts_samples_each_submolt() {
+    use moltbook_cli::cli::submolt::compare;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    let mock = MockMoltbook::start().await;
+    for name in ["tech", "rust"] {
+        let info = json!({ "submolt": { "name": name, "subscriber_count": 12 } });
+        let post = json!({
+            "id": "p1",
+            "title": "Hello",
+            "upvotes": 3,
+            "downvotes": 0,
+            "created_at": "2025-06-10T00:00:00Z",
+            "author": { "name": "ann" },
+        });
+        Mock::given(method("GET"))
+            .and(path(format!("/submolts/{}", name)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(info))
+            .expect(1)
+            .mount(mock.server())
+            .await;
+        Mock::given(method("GET"))
+            .and(path(format!("/submolts/{}/feed", name)))
+            .and(query_param("sort", "new"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "posts": [post] })))
+            .expect(1)
+            .mount(mock.server())
+            .await;
+    }
+
+    let names = ["tech".to_string(), "rust".to_string()];
+    compare(&mock.client(), &names, 25).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_submolt_settings_dry_run_does_not_patch() {
+    use moltbook_cli::cli::submolt::{SettingsUpdate, update_settings};
+    use moltbook_cli::color::parse_hex_color;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    let mock = MockMoltbook::start().await;
+    let info = json!({
+        "submolt": { "name": "tech", "description": "Old rules", "theme_color": "#fff" },
+        "your_role": "owner"
+    });
+    Mock::given(method("GET"))
+        .and(path("/submolts/tech"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(info))
+        .expect(1)
+        .mount(mock.server())
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/submolts/tech/settings"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": true })))
+        .expect(0)
+        .mount(mock.server())
+        .await;
+
+    let update = SettingsUpdate {
+        description: Some("New rules".to_string()),
+        banner_color: None,
+        theme_color: parse_hex_color("#FFFFFF").ok(),
+    };
+    update_settings(&mock.client(), "tech", update, false, true, false)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_pin_requires_moderator_role() {
+    use moltbook_cli::api::error::ApiError;
+    use moltbook_cli::cli::submolt::pin_post;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    let mock = MockMoltbook::start().await;
+    let post = json!({
+        "post": {
+            "id": "p1",
+            "title": "Hi",
+            "author": { "name": "ann" },
+            "submolt": { "name": "tech" },
+        }
+    });
+    let info = json!({ "submolt": { "name": "tech" }, "your_role": "member" });
+    Mock::given(method("GET"))
+        .and(path("/posts/p1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(post))
+        .mount(mock.server())
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/submolts/tech"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(info))
+        .mount(mock.server())
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/posts/p1/pin"))
+        .respond_with(ResponseTemplate::new(403))
+        .expect(0)
+        .mount(mock.server())
+        .await;
+
+    let error = pin_post(&mock.client(), "p1").await.unwrap_err();
+    assert!(matches!(error, ApiError::NotModerator(ref name) if name == "tech"));
+    assert_eq!(error.to_string(), "You are not a moderator of m/tech");
+}
+
+#[tokio::test]
+async fn test_capabilities_reports_moderated_submolts() {
+    use moltbook_cli::cli::account::capabilities;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    let mock = MockMoltbook::start().await;
+    let me = json!({
+        "agent": {
+            "id": "1",
+            "name": "bot",
+            "karma": 42,
+            "is_claimed": true,
+            "created_at": "2025-01-01T00:00:00Z",
+        }
+    });
+    let home = json!({
+        "check_in": { "briefings": [{ "your_role": "moderator", "submolt_name": "rust" }] }
+    });
+    let routes = [
+        ("/agents/status", json!({ "status": "claimed" })),
+        ("/agents/me", me),
+        ("/home", home),
+        (
+            "/submolts/tech",
+            json!({ "submolt": { "name": "tech" }, "your_role": null }),
+        ),
+    ];
+    for (route, body) in routes {
+        Mock::given(method("GET"))
+            .and(path(route))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .expect(1)
+            .mount(mock.server())
+            .await;
+    }
+
+    capabilities(&mock.client(), &["tech".to_string()], true)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_resend_owner_email() {
+    use moltbook_cli::cli::account::resend_owner_email;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    let mock = MockMoltbook::start().await;
+    Mock::given(method("POST"))
+        .and(path("/agents/me/setup-owner-email/resend"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "success": true, "email": "owner@example.com" })),
+        )
+        .expect(1)
+        .mount(mock.server())
+        .await;
+
+    resend_owner_email(&mock.client()).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_search_jsonl_follows_all_pages() {
+    use moltbook_cli::cli::post::{SearchOptions, search};
+    use moltbook_cli::display::OutputFormat;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    let mock = MockMoltbook::start().await;
+    let result = |id: &str| {
+        json!({
+            "id": id,
+            "type": "post",
+            "title": "Crabs",
+            "upvotes": 3,
+            "downvotes": 0,
+            "author": { "name": "CrabBot" }
+        })
+    };
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("cursor", "c2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [result("p2"), result("p3")],
+            "has_more": false
+        })))
+        .expect(1)
+        .mount(mock.server())
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [result("p1"), result("p2")],
+            "has_more": true,
+            "next_cursor": "c2"
+        })))
+        .expect(1)
+        .mount(mock.server())
+        .await;
+
+    let options = SearchOptions {
+        all: true,
+        format: OutputFormat::Jsonl,
+        ..Default::default()
+    };
+    search(&mock.client(), "crabs", "all", 20, None, options)
+        .await
+        .unwrap();
+}